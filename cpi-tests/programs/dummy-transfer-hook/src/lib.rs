@@ -0,0 +1,188 @@
+#![allow(unexpected_cfgs)]
+
+//! Minimal transfer hook program, so transfers of hooked mints can be tested end-to-end.
+//!
+//! `InitializeExtraAccountMetaList` stores the extra account metas of a mint in its
+//! validation account, which the client funds beforehand. `Execute` checks that the
+//! accounts following the validation account are the resolved extra accounts, in order,
+//! and logs the amount.
+
+use {
+    core::mem::MaybeUninit,
+    pinocchio::{
+        account_info::AccountInfo,
+        entrypoint,
+        instruction::Signer,
+        log::sol_log,
+        program_error::ProgramError,
+        pubkey::{try_find_program_address, Pubkey},
+        seeds, ProgramResult,
+    },
+    pinocchio_pubkey::declare_id,
+    pinocchio_system::instructions::{Allocate, Assign},
+    pinocchio_token_2022::{
+        pod::read_pod_slice,
+        state::Mint,
+        transfer_hook_interface::{
+            ExtraAccountMeta, ExtraAccountMetaList, EXECUTE_DISCRIMINATOR,
+            EXTRA_ACCOUNT_METAS_SEED, INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR,
+            MAX_ADDITIONAL_ACCOUNTS,
+        },
+    },
+};
+
+entrypoint!(process_instruction);
+declare_id!("WMb9DD9rPbxJrTHRtnEWouieAnqMgLPUH7XhuDkL7jJ");
+
+/// Logged by `Execute` followed by the amount, for the tests to tell the hook ran
+pub const EXECUTE_LOG_PREFIX: &str = "Transfer hook executed: ";
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    match instruction_data.split_first_chunk::<8>() {
+        Some((&EXECUTE_DISCRIMINATOR, data)) => {
+            execute(program_id, accounts, instruction_data, data)
+        }
+        Some((&INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR, data)) => {
+            initialize_extra_account_meta_list(program_id, accounts, data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Return the bump of the validation account of `mint`, failing if `extra_account_metas`
+/// isn't that account
+fn validation_account_bump(
+    program_id: &Pubkey,
+    extra_account_metas: &AccountInfo,
+    mint: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    match try_find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, mint.key()], program_id) {
+        Some((address, bump)) if extra_account_metas.key() == &address => Ok(bump),
+        _ => Err(ProgramError::InvalidSeeds),
+    }
+}
+
+/// Instruction data: `[number of metas (u32), metas..]`
+fn initialize_extra_account_meta_list(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // The accounts should be:
+    // [extra_account_metas, mint, authority, system_program]
+
+    let [extra_account_metas, mint, authority, _system_program, ..] = accounts else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    if !authority.is_signer() {
+        Err(ProgramError::MissingRequiredSignature)?;
+    }
+
+    if Mint::from_account_info(mint)?.mint_authority() != Some(authority.key()) {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    let bump = [validation_account_bump(
+        program_id,
+        extra_account_metas,
+        mint,
+    )?];
+    let seeds = seeds!(EXTRA_ACCOUNT_METAS_SEED, mint.key(), &bump);
+    let signers = [Signer::from(&seeds)];
+
+    let (metas, _) = read_pod_slice::<ExtraAccountMeta>(data)?;
+
+    if metas.len() > MAX_ADDITIONAL_ACCOUNTS {
+        Err(ProgramError::InvalidInstructionData)?;
+    }
+
+    // the validation account is funded by the client, it's only allocated and assigned here
+    Allocate {
+        account: extra_account_metas,
+        space: ExtraAccountMetaList::size_of(metas.len()) as u64,
+    }
+    .invoke_signed(&signers)?;
+
+    Assign {
+        account: extra_account_metas,
+        owner: program_id,
+    }
+    .invoke_signed(&signers)?;
+
+    ExtraAccountMetaList::write(&mut extra_account_metas.try_borrow_mut_data()?, metas)
+}
+
+/// Instruction data: `[amount (u64)]`
+fn execute(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+    data: &[u8],
+) -> ProgramResult {
+    // The accounts should be:
+    // [source, mint, destination, owner, extra_account_metas, ..extra_accounts]
+
+    let [source, mint, destination, owner, extra_account_metas, extra_accounts @ ..] = accounts
+    else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    let Some(amount) = data.first_chunk::<8>() else {
+        Err(ProgramError::InvalidInstructionData)?
+    };
+
+    validation_account_bump(program_id, extra_account_metas, mint)?;
+
+    if !extra_account_metas.is_owned_by(program_id) {
+        Err(ProgramError::InvalidAccountOwner)?;
+    }
+
+    let validation_data = extra_account_metas.try_borrow_data()?;
+    let list = ExtraAccountMetaList::from_bytes(&validation_data)?;
+
+    const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::<&AccountInfo>::uninit();
+    let mut resolved = [UNINIT_INFO; MAX_ADDITIONAL_ACCOUNTS];
+
+    let len = list.resolve(
+        instruction_data,
+        program_id,
+        &[source, mint, destination, owner, extra_account_metas],
+        accounts,
+        &mut resolved,
+    )?;
+
+    if extra_accounts.len() < len {
+        Err(ProgramError::NotEnoughAccountKeys)?;
+    }
+
+    // Token-2022 passes the extra accounts in the order of the metas, with their flags
+    for ((meta, resolved), account) in list
+        .metas()
+        .iter()
+        .zip(&resolved[..len])
+        .zip(extra_accounts)
+    {
+        // SAFETY: the first `len` accounts are written by `resolve`
+        let resolved = unsafe { resolved.assume_init() };
+
+        if account.key() != resolved.key()
+            || (meta.is_signer() && !account.is_signer())
+            || (meta.is_writable() && !account.is_writable())
+        {
+            Err(ProgramError::InvalidAccountData)?;
+        }
+    }
+
+    sol_log(&format!(
+        "{}{}",
+        EXECUTE_LOG_PREFIX,
+        u64::from_le_bytes(*amount)
+    ));
+
+    Ok(())
+}
@@ -1,11 +1,23 @@
 use {
-    pinocchio::{log::sol_log, program_error::ProgramError, pubkey::Pubkey},
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        log::sol_log,
+        program_error::ProgramError,
+        pubkey::{try_find_program_address, Pubkey},
+        seeds, ProgramResult,
+    },
     solana_program_option::COption,
     spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_group_interface::instruction::TokenGroupInstruction,
+    spl_token_metadata_interface::instruction::TokenMetadataInstruction,
 };
 
 pub type Result<T> = core::result::Result<T, ProgramError>;
 
+/// Seed of the proxy PDA which can be used as mint, freeze, fee or pause authority
+pub const AUTHORITY_SEED: &[u8] = b"authority";
+
 pub fn from_c_option<T>(data: COption<T>) -> Option<T> {
     if data.is_some() {
         Some(data.unwrap())
@@ -18,6 +30,99 @@ pub fn from_optional_non_zero_pubkey(address: OptionalNonZeroPubkey) -> Option<P
     Option::<solana_address::Address>::from(address).map(|x| x.to_bytes())
 }
 
+/// Split proxy accounts laid out as `[...core_accounts, ...signers, token_program]`.
+///
+/// Returns the first `N` accounts, everything between them and the last account (multisig
+/// signers, empty for a single authority) and the token program, which is always last.
+/// Fails with `NotEnoughAccountKeys` if there are fewer than `N + 1` accounts.
+pub fn split_accounts<const N: usize>(
+    accounts: &[AccountInfo],
+) -> Result<(&[AccountInfo; N], &[AccountInfo], &AccountInfo)> {
+    let [accounts @ .., token_program] = accounts else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    if accounts.len() < N {
+        Err(ProgramError::NotEnoughAccountKeys)?;
+    }
+
+    let (core_accounts, signers) = accounts.split_at(N);
+    let core_accounts = core_accounts
+        .try_into()
+        .map_err(|_| ProgramError::NotEnoughAccountKeys)?;
+
+    Ok((core_accounts, signers, token_program))
+}
+
+/// Call `invoke_signed` with the signer seeds of the proxy PDA if `authority` is the PDA,
+/// or with no signer seeds if the authority signed the transaction itself.
+///
+/// The PDA is derived from the id the proxy is deployed under, which isn't `crate::ID`
+/// when the tests deploy it under a random id.
+pub fn invoke_as_authority(
+    program_id: &Pubkey,
+    authority: &AccountInfo,
+    invoke_signed: impl FnOnce(&[Signer]) -> ProgramResult,
+) -> ProgramResult {
+    match try_find_program_address(&[AUTHORITY_SEED], program_id) {
+        Some((address, bump)) if authority.key() == &address => {
+            let bump = [bump];
+            let seeds = seeds!(AUTHORITY_SEED, &bump);
+
+            invoke_signed(&[Signer::from(&seeds)])
+        }
+        _ => invoke_signed(&[]),
+    }
+}
+
+/// Log the handler that failed along with the error it returned, so a failed test shows e.g.
+/// `❌ MemoTransferExtension (instruction 1): InvalidInstructionData` rather than the bare error
+pub fn log_error_context(instruction_data: &[u8], error: &ProgramError) {
+    // follow the same order as `process_instruction` to tell instructions apart
+    let handler = if spl_token_2022_interface::instruction::TokenInstruction::unpack(
+        instruction_data,
+    )
+    .is_ok()
+    {
+        match pinocchio_token_2022::instruction::TokenInstruction::unpack(instruction_data) {
+            Ok(instruction) => match instruction.extension_instruction() {
+                Ok((extension_instruction, _)) => format!(
+                    "{:?} (instruction {})",
+                    instruction.discriminator, extension_instruction
+                ),
+                Err(_) => format!("{:?}", instruction.discriminator),
+            },
+            Err(_) => "TokenInstruction".to_string(),
+        }
+    } else if TokenGroupInstruction::unpack(instruction_data).is_ok() {
+        "TokenGroup".to_string()
+    } else if TokenMetadataInstruction::unpack(instruction_data).is_ok() {
+        "TokenMetadata".to_string()
+    } else if let [crate::CREATE_ASSOCIATED_TOKEN_ACCOUNT, ..] = instruction_data {
+        "CreateAssociatedTokenAccount".to_string()
+    } else if let [crate::RECOVER_NESTED_ASSOCIATED_TOKEN_ACCOUNT] = instruction_data {
+        "RecoverNestedAssociatedTokenAccount".to_string()
+    } else if let [crate::TRANSFER_CHECKED_WITH_MEMO, ..] = instruction_data {
+        "TransferCheckedWithMemo".to_string()
+    } else if let [crate::TRANSFER_CHECKED_WITH_HOOK, ..] = instruction_data {
+        "TransferCheckedWithHook".to_string()
+    } else if let [crate::INITIALIZE_METADATA_WITH_POINTER, ..] = instruction_data {
+        "InitializeMetadataWithPointer".to_string()
+    } else if let [crate::CREATE_MINT_WITH_EXTENSIONS, ..] = instruction_data {
+        "CreateMintWithExtensions".to_string()
+    } else if let [crate::INITIALIZE_ACCOUNT_WITH_EXTENSIONS] = instruction_data {
+        "InitializeAccountWithExtensions".to_string()
+    } else if let [crate::REALLOCATE_FOR_MINT] = instruction_data {
+        "ReallocateForMint".to_string()
+    } else if let [crate::INITIALIZE_ACCOUNT_THAWED] = instruction_data {
+        "InitializeAccountThawed".to_string()
+    } else {
+        "Unknown instruction".to_string()
+    };
+
+    sol_log(&format!("❌ {}: {:?}", handler, error));
+}
+
 pub fn show<T: core::fmt::Debug>(label: &str, data: T) {
     sol_log(&format!("✅ {}: {:?}", label, data));
 }
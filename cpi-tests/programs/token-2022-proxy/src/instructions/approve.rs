@@ -0,0 +1,36 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn approve(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let [source, delegate, authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::instructions::Approve {
+        source,
+        delegate,
+        authority,
+        amount,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
+
+pub fn approve_checked(accounts: &[AccountInfo], amount: u64, decimals: u8) -> ProgramResult {
+    let [source, mint, delegate, authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::instructions::ApproveChecked {
+        source,
+        mint,
+        delegate,
+        authority,
+        amount,
+        decimals,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
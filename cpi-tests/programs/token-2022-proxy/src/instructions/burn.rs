@@ -0,0 +1,35 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn burn(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let [account, mint, authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::instructions::Burn {
+        account,
+        mint,
+        authority,
+        amount,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
+
+pub fn burn_checked(accounts: &[AccountInfo], amount: u64, decimals: u8) -> ProgramResult {
+    let [account, mint, authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::instructions::BurnChecked {
+        account,
+        mint,
+        authority,
+        amount,
+        decimals,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
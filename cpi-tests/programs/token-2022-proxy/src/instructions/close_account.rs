@@ -0,0 +1,18 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn close_account(accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, destination, authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::instructions::CloseAccount {
+        account,
+        destination,
+        authority,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
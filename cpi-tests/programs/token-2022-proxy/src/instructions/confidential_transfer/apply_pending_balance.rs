@@ -0,0 +1,30 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::confidential::PodAeCiphertext},
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn apply_pending_balance(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // The accounts should be: [token_account, authority, ...signers, token_program]
+
+    let ([token_account, authority], signers, token_program) = split_accounts(accounts)?;
+
+    let &spl_token_2022_interface::extension::confidential_transfer::instruction::ApplyPendingBalanceData {
+        expected_pending_balance_credit_counter,
+        new_decryptable_available_balance,
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    pinocchio_token_2022::extension::confidential_transfer::ApplyPendingBalance {
+        token_account,
+        authority,
+        signers,
+        expected_pending_balance_credit_counter: expected_pending_balance_credit_counter.into(),
+        new_decryptable_available_balance: &PodAeCiphertext::new(bytemuck::cast(
+            new_decryptable_available_balance,
+        )),
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
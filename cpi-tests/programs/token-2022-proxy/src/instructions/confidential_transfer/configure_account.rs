@@ -0,0 +1,38 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::confidential::PodAeCiphertext},
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn configure_account(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // The accounts should be: [token_account, mint, instructions_sysvar_or_context_state, authority, ...signers, token_program]
+    // Proofs read from a record account are not supported by the proxy
+
+    let (
+        [token_account, mint, instructions_sysvar_or_context_state, authority],
+        signers,
+        token_program,
+    ) = split_accounts(accounts)?;
+
+    let &spl_token_2022_interface::extension::confidential_transfer::instruction::ConfigureAccountInstructionData {
+        decryptable_zero_balance,
+        maximum_pending_balance_credit_counter,
+        proof_instruction_offset,
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    pinocchio_token_2022::extension::confidential_transfer::ConfigureAccount {
+        token_account,
+        mint,
+        instructions_sysvar_or_context_state,
+        record: None,
+        authority,
+        signers,
+        decryptable_zero_balance: &PodAeCiphertext::new(bytemuck::cast(decryptable_zero_balance)),
+        maximum_pending_balance_credit_counter: maximum_pending_balance_credit_counter.into(),
+        proof_instruction_offset,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
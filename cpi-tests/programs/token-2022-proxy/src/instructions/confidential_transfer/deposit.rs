@@ -0,0 +1,29 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn deposit(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // The accounts should be: [token_account, mint, authority, ...signers, token_program]
+
+    let ([token_account, mint, authority], signers, token_program) = split_accounts(accounts)?;
+
+    let &spl_token_2022_interface::extension::confidential_transfer::instruction::DepositInstructionData {
+        amount,
+        decimals,
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    pinocchio_token_2022::extension::confidential_transfer::Deposit {
+        token_account,
+        mint,
+        authority,
+        signers,
+        amount: amount.into(),
+        decimals,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
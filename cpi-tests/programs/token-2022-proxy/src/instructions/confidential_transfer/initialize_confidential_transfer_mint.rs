@@ -0,0 +1,56 @@
+use {
+    crate::helpers::from_optional_non_zero_pubkey,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::confidential::PodElGamalPubkey},
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn initialize_confidential_transfer_mint(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let &spl_token_2022_interface::extension::confidential_transfer::instruction::InitializeMintData {
+        authority,
+        auto_approve_new_accounts,
+        auditor_elgamal_pubkey,
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let authority = from_optional_non_zero_pubkey(authority);
+    let auto_approve_new_accounts = bool::from(auto_approve_new_accounts);
+    // the auditor pubkey is zeroed if none
+    let auditor_elgamal_pubkey = PodElGamalPubkey::new(bytemuck::cast(auditor_elgamal_pubkey));
+    let auditor_elgamal_pubkey =
+        (!auditor_elgamal_pubkey.is_zeroed()).then_some(auditor_elgamal_pubkey);
+
+    if pinocchio_token_2022::state::Mint::from_account_info(mint)?.is_initialized() {
+        let config = pinocchio_token_2022::extension::confidential_transfer::state::ConfidentialTransferMint::from_account_info(mint)?;
+
+        if config.authority() != authority.as_ref() {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        if config.auto_approve_new_accounts() != auto_approve_new_accounts {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        if config.auditor_elgamal_pubkey() != auditor_elgamal_pubkey.as_ref() {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        return Ok(());
+    }
+
+    pinocchio_token_2022::extension::confidential_transfer::InitializeConfidentialTransferMint {
+        mint,
+        authority: authority.as_ref(),
+        auto_approve_new_accounts,
+        auditor_elgamal_pubkey: auditor_elgamal_pubkey.as_ref(),
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
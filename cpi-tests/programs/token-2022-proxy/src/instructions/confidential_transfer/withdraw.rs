@@ -0,0 +1,60 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::confidential::PodAeCiphertext},
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn withdraw(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // The accounts should be: [token_account, mint, instructions_sysvar?, equality_proof_context_state?, range_proof_context_state?, authority, ...signers, token_program]
+    // Proofs read from a record account are not supported by the proxy, so the instructions
+    // sysvar is passed if a proof is in the transaction, and a context state account for
+    // each proof which isn't
+
+    let ([token_account, mint], accounts, token_program) = split_accounts(accounts)?;
+
+    let &spl_token_2022_interface::extension::confidential_transfer::instruction::WithdrawInstructionData {
+        amount,
+        decimals,
+        new_decryptable_available_balance,
+        equality_proof_instruction_offset,
+        range_proof_instruction_offset,
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let mut accounts = accounts.iter();
+    let mut next_if = |is_passed: bool| match is_passed {
+        true => accounts
+            .next()
+            .map(Some)
+            .ok_or(ProgramError::NotEnoughAccountKeys),
+        false => Ok(None),
+    };
+
+    let instructions_sysvar =
+        next_if(equality_proof_instruction_offset != 0 || range_proof_instruction_offset != 0)?;
+    let equality_proof_account = next_if(equality_proof_instruction_offset == 0)?;
+    let range_proof_account = next_if(range_proof_instruction_offset == 0)?;
+
+    let authority = accounts.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let signers = accounts.as_slice();
+
+    pinocchio_token_2022::extension::confidential_transfer::Withdraw {
+        token_account,
+        mint,
+        instructions_sysvar,
+        equality_proof_account,
+        range_proof_account,
+        authority,
+        signers,
+        amount: amount.into(),
+        decimals,
+        new_decryptable_available_balance: &PodAeCiphertext::new(bytemuck::cast(
+            new_decryptable_available_balance,
+        )),
+        equality_proof_instruction_offset,
+        range_proof_instruction_offset,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
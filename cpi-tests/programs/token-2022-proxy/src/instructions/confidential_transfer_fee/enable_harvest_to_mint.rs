@@ -0,0 +1,21 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn enable_harvest_to_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    // The accounts should be: [mint, authority, ...signers, token_program]
+    // For single authority: [mint, authority, authority (as signer), token_program]
+    // For multisig: [mint, authority, signer1, signer2, ..., token_program]
+
+    let ([mint, authority], signers, token_program) = split_accounts(accounts)?;
+
+    pinocchio_token_2022::extension::confidential_transfer_fee::EnableHarvestToMint {
+        mint,
+        authority,
+        signers,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,52 @@
+use {
+    crate::helpers::from_optional_non_zero_pubkey,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::confidential::PodElGamalPubkey},
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn initialize_confidential_transfer_fee_config(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [mint, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let &spl_token_2022_interface::extension::confidential_transfer_fee::instruction::InitializeConfidentialTransferFeeConfigData {
+        authority,
+        withdraw_withheld_authority_elgamal_pubkey,
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let authority = from_optional_non_zero_pubkey(authority);
+    let elgamal_pubkey =
+        PodElGamalPubkey::new(bytemuck::cast(withdraw_withheld_authority_elgamal_pubkey));
+
+    if pinocchio_token_2022::state::Mint::from_account_info(mint)?.is_initialized() {
+        let config = pinocchio_token_2022::extension::confidential_transfer_fee::state::ConfidentialTransferFeeConfig::from_account_info(mint)?;
+
+        if config.authority() != authority.as_ref() {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        if config.withdraw_withheld_authority_elgamal_pubkey() != &elgamal_pubkey {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        // harvesting to the mint is enabled on initialization
+        if !config.harvest_to_mint_enabled() {
+            Err(ProgramError::InvalidAccountData)?
+        }
+
+        return Ok(());
+    }
+
+    pinocchio_token_2022::extension::confidential_transfer_fee::InitializeConfidentialTransferFeeConfig {
+        mint,
+        authority: authority.as_ref(),
+        withdraw_withheld_authority_elgamal_pubkey: &elgamal_pubkey,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,50 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::confidential::PodAeCiphertext},
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn withdraw_withheld_tokens_from_accounts(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // The accounts should be: [mint, destination, instructions_sysvar_or_context_state, authority, ...signers, ...sources, token_program]
+    // The last `num_token_accounts` accounts before token_program are the sources
+    // Proofs read from a record account are not supported by the proxy
+
+    let &spl_token_2022_interface::extension::confidential_transfer_fee::instruction::WithdrawWithheldTokensFromAccountsData {
+        num_token_accounts,
+        proof_instruction_offset,
+        new_decryptable_available_balance,
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let (
+        [mint, destination, instructions_sysvar_or_context_state, authority],
+        signers_and_sources,
+        token_program,
+    ) = split_accounts(accounts)?;
+
+    let sources_start = signers_and_sources
+        .len()
+        .checked_sub(num_token_accounts as usize)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let (signers, sources) = signers_and_sources.split_at(sources_start);
+
+    pinocchio_token_2022::extension::confidential_transfer_fee::WithdrawWithheldTokensFromAccounts {
+        mint,
+        destination,
+        instructions_sysvar_or_context_state,
+        record: None,
+        authority,
+        signers,
+        sources,
+        proof_instruction_offset,
+        new_decryptable_available_balance: &PodAeCiphertext::new(bytemuck::cast(
+            new_decryptable_available_balance,
+        )),
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,41 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::confidential::PodAeCiphertext},
+    spl_token_2022_interface::instruction::decode_instruction_data,
+};
+
+pub fn withdraw_withheld_tokens_from_mint(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // The accounts should be: [mint, destination, instructions_sysvar_or_context_state, authority, ...signers, token_program]
+    // Proofs read from a record account are not supported by the proxy
+
+    let (
+        [mint, destination, instructions_sysvar_or_context_state, authority],
+        signers,
+        token_program,
+    ) = split_accounts(accounts)?;
+
+    let &spl_token_2022_interface::extension::confidential_transfer_fee::instruction::WithdrawWithheldTokensFromMintData {
+        proof_instruction_offset,
+        new_decryptable_available_balance,
+    } = decode_instruction_data(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    pinocchio_token_2022::extension::confidential_transfer_fee::WithdrawWithheldTokensFromMint {
+        mint,
+        destination,
+        instructions_sysvar_or_context_state,
+        record: None,
+        authority,
+        signers,
+        proof_instruction_offset,
+        new_decryptable_available_balance: &PodAeCiphertext::new(bytemuck::cast(
+            new_decryptable_available_balance,
+        )),
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,74 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_associated_token_account::instructions::Create,
+    pinocchio_token_2022::{
+        extension::{
+            consts::ExtensionType, memo_transfer::Enable, tlv::get_extension_data_from_bytes,
+        },
+        instructions::Reallocate,
+    },
+};
+
+/// Create the associated token account of `owner` for `mint` and set up its extensions:
+/// - `ImmutableOwner` is initialized by the ATA program, the proxy only checks it's present
+/// - `MemoTransfer` is added and enabled if `instruction_data[0]` is `1`, the owner must sign
+pub fn create_associated_token_account(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // The accounts should be:
+    // [payer, associated_token_account, owner, mint, system_program, associated_token_program, token_program]
+
+    let [payer, associated_token_account, owner, mint, system_program, _associated_token_program, token_program] =
+        accounts
+    else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    let require_memo = match instruction_data {
+        [0] => false,
+        [1] => true,
+        _ => Err(ProgramError::InvalidInstructionData)?,
+    };
+
+    Create {
+        funding_account: payer,
+        account: associated_token_account,
+        wallet: owner,
+        mint,
+        system_program,
+        token_program,
+    }
+    .invoke()?;
+
+    if get_extension_data_from_bytes(
+        ExtensionType::ImmutableOwner,
+        &associated_token_account.try_borrow_data()?,
+    )
+    .is_none()
+    {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    if require_memo {
+        Reallocate {
+            account: associated_token_account,
+            payer,
+            system_program,
+            owner,
+            extension_types: &[ExtensionType::MemoTransfer],
+            token_program: token_program.key(),
+        }
+        .invoke()?;
+
+        Enable {
+            token_account: associated_token_account,
+            authority: owner,
+            signers: &[],
+            token_program: token_program.key(),
+        }
+        .invoke()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_2022::{
+        extension::{
+            mint_close_authority::InitializeMintCloseAuthority,
+            non_transferable::InitializeNonTransferableMint,
+        },
+        instructions::{create_mint_with_extensions as create_mint, MintBuilder},
+    },
+};
+
+/// Create and initialize a non-transferable mint which `mint_authority` can close, the payer
+/// funding its rent. Instruction data: `[decimals (u8)]`
+pub fn create_mint_with_extensions(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // The accounts should be:
+    // [payer, mint, mint_authority, system_program, token_program]
+
+    let [payer, mint, mint_authority, _system_program, token_program] = accounts else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    let [decimals] = instruction_data else {
+        Err(ProgramError::InvalidInstructionData)?
+    };
+
+    let close_authority = InitializeMintCloseAuthority {
+        mint,
+        close_authority: Some(mint_authority.key()),
+        token_program: token_program.key(),
+    };
+    let non_transferable = InitializeNonTransferableMint {
+        mint,
+        token_program: token_program.key(),
+    };
+
+    create_mint(
+        payer,
+        &Rent::get()?,
+        &MintBuilder {
+            mint,
+            decimals: *decimals,
+            mint_authority: mint_authority.key(),
+            freeze_authority: None,
+            extensions: &[&close_authority, &non_transferable],
+            token_program: token_program.key(),
+        },
+        &[],
+    )?;
+
+    Ok(())
+}
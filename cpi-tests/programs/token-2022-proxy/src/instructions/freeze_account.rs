@@ -0,0 +1,24 @@
+use {
+    crate::helpers::invoke_as_authority,
+    pinocchio::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+    },
+    pinocchio_token_2022,
+};
+
+pub fn freeze_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, mint, freeze_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let instruction = pinocchio_token_2022::instructions::FreezeAccount {
+        account,
+        mint,
+        freeze_authority,
+        token_program: token_program.key(),
+    };
+
+    invoke_as_authority(program_id, freeze_authority, |signers| {
+        instruction.invoke_signed(signers)
+    })
+}
@@ -1,4 +1,5 @@
 use {
+    crate::helpers::split_accounts,
     pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
     pinocchio_token_2022,
     spl_token_2022_interface::instruction::decode_instruction_data,
@@ -9,14 +10,7 @@ pub fn update(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResul
     // For single authority: [mint, authority, authority (as signer), token_program]
     // For multisig: [mint, authority, signer1, signer2, ..., token_program]
 
-    if accounts.len() < 4 {
-        Err(ProgramError::NotEnoughAccountKeys)?;
-    }
-
-    let token_program = accounts.last().unwrap(); // token_program is always last
-    let mint = &accounts[0];
-    let authority = &accounts[1];
-    let signers = &accounts[2..accounts.len() - 1]; // everything between authority and token_program
+    let ([mint, authority], signers, token_program) = split_accounts(accounts)?;
 
     let &spl_token_2022_interface::extension::group_pointer::instruction::UpdateInstructionData {
         group_address,
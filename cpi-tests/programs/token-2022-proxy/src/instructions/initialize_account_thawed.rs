@@ -0,0 +1,25 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::extension::default_account_state::instructions::initialize_account::InitializeAccountThawed,
+};
+
+/// Initialize a token account and thaw it if the mint freezes new accounts by default and
+/// `freeze_authority` is the freeze authority of the mint. Instruction data: `[]`
+pub fn initialize_account_thawed(accounts: &[AccountInfo]) -> ProgramResult {
+    // The accounts should be:
+    // [account, mint, owner, freeze_authority, token_program]
+
+    let [account, mint, owner, freeze_authority, token_program] = accounts else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    InitializeAccountThawed {
+        account,
+        mint,
+        owner: owner.key(),
+        freeze_authority,
+        extensions: &[],
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,40 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{
+        extension::{immutable_owner::InitializeImmutableOwner, memo_transfer::Enable},
+        instructions::AccountBuilder,
+    },
+};
+
+/// Initialize a token account with an immutable owner, requiring incoming transfers to have
+/// a memo. The account must have been created with the space of both extensions and the owner
+/// must sign. Instruction data: `[]`
+pub fn initialize_account_with_extensions(accounts: &[AccountInfo]) -> ProgramResult {
+    // The accounts should be:
+    // [account, mint, owner, token_program]
+
+    let [account, mint, owner, token_program] = accounts else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    let immutable_owner = InitializeImmutableOwner {
+        token_account: account,
+        token_program: token_program.key(),
+    };
+    let memo_transfer = Enable {
+        token_account: account,
+        authority: owner,
+        signers: &[],
+        token_program: token_program.key(),
+    };
+
+    AccountBuilder {
+        account,
+        mint,
+        owner: owner.key(),
+        extensions: &[&immutable_owner],
+        enabled_extensions: &[&memo_transfer],
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
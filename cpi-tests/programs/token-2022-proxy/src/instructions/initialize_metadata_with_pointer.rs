@@ -0,0 +1,61 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_2022::extension::token_metadata::InitializeMetadataWithPointer,
+};
+
+/// Initialize a mint holding its own metadata, the payer funding the rent of the metadata.
+/// Instruction data: `[decimals (u8), name_len (u8), name.., symbol_len (u8), symbol.., uri..]`
+pub fn initialize_metadata_with_pointer(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // The accounts should be:
+    // [mint, update_authority, mint_authority, payer, system_program, token_program]
+
+    let [mint, update_authority, mint_authority, payer, _system_program, token_program] = accounts
+    else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    let [decimals, instruction_data @ ..] = instruction_data else {
+        Err(ProgramError::InvalidInstructionData)?
+    };
+    let (name, instruction_data) = split_str(instruction_data)?;
+    let (symbol, uri) = split_str(instruction_data)?;
+    let uri = core::str::from_utf8(uri).map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    InitializeMetadataWithPointer {
+        mint,
+        decimals: *decimals,
+        mint_authority,
+        freeze_authority: None,
+        update_authority,
+        name,
+        symbol,
+        uri,
+        rent_payer: Some((payer, &Rent::get()?)),
+        extensions: &[],
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
+
+/// Split a string prefixed with its length (u8) from the rest of the data
+fn split_str(data: &[u8]) -> Result<(&str, &[u8]), ProgramError> {
+    let [len, data @ ..] = data else {
+        Err(ProgramError::InvalidInstructionData)?
+    };
+    if data.len() < *len as usize {
+        Err(ProgramError::InvalidInstructionData)?;
+    }
+
+    let (value, data) = data.split_at(*len as usize);
+    let value = core::str::from_utf8(value).map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    Ok((value, data))
+}
@@ -1,4 +1,5 @@
 use {
+    crate::helpers::split_accounts,
     pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
     pinocchio_token_2022,
 };
@@ -13,14 +14,7 @@ pub fn enable(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResul
     // Multisig case:
     //   [ token_account, authority/owner , signer1, signer2, ... signer n, token_program ]
 
-    if accounts.len() < 3 {
-        Err(ProgramError::NotEnoughAccountKeys)?;
-    }
-
-    let token_account = &accounts[0];
-    let authority = &accounts[1];
-    let token_program = accounts.last().unwrap(); // dev : since token_program is always the last account
-    let signers = &accounts[2..accounts.len() - 1]; // dev : everything between authority and token_program ; note : in Single authority case: [2..2] will result in empty array
+    let ([token_account, authority], signers, token_program) = split_accounts(accounts)?;
 
     match instruction_data[0] {
         // dev: use index 0 — lib.rs already strips the first byte (extension tag) from original instruction data ; value `0` = Enable.
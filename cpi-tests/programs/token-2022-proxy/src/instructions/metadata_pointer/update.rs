@@ -1,4 +1,5 @@
 use {
+    crate::helpers::split_accounts,
     pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
     pinocchio_token_2022,
     spl_token_2022_interface::instruction::decode_instruction_data,
@@ -14,14 +15,7 @@ pub fn update(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResul
     // Multisig case:
     //   [ mint, authority, signer1, signer2, ... signer n, token_program ]
 
-    if accounts.len() < 3 {
-        Err(ProgramError::NotEnoughAccountKeys)?;
-    }
-
-    let mint = &accounts[0];
-    let authority = &accounts[1];
-    let token_program = accounts.last().unwrap(); // dev : since token_program is always the last account
-    let signers = &accounts[2..accounts.len() - 1]; // dev : everything between authority and token_program ; note : in Single authority case: [2..2] will result in empty array
+    let ([mint, authority], signers, token_program) = split_accounts(accounts)?;
 
     let &spl_token_2022_interface::extension::metadata_pointer::instruction::UpdateInstructionData {
         metadata_address,
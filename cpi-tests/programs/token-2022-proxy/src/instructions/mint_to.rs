@@ -0,0 +1,49 @@
+use {
+    crate::helpers::invoke_as_authority,
+    pinocchio::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+    },
+    pinocchio_token_2022,
+};
+
+pub fn mint_to(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let [mint, account, mint_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let instruction = pinocchio_token_2022::instructions::MintTo {
+        mint,
+        account,
+        mint_authority,
+        amount,
+        token_program: token_program.key(),
+    };
+
+    invoke_as_authority(program_id, mint_authority, |signers| {
+        instruction.invoke_signed(signers)
+    })
+}
+
+pub fn mint_to_checked(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    decimals: u8,
+) -> ProgramResult {
+    let [mint, account, mint_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let instruction = pinocchio_token_2022::instructions::MintToChecked {
+        mint,
+        account,
+        mint_authority,
+        amount,
+        decimals,
+        token_program: token_program.key(),
+    };
+
+    invoke_as_authority(program_id, mint_authority, |signers| {
+        instruction.invoke_signed(signers)
+    })
+}
@@ -1,3 +1,5 @@
+pub mod confidential_transfer;
+pub mod confidential_transfer_fee;
 pub mod cpi_guard;
 pub mod default_account_state;
 pub mod group_member_pointer;
@@ -8,12 +10,56 @@ pub mod scaled_ui_amount;
 pub mod memo_transfer;
 pub mod metadata_pointer;
 pub mod token_group;
+pub mod token_metadata;
+pub mod transfer_fee;
 pub mod transfer_hook;
 
+mod approve;
+mod burn;
+mod close_account;
+mod create_associated_token_account;
+mod create_mint_with_extensions;
+mod freeze_account;
+mod initialize_account_thawed;
+mod initialize_account_with_extensions;
+mod initialize_metadata_with_pointer;
 mod initialize_mint;
+mod initialize_mint_close_authority;
+mod initialize_non_transferable_mint;
 mod initialize_permanent_delegate;
 mod initialize_token_account;
+mod mint_to;
+mod pass_through;
+mod reallocate_for_mint;
+mod recover_nested_associated_token_account;
+mod revoke;
+mod set_authority;
+mod thaw_account;
+mod transfer_checked;
+mod transfer_checked_with_hook;
+mod transfer_checked_with_memo;
 
+pub use approve::{approve, approve_checked};
+pub use burn::{burn, burn_checked};
+pub use close_account::close_account;
+pub use create_associated_token_account::create_associated_token_account;
+pub use create_mint_with_extensions::create_mint_with_extensions;
+pub use freeze_account::freeze_account;
+pub use initialize_account_thawed::initialize_account_thawed;
+pub use initialize_account_with_extensions::initialize_account_with_extensions;
+pub use initialize_metadata_with_pointer::initialize_metadata_with_pointer;
 pub use initialize_mint::initialize_mint;
+pub use initialize_mint_close_authority::initialize_mint_close_authority;
+pub use initialize_non_transferable_mint::initialize_non_transferable_mint;
 pub use initialize_permanent_delegate::initialize_permanent_delegate;
 pub use initialize_token_account::initialize_token_account;
+pub use mint_to::{mint_to, mint_to_checked};
+pub use pass_through::{pass_through, pass_through_with_return_data};
+pub use reallocate_for_mint::reallocate_for_mint;
+pub use recover_nested_associated_token_account::recover_nested_associated_token_account;
+pub use revoke::revoke;
+pub use set_authority::set_authority;
+pub use thaw_account::thaw_account;
+pub use transfer_checked::transfer_checked;
+pub use transfer_checked_with_hook::transfer_checked_with_hook;
+pub use transfer_checked_with_memo::transfer_checked_with_memo;
@@ -0,0 +1,71 @@
+use {
+    crate::helpers::split_accounts,
+    core::{mem::MaybeUninit, slice},
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::{get_return_data, set_return_data, slice_invoke, MAX_CPI_ACCOUNTS},
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        ProgramResult,
+    },
+};
+
+/// Forward an instruction the proxy doesn't handle explicitly to Token-2022 as is
+pub fn pass_through(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // The accounts should be: [...instruction_accounts, token_program]
+
+    let ([], accounts, token_program) = split_accounts(accounts)?;
+
+    if accounts.len() > MAX_CPI_ACCOUNTS {
+        Err(ProgramError::InvalidArgument)?;
+    }
+
+    const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+    let mut acc_metas = [UNINIT_META; MAX_CPI_ACCOUNTS];
+
+    const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+    let mut acc_infos = [UNINIT_INFO; MAX_CPI_ACCOUNTS];
+
+    // keep the original account order and privileges
+    for ((account_meta, account_info), account) in acc_metas
+        .iter_mut()
+        .zip(acc_infos.iter_mut())
+        .zip(accounts.iter())
+    {
+        account_meta.write(AccountMeta::new(
+            account.key(),
+            account.is_writable(),
+            account.is_signer(),
+        ));
+        account_info.write(account);
+    }
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, accounts.len()) },
+        data: instruction_data,
+    };
+
+    slice_invoke(&instruction, unsafe {
+        slice::from_raw_parts(acc_infos.as_ptr() as _, accounts.len())
+    })
+}
+
+/// Forward a query instruction to Token-2022 as is and set its return data as the proxy
+/// return data, so the caller gets the same result as from a direct call
+pub fn pass_through_with_return_data(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    pass_through(accounts, instruction_data)?;
+
+    let ([], _, token_program) = split_accounts(accounts)?;
+
+    if let Some(return_data) = get_return_data() {
+        if return_data.program_id() == token_program.key() {
+            set_return_data(&return_data);
+        }
+    }
+
+    Ok(())
+}
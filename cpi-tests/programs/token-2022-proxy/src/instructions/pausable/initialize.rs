@@ -18,7 +18,10 @@ pub fn initialize(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramR
     let auth_bytes = authority.to_bytes();
 
     if pinocchio_token_2022::state::Mint::from_account_info(mint)?.is_initialized() {
-        let pausable_config = pinocchio_token_2022::extension::pausable::state::PausableConfig::from_account_info(mint)?;
+        let pausable_config =
+            pinocchio_token_2022::extension::pausable::state::PausableConfig::from_account_info(
+                mint,
+            )?;
 
         if pausable_config.authority() != &auth_bytes {
             Err(ProgramError::InvalidAccountData)?
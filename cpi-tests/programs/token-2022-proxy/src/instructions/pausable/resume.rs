@@ -1,34 +1,36 @@
 use {
-    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    crate::helpers::{invoke_as_authority, split_accounts},
+    pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult},
     pinocchio_token_2022::extension::pausable,
 };
 
-pub fn resume(accounts: &[AccountInfo], _instruction_data: &[u8]) -> ProgramResult {
+pub fn resume(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
     // accounts should be: [mint, authority, ...signers, token_program]
 
-    if accounts.len() < 4 {
-        Err(ProgramError::NotEnoughAccountKeys)?
-    }
-
-    let token_program = accounts.last().unwrap(); // token_program is always last
-    let mint = &accounts[0];
-    let authority = &accounts[1];
-    let signers = &accounts[2..accounts.len() - 1]; // everything between authority and token_program
+    let ([mint, authority], signers, token_program) = split_accounts(accounts)?;
 
     {
         let acc = mint.try_borrow_data()?;
-        let state = pinocchio_token_2022::extension::pausable::state::PausableConfig::from_bytes(&acc)?;
+        let state =
+            pinocchio_token_2022::extension::pausable::state::PausableConfig::from_bytes(&acc)?;
 
         if !state.is_paused() {
             return Ok(());
         }
     }
 
-    pausable::Resume {
+    let instruction = pausable::Resume {
         mint_account: mint,
         authority,
         signers,
         token_program: &token_program.key(),
-    }
-    .invoke()
+    };
+
+    invoke_as_authority(program_id, authority, |signers| {
+        instruction.invoke_signed(signers)
+    })
 }
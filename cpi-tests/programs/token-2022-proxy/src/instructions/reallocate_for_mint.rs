@@ -0,0 +1,25 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::instructions::ReallocateForMint,
+};
+
+/// Add the account extensions required by the mint and missing from the token account, the
+/// owner must sign. Instruction data: `[]`
+pub fn reallocate_for_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    // The accounts should be:
+    // [account, mint, payer, system_program, owner, token_program]
+
+    let [account, mint, payer, system_program, owner, token_program] = accounts else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    ReallocateForMint {
+        account,
+        mint,
+        payer,
+        system_program,
+        owner,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,29 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_associated_token_account::instructions::RecoverNested,
+};
+
+/// Move the tokens of the nested associated token account to the wallet's associated token
+/// account and close the nested one, the wallet must sign
+pub fn recover_nested_associated_token_account(accounts: &[AccountInfo]) -> ProgramResult {
+    // The accounts should be:
+    // [nested_associated_token_account, nested_mint, destination_associated_token_account,
+    //  owner_associated_token_account, owner_mint, wallet, token_program, associated_token_program]
+
+    let [account, mint, destination_account, owner_account, owner_mint, wallet, token_program, _associated_token_program] =
+        accounts
+    else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    RecoverNested {
+        account,
+        mint,
+        destination_account,
+        owner_account,
+        owner_mint,
+        wallet,
+        token_program,
+    }
+    .invoke()
+}
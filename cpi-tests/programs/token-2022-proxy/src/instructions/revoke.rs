@@ -0,0 +1,17 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn revoke(accounts: &[AccountInfo]) -> ProgramResult {
+    let [source, authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::instructions::Revoke {
+        source,
+        authority,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,49 @@
+use {
+    crate::helpers::from_c_option,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, instructions::AuthorityType as PinocchioAuthorityType},
+    solana_address::Address,
+    solana_program_option::COption,
+    spl_token_2022_interface::instruction::AuthorityType,
+};
+
+pub fn set_authority(
+    accounts: &[AccountInfo],
+    authority_type: AuthorityType,
+    new_authority: COption<Address>,
+) -> ProgramResult {
+    let [account, authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let authority_type = match authority_type {
+        AuthorityType::MintTokens => PinocchioAuthorityType::MintTokens,
+        AuthorityType::FreezeAccount => PinocchioAuthorityType::FreezeAccount,
+        AuthorityType::AccountOwner => PinocchioAuthorityType::AccountOwner,
+        AuthorityType::CloseAccount => PinocchioAuthorityType::CloseAccount,
+        AuthorityType::TransferFeeConfig => PinocchioAuthorityType::TransferFeeConfig,
+        AuthorityType::WithheldWithdraw => PinocchioAuthorityType::WithheldWithdraw,
+        AuthorityType::CloseMint => PinocchioAuthorityType::CloseMint,
+        AuthorityType::InterestRate => PinocchioAuthorityType::InterestRate,
+        AuthorityType::PermanentDelegate => PinocchioAuthorityType::PermanentDelegate,
+        AuthorityType::ConfidentialTransferMint => PinocchioAuthorityType::ConfidentialTransferMint,
+        AuthorityType::TransferHookProgramId => PinocchioAuthorityType::TransferHookProgramId,
+        AuthorityType::ConfidentialTransferFeeConfig => {
+            PinocchioAuthorityType::ConfidentialTransferFeeConfig
+        }
+        AuthorityType::MetadataPointer => PinocchioAuthorityType::MetadataPointer,
+        AuthorityType::GroupPointer => PinocchioAuthorityType::GroupPointer,
+        AuthorityType::GroupMemberPointer => PinocchioAuthorityType::GroupMemberPointer,
+        AuthorityType::ScaledUiAmount => PinocchioAuthorityType::ScaledUiAmount,
+        AuthorityType::Pause => PinocchioAuthorityType::Pause,
+    };
+
+    pinocchio_token_2022::instructions::SetAuthority {
+        account,
+        authority,
+        authority_type,
+        new_authority: from_c_option(new_authority).map(|x| x.to_bytes()).as_ref(),
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
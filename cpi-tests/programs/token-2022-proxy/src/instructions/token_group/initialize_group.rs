@@ -17,8 +17,12 @@ pub fn initialize_group(
     let update_authority = from_optional_non_zero_pubkey(update_authority);
     let max_size: u64 = max_size.into();
 
+    // the group is the mint itself for Token-2022, but a separate account for other programs
     if let Ok(token_group) =
-        pinocchio_token_2022::extension::token_group::state::TokenGroup::from_account_info(mint)
+        pinocchio_token_2022::extension::token_group::state::TokenGroup::from_account_info_with_program_id(
+            group,
+            token_program.key(),
+        )
     {
         if token_group.update_authority() != update_authority.as_ref() {
             Err(ProgramError::InvalidAccountData)?
@@ -11,8 +11,9 @@ pub fn initialize_member(accounts: &[AccountInfo]) -> ProgramResult {
     };
 
     if let Ok(token_group_member) =
-        pinocchio_token_2022::extension::token_group::state::TokenGroupMember::from_account_info(
+        pinocchio_token_2022::extension::token_group::state::TokenGroupMember::from_account_info_with_program_id(
             member,
+            token_program.key(),
         )
     {
         if token_group_member.group() != group.key() {
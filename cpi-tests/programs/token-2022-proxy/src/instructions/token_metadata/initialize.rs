@@ -0,0 +1,24 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+    spl_token_metadata_interface::instruction::Initialize,
+};
+
+pub fn initialize(accounts: &[AccountInfo], data: Initialize) -> ProgramResult {
+    let [metadata, update_authority, mint, mint_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    // the metadata is the mint itself for Token-2022, but a separate account for other programs
+    pinocchio_token_2022::extension::token_metadata::InitializeTokenMetadata {
+        metadata,
+        update_authority,
+        mint,
+        mint_authority,
+        name: &data.name,
+        symbol: &data.symbol,
+        uri: &data.uri,
+        program_id: &token_program.key(),
+    }
+    .invoke()
+}
@@ -0,0 +1,9 @@
+mod initialize;
+mod remove_key;
+mod update_authority;
+mod update_field;
+
+pub use initialize::*;
+pub use remove_key::*;
+pub use update_authority::*;
+pub use update_field::*;
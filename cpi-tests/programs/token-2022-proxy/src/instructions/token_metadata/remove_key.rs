@@ -0,0 +1,20 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+    spl_token_metadata_interface::instruction::RemoveKey,
+};
+
+pub fn remove_key(accounts: &[AccountInfo], data: RemoveKey) -> ProgramResult {
+    let [metadata, update_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::extension::token_metadata::RemoveTokenMetadataKey {
+        metadata,
+        update_authority,
+        idempotent: data.idempotent,
+        key: &data.key,
+        program_id: &token_program.key(),
+    }
+    .invoke()
+}
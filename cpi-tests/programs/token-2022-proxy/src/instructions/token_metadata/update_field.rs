@@ -0,0 +1,27 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{self, extension::token_metadata::Field},
+    spl_token_metadata_interface::{instruction::UpdateField, state::Field as SplField},
+};
+
+pub fn update_field(accounts: &[AccountInfo], data: UpdateField) -> ProgramResult {
+    let [metadata, update_authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    let field = match &data.field {
+        SplField::Name => Field::Name,
+        SplField::Symbol => Field::Symbol,
+        SplField::Uri => Field::Uri,
+        SplField::Key(key) => Field::Key(key),
+    };
+
+    pinocchio_token_2022::extension::token_metadata::UpdateTokenMetadataField {
+        metadata,
+        update_authority,
+        field,
+        value: &data.value,
+        program_id: &token_program.key(),
+    }
+    .invoke()
+}
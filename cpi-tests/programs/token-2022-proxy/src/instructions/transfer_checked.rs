@@ -0,0 +1,21 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn transfer_checked(accounts: &[AccountInfo], amount: u64, decimals: u8) -> ProgramResult {
+    let [from, mint, to, authority, token_program] = accounts else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    pinocchio_token_2022::instructions::TransferChecked {
+        from,
+        mint,
+        to,
+        authority,
+        amount,
+        decimals,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
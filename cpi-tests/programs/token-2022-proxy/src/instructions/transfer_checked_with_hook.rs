@@ -0,0 +1,34 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::transfer_hook_interface::TransferCheckedWithHook,
+};
+
+/// Transfer with the accounts required by the transfer hook of the mint, picked from the
+/// remaining accounts. Instruction data: `[amount (u64), decimals (u8)]`
+pub fn transfer_checked_with_hook(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // The accounts should be:
+    // [from, mint, to, authority, token_program, ..additional_accounts]
+
+    let [from, mint, to, authority, token_program, additional_accounts @ ..] = accounts else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    let Some((amount, [decimals])) = instruction_data.split_first_chunk::<8>() else {
+        Err(ProgramError::InvalidInstructionData)?
+    };
+
+    TransferCheckedWithHook {
+        from,
+        mint,
+        to,
+        authority,
+        additional_accounts,
+        amount: u64::from_le_bytes(*amount),
+        decimals: *decimals,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
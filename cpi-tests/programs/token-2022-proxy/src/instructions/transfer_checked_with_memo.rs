@@ -0,0 +1,35 @@
+use {
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022::{instructions::TransferChecked, memo::Memo},
+};
+
+/// Log the memo and transfer right after it, as required by the `MemoTransfer` extension
+/// of the destination account. Instruction data: `[amount (u64), decimals (u8), memo..]`
+pub fn transfer_checked_with_memo(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // The accounts should be:
+    // [from, mint, to, authority, token_program, memo_program]
+
+    let [from, mint, to, authority, token_program, _memo_program] = accounts else {
+        Err(ProgramError::NotEnoughAccountKeys)?
+    };
+
+    let Some((amount, [decimals, memo @ ..])) = instruction_data.split_first_chunk::<8>() else {
+        Err(ProgramError::InvalidInstructionData)?
+    };
+
+    Memo { signers: &[], memo }.invoke()?;
+
+    TransferChecked {
+        from,
+        mint,
+        to,
+        authority,
+        amount: u64::from_le_bytes(*amount),
+        decimals: *decimals,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
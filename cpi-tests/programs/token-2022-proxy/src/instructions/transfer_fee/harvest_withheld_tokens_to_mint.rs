@@ -0,0 +1,18 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn harvest_withheld_tokens_to_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    // The accounts should be: [mint, ...sources, token_program]
+
+    let ([mint], sources, token_program) = split_accounts(accounts)?;
+
+    pinocchio_token_2022::extension::transfer_fee::HarvestWithheldTokensToMint {
+        mint,
+        sources,
+        token_program: token_program.key(),
+    }
+    .invoke_chunked()
+}
@@ -0,0 +1,31 @@
+use {
+    crate::helpers::{invoke_as_authority, split_accounts},
+    pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn set_transfer_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> ProgramResult {
+    // The accounts should be: [mint, authority, ...signers, token_program]
+    // For single authority: [mint, authority, authority (as signer), token_program]
+    // For multisig: [mint, authority, signer1, signer2, ..., token_program]
+
+    let ([mint, authority], signers, token_program) = split_accounts(accounts)?;
+
+    let instruction = pinocchio_token_2022::extension::transfer_fee::SetTransferFee {
+        mint,
+        authority,
+        signers,
+        transfer_fee_basis_points,
+        maximum_fee,
+        token_program: token_program.key(),
+    };
+
+    invoke_as_authority(program_id, authority, |signers| {
+        instruction.invoke_signed(signers)
+    })
+}
@@ -0,0 +1,30 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn transfer_checked_with_fee(
+    accounts: &[AccountInfo],
+    amount: u64,
+    decimals: u8,
+    fee: u64,
+) -> ProgramResult {
+    // The accounts should be: [source, mint, destination, authority, ...signers, token_program]
+
+    let ([source, mint, destination, authority], signers, token_program) =
+        split_accounts(accounts)?;
+
+    pinocchio_token_2022::extension::transfer_fee::TransferCheckedWithFee {
+        source,
+        mint,
+        destination,
+        authority,
+        signers,
+        amount,
+        decimals,
+        fee,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
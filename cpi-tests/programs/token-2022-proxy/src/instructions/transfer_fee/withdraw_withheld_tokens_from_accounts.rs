@@ -0,0 +1,32 @@
+use {
+    crate::helpers::split_accounts,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn withdraw_withheld_tokens_from_accounts(
+    accounts: &[AccountInfo],
+    num_token_accounts: u8,
+) -> ProgramResult {
+    // The accounts should be: [mint, destination, authority, ...signers, ...sources, token_program]
+    // The last `num_token_accounts` accounts before token_program are the sources
+
+    let ([mint, destination, authority], signers_and_sources, token_program) =
+        split_accounts(accounts)?;
+
+    let sources_start = signers_and_sources
+        .len()
+        .checked_sub(num_token_accounts as usize)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let (signers, sources) = signers_and_sources.split_at(sources_start);
+
+    pinocchio_token_2022::extension::transfer_fee::WithdrawWithheldTokensFromAccounts {
+        mint,
+        destination,
+        authority,
+        signers,
+        sources,
+        token_program: token_program.key(),
+    }
+    .invoke_chunked()
+}
@@ -0,0 +1,27 @@
+use {
+    crate::helpers::{invoke_as_authority, split_accounts},
+    pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult},
+    pinocchio_token_2022,
+};
+
+pub fn withdraw_withheld_tokens_from_mint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    // The accounts should be: [mint, destination, authority, ...signers, token_program]
+
+    let ([mint, destination, authority], signers, token_program) = split_accounts(accounts)?;
+
+    let instruction =
+        pinocchio_token_2022::extension::transfer_fee::WithdrawWithheldTokensFromMint {
+            mint,
+            destination,
+            authority,
+            signers,
+            token_program: token_program.key(),
+        };
+
+    invoke_as_authority(program_id, authority, |signers| {
+        instruction.invoke_signed(signers)
+    })
+}
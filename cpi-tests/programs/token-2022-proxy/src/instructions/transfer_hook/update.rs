@@ -1,4 +1,5 @@
 use {
+    crate::helpers::split_accounts,
     pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
     pinocchio_token_2022::extension::transfer_hook,
     spl_token_2022_interface::instruction::decode_instruction_data,
@@ -7,14 +8,7 @@ use {
 pub fn update(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
     // accounts should be: [mint, authority, ...signers, token_program]
 
-    if accounts.len() < 4 {
-        Err(ProgramError::NotEnoughAccountKeys)?
-    }
-
-    let token_program = accounts.last().unwrap(); // token_program is always last
-    let mint = &accounts[0];
-    let authority = &accounts[1];
-    let signers = &accounts[2..accounts.len() - 1]; // everything between authority and token_program
+    let ([mint, authority], signers, token_program) = split_accounts(accounts)?;
 
     let &spl_token_2022_interface::extension::transfer_hook::instruction::UpdateInstructionData {
         program_id,
@@ -32,4 +26,3 @@ pub fn update(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResul
     }
     .invoke()
 }
-
@@ -9,6 +9,8 @@ use {
     pinocchio_pubkey::declare_id,
     spl_token_2022_interface::{
         extension::{
+            confidential_transfer::instruction::ConfidentialTransferInstruction,
+            confidential_transfer_fee::instruction::ConfidentialTransferFeeInstruction,
             cpi_guard::instruction::CpiGuardInstruction,
             default_account_state::instruction::DefaultAccountStateInstruction,
             group_member_pointer::instruction::GroupMemberPointerInstruction,
@@ -16,6 +18,7 @@ use {
             interest_bearing_mint::instruction::InterestBearingMintInstruction,
             pausable::instruction::PausableInstruction,
             scaled_ui_amount::instruction::ScaledUiAmountMintInstruction,
+            transfer_fee::instruction::TransferFeeInstruction,
             transfer_hook::instruction::TransferHookInstruction,
             memo_transfer::instruction::RequiredMemoTransfersInstruction,
             metadata_pointer::instruction::MetadataPointerInstruction,
@@ -26,6 +29,7 @@ use {
         InitializeGroup, InitializeMember, TokenGroupInstruction, UpdateGroupAuthority,
         UpdateGroupMaxSize,
     },
+    spl_token_metadata_interface::instruction::{TokenMetadataInstruction, UpdateAuthority},
 };
 
 pub mod helpers;
@@ -36,8 +40,54 @@ use instructions as i;
 entrypoint!(process_instruction);
 declare_id!("4ibrEMW5F6hKnkW4jVedswYv6H6VtwPN6ar6dvXDN1nT");
 
+/// Discriminator of the proxy's own instruction creating an associated token account with
+/// its extensions set up. Instruction data: `[255, require_memo (0 or 1)]`
+pub const CREATE_ASSOCIATED_TOKEN_ACCOUNT: u8 = 255;
+
+/// Discriminator of the proxy's own instruction recovering the tokens of a nested associated
+/// token account. Instruction data: `[254]`
+pub const RECOVER_NESTED_ASSOCIATED_TOKEN_ACCOUNT: u8 = 254;
+
+/// Discriminator of the proxy's own instruction invoking the Memo program before
+/// `TransferChecked`. Instruction data: `[253, amount (u64), decimals (u8), memo..]`
+pub const TRANSFER_CHECKED_WITH_MEMO: u8 = 253;
+
+/// Discriminator of the proxy's own instruction forwarding the transfer hook accounts to
+/// `TransferChecked`. Instruction data: `[252, amount (u64), decimals (u8)]`
+pub const TRANSFER_CHECKED_WITH_HOOK: u8 = 252;
+
+/// Discriminator of the proxy's own instruction initializing a mint holding its own metadata,
+/// the rent of the metadata being paid by the payer account. Instruction data:
+/// `[251, decimals (u8), name_len (u8), name.., symbol_len (u8), symbol.., uri..]`
+pub const INITIALIZE_METADATA_WITH_POINTER: u8 = 251;
+
+/// Discriminator of the proxy's own instruction creating a non-transferable mint which its
+/// mint authority can close. Instruction data: `[250, decimals (u8)]`
+pub const CREATE_MINT_WITH_EXTENSIONS: u8 = 250;
+
+/// Discriminator of the proxy's own instruction initializing a token account with an immutable
+/// owner and required memo transfers. Instruction data: `[249]`
+pub const INITIALIZE_ACCOUNT_WITH_EXTENSIONS: u8 = 249;
+
+/// Discriminator of the proxy's own instruction adding the account extensions required by the
+/// mint to a token account. Instruction data: `[248]`
+pub const REALLOCATE_FOR_MINT: u8 = 248;
+
+/// Discriminator of the proxy's own instruction initializing a token account thawed by the
+/// freeze authority of a mint freezing new accounts by default. Instruction data: `[247]`
+pub const INITIALIZE_ACCOUNT_THAWED: u8 = 247;
+
 pub fn process_instruction(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    process(program_id, accounts, instruction_data)
+        .inspect_err(|error| helpers::log_error_context(instruction_data, error))
+}
+
+fn process(
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
@@ -54,6 +104,41 @@ pub fn process_instruction(
                 // For Initializing TokenAccount
                 TokenInstruction::InitializeAccount => i::initialize_token_account(accounts),
 
+                TokenInstruction::TransferChecked { amount, decimals } => {
+                    i::transfer_checked(accounts, amount, decimals)
+                }
+
+                TokenInstruction::MintTo { amount } => i::mint_to(program_id, accounts, amount),
+
+                TokenInstruction::MintToChecked { amount, decimals } => {
+                    i::mint_to_checked(program_id, accounts, amount, decimals)
+                }
+
+                TokenInstruction::Burn { amount } => i::burn(accounts, amount),
+
+                TokenInstruction::BurnChecked { amount, decimals } => {
+                    i::burn_checked(accounts, amount, decimals)
+                }
+
+                TokenInstruction::Approve { amount } => i::approve(accounts, amount),
+
+                TokenInstruction::ApproveChecked { amount, decimals } => {
+                    i::approve_checked(accounts, amount, decimals)
+                }
+
+                TokenInstruction::Revoke => i::revoke(accounts),
+
+                TokenInstruction::SetAuthority {
+                    authority_type,
+                    new_authority,
+                } => i::set_authority(accounts, authority_type, new_authority),
+
+                TokenInstruction::CloseAccount => i::close_account(accounts),
+
+                TokenInstruction::FreezeAccount => i::freeze_account(program_id, accounts),
+
+                TokenInstruction::ThawAccount => i::thaw_account(program_id, accounts),
+
                 TokenInstruction::GroupPointerExtension => {
                     let instruction_data = &instruction_data[1..]; // Remove extension discriminator
                     let ix: GroupPointerInstruction = decode_instruction_type(instruction_data)
@@ -104,6 +189,15 @@ pub fn process_instruction(
                 TokenInstruction::InitializePermanentDelegate { delegate } => {
                     initialize_permanent_delegate(accounts, delegate)
                 }
+
+                TokenInstruction::InitializeNonTransferableMint => {
+                    i::initialize_non_transferable_mint(accounts)
+                }
+
+                TokenInstruction::InitializeMintCloseAuthority { close_authority } => {
+                    i::initialize_mint_close_authority(accounts, close_authority)
+                }
+
                 TokenInstruction::CpiGuardExtension => {
                     let instruction_data = &instruction_data[1..]; // Remove extension discriminator
                     let ix: CpiGuardInstruction = decode_instruction_type(instruction_data)
@@ -159,10 +253,10 @@ pub fn process_instruction(
                             i::pausable::initialize(accounts, instruction_data)
                         }
                         PausableInstruction::Pause => {
-                            i::pausable::pause(accounts, instruction_data)
+                            i::pausable::pause(program_id, accounts, instruction_data)
                         }
                         PausableInstruction::Resume => {
-                            i::pausable::resume(accounts, instruction_data)
+                            i::pausable::resume(program_id, accounts, instruction_data)
                         }
                     }
                 }
@@ -214,7 +308,130 @@ pub fn process_instruction(
                     }
                 }
 
-                _ => Err(ProgramError::InvalidInstructionData)?,
+                TokenInstruction::ConfidentialTransferExtension => {
+                    let extension_data = &instruction_data[1..]; // Remove extension discriminator
+                    let ix: ConfidentialTransferInstruction = decode_instruction_type(extension_data)
+                        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+                    match ix {
+                        ConfidentialTransferInstruction::InitializeMint => {
+                            i::confidential_transfer::initialize_confidential_transfer_mint(
+                                accounts,
+                                extension_data,
+                            )
+                        }
+                        ConfidentialTransferInstruction::ConfigureAccount => {
+                            i::confidential_transfer::configure_account(accounts, extension_data)
+                        }
+                        ConfidentialTransferInstruction::Deposit => {
+                            i::confidential_transfer::deposit(accounts, extension_data)
+                        }
+                        ConfidentialTransferInstruction::Withdraw => {
+                            i::confidential_transfer::withdraw(accounts, extension_data)
+                        }
+                        ConfidentialTransferInstruction::ApplyPendingBalance => {
+                            i::confidential_transfer::apply_pending_balance(accounts, extension_data)
+                        }
+                        // the instructions without wrappers are forwarded as is
+                        _ => i::pass_through(accounts, instruction_data),
+                    }
+                }
+
+                TokenInstruction::ConfidentialTransferFeeExtension => {
+                    let instruction_data = &instruction_data[1..]; // Remove extension discriminator
+                    let ix: ConfidentialTransferFeeInstruction =
+                        decode_instruction_type(instruction_data)
+                            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+                    match ix {
+                        ConfidentialTransferFeeInstruction::InitializeConfidentialTransferFeeConfig => {
+                            i::confidential_transfer_fee::initialize_confidential_transfer_fee_config(
+                                accounts,
+                                instruction_data,
+                            )
+                        }
+                        ConfidentialTransferFeeInstruction::WithdrawWithheldTokensFromMint => {
+                            i::confidential_transfer_fee::withdraw_withheld_tokens_from_mint(
+                                accounts,
+                                instruction_data,
+                            )
+                        }
+                        ConfidentialTransferFeeInstruction::WithdrawWithheldTokensFromAccounts => {
+                            i::confidential_transfer_fee::withdraw_withheld_tokens_from_accounts(
+                                accounts,
+                                instruction_data,
+                            )
+                        }
+                        ConfidentialTransferFeeInstruction::HarvestWithheldTokensToMint => {
+                            i::confidential_transfer_fee::harvest_withheld_tokens_to_mint(accounts)
+                        }
+                        ConfidentialTransferFeeInstruction::EnableHarvestToMint => {
+                            i::confidential_transfer_fee::enable_harvest_to_mint(accounts)
+                        }
+                        ConfidentialTransferFeeInstruction::DisableHarvestToMint => {
+                            i::confidential_transfer_fee::disable_harvest_to_mint(accounts)
+                        }
+                    }
+                }
+
+                TokenInstruction::TransferFeeExtension => {
+                    let instruction_data = &instruction_data[1..]; // Remove extension discriminator
+                    let ix = TransferFeeInstruction::unpack(instruction_data)
+                        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+                    match ix {
+                        TransferFeeInstruction::InitializeTransferFeeConfig {
+                            transfer_fee_config_authority,
+                            withdraw_withheld_authority,
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        } => i::transfer_fee::initialize_transfer_fee_config(
+                            accounts,
+                            transfer_fee_config_authority,
+                            withdraw_withheld_authority,
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        ),
+                        TransferFeeInstruction::TransferCheckedWithFee {
+                            amount,
+                            decimals,
+                            fee,
+                        } => i::transfer_fee::transfer_checked_with_fee(
+                            accounts, amount, decimals, fee,
+                        ),
+                        TransferFeeInstruction::WithdrawWithheldTokensFromMint => {
+                            i::transfer_fee::withdraw_withheld_tokens_from_mint(program_id, accounts)
+                        }
+                        TransferFeeInstruction::WithdrawWithheldTokensFromAccounts {
+                            num_token_accounts,
+                        } => i::transfer_fee::withdraw_withheld_tokens_from_accounts(
+                            accounts,
+                            num_token_accounts,
+                        ),
+                        TransferFeeInstruction::HarvestWithheldTokensToMint => {
+                            i::transfer_fee::harvest_withheld_tokens_to_mint(accounts)
+                        }
+                        TransferFeeInstruction::SetTransferFee {
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        } => i::transfer_fee::set_transfer_fee(
+                            program_id,
+                            accounts,
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        ),
+                    }
+                }
+
+                // query instructions, the proxy returns the Token-2022 return data as its own
+                TokenInstruction::GetAccountDataSize { .. }
+                | TokenInstruction::AmountToUiAmount { .. }
+                | TokenInstruction::UiAmountToAmount { .. } => {
+                    i::pass_through_with_return_data(accounts, instruction_data)
+                }
+
+                // forward everything else to Token-2022 as is
+                _ => i::pass_through(accounts, instruction_data),
             }
         }
         Err(_) => {
@@ -235,7 +452,51 @@ pub fn process_instruction(
                         i::token_group::initialize_member(accounts)
                     }
                 },
-                _ => Err(ProgramError::InvalidInstructionData)?,
+                // try to match TokenMetadataInstruction
+                _ => match TokenMetadataInstruction::unpack(instruction_data) {
+                    Ok(TokenMetadataInstruction::Initialize(data)) => {
+                        i::token_metadata::initialize(accounts, data)
+                    }
+                    Ok(TokenMetadataInstruction::UpdateField(data)) => {
+                        i::token_metadata::update_field(accounts, data)
+                    }
+                    Ok(TokenMetadataInstruction::RemoveKey(data)) => {
+                        i::token_metadata::remove_key(accounts, data)
+                    }
+                    Ok(TokenMetadataInstruction::UpdateAuthority(UpdateAuthority {
+                        new_authority,
+                    })) => i::token_metadata::update_authority(accounts, new_authority),
+                    Ok(TokenMetadataInstruction::Emit(_)) => {
+                        i::pass_through_with_return_data(accounts, instruction_data)
+                    }
+                    _ => match instruction_data {
+                        // try to match the proxy's own instructions
+                        [CREATE_ASSOCIATED_TOKEN_ACCOUNT, instruction_data @ ..] => {
+                            i::create_associated_token_account(accounts, instruction_data)
+                        }
+                        [RECOVER_NESTED_ASSOCIATED_TOKEN_ACCOUNT] => {
+                            i::recover_nested_associated_token_account(accounts)
+                        }
+                        [TRANSFER_CHECKED_WITH_MEMO, instruction_data @ ..] => {
+                            i::transfer_checked_with_memo(accounts, instruction_data)
+                        }
+                        [TRANSFER_CHECKED_WITH_HOOK, instruction_data @ ..] => {
+                            i::transfer_checked_with_hook(accounts, instruction_data)
+                        }
+                        [INITIALIZE_METADATA_WITH_POINTER, instruction_data @ ..] => {
+                            i::initialize_metadata_with_pointer(accounts, instruction_data)
+                        }
+                        [CREATE_MINT_WITH_EXTENSIONS, instruction_data @ ..] => {
+                            i::create_mint_with_extensions(accounts, instruction_data)
+                        }
+                        [INITIALIZE_ACCOUNT_WITH_EXTENSIONS] => {
+                            i::initialize_account_with_extensions(accounts)
+                        }
+                        [REALLOCATE_FOR_MINT] => i::reallocate_for_mint(accounts),
+                        [INITIALIZE_ACCOUNT_THAWED] => i::initialize_account_thawed(accounts),
+                        _ => Err(ProgramError::InvalidInstructionData)?,
+                    },
+                },
             }
         }
     }
@@ -1,5 +1,6 @@
 # 1. build and test with "show-output":     ./test.sh s
-# 2. build and test:                        ./test.sh
+# 2. build and test with mollusk harness:  ./test.sh m
+# 3. build and test:                        ./test.sh
 
 (
     clear
@@ -12,8 +13,16 @@
     done
 )
 
+# instructions sent to the proxy are recorded for the coverage report
+rm -f ../target/proxy-coverage.log
+
 if [[ "$1" == "s" ]]; then
     (cd tests && clear && cargo test -- --show-output)
+elif [[ "$1" == "m" ]]; then
+    (cd tests && clear && cargo test --features mollusk)
 else
     (cd tests && clear && cargo test)
 fi
+
+# report the Token-2022 instructions exercised via the proxy by the run above
+(cd tests && cargo test proxy_coverage_report -- --ignored --show-output)
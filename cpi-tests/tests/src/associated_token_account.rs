@@ -0,0 +1,244 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            associated_token_account::Token2022AssociatedTokenAccountExtension,
+            immutable_owner::Token2022ImmutableOwnerExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            mint_factory::{ExtensionSpec, Token2022MintFactoryExtension},
+            token_instructions::Token2022TokenInstructionsExtension,
+        },
+        suite::{
+            core::{
+                extension::{get_account_data, send_tx},
+                App,
+            },
+            types::{pin_to_sol_pubkey, sol_to_pin_pubkey, AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_program_pack::IsInitialized,
+    spl_associated_token_account::{
+        get_associated_token_address_with_program_id,
+        instruction::create_associated_token_account_idempotent,
+    },
+    spl_token_2022_interface::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::Account,
+    },
+};
+
+#[test]
+fn proxy_create_associated_token_account() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let alice = AppUser::Alice.pubkey();
+
+    let (_, associated_token_account) = app.token_2022_try_create_associated_token_account(
+        AppUser::Admin,
+        AppUser::Alice,
+        &mint,
+        false,
+    )?;
+
+    assert_eq!(
+        associated_token_account,
+        app.token_2022_query_associated_token_address(AppUser::Alice, &mint)
+    );
+
+    let token_account_data =
+        app.token_2022_query_token_account(Target::Spl, &associated_token_account)?;
+    assert!(token_account_data.is_initialized());
+    assert_eq!(token_account_data.mint.to_bytes(), mint);
+    assert_eq!(token_account_data.owner.to_bytes(), alice);
+
+    assert_eq!(
+        app.token_2022_query_immutable_owner(Target::Spl, &associated_token_account)?,
+        true
+    );
+    assert_eq!(
+        app.token_2022_query_require_memo(&associated_token_account)?,
+        false
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_create_associated_token_account_with_memo() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let alice = AppUser::Alice.pubkey();
+
+    let (_, associated_token_account) = app.token_2022_try_create_associated_token_account(
+        AppUser::Admin,
+        AppUser::Alice,
+        &mint,
+        true,
+    )?;
+
+    let token_account_data =
+        app.token_2022_query_token_account(Target::Spl, &associated_token_account)?;
+    assert!(token_account_data.is_initialized());
+    assert_eq!(token_account_data.owner.to_bytes(), alice);
+
+    assert_eq!(
+        app.token_2022_query_immutable_owner(Target::Spl, &associated_token_account)?,
+        true
+    );
+    assert_eq!(
+        app.token_2022_query_require_memo(&associated_token_account)?,
+        true
+    );
+
+    Ok(())
+}
+
+#[test]
+fn create_associated_token_account_idempotent_with_required_extensions() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let admin = AppUser::Admin.pubkey();
+    let mint = &app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[ExtensionSpec::TransferFeeConfig {
+            transfer_fee_config_authority: Some(admin),
+            withdraw_withheld_authority: Some(admin),
+            transfer_fee_basis_points: 100,
+            maximum_fee: 5_000,
+        }],
+        6,
+        &admin,
+        None,
+    )?;
+
+    let (_, associated_token_account) = app
+        .token_2022_try_create_associated_token_account_idempotent(
+            AppUser::Admin,
+            AppUser::Alice,
+            mint,
+        )?;
+
+    assert_eq!(
+        associated_token_account,
+        app.token_2022_query_associated_token_address(AppUser::Alice, mint)
+    );
+
+    // the account is sized for the extensions required by the mint
+    let data = get_account_data(&app, &associated_token_account)?;
+    let extension_types = StateWithExtensions::<Account>::unpack(&data)
+        .unwrap()
+        .get_extension_types()
+        .unwrap();
+    assert!(extension_types.contains(&ExtensionType::TransferFeeAmount));
+    assert!(extension_types.contains(&ExtensionType::ImmutableOwner));
+
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        &associated_token_account,
+        1_000,
+    )?;
+
+    // the existing account is kept as is
+    app.token_2022_try_create_associated_token_account_idempotent(
+        AppUser::Admin,
+        AppUser::Alice,
+        mint,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_balance(&associated_token_account)?,
+        1_000
+    );
+    assert_eq!(
+        app.token_2022_query_associated_token_balance(AppUser::Alice, mint)?,
+        1_000
+    );
+
+    Ok(())
+}
+
+fn recover_nested_associated_token_account(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+    let token_2022_program = app.program_id.token_2022_program;
+
+    let [owner_mint, nested_mint] = [0, 1].map(|_| {
+        app.token_2022_try_create_mint_with_extensions(Target::Spl, &[], 6, &admin, None)
+            .unwrap()
+    });
+
+    let (_, owner_account) = app.token_2022_try_create_associated_token_account_idempotent(
+        AppUser::Admin,
+        AppUser::Alice,
+        &owner_mint,
+    )?;
+
+    // tokens sent by mistake to the associated token account of another token account
+    let nested_account = sol_to_pin_pubkey(&get_associated_token_address_with_program_id(
+        &pin_to_sol_pubkey(&owner_account),
+        &pin_to_sol_pubkey(&nested_mint),
+        &token_2022_program,
+    ));
+    send_tx(
+        &mut app.litesvm,
+        &[create_associated_token_account_idempotent(
+            &pin_to_sol_pubkey(&admin),
+            &pin_to_sol_pubkey(&owner_account),
+            &pin_to_sol_pubkey(&nested_mint),
+            &token_2022_program,
+        )],
+        &[AppUser::Admin.keypair()],
+        false,
+    )?;
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        &nested_mint,
+        &nested_account,
+        1_000,
+    )?;
+
+    // only the wallet can recover the tokens
+    app.token_2022_try_create_associated_token_account_idempotent(
+        AppUser::Admin,
+        AppUser::Alice,
+        &nested_mint,
+    )?;
+    assert!(app
+        .token_2022_try_recover_nested_associated_token_account(
+            target,
+            AppUser::Bob,
+            &owner_mint,
+            &nested_mint,
+        )
+        .is_err());
+
+    app.token_2022_try_recover_nested_associated_token_account(
+        target,
+        AppUser::Alice,
+        &owner_mint,
+        &nested_mint,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_associated_token_balance(AppUser::Alice, &nested_mint)?,
+        1_000
+    );
+    assert!(get_account_data(&app, &nested_account).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn recover_nested_associated_token_account_spl() -> TestResult<()> {
+    recover_nested_associated_token_account(Target::Spl)
+}
+
+#[test]
+fn recover_nested_associated_token_account_proxy() -> TestResult<()> {
+    recover_nested_associated_token_account(Target::Proxy)
+}
@@ -0,0 +1,232 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            associated_token_account::Token2022AssociatedTokenAccountExtension,
+            builders::Token2022BuildersExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            mint_close_authority::Token2022MintCloseAuthorityExtension,
+            mint_factory::{ExtensionSpec, Token2022MintFactoryExtension},
+            token_account::Token2022TokenAccountExtension,
+        },
+        suite::{
+            core::{extension::get_account_data, App},
+            types::{
+                pin_pubkey_to_addr, pin_to_sol_pubkey, AppUser, PinPubkey, Target, TestError,
+                TestResult,
+            },
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_program_pack::Pack,
+    solana_signer::Signer,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022_interface::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::{Account, AccountState, Mint},
+    },
+};
+
+const DECIMALS: u8 = 6;
+
+/// Return the data length and the lamports of the account
+fn account_len_and_lamports(app: &App, address: &[u8; 32]) -> (usize, u64) {
+    let account = app
+        .litesvm
+        .get_account(&pin_to_sol_pubkey(address))
+        .unwrap();
+
+    (account.data.len(), account.lamports)
+}
+
+/// The proxy creates a mint with the space of its extensions and initializes them
+#[test]
+fn create_mint_with_builder_proxy() -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+
+    let (_, mint) = app.token_2022_try_create_mint_with_builder(AppUser::Admin, DECIMALS)?;
+
+    // the payer funds the exact length of the extensions
+    let (len, lamports) = account_len_and_lamports(&app, &mint);
+    assert_eq!(
+        len,
+        ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::NonTransferable,
+        ])
+        .unwrap()
+    );
+    assert_eq!(
+        lamports,
+        app.litesvm.minimum_balance_for_rent_exemption(len)
+    );
+
+    // the extensions are laid out in the initialization order
+    let data = get_account_data(&app, &mint)?;
+    let state = StateWithExtensions::<Mint>::unpack(&data).unwrap();
+    assert_eq!(
+        state.get_extension_types().unwrap(),
+        vec![
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::NonTransferable
+        ]
+    );
+
+    let mint_state = app.token_2022_query_mint(Target::Spl, &mint)?;
+    assert!(mint_state.is_initialized);
+    assert_eq!(mint_state.decimals, DECIMALS);
+    assert_eq!(
+        app.token_2022_query_mint_close_authority(Target::Proxy, &mint)?
+            .close_authority,
+        OptionalNonZeroPubkey::try_from(Some(pin_pubkey_to_addr(&admin))).unwrap()
+    );
+
+    Ok(())
+}
+
+/// The proxy initializes the extensions of a token account before and after
+/// `InitializeAccount3`, the owner signing the enabled ones
+#[test]
+fn initialize_account_with_builder_proxy() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let (_, account_keypair) = app.token_2022_try_create_token_account(
+        AppUser::Alice,
+        None,
+        Some(&[ExtensionType::ImmutableOwner, ExtensionType::MemoTransfer]),
+    )?;
+    let account = account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_account_with_builder(AppUser::Alice, &account, &mint)?;
+
+    let data = get_account_data(&app, &account)?;
+    let state = StateWithExtensions::<Account>::unpack(&data).unwrap();
+    assert_eq!(
+        state.get_extension_types().unwrap(),
+        vec![ExtensionType::ImmutableOwner, ExtensionType::MemoTransfer]
+    );
+    assert_eq!(state.base.mint, pin_pubkey_to_addr(&mint));
+    assert_eq!(
+        state.base.owner,
+        pin_pubkey_to_addr(&AppUser::Alice.pubkey())
+    );
+    assert_eq!(state.base.state, AccountState::Initialized);
+    assert!(app.token_2022_query_require_memo(&account)?);
+
+    Ok(())
+}
+
+/// The proxy adds `TransferFeeAmount` to a token account of a transfer fee mint lacking it,
+/// e.g. created before the mint was upgraded, and doesn't touch a complete account
+#[test]
+fn reallocate_for_mint_proxy() -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+    let alice = AppUser::Alice.pubkey();
+    let mint = app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[ExtensionSpec::TransferFeeConfig {
+            transfer_fee_config_authority: Some(admin),
+            withdraw_withheld_authority: Some(admin),
+            transfer_fee_basis_points: 100,
+            maximum_fee: 5_000,
+        }],
+        DECIMALS,
+        &admin,
+        None,
+    )?;
+
+    // Token-2022 adds the required extensions on initialization, so the account lacking
+    // them is written directly
+    let account = [7; 32];
+    let mut data = vec![0; Account::LEN];
+    Account {
+        mint: pin_pubkey_to_addr(&mint),
+        owner: pin_pubkey_to_addr(&alice),
+        state: AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    app.litesvm
+        .set_account(
+            pin_to_sol_pubkey(&account),
+            solana_account::Account {
+                lamports: app.litesvm.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: app.program_id.token_2022_program,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .map_err(TestError::from_raw_error)?;
+
+    app.token_2022_try_reallocate_for_mint(AppUser::Alice, &account, &mint)?;
+
+    // the owner funds the space of the missing extension
+    let (len, lamports) = account_len_and_lamports(&app, &account);
+    assert_eq!(
+        len,
+        ExtensionType::try_calculate_account_len::<Account>(&[ExtensionType::TransferFeeAmount])
+            .unwrap()
+    );
+    assert_eq!(
+        lamports,
+        app.litesvm.minimum_balance_for_rent_exemption(len)
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Spl, &account)?
+            .owner,
+        pin_pubkey_to_addr(&alice)
+    );
+
+    // nothing is missing from an account initialized by Token-2022
+    let (_, complete_account) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Alice,
+        &alice,
+        &mint,
+        &[ExtensionType::TransferFeeAmount],
+    )?;
+    let complete_account = complete_account.pubkey().to_bytes();
+    let expected = account_len_and_lamports(&app, &complete_account);
+
+    app.token_2022_try_reallocate_for_mint(AppUser::Alice, &complete_account, &mint)?;
+    assert_eq!(account_len_and_lamports(&app, &complete_account), expected);
+
+    Ok(())
+}
+
+/// The proxy thaws the new account of a mint freezing new accounts by default when the
+/// caller is the freeze authority, and leaves it frozen otherwise
+#[test]
+fn initialize_account_thawed_proxy() -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+    let alice = AppUser::Alice.pubkey();
+    let mint = app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[ExtensionSpec::DefaultAccountState {
+            state: AccountState::Frozen,
+        }],
+        DECIMALS,
+        &admin,
+        Some(&admin),
+    )?;
+
+    for (sender, expected_state) in [
+        (AppUser::Admin, AccountState::Initialized),
+        (AppUser::Bob, AccountState::Frozen),
+    ] {
+        let (_, account_keypair) =
+            app.token_2022_try_create_token_account(AppUser::Alice, None, Some(&[]))?;
+        let account = account_keypair.pubkey().to_bytes();
+
+        app.token_2022_try_initialize_account_thawed(sender, &account, &mint, &alice)?;
+
+        let state = app.token_2022_query_token_account(Target::Spl, &account)?;
+        assert_eq!(state.owner, pin_pubkey_to_addr(&alice));
+        assert_eq!(state.state, expected_state);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,253 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+        },
+        suite::{
+            core::{App, ProgramId},
+            types::{
+                addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestError,
+                TestResult,
+            },
+        },
+    },
+    pinocchio::pubkey::Pubkey,
+    pretty_assertions::assert_eq,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_signer::Signer,
+    spl_token_2022_interface::error::TokenError,
+    std::collections::HashMap,
+};
+
+/// Max proxy overhead per instruction, stored next to the tests
+const BUDGETS: &str = include_str!("compute_units.txt");
+
+fn parse_budgets() -> HashMap<String, u64> {
+    BUDGETS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, budget) = line
+                .split_once('=')
+                .unwrap_or_else(|| panic!("Invalid budget line: {line}"));
+
+            (
+                name.trim().to_string(),
+                budget
+                    .trim()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Invalid budget value: {line}")),
+            )
+        })
+        .collect()
+}
+
+/// Run the same instruction flow on `target` and return CU consumed by each instruction
+fn measure(target: Target) -> TestResult<Vec<(&'static str, u64)>> {
+    let mut app = App::new(false);
+    let mut compute_units = vec![];
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint = &mint_keypair.pubkey().to_bytes();
+
+    let tx = app.token_2022_try_initialize_mint(
+        target,
+        AppUser::Admin,
+        mint,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+    compute_units.push(("initialize_mint", tx.compute_units_consumed));
+
+    let mut token_accounts = vec![];
+    for owner in [AppUser::Alice, AppUser::Bob] {
+        let (_, keypair) = app.token_2022_try_create_token_account(AppUser::Admin, None, None)?;
+        let token_account = keypair.pubkey().to_bytes();
+
+        let tx = app.token_2022_try_initialize_token_account(
+            target,
+            AppUser::Admin,
+            &token_account,
+            mint,
+            &owner.pubkey(),
+        )?;
+        token_accounts.push(token_account);
+
+        if owner == AppUser::Alice {
+            compute_units.push(("initialize_account", tx.compute_units_consumed));
+        }
+    }
+    let [alice_account, bob_account] = &token_accounts[..] else {
+        unreachable!()
+    };
+
+    let tx = app.token_2022_try_mint_to(target, AppUser::Admin, mint, alice_account, 1_000)?;
+    compute_units.push(("mint_to", tx.compute_units_consumed));
+
+    let tx = app.token_2022_try_transfer_checked(
+        target,
+        AppUser::Alice,
+        alice_account,
+        mint,
+        bob_account,
+        100,
+        6,
+    )?;
+    compute_units.push(("transfer_checked", tx.compute_units_consumed));
+
+    let tx = app.token_2022_try_approve(
+        target,
+        AppUser::Alice,
+        alice_account,
+        &AppUser::Bob.pubkey(),
+        100,
+    )?;
+    compute_units.push(("approve", tx.compute_units_consumed));
+
+    let tx = app.token_2022_try_revoke(target, AppUser::Alice, alice_account)?;
+    compute_units.push(("revoke", tx.compute_units_consumed));
+
+    let tx = app.token_2022_try_burn(target, AppUser::Alice, alice_account, mint, 100)?;
+    compute_units.push(("burn", tx.compute_units_consumed));
+
+    Ok(compute_units)
+}
+
+/// `TransferChecked` of 6 decimals routed to `target`, to be simulated without the helpers
+fn transfer_checked_ix(
+    app: &App,
+    target: Target,
+    from: &Pubkey,
+    mint: &Pubkey,
+    to: &Pubkey,
+    amount: u64,
+) -> TestResult<Instruction> {
+    let ProgramId {
+        token_2022_program,
+        token_2022_proxy,
+        ..
+    } = app.program_id;
+
+    let ix = spl_token_2022_interface::instruction::transfer_checked(
+        &token_2022_program.to_bytes().into(),
+        &pin_pubkey_to_addr(from),
+        &pin_pubkey_to_addr(mint),
+        &pin_pubkey_to_addr(to),
+        &pin_pubkey_to_addr(&AppUser::Alice.pubkey()),
+        &[],
+        amount,
+        6,
+    )
+    .map_err(TestError::from_raw_error)?;
+
+    let mut ix_legacy = Instruction {
+        program_id: addr_to_sol_pubkey(&ix.program_id),
+        accounts: ix
+            .accounts
+            .into_iter()
+            .map(|x| AccountMeta {
+                pubkey: addr_to_sol_pubkey(&x.pubkey),
+                is_signer: x.is_signer,
+                is_writable: x.is_writable,
+            })
+            .collect(),
+        data: ix.data,
+    };
+
+    if let Target::Proxy = target {
+        ix_legacy.program_id = token_2022_proxy;
+        ix_legacy
+            .accounts
+            .push(AccountMeta::new_readonly(token_2022_program, false));
+    }
+
+    Ok(ix_legacy)
+}
+
+fn simulated_compute_units(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mut client = app.token_client(target);
+
+    let mint = client.create_mint(&[], 6, AppUser::Admin, None)?;
+    let alice_account = client.create_token_account(AppUser::Alice, &mint, &[])?;
+    let bob_account = client.create_token_account(AppUser::Bob, &mint, &[])?;
+    client.mint_to(AppUser::Admin, &mint, &alice_account, 1_000)?;
+
+    let ix = transfer_checked_ix(&app, target, &alice_account, &mint, &bob_account, 100)?;
+    let signers = [&AppUser::Alice.keypair()];
+
+    // the state isn't committed, so each run starts from the same state
+    let simulated = app.simulate_tx(&[ix.clone()], &signers)?;
+    for _ in 0..3 {
+        let tx = app.simulate_tx(&[ix.clone()], &signers)?;
+
+        assert_eq!(tx.compute_units_consumed, simulated.compute_units_consumed);
+        assert_eq!(tx.logs, simulated.logs);
+    }
+
+    // a failed simulation returns the error like a sent transaction
+    let ix = transfer_checked_ix(&app, target, &alice_account, &mint, &bob_account, 2_000)?;
+    assert_eq!(
+        app.simulate_tx(&[ix], &signers).unwrap_err(),
+        TestError::from_token_error(TokenError::InsufficientFunds)
+    );
+
+    let mut client = app.token_client(target);
+    assert_eq!(client.balance(&alice_account)?, 1_000);
+    assert_eq!(client.balance(&bob_account)?, 0);
+
+    // sending the simulated transaction consumes the same CU
+    let tx = client.transfer(AppUser::Alice, &alice_account, &bob_account, 100)?;
+    assert_eq!(tx.compute_units_consumed, simulated.compute_units_consumed);
+    assert_eq!(client.balance(&alice_account)?, 900);
+    assert_eq!(client.balance(&bob_account)?, 100);
+
+    Ok(())
+}
+
+#[test]
+fn proxy_compute_units_overhead() -> TestResult<()> {
+    let budgets = parse_budgets();
+    let spl = measure(Target::Spl)?;
+    let proxy = measure(Target::Proxy)?;
+
+    let mut regressions = vec![];
+    println!(
+        "{:<20} {:>10} {:>10} {:>10} {:>10}",
+        "instruction", "spl", "proxy", "overhead", "budget"
+    );
+
+    for ((name, spl_cu), (_, proxy_cu)) in spl.into_iter().zip(proxy) {
+        let overhead = proxy_cu.saturating_sub(spl_cu);
+        let budget = *budgets
+            .get(name)
+            .unwrap_or_else(|| panic!("No budget for {name} in compute_units.txt"));
+
+        println!("{name:<20} {spl_cu:>10} {proxy_cu:>10} {overhead:>10} {budget:>10}");
+
+        if overhead > budget {
+            regressions.push(format!("{name}: {overhead} > {budget}"));
+        }
+    }
+
+    assert!(
+        regressions.is_empty(),
+        "Proxy overhead exceeds the budget: {regressions:#?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn simulated_compute_units_spl() -> TestResult<()> {
+    simulated_compute_units(Target::Spl)
+}
+
+#[test]
+fn simulated_compute_units_proxy() -> TestResult<()> {
+    simulated_compute_units(Target::Proxy)
+}
@@ -0,0 +1,100 @@
+use {
+    crate::helpers::suite::{
+        confidential::{ciphertext_validity_proof, range_proof, ConfidentialKeys},
+        types::{AppUser, TestResult},
+    },
+    pretty_assertions::assert_eq,
+    solana_zk_sdk::zk_elgamal_proof_program::proof_data::ZkProofData,
+};
+
+const ALICE_ACCOUNT: [u8; 32] = [1; 32];
+const BOB_ACCOUNT: [u8; 32] = [2; 32];
+
+#[test]
+fn confidential_keys_are_derived_from_test_keypairs() -> TestResult<()> {
+    let alice_keys = ConfidentialKeys::new(AppUser::Alice, &ALICE_ACCOUNT)?;
+
+    // the same for the same owner and account
+    let same_keys = ConfidentialKeys::new(AppUser::Alice, &ALICE_ACCOUNT)?;
+    assert_eq!(alice_keys.pubkey(), same_keys.pubkey());
+    assert_eq!(
+        same_keys.ae_key.decrypt(&alice_keys.ae_key.encrypt(1_000)),
+        Some(1_000)
+    );
+
+    // different for another account or another owner
+    let other_account_keys = ConfidentialKeys::new(AppUser::Alice, &BOB_ACCOUNT)?;
+    let bob_keys = ConfidentialKeys::new(AppUser::Bob, &ALICE_ACCOUNT)?;
+    assert_ne!(alice_keys.pubkey(), other_account_keys.pubkey());
+    assert_ne!(alice_keys.pubkey(), bob_keys.pubkey());
+
+    let ciphertext = alice_keys.pubkey().encrypt(1_000_u64);
+    assert_eq!(alice_keys.decrypt(&ciphertext), Some(1_000));
+    assert_ne!(bob_keys.decrypt(&ciphertext), Some(1_000));
+
+    Ok(())
+}
+
+#[test]
+fn proofs_are_verified() -> TestResult<()> {
+    let alice_keys = ConfidentialKeys::new(AppUser::Alice, &ALICE_ACCOUNT)?;
+    let bob_keys = ConfidentialKeys::new(AppUser::Bob, &BOB_ACCOUNT)?;
+
+    assert!(alice_keys
+        .pubkey_validity_proof()?
+        .verify_proof()
+        .is_ok());
+
+    let zero_balance = alice_keys.pubkey().encrypt(0_u64);
+    assert!(alice_keys
+        .zero_balance_proof(&zero_balance)?
+        .verify_proof()
+        .is_ok());
+
+    let balance = alice_keys.pubkey().encrypt(1_000_u64);
+    let equality = alice_keys.equality_proof(&balance, 1_000)?;
+    assert!(equality.proof.verify_proof().is_ok());
+    // over the commitment of the equality proof
+    assert!(equality.range_proof(1_000)?.verify_proof().is_ok());
+
+    let validity = ciphertext_validity_proof(alice_keys.pubkey(), bob_keys.pubkey(), 100)?;
+    assert!(validity.proof.verify_proof().is_ok());
+    // each pubkey decrypts its own handle
+    for (index, keys) in [&alice_keys, &bob_keys].into_iter().enumerate() {
+        let ciphertext = validity
+            .grouped_ciphertext
+            .to_elgamal_ciphertext(index)
+            .unwrap();
+        assert_eq!(keys.decrypt(&ciphertext), Some(100));
+    }
+
+    assert!(range_proof(u64::MAX)?.proof.verify_proof().is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn proofs_of_false_statements_are_rejected() -> TestResult<()> {
+    let alice_keys = ConfidentialKeys::new(AppUser::Alice, &ALICE_ACCOUNT)?;
+    let bob_keys = ConfidentialKeys::new(AppUser::Bob, &BOB_ACCOUNT)?;
+
+    let balance = alice_keys.pubkey().encrypt(1_000_u64);
+    assert!(alice_keys
+        .zero_balance_proof(&balance)?
+        .verify_proof()
+        .is_err());
+    assert!(alice_keys
+        .equality_proof(&balance, 999)?
+        .proof
+        .verify_proof()
+        .is_err());
+
+    // the ciphertext of another key
+    assert!(bob_keys
+        .equality_proof(&balance, 1_000)?
+        .proof
+        .verify_proof()
+        .is_err());
+
+    Ok(())
+}
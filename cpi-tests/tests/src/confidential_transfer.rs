@@ -0,0 +1,251 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            confidential_transfer::Token2022ConfidentialTransferExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+        },
+        suite::{
+            confidential::ConfidentialKeys,
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    solana_zk_sdk::encryption::{
+        auth_encryption::AeCiphertext, elgamal::ElGamalCiphertext, pod::elgamal::PodElGamalPubkey,
+    },
+    spl_token_2022_interface::extension::{
+        confidential_transfer::ConfidentialTransferAccount, ExtensionType,
+    },
+};
+
+const DECIMALS: u8 = 6;
+const MAXIMUM_PENDING_BALANCE_CREDIT_COUNTER: u64 = 65_536;
+const MINTED: u64 = 1_000;
+const DEPOSITED: u64 = 400;
+const WITHDRAWN: u64 = 150;
+
+/// Create a confidential mint approving new accounts automatically, and a token account
+/// owned by Alice with `MINTED` tokens, configured with her confidential keys
+fn prepare(app: &mut App, target: Target) -> TestResult<([u8; 32], [u8; 32], ConfidentialKeys)> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ConfidentialTransferMint]),
+    )?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_confidential_transfer_mint(
+        target,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        true,
+        None,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let (_, account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint,
+        &[ExtensionType::ConfidentialTransferAccount],
+    )?;
+    let alice_account = account_keypair.pubkey().to_bytes();
+    let alice_keys = ConfidentialKeys::new(AppUser::Alice, &alice_account)?;
+
+    app.token_2022_try_configure_account(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &alice_keys,
+        MAXIMUM_PENDING_BALANCE_CREDIT_COUNTER,
+    )?;
+
+    app.token_2022_try_mint_to(target, AppUser::Admin, &mint, &alice_account, MINTED)?;
+
+    Ok((mint, alice_account, alice_keys))
+}
+
+/// Available balance decrypted with both the ElGamal and the AE keys
+fn available_balance(
+    keys: &ConfidentialKeys,
+    state: &ConfidentialTransferAccount,
+) -> (Option<u64>, Option<u64>) {
+    let available_balance =
+        ElGamalCiphertext::from_bytes(bytemuck::bytes_of(&state.available_balance)).unwrap();
+    let decryptable_available_balance =
+        AeCiphertext::from_bytes(bytemuck::bytes_of(&state.decryptable_available_balance)).unwrap();
+
+    (
+        keys.decrypt(&available_balance),
+        keys.ae_key.decrypt(&decryptable_available_balance),
+    )
+}
+
+fn initialize_confidential_transfer_mint(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, ..) = prepare(&mut app, target)?;
+
+    let config = app.token_2022_query_confidential_transfer_mint(target, &mint)?;
+
+    assert_eq!(
+        Option::<solana_address::Address>::from(config.authority).map(|x| x.to_bytes()),
+        Some(AppUser::Admin.pubkey())
+    );
+    assert!(bool::from(config.auto_approve_new_accounts));
+    assert_eq!(
+        Option::<PodElGamalPubkey>::from(config.auditor_elgamal_pubkey),
+        None
+    );
+
+    Ok(())
+}
+
+fn configure_account(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, alice_account, alice_keys) = prepare(&mut app, target)?;
+
+    let state = app.token_2022_query_confidential_transfer_account(target, &alice_account)?;
+
+    assert!(bool::from(state.approved));
+    assert_eq!(
+        state.elgamal_pubkey,
+        PodElGamalPubkey::from(*alice_keys.pubkey())
+    );
+    assert!(bool::from(state.allow_confidential_credits));
+    assert!(bool::from(state.allow_non_confidential_credits));
+    assert_eq!(
+        u64::from(state.maximum_pending_balance_credit_counter),
+        MAXIMUM_PENDING_BALANCE_CREDIT_COUNTER
+    );
+    assert_eq!(u64::from(state.pending_balance_credit_counter), 0);
+    assert_eq!(available_balance(&alice_keys, &state), (Some(0), Some(0)));
+
+    Ok(())
+}
+
+fn deposit_apply_and_withdraw(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, alice_keys) = prepare(&mut app, target)?;
+
+    // more than the non-confidential balance
+    assert_eq!(
+        app.token_2022_try_confidential_deposit(
+            target,
+            AppUser::Alice,
+            &alice_account,
+            &mint,
+            MINTED + 1,
+            DECIMALS,
+        )
+        .unwrap_err(),
+        // TokenError::InsufficientFunds
+        TestError {
+            info: "custom program error: 0x1".to_string(),
+            index: None,
+        }
+    );
+
+    app.token_2022_try_confidential_deposit(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        DEPOSITED,
+        DECIMALS,
+    )?;
+
+    let state = app.token_2022_query_confidential_transfer_account(target, &alice_account)?;
+    assert_eq!(u64::from(state.pending_balance_credit_counter), 1);
+    assert_eq!(available_balance(&alice_keys, &state), (Some(0), Some(0)));
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .amount,
+        MINTED - DEPOSITED
+    );
+
+    app.token_2022_try_apply_pending_balance(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &alice_keys,
+        u64::from(state.pending_balance_credit_counter),
+        DEPOSITED,
+    )?;
+
+    let state = app.token_2022_query_confidential_transfer_account(target, &alice_account)?;
+    assert_eq!(u64::from(state.pending_balance_credit_counter), 0);
+    assert_eq!(u64::from(state.expected_pending_balance_credit_counter), 1);
+    assert_eq!(u64::from(state.actual_pending_balance_credit_counter), 1);
+    assert_eq!(
+        available_balance(&alice_keys, &state),
+        (Some(DEPOSITED), Some(DEPOSITED))
+    );
+
+    app.token_2022_try_confidential_withdraw(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &alice_keys,
+        WITHDRAWN,
+        DECIMALS,
+    )?;
+
+    let state = app.token_2022_query_confidential_transfer_account(target, &alice_account)?;
+    assert_eq!(
+        available_balance(&alice_keys, &state),
+        (Some(DEPOSITED - WITHDRAWN), Some(DEPOSITED - WITHDRAWN))
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .amount,
+        MINTED - DEPOSITED + WITHDRAWN
+    );
+
+    Ok(())
+}
+
+#[test]
+fn initialize_confidential_transfer_mint_spl() -> TestResult<()> {
+    initialize_confidential_transfer_mint(Target::Spl)
+}
+
+#[test]
+fn initialize_confidential_transfer_mint_proxy() -> TestResult<()> {
+    initialize_confidential_transfer_mint(Target::Proxy)
+}
+
+#[test]
+fn configure_account_spl() -> TestResult<()> {
+    configure_account(Target::Spl)
+}
+
+#[test]
+fn configure_account_proxy() -> TestResult<()> {
+    configure_account(Target::Proxy)
+}
+
+#[test]
+fn deposit_apply_and_withdraw_spl() -> TestResult<()> {
+    deposit_apply_and_withdraw(Target::Spl)
+}
+
+#[test]
+fn deposit_apply_and_withdraw_proxy() -> TestResult<()> {
+    deposit_apply_and_withdraw(Target::Proxy)
+}
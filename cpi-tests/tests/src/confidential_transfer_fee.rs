@@ -0,0 +1,166 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            confidential_transfer::Token2022ConfidentialTransferExtension,
+            confidential_transfer_fee::Token2022ConfidentialTransferFeeExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+        },
+        suite::{
+            confidential::ConfidentialKeys,
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    solana_zk_sdk::encryption::pod::elgamal::PodElGamalPubkey,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+const DECIMALS: u8 = 6;
+
+/// Create a mint with a transfer fee and confidential transfers, Admin being the
+/// authority of the confidential fees. Returns the mint and the ElGamal pubkey the
+/// withheld fees are encrypted under
+fn prepare(app: &mut App, target: Target) -> TestResult<([u8; 32], PodElGamalPubkey)> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::ConfidentialTransferMint,
+            ExtensionType::ConfidentialTransferFeeConfig,
+        ]),
+    )?;
+    let mint = mint_keypair.pubkey().to_bytes();
+    let withdraw_withheld_authority_elgamal_pubkey =
+        PodElGamalPubkey::from(*ConfidentialKeys::new(AppUser::Admin, &mint)?.pubkey());
+
+    app.token_2022_try_initialize_transfer_fee_config(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&AppUser::Admin.pubkey()),
+        100,
+        5_000,
+    )?;
+
+    app.token_2022_try_initialize_confidential_transfer_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        true,
+        None,
+    )?;
+
+    app.token_2022_try_initialize_confidential_transfer_fee_config(
+        target,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        &withdraw_withheld_authority_elgamal_pubkey,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    Ok((mint, withdraw_withheld_authority_elgamal_pubkey))
+}
+
+fn initialize_confidential_transfer_fee_config(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, withdraw_withheld_authority_elgamal_pubkey) = prepare(&mut app, target)?;
+
+    let config = app.token_2022_query_confidential_transfer_fee_config(target, &mint)?;
+
+    assert_eq!(
+        Option::<solana_address::Address>::from(config.authority).map(|x| x.to_bytes()),
+        Some(AppUser::Admin.pubkey())
+    );
+    assert_eq!(
+        config.withdraw_withheld_authority_elgamal_pubkey,
+        withdraw_withheld_authority_elgamal_pubkey
+    );
+    // enabled on initialization
+    assert!(bool::from(config.harvest_to_mint_enabled));
+
+    Ok(())
+}
+
+fn enable_and_disable_harvest_to_mint(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, _) = prepare(&mut app, target)?;
+
+    // only the authority can toggle harvesting
+    assert_eq!(
+        app.token_2022_try_disable_harvest_to_mint(target, AppUser::Alice, &mint)
+            .unwrap_err(),
+        // TokenError::OwnerMismatch
+        TestError {
+            info: "custom program error: 0x4".to_string(),
+            index: None,
+        }
+    );
+
+    app.token_2022_try_disable_harvest_to_mint(target, AppUser::Admin, &mint)?;
+    let config = app.token_2022_query_confidential_transfer_fee_config(target, &mint)?;
+    assert!(!bool::from(config.harvest_to_mint_enabled));
+
+    assert_eq!(
+        app.token_2022_try_harvest_confidential_withheld_tokens_to_mint(
+            target,
+            AppUser::Alice,
+            &mint,
+            &[]
+        )
+        .unwrap_err(),
+        // TokenError::HarvestToMintDisabled
+        TestError {
+            info: "custom program error: 0x38".to_string(),
+            index: None,
+        }
+    );
+
+    app.token_2022_try_enable_harvest_to_mint(target, AppUser::Admin, &mint)?;
+    let config = app.token_2022_query_confidential_transfer_fee_config(target, &mint)?;
+    assert!(bool::from(config.harvest_to_mint_enabled));
+
+    // permissionless, nothing withheld to harvest
+    app.token_2022_try_harvest_confidential_withheld_tokens_to_mint(
+        target,
+        AppUser::Alice,
+        &mint,
+        &[],
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn initialize_confidential_transfer_fee_config_spl() -> TestResult<()> {
+    initialize_confidential_transfer_fee_config(Target::Spl)
+}
+
+#[test]
+fn initialize_confidential_transfer_fee_config_proxy() -> TestResult<()> {
+    initialize_confidential_transfer_fee_config(Target::Proxy)
+}
+
+#[test]
+fn enable_and_disable_harvest_to_mint_spl() -> TestResult<()> {
+    enable_and_disable_harvest_to_mint(Target::Spl)
+}
+
+#[test]
+fn enable_and_disable_harvest_to_mint_proxy() -> TestResult<()> {
+    enable_and_disable_harvest_to_mint(Target::Proxy)
+}
@@ -0,0 +1,420 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            mint_close_authority::Token2022MintCloseAuthorityExtension,
+            token_metadata::Token2022TokenMetadataExtension,
+            transfer_hook::Token2022TransferHookExtension,
+        },
+        suite::{
+            core::{extension::get_account_data, App},
+            fixtures::AccountFixture,
+            types::{pin_to_sol_pubkey, AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+    pinocchio_token_2022::{
+        client::{account_type, extension_types, AccountType},
+        extension::{
+            consts::ExtensionType,
+            mint_close_authority::MintCloseAuthority,
+            tlv::{
+                get_extension_from_bytes, get_extensions_from_bytes, BASE_ACCOUNT_LENGTH,
+                EXTENSIONS_START, TLV_HEADER_LEN,
+            },
+            token_metadata::TokenMetadata,
+            transfer_hook::TransferHook,
+        },
+        state::{Mint, Multisig},
+    },
+    pretty_assertions::assert_eq,
+    solana_account::Account,
+    solana_signer::Signer,
+    spl_token_2022_interface::{
+        extension::{
+            mint_close_authority::MintCloseAuthority as SplMintCloseAuthority,
+            BaseStateWithExtensions, ExtensionType as SplExtensionType, StateWithExtensions,
+        },
+        state::Mint as SplMint,
+    },
+    spl_token_metadata_interface::state::Field,
+};
+
+const CLOSE_AUTHORITY: Pubkey = [1; 32];
+const HOOK_PROGRAM_ID: Pubkey = [7; 32];
+const NAME: &str = "Corrupted Token";
+
+/// What the pinocchio parsers read from the mint data
+#[derive(Debug, PartialEq)]
+struct Parsed {
+    extension_types: Vec<ExtensionType>,
+    close_authority: Option<Pubkey>,
+    hook_program_id: Option<Pubkey>,
+    /// name and `color` field
+    metadata: Result<(String, Option<String>), ProgramError>,
+}
+
+fn parse(data: &[u8]) -> Parsed {
+    let close_authority =
+        get_extension_from_bytes::<MintCloseAuthority>(data).and_then(|x| x.close_authority());
+    let hook_program_id =
+        get_extension_from_bytes::<TransferHook>(data).and_then(|x| x.program_id());
+
+    // the single walk finds the same entries
+    let (close_authority_set, hook_set) =
+        get_extensions_from_bytes::<(MintCloseAuthority, TransferHook)>(data);
+    assert_eq!(
+        close_authority_set.and_then(|x| x.close_authority()),
+        close_authority
+    );
+    assert_eq!(hook_set.and_then(|x| x.program_id()), hook_program_id);
+
+    Parsed {
+        extension_types: extension_types(data).collect(),
+        close_authority: close_authority.copied(),
+        hook_program_id: hook_program_id.copied(),
+        metadata: TokenMetadata::from_bytes(data)
+            .map(|x| (x.name.to_string(), x.get("color").map(|x| x.to_string()))),
+    }
+}
+
+fn valid_parsed() -> Parsed {
+    Parsed {
+        extension_types: vec![
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::TransferHook,
+            ExtensionType::MetadataPointer,
+            ExtensionType::TokenMetadata,
+        ],
+        close_authority: Some(CLOSE_AUTHORITY),
+        hook_program_id: Some(HOOK_PROGRAM_ID),
+        metadata: Ok((NAME.to_string(), Some("blue".to_string()))),
+    }
+}
+
+/// Mint with `MintCloseAuthority`, `TransferHook` and `MetadataPointer` entries followed
+/// by its `TokenMetadata`, the last entry ending with the data. The metadata pointer has
+/// no authority, its value starting with zeros
+fn create_extended_mint(app: &mut App) -> TestResult<AccountFixture> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[
+            SplExtensionType::MintCloseAuthority,
+            SplExtensionType::TransferHook,
+            SplExtensionType::MetadataPointer,
+        ]),
+    )?;
+    let mint = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_mint_close_authority(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        Some(&CLOSE_AUTHORITY),
+    )?;
+    app.token_2022_try_initialize_transfer_hook(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&HOOK_PROGRAM_ID),
+    )?;
+    app.token_2022_try_initialize_metadata_pointer(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        None,
+        Some(mint),
+    )?;
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.token_2022_try_initialize_token_metadata(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        NAME,
+        "BAD",
+        "https://example.com/corrupted.json",
+    )?;
+    app.token_2022_try_update_token_metadata_field(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        Field::Key("color".to_string()),
+        "blue",
+    )?;
+
+    let fixture = app.get_account_fixture(&pin_to_sol_pubkey(mint))?;
+    assert_eq!(parse(&fixture.account.data), valid_parsed());
+
+    Ok(fixture)
+}
+
+/// Write the mutated mint data back into litesvm and read it again
+fn write_back(app: &mut App, fixture: &AccountFixture, data: Vec<u8>) -> TestResult<Vec<u8>> {
+    app.set_account_fixture(&AccountFixture {
+        pubkey: fixture.pubkey,
+        account: Account {
+            data,
+            ..fixture.account.clone()
+        },
+    })?;
+
+    get_account_data(app, &fixture.pubkey.to_bytes())
+}
+
+/// TLV entries of the valid mint data as (type, header offset, value end), walked by hand
+fn layout(data: &[u8]) -> Vec<(ExtensionType, usize, usize)> {
+    let mut entries = vec![];
+    let mut offset = EXTENSIONS_START;
+
+    while offset < data.len() {
+        let tlv_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        if tlv_type == ExtensionType::Uninitialized as u16 {
+            break;
+        }
+
+        let tlv_len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        let end = offset + TLV_HEADER_LEN + tlv_len;
+
+        entries.push((ExtensionType::try_from(tlv_type).unwrap(), offset, end));
+        offset = end;
+    }
+
+    entries
+}
+
+fn entry_offset(data: &[u8], extension_type: ExtensionType) -> usize {
+    layout(data)
+        .into_iter()
+        .find(|(x, _, _)| *x == extension_type)
+        .map(|(_, offset, _)| offset)
+        .unwrap()
+}
+
+fn set_entry_len(data: &mut [u8], offset: usize, len: usize) {
+    data[offset + 2..offset + TLV_HEADER_LEN].copy_from_slice(&(len as u16).to_le_bytes());
+}
+
+#[test]
+fn corrupted_tlv_length_overflow() -> TestResult<()> {
+    let mut app = App::new(false);
+    let fixture = create_extended_mint(&mut app)?;
+    let valid = &fixture.account.data;
+
+    // the first entry runs past the end of the data, hiding all the entries
+    let mut data = valid.clone();
+    set_entry_len(
+        &mut data,
+        entry_offset(valid, ExtensionType::MintCloseAuthority),
+        u16::MAX as usize,
+    );
+    let data = write_back(&mut app, &fixture, data)?;
+
+    assert_eq!(
+        parse(&data),
+        Parsed {
+            extension_types: vec![],
+            close_authority: None,
+            hook_program_id: None,
+            metadata: Err(ProgramError::InvalidAccountData),
+        }
+    );
+    // the account type is still readable, and SPL rejects the TLV area as well
+    assert_eq!(account_type(&data), Some(AccountType::Mint));
+    assert!(StateWithExtensions::<SplMint>::unpack(&data)
+        .unwrap()
+        .get_extension_types()
+        .is_err());
+
+    // the transfer hook swallows the header of the metadata pointer, its length doesn't
+    // match `TransferHook` anymore and the walk stops at the zeroed pointer authority
+    let mut data = valid.clone();
+    let offset = entry_offset(valid, ExtensionType::TransferHook);
+    set_entry_len(&mut data, offset, 64 + TLV_HEADER_LEN);
+    let data = write_back(&mut app, &fixture, data)?;
+
+    assert_eq!(
+        parse(&data),
+        Parsed {
+            extension_types: vec![
+                ExtensionType::MintCloseAuthority,
+                ExtensionType::TransferHook
+            ],
+            close_authority: Some(CLOSE_AUTHORITY),
+            hook_program_id: None,
+            metadata: Err(ProgramError::InvalidAccountData),
+        }
+    );
+
+    // the last entry runs one byte past the end of the data
+    let mut data = valid.clone();
+    let (_, offset, end) = *layout(valid).last().unwrap();
+    set_entry_len(&mut data, offset, end - offset - TLV_HEADER_LEN + 1);
+    let data = write_back(&mut app, &fixture, data)?;
+
+    assert_eq!(
+        parse(&data),
+        Parsed {
+            extension_types: valid_parsed().extension_types[..3].to_vec(),
+            metadata: Err(ProgramError::InvalidAccountData),
+            ..valid_parsed()
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn corrupted_tlv_truncated_tail() -> TestResult<()> {
+    let mut app = App::new(false);
+    let fixture = create_extended_mint(&mut app)?;
+    let valid = &fixture.account.data;
+    let entries = layout(valid);
+
+    for len in (0..valid.len()).rev() {
+        let data = write_back(&mut app, &fixture, valid[..len].to_vec())?;
+        // data of the multisig length isn't read as extensions, like SPL does
+        let is_read = |end: usize| end <= len && len != Multisig::LEN;
+        let is_complete = |extension_type| {
+            entries
+                .iter()
+                .any(|&(x, _, end)| x == extension_type && is_read(end))
+        };
+
+        // only the entries fully within the data are read
+        assert_eq!(
+            parse(&data),
+            Parsed {
+                extension_types: entries
+                    .iter()
+                    .filter(|(_, _, end)| is_read(*end))
+                    .map(|(x, _, _)| *x)
+                    .collect(),
+                close_authority: is_complete(ExtensionType::MintCloseAuthority)
+                    .then_some(CLOSE_AUTHORITY),
+                hook_program_id: is_complete(ExtensionType::TransferHook)
+                    .then_some(HOOK_PROGRAM_ID),
+                metadata: Err(ProgramError::InvalidAccountData),
+            },
+            "{} bytes",
+            len
+        );
+
+        assert_eq!(
+            account_type(&data),
+            (len > BASE_ACCOUNT_LENGTH).then_some(AccountType::Mint)
+        );
+        assert_eq!(
+            Mint::from_bytes(&data).err(),
+            (len < Mint::BASE_LEN).then_some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn corrupted_tlv_duplicated_entries() -> TestResult<()> {
+    let mut app = App::new(false);
+    let fixture = create_extended_mint(&mut app)?;
+    let valid = &fixture.account.data;
+
+    // duplicates stored after the valid entries are ignored, like SPL does
+    let mut data = valid.clone();
+    for (extension_type, value) in [
+        (ExtensionType::MintCloseAuthority, [9; 32].as_slice()),
+        (ExtensionType::TransferHook, &[8; 64]),
+        (ExtensionType::TokenMetadata, &[0xff; 8]),
+    ] {
+        data.extend_from_slice(&(extension_type as u16).to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+    }
+    let data = write_back(&mut app, &fixture, data)?;
+
+    let parsed = parse(&data);
+    assert_eq!(
+        parsed,
+        Parsed {
+            extension_types: [
+                valid_parsed().extension_types,
+                vec![
+                    ExtensionType::MintCloseAuthority,
+                    ExtensionType::TransferHook,
+                    ExtensionType::TokenMetadata,
+                ],
+            ]
+            .concat(),
+            ..valid_parsed()
+        }
+    );
+
+    let spl_mint = StateWithExtensions::<SplMint>::unpack(&data).unwrap();
+    assert_eq!(
+        spl_mint
+            .get_extension_types()
+            .unwrap()
+            .into_iter()
+            .map(|x| x as u16)
+            .collect::<Vec<_>>(),
+        parsed
+            .extension_types
+            .iter()
+            .map(|x| *x as u16)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Option::<solana_address::Address>::from(
+            spl_mint
+                .get_extension::<SplMintCloseAuthority>()
+                .unwrap()
+                .close_authority
+        )
+        .map(|x| x.to_bytes()),
+        Some(CLOSE_AUTHORITY)
+    );
+
+    // a malformed duplicate stored first shadows the valid entry
+    let data = [
+        &valid[..EXTENSIONS_START],
+        &(ExtensionType::MintCloseAuthority as u16).to_le_bytes(),
+        &3u16.to_le_bytes(),
+        &[9; 3],
+        &valid[EXTENSIONS_START..],
+    ]
+    .concat();
+    let data = write_back(&mut app, &fixture, data)?;
+
+    assert_eq!(
+        parse(&data),
+        Parsed {
+            extension_types: [
+                vec![ExtensionType::MintCloseAuthority],
+                valid_parsed().extension_types,
+            ]
+            .concat(),
+            close_authority: None,
+            ..valid_parsed()
+        }
+    );
+    assert_eq!(
+        MintCloseAuthority::from_bytes(&data).err(),
+        Some(ProgramError::InvalidAccountData)
+    );
+    assert!(StateWithExtensions::<SplMint>::unpack(&data)
+        .unwrap()
+        .get_extension::<SplMintCloseAuthority>()
+        .is_err());
+
+    Ok(())
+}
@@ -4,18 +4,25 @@ use {
             cpi_guard::Token2022CpiGuardExtension,
             initialize_mint::Token2022InitializeMintExtension,
             initialize_multisig::Token2022InitializeMultisigExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
             token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
         },
         suite::{
             core::App,
-            types::{AppUser, SolPubkey, Target, TestError, TestResult},
+            types::{pin_to_sol_pubkey, AppUser, SolPubkey, Target, TestError, TestResult},
         },
     },
+    pinocchio::pubkey::Pubkey,
     pretty_assertions::assert_eq,
     solana_signer::Signer,
-    spl_token_2022_interface::extension::ExtensionType,
+    spl_token_2022_interface::{
+        error::TokenError, extension::ExtensionType, instruction::AuthorityType,
+    },
 };
 
+const DECIMALS: u8 = 6;
+
 #[test]
 fn cpi_guard_enable_and_disable() -> TestResult<()> {
     let mut app = App::new(false);
@@ -308,3 +315,183 @@ fn proxy_cpi_guard_enable_and_disable_multisig() -> TestResult<()> {
 
     Ok(())
 }
+
+/// Create a mint with Admin as the mint authority
+fn create_mint(app: &mut App) -> TestResult<Pubkey> {
+    let (_, mint_kp) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint = mint_kp.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        DECIMALS,
+        &AppUser::Admin.pubkey().to_bytes(),
+        None,
+    )?;
+
+    Ok(mint)
+}
+
+/// Create a token account of Alice holding `amount`, with the CPI Guard enabled
+fn create_guarded_account(app: &mut App, mint: &Pubkey, amount: u64) -> TestResult<Pubkey> {
+    let (_, account_kp) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey().to_bytes(),
+        mint,
+        &[ExtensionType::CpiGuard],
+    )?;
+    let account = account_kp.pubkey().to_bytes();
+
+    if amount != 0 {
+        app.token_2022_try_mint_to(Target::Spl, AppUser::Admin, mint, &account, amount)?;
+    }
+    app.token_2022_try_enable_cpi_guard(Target::Spl, AppUser::Alice, &account)?;
+
+    Ok(account)
+}
+
+#[test]
+fn proxy_cpi_guard_blocks_owner_operations() -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = &create_mint(&mut app)?;
+    let account = &create_guarded_account(&mut app, mint, 1_000)?;
+    let bob = &AppUser::Bob.pubkey().to_bytes();
+    let (_, bob_account_kp) =
+        app.token_2022_try_create_and_init_token_account(AppUser::Admin, bob, mint, &[])?;
+    let bob_account = &bob_account_kp.pubkey().to_bytes();
+
+    // the owner signing is passed through the proxy, but Token-2022 is invoked via CPI
+    assert_eq!(
+        app.token_2022_try_transfer_checked(
+            Target::Proxy,
+            AppUser::Alice,
+            account,
+            mint,
+            bob_account,
+            100,
+            DECIMALS,
+        )
+        .unwrap_err(),
+        TestError::from_token_error(TokenError::CpiGuardTransferBlocked)
+    );
+    assert_eq!(
+        app.token_2022_try_burn(Target::Proxy, AppUser::Alice, account, mint, 100)
+            .unwrap_err(),
+        TestError::from_token_error(TokenError::CpiGuardBurnBlocked)
+    );
+    assert_eq!(
+        app.token_2022_try_approve(Target::Proxy, AppUser::Alice, account, bob, 100)
+            .unwrap_err(),
+        TestError::from_token_error(TokenError::CpiGuardApproveBlocked)
+    );
+
+    // an owner change is reported as a blocked authority change in CPI
+    assert_eq!(
+        app.token_2022_try_set_authority(
+            Target::Proxy,
+            AppUser::Alice,
+            account,
+            AuthorityType::AccountOwner,
+            Some(bob),
+        )
+        .unwrap_err(),
+        TestError::from_token_error(TokenError::CpiGuardSetAuthorityBlocked)
+    );
+    assert_eq!(
+        app.token_2022_try_set_authority(
+            Target::Spl,
+            AppUser::Alice,
+            account,
+            AuthorityType::AccountOwner,
+            Some(bob),
+        )
+        .unwrap_err(),
+        TestError::from_token_error(TokenError::CpiGuardOwnerChangeBlocked)
+    );
+    assert_eq!(
+        app.token_2022_try_set_authority(
+            Target::Proxy,
+            AppUser::Alice,
+            account,
+            AuthorityType::CloseAccount,
+            Some(bob),
+        )
+        .unwrap_err(),
+        TestError::from_token_error(TokenError::CpiGuardSetAuthorityBlocked)
+    );
+
+    // the balance is checked first, so the account to close is empty
+    let empty_account = &create_guarded_account(&mut app, mint, 0)?;
+    assert_eq!(
+        app.token_2022_try_close_account(Target::Proxy, AppUser::Alice, empty_account, bob)
+            .unwrap_err(),
+        TestError::from_token_error(TokenError::CpiGuardCloseAccountBlocked)
+    );
+
+    // the account is left untouched
+    let state = app.token_2022_query_token_account(Target::Spl, account)?;
+    assert_eq!(state.amount, 1_000);
+    assert_eq!(state.owner.to_bytes(), AppUser::Alice.pubkey().to_bytes());
+    assert!(state.delegate.is_none());
+    assert!(state.close_authority.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn proxy_cpi_guard_allows_delegate_and_owner_destination() -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = &create_mint(&mut app)?;
+    let account = &create_guarded_account(&mut app, mint, 1_000)?;
+    let alice = &AppUser::Alice.pubkey().to_bytes();
+    let bob = &AppUser::Bob.pubkey().to_bytes();
+    let (_, bob_account_kp) =
+        app.token_2022_try_create_and_init_token_account(AppUser::Admin, bob, mint, &[])?;
+    let bob_account = &bob_account_kp.pubkey().to_bytes();
+
+    // the owner approves and transfers outside of CPI
+    app.token_2022_try_approve(Target::Spl, AppUser::Alice, account, bob, 400)?;
+    app.token_2022_try_transfer_checked(
+        Target::Spl,
+        AppUser::Alice,
+        account,
+        mint,
+        bob_account,
+        500,
+        DECIMALS,
+    )?;
+
+    // a delegate isn't blocked in CPI
+    app.token_2022_try_transfer_checked(
+        Target::Proxy,
+        AppUser::Bob,
+        account,
+        mint,
+        bob_account,
+        400,
+        DECIMALS,
+    )?;
+    app.token_2022_try_burn(Target::Spl, AppUser::Alice, account, mint, 100)?;
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Spl, bob_account)?
+            .amount,
+        900
+    );
+
+    // neither is removing the close authority nor closing to the owner
+    app.token_2022_try_set_authority(
+        Target::Proxy,
+        AppUser::Alice,
+        account,
+        AuthorityType::CloseAccount,
+        None,
+    )?;
+    app.token_2022_try_close_account(Target::Proxy, AppUser::Alice, account, alice)?;
+    assert!(app
+        .litesvm
+        .get_account(&pin_to_sol_pubkey(account))
+        .is_none_or(|x| x.lamports == 0));
+
+    Ok(())
+}
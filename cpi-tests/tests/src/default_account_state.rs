@@ -4,10 +4,12 @@ use {
             default_account_state::Token2022DefaultAccountStateExtension,
             initialize_mint::Token2022InitializeMintExtension,
             initialize_multisig::Token2022InitializeMultisigExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
         },
         suite::{
             core::App,
-            types::{AppUser, PinPubkey, Target, TestResult},
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
         },
     },
     pretty_assertions::assert_eq,
@@ -426,3 +428,116 @@ fn proxy_initialize_and_update_default_account_state_multisig() -> TestResult<()
 
     Ok(())
 }
+
+/// Create and initialize a token account owned by `owner`, routing the initialization
+/// through `target`
+fn create_token_account(
+    app: &mut App,
+    target: Target,
+    mint: &[u8; 32],
+    owner: AppUser,
+) -> TestResult<[u8; 32]> {
+    let (_, token_account_keypair) =
+        app.token_2022_try_create_token_account(AppUser::Admin, None, None)?;
+    let token_account = token_account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_token_account(
+        target,
+        AppUser::Admin,
+        &token_account,
+        mint,
+        &owner.pubkey(),
+    )?;
+
+    Ok(token_account)
+}
+
+fn default_account_state_applies_to_new_accounts(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::DefaultAccountState]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_default_account_state(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        AccountState::Frozen,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        Some(&AppUser::Admin.pubkey()),
+    )?;
+
+    // accounts are frozen by default
+    let alice_account = create_token_account(&mut app, target, mint_pubkey, AppUser::Alice)?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .state,
+        AccountState::Frozen
+    );
+
+    let res = app
+        .token_2022_try_mint_to(target, AppUser::Admin, mint_pubkey, &alice_account, 1_000)
+        .unwrap_err();
+
+    // TokenError::AccountFrozen
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x11".to_string(),
+            index: None,
+        }
+    );
+
+    app.token_2022_try_update_default_account_state(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        AccountState::Initialized,
+    )?;
+
+    // the update affects only accounts created later
+    let bob_account = create_token_account(&mut app, target, mint_pubkey, AppUser::Bob)?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &bob_account)?
+            .state,
+        AccountState::Initialized
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .state,
+        AccountState::Frozen
+    );
+
+    app.token_2022_try_mint_to(target, AppUser::Admin, mint_pubkey, &bob_account, 1_000)?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &bob_account)?
+            .amount,
+        1_000
+    );
+
+    Ok(())
+}
+
+#[test]
+fn default_account_state_applies_to_new_accounts_spl() -> TestResult<()> {
+    default_account_state_applies_to_new_accounts(Target::Spl)
+}
+
+#[test]
+fn default_account_state_applies_to_new_accounts_proxy() -> TestResult<()> {
+    default_account_state_applies_to_new_accounts(Target::Proxy)
+}
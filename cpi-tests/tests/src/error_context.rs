@@ -0,0 +1,102 @@
+use {
+    crate::helpers::suite::{
+        core::{App, ProgramId},
+        rng::new_keypair,
+        types::{AppUser, SolPubkey},
+    },
+    pinocchio::program_error::ProgramError,
+    pinocchio_token_2022::error::TokenError,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    spl_token_2022_interface::error::TokenError as SplTokenError,
+};
+
+/// Send the instruction and return the logs of the failed transaction
+fn send_failing_ix(app: &mut App, sender: AppUser, ix: Instruction) -> Vec<String> {
+    app.litesvm.expire_blockhash();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&sender.pubkey()),
+        &[sender.keypair()],
+        app.litesvm.latest_blockhash(),
+    );
+
+    app.litesvm
+        .send_transaction(transaction)
+        .expect_err("The transaction must fail")
+        .meta
+        .logs
+}
+
+#[test]
+fn proxy_logs_failed_extension_instruction() {
+    let mut app = App::new(false);
+    let ProgramId {
+        token_2022_program,
+        token_2022_proxy,
+        ..
+    } = app.program_id;
+
+    // MemoTransfer has no instruction 5
+    let ix = Instruction {
+        program_id: token_2022_proxy,
+        accounts: vec![
+            AccountMeta::new(new_keypair().pubkey(), false),
+            AccountMeta::new_readonly(AppUser::Alice.pubkey(), true),
+            AccountMeta::new_readonly(token_2022_program, false),
+        ],
+        data: vec![30, 5],
+    };
+
+    let logs = send_failing_ix(&mut app, AppUser::Alice, ix);
+
+    assert!(logs
+        .iter()
+        .any(|x| x.contains("❌ MemoTransferExtension (instruction 5): InvalidInstructionData")));
+}
+
+#[test]
+fn proxy_logs_failed_proxy_instruction() {
+    let mut app = App::new(false);
+    let ProgramId {
+        token_2022_program,
+        token_2022_proxy,
+        ..
+    } = app.program_id;
+
+    // the accounts are missing
+    let ix = Instruction {
+        program_id: token_2022_proxy,
+        accounts: vec![AccountMeta::new_readonly(token_2022_program, false)],
+        data: vec![token_2022_proxy::CREATE_ASSOCIATED_TOKEN_ACCOUNT, 0],
+    };
+
+    let logs = send_failing_ix(&mut app, AppUser::Alice, ix);
+
+    assert!(logs
+        .iter()
+        .any(|x| x.contains("❌ CreateAssociatedTokenAccount: NotEnoughAccountKeys")));
+}
+
+#[test]
+fn token_error_codes() {
+    for (error, spl_error) in [
+        (TokenError::NotRentExempt, SplTokenError::NotRentExempt),
+        (TokenError::NonTransferable, SplTokenError::NonTransferable),
+        (
+            TokenError::CpiGuardTransferBlocked,
+            SplTokenError::CpiGuardTransferBlocked,
+        ),
+        (TokenError::MintPaused, SplTokenError::MintPaused),
+    ] {
+        assert_eq!(
+            ProgramError::from(error),
+            ProgramError::Custom(spl_error.clone() as u32)
+        );
+        assert_eq!(TokenError::try_from(spl_error as u32), Ok(error));
+    }
+
+    assert!(TokenError::try_from(u32::MAX).is_err());
+}
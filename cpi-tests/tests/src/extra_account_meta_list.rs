@@ -0,0 +1,222 @@
+use {
+    pinocchio::program_error::ProgramError,
+    pinocchio_token_2022::transfer_hook_interface::{
+        ExtraAccountMeta, ExtraAccountMetaList, Seed, EXECUTE_DISCRIMINATOR,
+        EXTERNAL_PDA_DISCRIMINATOR_START,
+    },
+    pretty_assertions::assert_eq,
+};
+
+/// Discriminator of another TLV entry stored before the `Execute` metas
+const OTHER_DISCRIMINATOR: [u8; 8] = [1; 8];
+
+fn fixed_meta(address: u8, is_signer: bool, is_writable: bool) -> ExtraAccountMeta {
+    ExtraAccountMeta {
+        discriminator: 0,
+        address_config: [address; 32],
+        is_signer: is_signer.into(),
+        is_writable: is_writable.into(),
+    }
+}
+
+fn seeds_meta(seeds: &[u8]) -> ExtraAccountMeta {
+    let mut address_config = [0; 32];
+    address_config[..seeds.len()].copy_from_slice(seeds);
+
+    ExtraAccountMeta {
+        discriminator: 1,
+        address_config,
+        is_signer: 0,
+        is_writable: 1,
+    }
+}
+
+/// TLV entry holding the number of metas followed by the metas
+fn encode(discriminator: [u8; 8], metas: &[ExtraAccountMeta]) -> Vec<u8> {
+    let mut value = (metas.len() as u32).to_le_bytes().to_vec();
+    for meta in metas {
+        value.push(meta.discriminator);
+        value.extend_from_slice(&meta.address_config);
+        value.extend_from_slice(&[meta.is_signer, meta.is_writable]);
+    }
+
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    data.extend_from_slice(&value);
+    data
+}
+
+#[test]
+fn extra_account_meta_list_from_bytes() {
+    let metas = [
+        fixed_meta(1, false, false),
+        fixed_meta(2, true, false),
+        seeds_meta(&[1, 3, b'f', b'o', b'o', 3, 0]),
+    ];
+    assert_eq!(ExtraAccountMeta::LEN, 35);
+
+    let data = encode(EXECUTE_DISCRIMINATOR, &metas);
+    let list = ExtraAccountMetaList::from_bytes(&data).unwrap();
+    assert_eq!(list.metas(), &metas);
+    assert!(list.metas()[1].is_signer());
+    assert!(!list.metas()[1].is_writable());
+
+    // the `Execute` entry is found after other entries
+    let data = [encode(OTHER_DISCRIMINATOR, &metas[..1]), data].concat();
+    assert_eq!(
+        ExtraAccountMetaList::from_bytes(&data).unwrap().metas(),
+        &metas
+    );
+
+    // account data is allocated with some padding
+    let data = [data, vec![0; 16]].concat();
+    assert_eq!(ExtraAccountMetaList::from_bytes(&data).unwrap().len(), 3);
+
+    let data = encode(EXECUTE_DISCRIMINATOR, &[]);
+    assert!(ExtraAccountMetaList::from_bytes(&data).unwrap().is_empty());
+}
+
+#[test]
+fn extra_account_meta_list_from_bytes_invalid() {
+    let metas = [fixed_meta(1, false, false), fixed_meta(2, false, true)];
+
+    // no `Execute` entry
+    let data = encode(OTHER_DISCRIMINATOR, &metas);
+    assert_eq!(
+        ExtraAccountMetaList::from_bytes(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+
+    // truncated value
+    let data = encode(EXECUTE_DISCRIMINATOR, &metas);
+    assert_eq!(
+        ExtraAccountMetaList::from_bytes(&data[..data.len() - 1]).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+
+    // the count exceeds the stored metas
+    let mut data = encode(EXECUTE_DISCRIMINATOR, &metas);
+    data[12] = 3;
+    assert_eq!(
+        ExtraAccountMetaList::from_bytes(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+}
+
+#[test]
+fn extra_account_meta_seeds() {
+    let meta = seeds_meta(&[1, 3, b'f', b'o', b'o', 2, 8, 8, 3, 0, 4, 2, 32, 8]);
+
+    assert_eq!(
+        meta.seeds().collect::<Result<Vec<_>, _>>().unwrap(),
+        [
+            Seed::Literal { bytes: b"foo" },
+            Seed::InstructionData {
+                index: 8,
+                length: 8
+            },
+            Seed::AccountKey { index: 0 },
+            Seed::AccountData {
+                account_index: 2,
+                data_index: 32,
+                length: 8
+            },
+        ]
+    );
+
+    // the literal exceeds the config
+    let meta = seeds_meta(&[1, 32, b'f', b'o', b'o']);
+    let seeds: Vec<_> = meta.seeds().collect();
+    assert_eq!(seeds, [Err(ProgramError::InvalidAccountData)]);
+
+    // unknown seed type
+    let meta = seeds_meta(&[3, 0, 5, 1]);
+    let seeds: Vec<_> = meta.seeds().collect();
+    assert_eq!(
+        seeds,
+        [
+            Ok(Seed::AccountKey { index: 0 }),
+            Err(ProgramError::InvalidAccountData)
+        ]
+    );
+}
+
+#[test]
+fn extra_account_meta_new_with_seeds() {
+    let seeds = [
+        Seed::Literal { bytes: b"foo" },
+        Seed::InstructionData {
+            index: 8,
+            length: 8,
+        },
+        Seed::AccountKey { index: 0 },
+        Seed::AccountData {
+            account_index: 2,
+            data_index: 32,
+            length: 8,
+        },
+    ];
+
+    let meta = ExtraAccountMeta::new_with_seeds(&seeds, false, true).unwrap();
+    assert_eq!(
+        meta,
+        seeds_meta(&[1, 3, b'f', b'o', b'o', 2, 8, 8, 3, 0, 4, 2, 32, 8])
+    );
+    assert_eq!(meta.seeds().collect::<Result<Vec<_>, _>>().unwrap(), seeds);
+
+    let meta = ExtraAccountMeta::new_external_pda_with_seeds(6, &seeds, true, false).unwrap();
+    assert_eq!(meta.discriminator, EXTERNAL_PDA_DISCRIMINATOR_START + 6);
+    assert!(meta.is_signer() && !meta.is_writable());
+    assert_eq!(meta.seeds().collect::<Result<Vec<_>, _>>().unwrap(), seeds);
+
+    // the packed seeds take 33 bytes
+    let seeds = [
+        Seed::Literal { bytes: &[7; 29] },
+        Seed::AccountKey { index: 0 },
+    ];
+    assert_eq!(
+        ExtraAccountMeta::new_with_seeds(&seeds, false, false).unwrap_err(),
+        ProgramError::MaxSeedLengthExceeded
+    );
+
+    assert_eq!(
+        ExtraAccountMeta::new_with_pubkey(&[5; 32], true, true),
+        ExtraAccountMeta {
+            discriminator: 0,
+            address_config: [5; 32],
+            is_signer: 1,
+            is_writable: 1,
+        }
+    );
+}
+
+#[test]
+fn extra_account_meta_list_write() {
+    let metas = [
+        fixed_meta(1, true, false),
+        seeds_meta(&[1, 3, b'f', b'o', b'o', 3, 0]),
+    ];
+    let len = ExtraAccountMetaList::size_of(metas.len());
+    assert_eq!(len, 12 + 4 + 2 * 35);
+
+    let mut data = vec![0xff; len + 8];
+    ExtraAccountMetaList::write(&mut data, &metas).unwrap();
+    assert_eq!(data[..len], encode(EXECUTE_DISCRIMINATOR, &metas));
+    assert_eq!(data[len..], [0xff; 8]);
+    assert_eq!(
+        ExtraAccountMetaList::from_bytes(&data).unwrap().metas(),
+        &metas
+    );
+
+    // an update with fewer metas
+    ExtraAccountMetaList::write(&mut data, &metas[1..]).unwrap();
+    assert_eq!(
+        ExtraAccountMetaList::from_bytes(&data).unwrap().metas(),
+        &metas[1..]
+    );
+
+    assert_eq!(
+        ExtraAccountMetaList::write(&mut data[..len - 1], &metas).unwrap_err(),
+        ProgramError::AccountDataTooSmall
+    );
+}
@@ -0,0 +1,287 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+            token_metadata::Token2022TokenMetadataExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+            transfer_hook::Token2022TransferHookExtension,
+        },
+        suite::{
+            core::{extension::get_account_data, App},
+            fixtures::{load_account_fixtures, MAINNET_FIXTURES_DIR},
+            types::{pin_to_sol_pubkey, AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pinocchio::pubkey::Pubkey,
+    pinocchio_token_2022::{
+        client::{account_type, extension_types, AccountType},
+        extension::{
+            consts::ExtensionType as PinExtensionType,
+            cpi_guard::CpiGuard,
+            default_account_state::state::DefaultAccountStateConfig,
+            immutable_owner::ImmutableOwner,
+            mint_close_authority::MintCloseAuthority,
+            non_transferable::{NonTransferable, NonTransferableAccount},
+            pausable::PausableConfig,
+            permanent_delegate::PermanentDelegate,
+            tlv::{get_extension_from_bytes, Extension, BASE_ACCOUNT_LENGTH},
+            token_metadata::TokenMetadata as PinTokenMetadata,
+            transfer_fee::TransferFeeConfig as PinTransferFeeConfig,
+            transfer_hook::{TransferHook as PinTransferHook, TransferHookAccount},
+        },
+        state::{Mint as PinMint, TokenAccount as PinTokenAccount},
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::{
+        extension::{
+            transfer_fee::TransferFeeConfig, transfer_hook::TransferHook, BaseStateWithExtensions,
+            ExtensionType, StateWithExtensions,
+        },
+        state::{Account, Mint},
+    },
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+    std::path::Path,
+};
+
+const HOOK_PROGRAM_ID: Pubkey = [7; 32];
+
+/// A fixed-size extension is read by the pinocchio parser wherever it's stored
+fn assert_extension<T: Extension>(name: &str, data: &[u8], spl_extension_types: &[u16]) {
+    assert_eq!(
+        get_extension_from_bytes::<T>(data).is_some(),
+        spl_extension_types.contains(&(T::TYPE as u16)),
+        "{}: {:?}",
+        name,
+        T::TYPE
+    );
+}
+
+/// Check that the pinocchio parsers read a Token-2022 mint or token account the way the
+/// SPL parsers do
+fn assert_parsers_agree(name: &str, data: &[u8]) {
+    let (expected_account_type, spl_extension_types) =
+        if let Ok(mint) = StateWithExtensions::<Mint>::unpack(data) {
+            let pin_mint = PinMint::from_bytes(data).unwrap();
+            assert_eq!(pin_mint.supply(), mint.base.supply, "{}", name);
+            assert_eq!(pin_mint.decimals(), mint.base.decimals, "{}", name);
+
+            if let Ok(metadata) = mint.get_variable_len_extension::<TokenMetadata>() {
+                let pin_metadata = PinTokenMetadata::from_bytes(data).unwrap();
+                assert_eq!(pin_metadata.mint, &metadata.mint.to_bytes(), "{}", name);
+                assert_eq!(pin_metadata.name, metadata.name, "{}", name);
+                assert_eq!(pin_metadata.symbol, metadata.symbol, "{}", name);
+                assert_eq!(pin_metadata.uri, metadata.uri, "{}", name);
+                assert_eq!(
+                    pin_metadata.additional_metadata_len(),
+                    metadata.additional_metadata.len(),
+                    "{}",
+                    name
+                );
+            }
+
+            if let Ok(hook) = mint.get_extension::<TransferHook>() {
+                let pin_hook = get_extension_from_bytes::<PinTransferHook>(data).unwrap();
+                assert_eq!(
+                    pin_hook.program_id().copied(),
+                    Option::<solana_address::Address>::from(hook.program_id).map(|x| x.to_bytes()),
+                    "{}",
+                    name
+                );
+            }
+
+            if let Ok(config) = mint.get_extension::<TransferFeeConfig>() {
+                let pin_config = get_extension_from_bytes::<PinTransferFeeConfig>(data).unwrap();
+                assert_eq!(
+                    pin_config.withheld_amount(),
+                    u64::from(config.withheld_amount),
+                    "{}",
+                    name
+                );
+            }
+
+            (AccountType::Mint, mint.get_extension_types().unwrap())
+        } else {
+            let account = StateWithExtensions::<Account>::unpack(data).unwrap();
+
+            let pin_account = PinTokenAccount::from_bytes(data).unwrap();
+            assert_eq!(
+                pin_account.mint(),
+                &account.base.mint.to_bytes(),
+                "{}",
+                name
+            );
+            assert_eq!(
+                pin_account.owner(),
+                &account.base.owner.to_bytes(),
+                "{}",
+                name
+            );
+            assert_eq!(pin_account.amount(), account.base.amount, "{}", name);
+
+            (AccountType::Account, account.get_extension_types().unwrap())
+        };
+
+    // a base mint or token account has no account type
+    assert_eq!(
+        account_type(data),
+        (data.len() > BASE_ACCOUNT_LENGTH).then_some(expected_account_type),
+        "{}",
+        name
+    );
+
+    // the extensions unknown to pinocchio are skipped
+    let spl_extension_types: Vec<u16> = spl_extension_types
+        .into_iter()
+        .map(|x| x as u16)
+        .filter(|x| PinExtensionType::try_from(*x).is_ok())
+        .collect();
+    let pin_extension_types: Vec<u16> = extension_types(data).map(|x| x as u16).collect();
+    assert_eq!(pin_extension_types, spl_extension_types, "{}", name);
+
+    assert_extension::<MintCloseAuthority>(name, data, &spl_extension_types);
+    assert_extension::<PinTransferFeeConfig>(name, data, &spl_extension_types);
+    assert_extension::<DefaultAccountStateConfig>(name, data, &spl_extension_types);
+    assert_extension::<NonTransferable>(name, data, &spl_extension_types);
+    assert_extension::<PermanentDelegate>(name, data, &spl_extension_types);
+    assert_extension::<PinTransferHook>(name, data, &spl_extension_types);
+    assert_extension::<PausableConfig>(name, data, &spl_extension_types);
+    assert_extension::<ImmutableOwner>(name, data, &spl_extension_types);
+    assert_extension::<NonTransferableAccount>(name, data, &spl_extension_types);
+    assert_extension::<CpiGuard>(name, data, &spl_extension_types);
+    assert_extension::<TransferHookAccount>(name, data, &spl_extension_types);
+}
+
+/// Create a mint storing its own metadata next to a transfer fee and a transfer hook,
+/// and a token account of Alice holding some tokens
+fn prepare_accounts(app: &mut App) -> TestResult<(Pubkey, Pubkey)> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::TransferHook,
+            ExtensionType::MetadataPointer,
+        ]),
+    )?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_transfer_fee_config(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&AppUser::Admin.pubkey()),
+        100,
+        1_000,
+    )?;
+    app.token_2022_try_initialize_transfer_hook(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&HOOK_PROGRAM_ID),
+    )?;
+    app.token_2022_try_initialize_metadata_pointer(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&mint),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.token_2022_try_initialize_token_metadata(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        "Fixture Token",
+        "FIX",
+        "https://example.com/fixture.json",
+    )?;
+    app.token_2022_try_update_token_metadata_field(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        Field::Key("color".to_string()),
+        "blue",
+    )?;
+
+    let (_, account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint,
+        &[
+            ExtensionType::TransferFeeAmount,
+            ExtensionType::TransferHookAccount,
+        ],
+    )?;
+    let account = account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(Target::Spl, AppUser::Admin, &mint, &account, 1_000)?;
+
+    Ok((mint, account))
+}
+
+#[test]
+fn account_fixtures_round_trip() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, account) = prepare_accounts(&mut app)?;
+
+    let mint_fixture = app.get_account_fixture(&pin_to_sol_pubkey(&mint))?;
+    let account_fixture = app.get_account_fixture(&pin_to_sol_pubkey(&account))?;
+
+    // unique per test run, the mint address being random
+    let dir = std::env::temp_dir().join(format!("account-fixtures-{}", mint_fixture.pubkey));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    mint_fixture.save_json(&dir.join("mint.json"))?;
+    account_fixture.save_bincode(&dir)?;
+
+    let fixtures = load_account_fixtures(&dir)?;
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    // the files are sorted by name, the bincode one being named after a random address
+    assert_eq!(fixtures.len(), 2);
+    assert!(fixtures.contains(&mint_fixture));
+    assert!(fixtures.contains(&account_fixture));
+
+    // loaded into a fresh app, the accounts are parsed the same way
+    let mut app = App::new(false);
+    for fixture in &fixtures {
+        app.set_account_fixture(fixture)?;
+    }
+
+    for pubkey in [mint, account] {
+        assert_parsers_agree("round trip", &get_account_data(&app, &pubkey)?);
+    }
+
+    Ok(())
+}
+
+/// The mainnet accounts dumped into `MAINNET_FIXTURES_DIR`, see the README there
+#[test]
+fn mainnet_fixtures_are_parsed() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    for fixture in load_account_fixtures(Path::new(MAINNET_FIXTURES_DIR))? {
+        app.set_account_fixture(&fixture)?;
+
+        let name = fixture.pubkey.to_string();
+        let data = get_account_data(&app, &fixture.pubkey.to_bytes())?;
+        assert_parsers_agree(&name, &data);
+    }
+
+    Ok(())
+}
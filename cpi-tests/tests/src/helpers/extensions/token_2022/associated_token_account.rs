@@ -0,0 +1,223 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{
+            pin_to_sol_pubkey, sol_to_pin_pubkey, AppUser, SolPubkey, Target, TestError, TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    pinocchio_token_2022::extension::{consts::ExtensionType, tlv::get_extension_data_from_bytes},
+    spl_associated_token_account::instruction::{
+        create_associated_token_account_idempotent, recover_nested,
+    },
+    spl_token_2022_interface::{extension::StateWithExtensions, state::Account},
+    token_2022_proxy::{CREATE_ASSOCIATED_TOKEN_ACCOUNT, RECOVER_NESTED_ASSOCIATED_TOKEN_ACCOUNT},
+};
+
+pub trait Token2022AssociatedTokenAccountExtension {
+    /// Returns the Token-2022 associated token account address of `owner` for `mint`
+    fn token_2022_query_associated_token_address(&self, owner: AppUser, mint: &Pubkey) -> Pubkey;
+
+    /// Creates the associated token account through the proxy.
+    /// `owner` must sign as well if `require_memo` is set
+    fn token_2022_try_create_associated_token_account(
+        &mut self,
+        sender: AppUser,
+        owner: AppUser,
+        mint: &Pubkey,
+        require_memo: bool,
+    ) -> TestResult<(TransactionMetadata, Pubkey)>;
+
+    /// Creates the associated token account with the associated token program directly,
+    /// succeeds if it already exists. The account gets the extensions required by the mint
+    fn token_2022_try_create_associated_token_account_idempotent(
+        &mut self,
+        sender: AppUser,
+        owner: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<(TransactionMetadata, Pubkey)>;
+
+    /// Moves the tokens of the `nested_mint` associated token account owned by the `owner_mint`
+    /// associated token account of `wallet` to the `nested_mint` associated token account
+    /// of `wallet` and closes the nested account. `wallet` sends the transaction
+    fn token_2022_try_recover_nested_associated_token_account(
+        &mut self,
+        target: Target,
+        wallet: AppUser,
+        owner_mint: &Pubkey,
+        nested_mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Returns the token amount of the token account
+    fn token_2022_query_token_balance(&self, token_account: &Pubkey) -> TestResult<u64>;
+
+    /// Returns the token amount of the associated token account of `owner` for `mint`
+    fn token_2022_query_associated_token_balance(
+        &self,
+        owner: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<u64>;
+
+    /// Returns `true` if incoming transfers to the token account require a memo
+    fn token_2022_query_require_memo(&self, token_account: &Pubkey) -> TestResult<bool>;
+}
+
+impl Token2022AssociatedTokenAccountExtension for App {
+    fn token_2022_query_associated_token_address(&self, owner: AppUser, mint: &Pubkey) -> Pubkey {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        sol_to_pin_pubkey(
+            &spl_associated_token_account::get_associated_token_address_with_program_id(
+                &owner.pubkey(),
+                &pin_to_sol_pubkey(mint),
+                &token_2022_program,
+            ),
+        )
+    }
+
+    fn token_2022_try_create_associated_token_account(
+        &mut self,
+        sender: AppUser,
+        owner: AppUser,
+        mint: &Pubkey,
+        require_memo: bool,
+    ) -> TestResult<(TransactionMetadata, Pubkey)> {
+        let ProgramId {
+            system_program,
+            token_2022_program,
+            associated_token_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let associated_token_account = self.token_2022_query_associated_token_address(owner, mint);
+
+        let mut signers = vec![sender.keypair()];
+        if require_memo && owner != sender {
+            signers.push(owner.keypair());
+        }
+
+        let ix = solana_instruction::Instruction {
+            program_id: token_2022_proxy,
+            accounts: vec![
+                solana_instruction::AccountMeta::new(sender.pubkey(), true),
+                solana_instruction::AccountMeta::new(
+                    pin_to_sol_pubkey(&associated_token_account),
+                    false,
+                ),
+                solana_instruction::AccountMeta::new_readonly(owner.pubkey(), require_memo),
+                solana_instruction::AccountMeta::new_readonly(pin_to_sol_pubkey(mint), false),
+                solana_instruction::AccountMeta::new_readonly(system_program, false),
+                solana_instruction::AccountMeta::new_readonly(associated_token_program, false),
+                solana_instruction::AccountMeta::new_readonly(token_2022_program, false),
+            ],
+            data: vec![CREATE_ASSOCIATED_TOKEN_ACCOUNT, require_memo as u8],
+        };
+
+        let tx_metadata = send_tx(&mut self.litesvm, &[ix], &signers, self.is_log_displayed)?;
+
+        Ok((tx_metadata, associated_token_account))
+    }
+
+    fn token_2022_try_create_associated_token_account_idempotent(
+        &mut self,
+        sender: AppUser,
+        owner: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<(TransactionMetadata, Pubkey)> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = create_associated_token_account_idempotent(
+            &sender.pubkey(),
+            &owner.pubkey(),
+            &pin_to_sol_pubkey(mint),
+            &token_2022_program,
+        );
+
+        let tx_metadata = send_tx(
+            &mut self.litesvm,
+            &[ix],
+            &[sender.keypair()],
+            self.is_log_displayed,
+        )?;
+
+        Ok((
+            tx_metadata,
+            self.token_2022_query_associated_token_address(owner, mint),
+        ))
+    }
+
+    fn token_2022_try_recover_nested_associated_token_account(
+        &mut self,
+        target: Target,
+        wallet: AppUser,
+        owner_mint: &Pubkey,
+        nested_mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            associated_token_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let mut ix = recover_nested(
+            &wallet.pubkey(),
+            &pin_to_sol_pubkey(owner_mint),
+            &pin_to_sol_pubkey(nested_mint),
+            &token_2022_program,
+        );
+
+        if let Target::Proxy = target {
+            ix.program_id = token_2022_proxy;
+            ix.accounts
+                .push(solana_instruction::AccountMeta::new_readonly(
+                    associated_token_program,
+                    false,
+                ));
+            ix.data = vec![RECOVER_NESTED_ASSOCIATED_TOKEN_ACCOUNT];
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix],
+            &[wallet.keypair()],
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_query_token_balance(&self, token_account: &Pubkey) -> TestResult<u64> {
+        let data = &get_account_data(self, token_account)?;
+
+        StateWithExtensions::<Account>::unpack(data)
+            .map(|x| x.base.amount)
+            .map_err(TestError::from_raw_error)
+    }
+
+    fn token_2022_query_associated_token_balance(
+        &self,
+        owner: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<u64> {
+        self.token_2022_query_token_balance(
+            &self.token_2022_query_associated_token_address(owner, mint),
+        )
+    }
+
+    fn token_2022_query_require_memo(&self, token_account: &Pubkey) -> TestResult<bool> {
+        let data = &get_account_data(self, token_account)?;
+
+        Ok(matches!(
+            get_extension_data_from_bytes(ExtensionType::MemoTransfer, data),
+            Some([1])
+        ))
+    }
+}
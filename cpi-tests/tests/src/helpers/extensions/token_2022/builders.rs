@@ -0,0 +1,187 @@
+use {
+    crate::helpers::suite::{
+        core::{extension::send_tx, App, ProgramId},
+        rng::new_keypair,
+        types::{pin_to_sol_pubkey, AppUser, SolPubkey, TestResult},
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    solana_signer::Signer,
+    token_2022_proxy::{
+        CREATE_MINT_WITH_EXTENSIONS, INITIALIZE_ACCOUNT_THAWED, INITIALIZE_ACCOUNT_WITH_EXTENSIONS,
+        REALLOCATE_FOR_MINT,
+    },
+};
+
+pub trait Token2022BuildersExtension {
+    /// Creates a non-transferable mint which `sender` can close through the proxy, with
+    /// `MintBuilder` and `create_mint_with_extensions`. `sender` is the mint authority and
+    /// pays for the account
+    fn token_2022_try_create_mint_with_builder(
+        &mut self,
+        sender: AppUser,
+        decimals: u8,
+    ) -> TestResult<(TransactionMetadata, Pubkey)>;
+
+    /// Initializes the token account of `owner`, created with the space of `ImmutableOwner`
+    /// and `MemoTransfer`, with both extensions through the proxy, with `AccountBuilder`.
+    /// `owner` sends the transaction
+    fn token_2022_try_initialize_account_with_builder(
+        &mut self,
+        owner: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Adds the account extensions required by the mint and missing from the token account
+    /// through the proxy, with `ReallocateForMint`. `owner` pays for the reallocation
+    fn token_2022_try_reallocate_for_mint(
+        &mut self,
+        owner: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Initializes the created token account of `owner` through the proxy and thaws it if
+    /// `sender` is the freeze authority of a mint freezing new accounts, with
+    /// `InitializeAccountThawed`
+    fn token_2022_try_initialize_account_thawed(
+        &mut self,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+        owner: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+}
+
+impl Token2022BuildersExtension for App {
+    fn token_2022_try_create_mint_with_builder(
+        &mut self,
+        sender: AppUser,
+        decimals: u8,
+    ) -> TestResult<(TransactionMetadata, Pubkey)> {
+        let ProgramId {
+            system_program,
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let mint_keypair = new_keypair();
+        let signers = &[&sender.keypair(), &mint_keypair];
+
+        let ix = solana_instruction::Instruction {
+            program_id: token_2022_proxy,
+            accounts: vec![
+                solana_instruction::AccountMeta::new(sender.pubkey(), true),
+                solana_instruction::AccountMeta::new(mint_keypair.pubkey(), true),
+                solana_instruction::AccountMeta::new_readonly(sender.pubkey(), false),
+                solana_instruction::AccountMeta::new_readonly(system_program, false),
+                solana_instruction::AccountMeta::new_readonly(token_2022_program, false),
+            ],
+            data: vec![CREATE_MINT_WITH_EXTENSIONS, decimals],
+        };
+
+        let tx_metadata = send_tx(&mut self.litesvm, &[ix], signers, self.is_log_displayed)?;
+
+        Ok((tx_metadata, mint_keypair.pubkey().to_bytes()))
+    }
+
+    fn token_2022_try_initialize_account_with_builder(
+        &mut self,
+        owner: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let ix = solana_instruction::Instruction {
+            program_id: token_2022_proxy,
+            accounts: vec![
+                solana_instruction::AccountMeta::new(pin_to_sol_pubkey(account), false),
+                solana_instruction::AccountMeta::new_readonly(pin_to_sol_pubkey(mint), false),
+                solana_instruction::AccountMeta::new_readonly(owner.pubkey(), true),
+                solana_instruction::AccountMeta::new_readonly(token_2022_program, false),
+            ],
+            data: vec![INITIALIZE_ACCOUNT_WITH_EXTENSIONS],
+        };
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix],
+            &[&owner.keypair()],
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_reallocate_for_mint(
+        &mut self,
+        owner: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            system_program,
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let ix = solana_instruction::Instruction {
+            program_id: token_2022_proxy,
+            accounts: vec![
+                solana_instruction::AccountMeta::new(pin_to_sol_pubkey(account), false),
+                solana_instruction::AccountMeta::new_readonly(pin_to_sol_pubkey(mint), false),
+                solana_instruction::AccountMeta::new(owner.pubkey(), true),
+                solana_instruction::AccountMeta::new_readonly(system_program, false),
+                solana_instruction::AccountMeta::new_readonly(owner.pubkey(), true),
+                solana_instruction::AccountMeta::new_readonly(token_2022_program, false),
+            ],
+            data: vec![REALLOCATE_FOR_MINT],
+        };
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix],
+            &[&owner.keypair()],
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_initialize_account_thawed(
+        &mut self,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+        owner: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let ix = solana_instruction::Instruction {
+            program_id: token_2022_proxy,
+            accounts: vec![
+                solana_instruction::AccountMeta::new(pin_to_sol_pubkey(account), false),
+                solana_instruction::AccountMeta::new_readonly(pin_to_sol_pubkey(mint), false),
+                solana_instruction::AccountMeta::new_readonly(pin_to_sol_pubkey(owner), false),
+                solana_instruction::AccountMeta::new_readonly(sender.pubkey(), true),
+                solana_instruction::AccountMeta::new_readonly(token_2022_program, false),
+            ],
+            data: vec![INITIALIZE_ACCOUNT_THAWED],
+        };
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix],
+            &[&sender.keypair()],
+            self.is_log_displayed,
+        )
+    }
+}
@@ -0,0 +1,418 @@
+use {
+    crate::helpers::suite::{
+        confidential::ConfidentialKeys,
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{
+            pin_pubkey_to_addr, to_optional_non_zero_pubkey, AppUser, SolPubkey, Target, TestError,
+            TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    solana_zk_sdk::encryption::{
+        auth_encryption::AeCiphertext,
+        elgamal::{ElGamal, ElGamalCiphertext},
+        pod::{auth_encryption::PodAeCiphertext, elgamal::PodElGamalPubkey},
+    },
+    spl_token_2022_interface::extension::{
+        confidential_transfer::{
+            instruction, ConfidentialTransferAccount, ConfidentialTransferMint,
+        },
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    spl_token_2022_interface::state::{Account, Mint},
+    spl_token_confidential_transfer_proof_extraction::instruction::{ProofData, ProofLocation},
+    std::num::NonZeroI8,
+};
+
+/// Converts the instructions built by the interface to legacy ones
+macro_rules! to_legacy {
+    ($ixs:expr) => {
+        $ixs.into_iter()
+            .map(|ix| solana_instruction::Instruction {
+                program_id: $crate::helpers::suite::types::addr_to_sol_pubkey(&ix.program_id),
+                accounts: ix
+                    .accounts
+                    .into_iter()
+                    .map(|x| solana_instruction::AccountMeta {
+                        pubkey: $crate::helpers::suite::types::addr_to_sol_pubkey(&x.pubkey),
+                        is_signer: x.is_signer,
+                        is_writable: x.is_writable,
+                    })
+                    .collect(),
+                data: ix.data,
+            })
+            .collect::<Vec<_>>()
+    };
+}
+pub(crate) use to_legacy;
+
+pub trait Token2022ConfidentialTransferExtension {
+    fn token_2022_try_initialize_confidential_transfer_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        authority: Option<&Pubkey>,
+        auto_approve_new_accounts: bool,
+        auditor_elgamal_pubkey: Option<PodElGamalPubkey>,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Configures the account with the ElGamal pubkey of `keys`, proven valid by a proof
+    /// instruction following the configure one
+    fn token_2022_try_configure_account(
+        &mut self,
+        target: Target,
+        owner: AppUser,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        keys: &ConfidentialKeys,
+        maximum_pending_balance_credit_counter: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_confidential_deposit(
+        &mut self,
+        target: Target,
+        owner: AppUser,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Applies the pending balance, `new_available_balance` being encrypted with the AE key
+    /// of `keys` as the new decryptable balance
+    fn token_2022_try_apply_pending_balance(
+        &mut self,
+        target: Target,
+        owner: AppUser,
+        token_account: &Pubkey,
+        keys: &ConfidentialKeys,
+        expected_pending_balance_credit_counter: u64,
+        new_available_balance: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Withdraws `amount` from the available balance, read from the account and decrypted
+    /// with `keys`, along with the equality and range proofs of the remaining balance
+    fn token_2022_try_confidential_withdraw(
+        &mut self,
+        target: Target,
+        owner: AppUser,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        keys: &ConfidentialKeys,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_query_confidential_transfer_mint(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<ConfidentialTransferMint>;
+
+    fn token_2022_query_confidential_transfer_account(
+        &self,
+        target: Target,
+        token_account: &Pubkey,
+    ) -> TestResult<ConfidentialTransferAccount>;
+}
+
+impl Token2022ConfidentialTransferExtension for App {
+    fn token_2022_try_initialize_confidential_transfer_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        authority: Option<&Pubkey>,
+        auto_approve_new_accounts: bool,
+        auditor_elgamal_pubkey: Option<PodElGamalPubkey>,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = instruction::initialize_mint(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            authority.map(pin_pubkey_to_addr),
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, sender, to_legacy!([ix]))
+    }
+
+    fn token_2022_try_configure_account(
+        &mut self,
+        target: Target,
+        owner: AppUser,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        keys: &ConfidentialKeys,
+        maximum_pending_balance_credit_counter: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let proof = keys.pubkey_validity_proof()?;
+
+        let ixs = instruction::configure_account(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(token_account),
+            &pin_pubkey_to_addr(mint),
+            &PodAeCiphertext::from(keys.ae_key.encrypt(0)),
+            maximum_pending_balance_credit_counter,
+            &owner.pubkey().to_bytes().into(),
+            &[],
+            ProofLocation::InstructionOffset(
+                NonZeroI8::new(1).unwrap(),
+                ProofData::InstructionData(&proof),
+            ),
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, owner, to_legacy!(ixs))
+    }
+
+    fn token_2022_try_confidential_deposit(
+        &mut self,
+        target: Target,
+        owner: AppUser,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = instruction::deposit(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(token_account),
+            &pin_pubkey_to_addr(mint),
+            amount,
+            decimals,
+            &owner.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, owner, to_legacy!([ix]))
+    }
+
+    fn token_2022_try_apply_pending_balance(
+        &mut self,
+        target: Target,
+        owner: AppUser,
+        token_account: &Pubkey,
+        keys: &ConfidentialKeys,
+        expected_pending_balance_credit_counter: u64,
+        new_available_balance: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = instruction::apply_pending_balance(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(token_account),
+            expected_pending_balance_credit_counter,
+            &PodAeCiphertext::from(keys.ae_key.encrypt(new_available_balance)),
+            &owner.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, owner, to_legacy!([ix]))
+    }
+
+    fn token_2022_try_confidential_withdraw(
+        &mut self,
+        target: Target,
+        owner: AppUser,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        keys: &ConfidentialKeys,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let state =
+            self.token_2022_query_confidential_transfer_account(Target::Spl, token_account)?;
+
+        let available_balance =
+            ElGamalCiphertext::from_bytes(bytemuck::bytes_of(&state.available_balance))
+                .ok_or(TestError::from_raw_error("Invalid available balance"))?;
+        let decryptable_available_balance =
+            AeCiphertext::from_bytes(bytemuck::bytes_of(&state.decryptable_available_balance))
+                .ok_or(TestError::from_raw_error(
+                    "Invalid decryptable available balance",
+                ))?;
+
+        let remaining_balance = keys
+            .ae_key
+            .decrypt(&decryptable_available_balance)
+            .and_then(|x| x.checked_sub(amount))
+            .ok_or(TestError::from_raw_error("Insufficient available balance"))?;
+        let remaining_balance_ciphertext = &available_balance - &ElGamal::encode(amount);
+
+        // the range proof is about the commitment of the equality proof
+        let equality = keys.equality_proof(&remaining_balance_ciphertext, remaining_balance)?;
+        let range_proof = equality.range_proof(remaining_balance)?;
+
+        let ixs = instruction::withdraw(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(token_account),
+            &pin_pubkey_to_addr(mint),
+            amount,
+            decimals,
+            &PodAeCiphertext::from(keys.ae_key.encrypt(remaining_balance)),
+            &owner.pubkey().to_bytes().into(),
+            &[],
+            ProofLocation::InstructionOffset(
+                NonZeroI8::new(1).unwrap(),
+                ProofData::InstructionData(&equality.proof),
+            ),
+            ProofLocation::InstructionOffset(
+                NonZeroI8::new(2).unwrap(),
+                ProofData::InstructionData(&range_proof),
+            ),
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, owner, to_legacy!(ixs))
+    }
+
+    fn token_2022_query_confidential_transfer_mint(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<ConfidentialTransferMint> {
+        let data = &get_account_data(self, mint)?;
+
+        match target {
+            Target::Spl => {
+                // parse the mint with extensions
+                let mint_with_extensions =
+                    StateWithExtensions::<Mint>::unpack(data).map_err(TestError::from_raw_error)?;
+
+                mint_with_extensions
+                    .get_extension::<ConfidentialTransferMint>()
+                    .copied()
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::confidential_transfer::state::ConfidentialTransferMint as PinocchioConfidentialTransferMint;
+
+                let state = PinocchioConfidentialTransferMint::from_bytes(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                Ok(ConfidentialTransferMint {
+                    authority: to_optional_non_zero_pubkey(state.authority()),
+                    auto_approve_new_accounts: state.auto_approve_new_accounts().into(),
+                    // zeroed if none
+                    auditor_elgamal_pubkey: bytemuck::cast(
+                        state
+                            .auditor_elgamal_pubkey()
+                            .copied()
+                            .unwrap_or_default()
+                            .0,
+                    ),
+                })
+            }
+        }
+    }
+
+    fn token_2022_query_confidential_transfer_account(
+        &self,
+        target: Target,
+        token_account: &Pubkey,
+    ) -> TestResult<ConfidentialTransferAccount> {
+        let data = &get_account_data(self, token_account)?;
+
+        match target {
+            Target::Spl => {
+                // parse the token account with extensions
+                let account_with_extensions = StateWithExtensions::<Account>::unpack(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                account_with_extensions
+                    .get_extension::<ConfidentialTransferAccount>()
+                    .copied()
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::confidential_transfer::state::ConfidentialTransferAccount as PinocchioConfidentialTransferAccount;
+
+                let state = PinocchioConfidentialTransferAccount::from_bytes(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                Ok(ConfidentialTransferAccount {
+                    approved: state.approved().into(),
+                    elgamal_pubkey: bytemuck::cast(state.elgamal_pubkey().0),
+                    pending_balance_lo: bytemuck::cast(state.pending_balance_lo().0),
+                    pending_balance_hi: bytemuck::cast(state.pending_balance_hi().0),
+                    available_balance: bytemuck::cast(state.available_balance().0),
+                    decryptable_available_balance: bytemuck::cast(
+                        state.decryptable_available_balance().0,
+                    ),
+                    allow_confidential_credits: state.allow_confidential_credits().into(),
+                    allow_non_confidential_credits: state.allow_non_confidential_credits().into(),
+                    pending_balance_credit_counter: state.pending_balance_credit_counter().into(),
+                    maximum_pending_balance_credit_counter: state
+                        .maximum_pending_balance_credit_counter()
+                        .into(),
+                    expected_pending_balance_credit_counter: state
+                        .expected_pending_balance_credit_counter()
+                        .into(),
+                    actual_pending_balance_credit_counter: state
+                        .actual_pending_balance_credit_counter()
+                        .into(),
+                })
+            }
+        }
+    }
+}
+
+/// Send a confidential transfer instruction followed by its proof instructions, signed by
+/// `sender`, routing the first one through the proxy if required
+pub fn send_confidential_transfer_ixs(
+    app: &mut App,
+    target: Target,
+    sender: AppUser,
+    mut ixs_legacy: Vec<solana_instruction::Instruction>,
+) -> TestResult<TransactionMetadata> {
+    let ProgramId {
+        token_2022_program,
+        token_2022_proxy,
+        ..
+    } = app.program_id;
+
+    let signers = &[&sender.keypair()];
+
+    // required by runtime to validate programs
+    let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+        token_2022_program,
+        false,
+    )];
+
+    // the proof instructions are read by Token-2022 at their offset from the proxy one
+    if let Target::Proxy = target {
+        ixs_legacy[0].program_id = token_2022_proxy;
+        ixs_legacy[0]
+            .accounts
+            .extend_from_slice(&additional_accounts);
+    }
+
+    send_tx(&mut app.litesvm, &ixs_legacy, signers, app.is_log_displayed)
+}
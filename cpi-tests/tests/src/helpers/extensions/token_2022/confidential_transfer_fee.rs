@@ -0,0 +1,191 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::confidential_transfer::{
+            send_confidential_transfer_ixs, to_legacy,
+        },
+        suite::{
+            core::{extension::get_account_data, App, ProgramId},
+            types::{
+                pin_pubkey_to_addr, to_optional_non_zero_pubkey, AppUser, SolPubkey, Target,
+                TestError, TestResult,
+            },
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    solana_zk_sdk::encryption::pod::elgamal::PodElGamalPubkey,
+    spl_token_2022_interface::{
+        extension::{
+            confidential_transfer_fee::{instruction, ConfidentialTransferFeeConfig},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::Mint,
+    },
+};
+
+pub trait Token2022ConfidentialTransferFeeExtension {
+    fn token_2022_try_initialize_confidential_transfer_fee_config(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        authority: Option<&Pubkey>,
+        withdraw_withheld_authority_elgamal_pubkey: &PodElGamalPubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_enable_harvest_to_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_disable_harvest_to_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Harvests the confidential withheld fees of `sources` to the mint, signed by `sender`
+    /// paying the fees only
+    fn token_2022_try_harvest_confidential_withheld_tokens_to_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        sources: &[Pubkey],
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_query_confidential_transfer_fee_config(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<ConfidentialTransferFeeConfig>;
+}
+
+impl Token2022ConfidentialTransferFeeExtension for App {
+    fn token_2022_try_initialize_confidential_transfer_fee_config(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        authority: Option<&Pubkey>,
+        withdraw_withheld_authority_elgamal_pubkey: &PodElGamalPubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = instruction::initialize_confidential_transfer_fee_config(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            authority.map(pin_pubkey_to_addr),
+            withdraw_withheld_authority_elgamal_pubkey,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, sender, to_legacy!([ix]))
+    }
+
+    fn token_2022_try_enable_harvest_to_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = instruction::enable_harvest_to_mint(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, sender, to_legacy!([ix]))
+    }
+
+    fn token_2022_try_disable_harvest_to_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = instruction::disable_harvest_to_mint(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, sender, to_legacy!([ix]))
+    }
+
+    fn token_2022_try_harvest_confidential_withheld_tokens_to_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        sources: &[Pubkey],
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let sources: Vec<_> = sources.iter().map(pin_pubkey_to_addr).collect();
+
+        let ix = instruction::harvest_withheld_tokens_to_mint(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sources.iter().collect::<Vec<_>>(),
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        send_confidential_transfer_ixs(self, target, sender, to_legacy!([ix]))
+    }
+
+    fn token_2022_query_confidential_transfer_fee_config(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<ConfidentialTransferFeeConfig> {
+        let data = &get_account_data(self, mint)?;
+
+        match target {
+            Target::Spl => {
+                // parse the mint with extensions
+                let mint_with_extensions =
+                    StateWithExtensions::<Mint>::unpack(data).map_err(TestError::from_raw_error)?;
+
+                mint_with_extensions
+                    .get_extension::<ConfidentialTransferFeeConfig>()
+                    .copied()
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::confidential_transfer_fee::state::ConfidentialTransferFeeConfig as PinocchioConfidentialTransferFeeConfig;
+
+                let state = PinocchioConfidentialTransferFeeConfig::from_bytes(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                Ok(ConfidentialTransferFeeConfig {
+                    authority: to_optional_non_zero_pubkey(state.authority()),
+                    withdraw_withheld_authority_elgamal_pubkey: bytemuck::cast(
+                        state.withdraw_withheld_authority_elgamal_pubkey().0,
+                    ),
+                    harvest_to_mint_enabled: state.harvest_to_mint_enabled().into(),
+                    withheld_amount: bytemuck::cast(state.withheld_amount().0),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, Target, TestError, TestResult},
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    spl_token_2022_interface::{
+        extension::{
+            immutable_owner::ImmutableOwner, BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::Account,
+    },
+};
+
+pub trait Token2022ImmutableOwnerExtension {
+    fn token_2022_try_initialize_immutable_owner(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        token_account: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Returns `true` if the token account has the `ImmutableOwner` extension
+    fn token_2022_query_immutable_owner(
+        &self,
+        target: Target,
+        token_account: &Pubkey,
+    ) -> TestResult<bool>;
+}
+
+impl Token2022ImmutableOwnerExtension for App {
+    fn token_2022_try_initialize_immutable_owner(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        token_account: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::instruction::initialize_immutable_owner(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(token_account),
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_query_immutable_owner(
+        &self,
+        target: Target,
+        token_account: &Pubkey,
+    ) -> TestResult<bool> {
+        let data = &get_account_data(self, token_account)?;
+
+        match target {
+            Target::Spl => {
+                // parse the token account with extensions
+                let account_with_extensions = StateWithExtensions::<Account>::unpack(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                Ok(account_with_extensions
+                    .get_extension::<ImmutableOwner>()
+                    .is_ok())
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::{
+                    consts::ExtensionType, tlv::get_extension_data_from_bytes,
+                };
+
+                Ok(get_extension_data_from_bytes(ExtensionType::ImmutableOwner, data).is_some())
+            }
+        }
+    }
+}
@@ -131,7 +131,7 @@ impl Token2022InitializeMintExtension for App {
             Target::Proxy => {
                 use pinocchio_token_2022::state::Mint as PinocchioMint;
 
-                let state = unsafe { PinocchioMint::from_bytes_unchecked(data) };
+                let state = PinocchioMint::from_bytes(data).map_err(TestError::from_raw_error)?;
 
                 Ok(Mint {
                     mint_authority: to_c_option(state.mint_authority().map(pin_pubkey_to_addr)),
@@ -1,15 +1,50 @@
 use {
     crate::helpers::suite::{
         core::{extension::send_tx, App, ProgramId},
-        types::{addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, Target, TestError, TestResult},
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestError,
+            TestResult,
+        },
     },
     litesvm::types::TransactionMetadata,
     pinocchio::pubkey::Pubkey,
+    proptest::prelude::*,
     solana_keypair::Keypair,
     solana_program_pack::Pack,
-    spl_token_2022_interface::{instruction::MAX_SIGNERS, state::Multisig},
+    solana_signer::Signer,
+    spl_token_2022_interface::state::Multisig,
 };
 
+/// m-of-n multisig made of distinct app users
+#[derive(Debug, Clone)]
+pub struct MultisigConfig {
+    /// n members in the order they're stored in the multisig account
+    pub members: Vec<AppUser>,
+    /// m members signing, in the order they're passed to the instruction
+    pub signers: Vec<AppUser>,
+}
+
+impl MultisigConfig {
+    /// Random m-of-n configuration over the `AppUser` pool with random member and signer
+    /// ordering
+    pub fn strategy() -> impl Strategy<Value = Self> {
+        Just(AppUser::iter().collect::<Vec<_>>())
+            .prop_shuffle()
+            .prop_flat_map(|users| {
+                let len = users.len();
+                (Just(users), 1..=len)
+            })
+            .prop_flat_map(|(mut members, n)| {
+                members.truncate(n);
+                (Just(members.clone()), Just(members).prop_shuffle(), 1..=n)
+            })
+            .prop_map(|(members, mut signers, m)| {
+                signers.truncate(m);
+                Self { members, signers }
+            })
+    }
+}
+
 pub trait Token2022InitializeMultisigExtension {
     fn token_2022_try_create_multisig(
         &mut self,
@@ -25,6 +60,14 @@ pub trait Token2022InitializeMultisigExtension {
         required_signers: u8,
         signer_pubkeys: &[Pubkey],
     ) -> TestResult<TransactionMetadata>;
+
+    /// Creates and initializes the multisig described by `config`
+    fn token_2022_try_create_and_initialize_multisig(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        config: &MultisigConfig,
+    ) -> TestResult<Pubkey>;
 }
 
 impl Token2022InitializeMultisigExtension for App {
@@ -61,15 +104,14 @@ impl Token2022InitializeMultisigExtension for App {
 
         let signers = &[&sender.keypair()];
 
+        // n is the number of given signers, the list isn't padded with default keys
         let signer_pubkeys: &Vec<_> = &signer_pubkeys.iter().map(pin_pubkey_to_addr).collect();
-        let default_address = &solana_address::Address::default();
-        let signer_pubkeys_buffer: [&solana_address::Address; MAX_SIGNERS] =
-            core::array::from_fn(|i| signer_pubkeys.get(i).unwrap_or(default_address));
+        let signer_pubkeys: Vec<&solana_address::Address> = signer_pubkeys.iter().collect();
 
         let ix = spl_token_2022_interface::instruction::initialize_multisig(
             &token_2022_program.to_bytes().into(),
             &pin_pubkey_to_addr(multisig),
-            &signer_pubkeys_buffer,
+            &signer_pubkeys,
             required_signers,
         )
         .map_err(TestError::from_raw_error)?;
@@ -105,4 +147,25 @@ impl Token2022InitializeMultisigExtension for App {
             self.is_log_displayed,
         )
     }
+
+    fn token_2022_try_create_and_initialize_multisig(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        config: &MultisigConfig,
+    ) -> TestResult<Pubkey> {
+        let (_, multisig_keypair) = self.token_2022_try_create_multisig(sender, None)?;
+        let multisig = multisig_keypair.pubkey().to_bytes();
+
+        let members: Vec<Pubkey> = config.members.iter().map(|x| x.pubkey()).collect();
+        self.token_2022_try_initialize_multisig(
+            target,
+            sender,
+            &multisig,
+            config.signers.len() as u8,
+            &members,
+        )?;
+
+        Ok(multisig)
+    }
 }
@@ -139,7 +139,8 @@ impl Token2022InitializeAccountExtension for App {
         match target {
             Target::Spl => Account::unpack_from_slice(data).map_err(TestError::from_raw_error),
             Target::Proxy => {
-                let state = unsafe { PinocchioTokenAccount::from_bytes_unchecked(data) };
+                let state =
+                    PinocchioTokenAccount::from_bytes(data).map_err(TestError::from_raw_error)?;
 
                 Ok(Account {
                     mint: pin_pubkey_to_addr(&state.mint()),
@@ -10,8 +10,13 @@ use {
     pinocchio::pubkey::Pubkey,
     solana_address::Address,
     solana_keypair::Keypair,
+    token_2022_proxy::TRANSFER_CHECKED_WITH_MEMO,
 };
 
+/// SPL Memo v3, preloaded by litesvm
+pub const MEMO_PROGRAM_ID: solana_pubkey::Pubkey =
+    solana_pubkey::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
 pub trait Token2022MemoTransferExtension {
     fn token_2022_try_enable_memo_transfer(
         &mut self,
@@ -44,6 +49,21 @@ pub trait Token2022MemoTransferExtension {
         multisig_authority: &Pubkey,
         signers: &[AppUser],
     ) -> TestResult<TransactionMetadata>;
+
+    /// Transfers tokens, logging `memo` right before the transfer if it's specified.
+    /// The memo must be the previous sibling of the transfer, so the Spl target prepends
+    /// a memo instruction, while the proxy invokes both programs itself
+    fn token_2022_try_transfer_checked_with_memo(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        from: &Pubkey,
+        mint: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+        decimals: u8,
+        memo: Option<&str>,
+    ) -> TestResult<TransactionMetadata>;
 }
 
 impl Token2022MemoTransferExtension for App {
@@ -270,6 +290,96 @@ impl Token2022MemoTransferExtension for App {
             self.is_log_displayed,
         )
     }
+
+    fn token_2022_try_transfer_checked_with_memo(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        from: &Pubkey,
+        mint: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+        decimals: u8,
+        memo: Option<&str>,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::instruction::transfer_checked(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(from),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(to),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        let ixs: Vec<_> = match (target, memo) {
+            (Target::Proxy, Some(memo)) => {
+                let mut ix_proxy = ix_legacy;
+                ix_proxy.program_id = token_2022_proxy;
+                ix_proxy.accounts.extend_from_slice(&[
+                    solana_instruction::AccountMeta::new_readonly(token_2022_program, false),
+                    solana_instruction::AccountMeta::new_readonly(MEMO_PROGRAM_ID, false),
+                ]);
+                ix_proxy.data = [
+                    &[TRANSFER_CHECKED_WITH_MEMO][..],
+                    &amount.to_le_bytes(),
+                    &[decimals],
+                    memo.as_bytes(),
+                ]
+                .concat();
+
+                vec![ix_proxy]
+            }
+            (Target::Proxy, None) => {
+                let mut ix_proxy = ix_legacy;
+                ix_proxy.program_id = token_2022_proxy;
+                ix_proxy
+                    .accounts
+                    .push(solana_instruction::AccountMeta::new_readonly(
+                        token_2022_program,
+                        false,
+                    ));
+
+                vec![ix_proxy]
+            }
+            (Target::Spl, _) => memo
+                .map(|memo| solana_instruction::Instruction {
+                    program_id: MEMO_PROGRAM_ID,
+                    accounts: vec![],
+                    data: memo.as_bytes().to_vec(),
+                })
+                .into_iter()
+                .chain([ix_legacy])
+                .collect(),
+        };
+
+        send_tx(&mut self.litesvm, &ixs, signers, self.is_log_displayed)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////
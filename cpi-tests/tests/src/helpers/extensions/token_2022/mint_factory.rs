@@ -0,0 +1,230 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            default_account_state::Token2022DefaultAccountStateExtension,
+            group_member_pointer::Token2022GroupMemberPointerExtension,
+            group_pointer::Token2022GroupPointerExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            interest_bearing_mint::Token2022InterestBearingMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            mint_close_authority::Token2022MintCloseAuthorityExtension,
+            non_transferable::Token2022NonTransferableExtension,
+            pausable::Token2022PausableExtension,
+            permanent_delegate::Token2022PermanentDelegateExtension,
+            scaled_ui_amount::Token2022ScaledUiAmountExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+            transfer_hook::Token2022TransferHookExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, Target, TestResult},
+        },
+    },
+    pinocchio::pubkey::Pubkey,
+    solana_signer::Signer,
+    spl_token_2022_interface::{extension::ExtensionType, state::AccountState},
+};
+
+/// Mint extension initialized before `InitializeMint`, with its initialization arguments
+#[derive(Debug, Clone, Copy)]
+pub enum ExtensionSpec {
+    MintCloseAuthority {
+        close_authority: Option<Pubkey>,
+    },
+    TransferFeeConfig {
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    DefaultAccountState {
+        state: AccountState,
+    },
+    NonTransferable,
+    InterestBearingConfig {
+        rate_authority: Option<Pubkey>,
+        rate: i16,
+    },
+    PermanentDelegate {
+        delegate: Pubkey,
+    },
+    TransferHook {
+        authority: Option<Pubkey>,
+        program_id: Option<Pubkey>,
+    },
+    MetadataPointer {
+        authority: Option<Pubkey>,
+        metadata_address: Option<Pubkey>,
+    },
+    GroupPointer {
+        authority: Option<Pubkey>,
+        group_address: Option<Pubkey>,
+    },
+    GroupMemberPointer {
+        authority: Option<Pubkey>,
+        member_address: Option<Pubkey>,
+    },
+    ScaledUiAmount {
+        authority: Pubkey,
+        multiplier: f64,
+    },
+    Pausable {
+        authority: Pubkey,
+    },
+}
+
+impl ExtensionSpec {
+    pub fn extension_type(&self) -> ExtensionType {
+        match self {
+            Self::MintCloseAuthority { .. } => ExtensionType::MintCloseAuthority,
+            Self::TransferFeeConfig { .. } => ExtensionType::TransferFeeConfig,
+            Self::DefaultAccountState { .. } => ExtensionType::DefaultAccountState,
+            Self::NonTransferable => ExtensionType::NonTransferable,
+            Self::InterestBearingConfig { .. } => ExtensionType::InterestBearingConfig,
+            Self::PermanentDelegate { .. } => ExtensionType::PermanentDelegate,
+            Self::TransferHook { .. } => ExtensionType::TransferHook,
+            Self::MetadataPointer { .. } => ExtensionType::MetadataPointer,
+            Self::GroupPointer { .. } => ExtensionType::GroupPointer,
+            Self::GroupMemberPointer { .. } => ExtensionType::GroupMemberPointer,
+            Self::ScaledUiAmount { .. } => ExtensionType::ScaledUiAmount,
+            Self::Pausable { .. } => ExtensionType::Pausable,
+        }
+    }
+}
+
+pub trait Token2022MintFactoryExtension {
+    /// Create a mint account sized for `extensions`, initialize them in the given order
+    /// (the TLV entries are laid out the same way) and finish with `InitializeMint`.
+    /// Admin pays for the account and sends the extension instructions
+    fn token_2022_try_create_mint_with_extensions(
+        &mut self,
+        target: Target,
+        extensions: &[ExtensionSpec],
+        decimals: u8,
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
+    ) -> TestResult<Pubkey>;
+}
+
+impl Token2022MintFactoryExtension for App {
+    fn token_2022_try_create_mint_with_extensions(
+        &mut self,
+        target: Target,
+        extensions: &[ExtensionSpec],
+        decimals: u8,
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
+    ) -> TestResult<Pubkey> {
+        let sender = AppUser::Admin;
+        let extension_types: Vec<ExtensionType> =
+            extensions.iter().map(|x| x.extension_type()).collect();
+
+        let (_, mint_keypair) =
+            self.token_2022_try_create_mint_account(sender, None, Some(&extension_types))?;
+        let mint = &mint_keypair.pubkey().to_bytes();
+
+        for extension in extensions {
+            match *extension {
+                ExtensionSpec::MintCloseAuthority { close_authority } => self
+                    .token_2022_try_initialize_mint_close_authority(
+                        target,
+                        sender,
+                        mint,
+                        close_authority.as_ref(),
+                    ),
+                ExtensionSpec::TransferFeeConfig {
+                    transfer_fee_config_authority,
+                    withdraw_withheld_authority,
+                    transfer_fee_basis_points,
+                    maximum_fee,
+                } => self.token_2022_try_initialize_transfer_fee_config(
+                    target,
+                    sender,
+                    mint,
+                    transfer_fee_config_authority.as_ref(),
+                    withdraw_withheld_authority.as_ref(),
+                    transfer_fee_basis_points,
+                    maximum_fee,
+                ),
+                ExtensionSpec::DefaultAccountState { state } => self
+                    .token_2022_try_initialize_default_account_state(target, sender, mint, state),
+                ExtensionSpec::NonTransferable => {
+                    self.token_2022_try_initialize_non_transferable_mint(target, sender, mint)
+                }
+                ExtensionSpec::InterestBearingConfig {
+                    rate_authority,
+                    rate,
+                } => self.token_2022_try_initialize_interest_bearing_mint(
+                    target,
+                    sender,
+                    mint,
+                    rate_authority.as_ref(),
+                    rate,
+                ),
+                ExtensionSpec::PermanentDelegate { delegate } => self
+                    .token_2022_try_initialize_permanent_delegate(target, sender, mint, &delegate),
+                ExtensionSpec::TransferHook {
+                    authority,
+                    program_id,
+                } => self.token_2022_try_initialize_transfer_hook(
+                    target,
+                    sender,
+                    mint,
+                    authority.as_ref(),
+                    program_id.as_ref(),
+                ),
+                ExtensionSpec::MetadataPointer {
+                    authority,
+                    metadata_address,
+                } => self.token_2022_try_initialize_metadata_pointer(
+                    target,
+                    sender,
+                    mint,
+                    authority.as_ref(),
+                    metadata_address.as_ref(),
+                ),
+                ExtensionSpec::GroupPointer {
+                    authority,
+                    group_address,
+                } => self.token_2022_try_initialize_group_pointer(
+                    target,
+                    sender,
+                    mint,
+                    authority.as_ref(),
+                    group_address.as_ref(),
+                ),
+                ExtensionSpec::GroupMemberPointer {
+                    authority,
+                    member_address,
+                } => self.token_2022_try_initialize_group_member_pointer(
+                    target,
+                    sender,
+                    mint,
+                    authority.as_ref(),
+                    member_address.as_ref(),
+                ),
+                ExtensionSpec::ScaledUiAmount {
+                    authority,
+                    multiplier,
+                } => self.token_2022_try_initialize_scaled_ui_amount(
+                    target, sender, mint, &authority, multiplier,
+                ),
+                ExtensionSpec::Pausable { authority } => {
+                    self.token_2022_try_initialize_pausable(target, sender, mint, &authority)
+                }
+            }?;
+        }
+
+        // the mint is always initialized directly, the target applies to the extensions
+        self.token_2022_try_initialize_mint(
+            Target::Spl,
+            sender,
+            mint,
+            decimals,
+            mint_authority,
+            freeze_authority,
+        )?;
+
+        Ok(*mint)
+    }
+}
@@ -0,0 +1,137 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, Target, TestError, TestResult},
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    spl_token_2022_interface::{
+        extension::{
+            non_transferable::{NonTransferable, NonTransferableAccount},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::{Account, Mint},
+    },
+};
+
+pub trait Token2022NonTransferableExtension {
+    fn token_2022_try_initialize_non_transferable_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Returns `true` if the mint has the `NonTransferable` extension
+    fn token_2022_query_non_transferable(&self, target: Target, mint: &Pubkey) -> TestResult<bool>;
+
+    /// Returns `true` if the token account has the `NonTransferableAccount` extension
+    fn token_2022_query_non_transferable_account(
+        &self,
+        target: Target,
+        token_account: &Pubkey,
+    ) -> TestResult<bool>;
+}
+
+impl Token2022NonTransferableExtension for App {
+    fn token_2022_try_initialize_non_transferable_mint(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::instruction::initialize_non_transferable_mint(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        )];
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy.accounts.extend_from_slice(&additional_accounts);
+        }
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_query_non_transferable(&self, target: Target, mint: &Pubkey) -> TestResult<bool> {
+        let data = &get_account_data(self, mint)?;
+
+        match target {
+            Target::Spl => {
+                // parse the mint account with extensions
+                let mint_with_extensions =
+                    StateWithExtensions::<Mint>::unpack(data).map_err(TestError::from_raw_error)?;
+
+                Ok(mint_with_extensions
+                    .get_extension::<NonTransferable>()
+                    .is_ok())
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::non_transferable::state::NonTransferable as PinocchioNonTransferable;
+
+                Ok(PinocchioNonTransferable::is_present(data))
+            }
+        }
+    }
+
+    fn token_2022_query_non_transferable_account(
+        &self,
+        target: Target,
+        token_account: &Pubkey,
+    ) -> TestResult<bool> {
+        let data = &get_account_data(self, token_account)?;
+
+        match target {
+            Target::Spl => {
+                // parse the token account with extensions
+                let account_with_extensions = StateWithExtensions::<Account>::unpack(data)
+                    .map_err(TestError::from_raw_error)?;
+
+                Ok(account_with_extensions
+                    .get_extension::<NonTransferableAccount>()
+                    .is_ok())
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::non_transferable::state::NonTransferableAccount as PinocchioNonTransferableAccount;
+
+                Ok(PinocchioNonTransferableAccount::is_present(data))
+            }
+        }
+    }
+}
@@ -0,0 +1,666 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            pausable::Token2022PausableExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+        },
+        suite::{
+            core::{extension::send_tx, App, ProgramId},
+            types::{
+                addr_to_sol_pubkey, pin_pubkey_to_addr, pin_to_sol_pubkey, sol_to_pin_pubkey,
+                AppUser, PinPubkey, ProxyAuthority, Target, TestError, TestResult,
+            },
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    token_2022_proxy::helpers::AUTHORITY_SEED,
+};
+
+/// Instructions executed through the proxy with the proxy PDA as authority.
+/// `sender` only pays for the transaction.
+pub trait Token2022PdaAuthorityExtension {
+    /// Returns the proxy PDA which can be used as mint, freeze, fee or pause authority
+    fn token_2022_query_proxy_authority(&self) -> Pubkey;
+
+    fn token_2022_try_mint_to_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_mint_to_checked_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_freeze_account_as_proxy(
+        &mut self,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_thaw_account_as_proxy(
+        &mut self,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_pause_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_resume_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_set_transfer_fee_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_withdraw_withheld_tokens_from_mint_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        destination: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+}
+
+impl Token2022PdaAuthorityExtension for App {
+    fn token_2022_query_proxy_authority(&self) -> Pubkey {
+        let (authority, _) = solana_pubkey::Pubkey::find_program_address(
+            &[AUTHORITY_SEED],
+            &self.program_id.token_2022_proxy,
+        );
+
+        sol_to_pin_pubkey(&authority)
+    }
+
+    fn token_2022_try_mint_to_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::mint_to(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(&self.token_2022_query_proxy_authority()),
+            &[],
+            amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_as_proxy_authority(self, sender, ix_legacy)
+    }
+
+    fn token_2022_try_mint_to_checked_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::mint_to_checked(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(&self.token_2022_query_proxy_authority()),
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_as_proxy_authority(self, sender, ix_legacy)
+    }
+
+    fn token_2022_try_freeze_account_as_proxy(
+        &mut self,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::freeze_account(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(&self.token_2022_query_proxy_authority()),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_as_proxy_authority(self, sender, ix_legacy)
+    }
+
+    fn token_2022_try_thaw_account_as_proxy(
+        &mut self,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::thaw_account(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(&self.token_2022_query_proxy_authority()),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_as_proxy_authority(self, sender, ix_legacy)
+    }
+
+    fn token_2022_try_pause_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::extension::pausable::instruction::pause(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(&self.token_2022_query_proxy_authority()),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_as_proxy_authority(self, sender, ix_legacy)
+    }
+
+    fn token_2022_try_resume_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::extension::pausable::instruction::resume(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(&self.token_2022_query_proxy_authority()),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_as_proxy_authority(self, sender, ix_legacy)
+    }
+
+    fn token_2022_try_set_transfer_fee_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::extension::transfer_fee::instruction::set_transfer_fee(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(&self.token_2022_query_proxy_authority()),
+            &[],
+            transfer_fee_basis_points,
+            maximum_fee,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_as_proxy_authority(self, sender, ix_legacy)
+    }
+
+    fn token_2022_try_withdraw_withheld_tokens_from_mint_as_proxy(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        destination: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(destination),
+            &pin_pubkey_to_addr(&self.token_2022_query_proxy_authority()),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_as_proxy_authority(self, sender, ix_legacy)
+    }
+}
+
+/// Instructions executed through the proxy with either a user or the proxy PDA as
+/// authority, so that a test body runs both the `invoke` and `invoke_signed` paths of the
+/// wrappers. A user authority signs and pays for the transaction, `sender` only pays for the
+/// ones the proxy signs for its PDA.
+pub trait Token2022ProxyAuthorityExtension {
+    /// Returns the pubkey to set as mint, freeze, fee or pause authority
+    fn token_2022_query_authority_pubkey(&self, authority: ProxyAuthority) -> Pubkey;
+
+    fn token_2022_try_mint_to_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_mint_to_checked_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_freeze_account_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_thaw_account_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_pause_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_resume_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_set_transfer_fee_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_withdraw_withheld_tokens_from_mint_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+        destination: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+}
+
+impl Token2022ProxyAuthorityExtension for App {
+    fn token_2022_query_authority_pubkey(&self, authority: ProxyAuthority) -> Pubkey {
+        match authority {
+            ProxyAuthority::User(user) => user.pubkey(),
+            ProxyAuthority::Pda => self.token_2022_query_proxy_authority(),
+        }
+    }
+
+    fn token_2022_try_mint_to_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        match authority {
+            ProxyAuthority::User(user) => {
+                self.token_2022_try_mint_to(Target::Proxy, user, mint, account, amount)
+            }
+            ProxyAuthority::Pda => {
+                self.token_2022_try_mint_to_as_proxy(sender, mint, account, amount)
+            }
+        }
+    }
+
+    fn token_2022_try_mint_to_checked_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        match authority {
+            ProxyAuthority::User(user) => self.token_2022_try_mint_to_checked(
+                Target::Proxy,
+                user,
+                mint,
+                account,
+                amount,
+                decimals,
+            ),
+            ProxyAuthority::Pda => self
+                .token_2022_try_mint_to_checked_as_proxy(sender, mint, account, amount, decimals),
+        }
+    }
+
+    fn token_2022_try_freeze_account_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        match authority {
+            ProxyAuthority::User(user) => {
+                self.token_2022_try_freeze_account(Target::Proxy, user, account, mint)
+            }
+            ProxyAuthority::Pda => {
+                self.token_2022_try_freeze_account_as_proxy(sender, account, mint)
+            }
+        }
+    }
+
+    fn token_2022_try_thaw_account_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        match authority {
+            ProxyAuthority::User(user) => {
+                self.token_2022_try_thaw_account(Target::Proxy, user, account, mint)
+            }
+            ProxyAuthority::Pda => self.token_2022_try_thaw_account_as_proxy(sender, account, mint),
+        }
+    }
+
+    fn token_2022_try_pause_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        match authority {
+            ProxyAuthority::User(user) => {
+                self.token_2022_try_pause(Target::Proxy, user, mint, &user.pubkey())
+            }
+            ProxyAuthority::Pda => self.token_2022_try_pause_as_proxy(sender, mint),
+        }
+    }
+
+    fn token_2022_try_resume_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        match authority {
+            ProxyAuthority::User(user) => {
+                self.token_2022_try_resume(Target::Proxy, user, mint, &user.pubkey())
+            }
+            ProxyAuthority::Pda => self.token_2022_try_resume_as_proxy(sender, mint),
+        }
+    }
+
+    fn token_2022_try_set_transfer_fee_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> TestResult<TransactionMetadata> {
+        match authority {
+            ProxyAuthority::User(user) => self.token_2022_try_set_transfer_fee(
+                Target::Proxy,
+                user,
+                mint,
+                transfer_fee_basis_points,
+                maximum_fee,
+            ),
+            ProxyAuthority::Pda => self.token_2022_try_set_transfer_fee_as_proxy(
+                sender,
+                mint,
+                transfer_fee_basis_points,
+                maximum_fee,
+            ),
+        }
+    }
+
+    fn token_2022_try_withdraw_withheld_tokens_from_mint_with_authority(
+        &mut self,
+        sender: AppUser,
+        authority: ProxyAuthority,
+        mint: &Pubkey,
+        destination: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        match authority {
+            ProxyAuthority::User(user) => self.token_2022_try_withdraw_withheld_tokens_from_mint(
+                Target::Proxy,
+                user,
+                mint,
+                destination,
+            ),
+            ProxyAuthority::Pda => self.token_2022_try_withdraw_withheld_tokens_from_mint_as_proxy(
+                sender,
+                mint,
+                destination,
+            ),
+        }
+    }
+}
+
+/// Route an instruction through the proxy, which signs for its PDA authority
+fn send_as_proxy_authority(
+    app: &mut App,
+    sender: AppUser,
+    mut ix_legacy: solana_instruction::Instruction,
+) -> TestResult<TransactionMetadata> {
+    let ProgramId {
+        token_2022_program,
+        token_2022_proxy,
+        ..
+    } = app.program_id;
+
+    let signers = &[&sender.keypair()];
+    let authority = pin_to_sol_pubkey(&app.token_2022_query_proxy_authority());
+
+    // the PDA can't sign the transaction, the proxy signs for it with `invoke_signed`
+    for account in ix_legacy.accounts.iter_mut() {
+        if account.pubkey == authority {
+            account.is_signer = false;
+        }
+    }
+
+    // required by runtime to validate programs
+    ix_legacy.program_id = token_2022_proxy;
+    ix_legacy
+        .accounts
+        .push(solana_instruction::AccountMeta::new_readonly(
+            token_2022_program,
+            false,
+        ));
+
+    send_tx(
+        &mut app.litesvm,
+        &[ix_legacy],
+        signers,
+        app.is_log_displayed,
+    )
+}
@@ -0,0 +1,223 @@
+use {
+    crate::helpers::suite::{
+        core::{extension::send_tx, App, ProgramId},
+        types::{addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, Target, TestError, TestResult},
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+/// Instructions returning their result as return data.
+/// On `Target::Proxy` the proxy sets the Token-2022 return data as its own
+pub trait Token2022ReturnDataExtension {
+    fn token_2022_try_get_account_data_size(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        extension_types: &[ExtensionType],
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_amount_to_ui_amount(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_ui_amount_to_amount(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        ui_amount: &str,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_emit_token_metadata(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+}
+
+impl Token2022ReturnDataExtension for App {
+    fn token_2022_try_get_account_data_size(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        extension_types: &[ExtensionType],
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::get_account_data_size(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            extension_types,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_query_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_amount_to_ui_amount(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::amount_to_ui_amount(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_query_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_ui_amount_to_amount(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        ui_amount: &str,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::ui_amount_to_amount(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            ui_amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_query_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_emit_token_metadata(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        metadata: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_metadata_interface::instruction::emit(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(metadata),
+            None,
+            None,
+        );
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_query_ix(self, target, sender, ix_legacy)
+    }
+}
+
+/// Send a query instruction signed by `sender`, routing it through the proxy if required
+fn send_query_ix(
+    app: &mut App,
+    target: Target,
+    sender: AppUser,
+    mut ix_legacy: solana_instruction::Instruction,
+) -> TestResult<TransactionMetadata> {
+    let ProgramId {
+        token_2022_program,
+        token_2022_proxy,
+        ..
+    } = app.program_id;
+
+    let signers = &[&sender.keypair()];
+
+    // required by runtime to validate programs
+    let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+        token_2022_program,
+        false,
+    )];
+
+    if let Target::Proxy = target {
+        ix_legacy.program_id = token_2022_proxy;
+        ix_legacy.accounts.extend_from_slice(&additional_accounts);
+    }
+
+    send_tx(
+        &mut app.litesvm,
+        &[ix_legacy],
+        signers,
+        app.is_log_displayed,
+    )
+}
@@ -263,10 +263,10 @@ impl Token2022ScaledUiAmountExtension for App {
                      PinocchioScaledUiAmountConfig::from_bytes(data).map_err(TestError::from_raw_error)?;
 
                  Ok(ScaledUiAmountConfig {
-                     authority: to_optional_non_zero_pubkey(Some(&state.authority)),
-                     multiplier: state.multiplier.into(),
-                     new_multiplier_effective_timestamp: state.new_multiplier_effective_timestamp.into(),
-                     new_multiplier: state.new_multiplier.into(),
+                     authority: to_optional_non_zero_pubkey(Some(state.authority())),
+                     multiplier: state.multiplier().into(),
+                     new_multiplier_effective_timestamp: state.new_multiplier_effective_timestamp().into(),
+                     new_multiplier: state.new_multiplier().into(),
                  })
             }
         }
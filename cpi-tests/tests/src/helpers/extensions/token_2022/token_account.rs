@@ -1,6 +1,7 @@
 use {
     crate::helpers::suite::{
         core::{extension::send_tx, App, ProgramId},
+        rng::new_keypair,
         types::{
             addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, SolPubkey, TestError, TestResult,
         },
@@ -39,7 +40,7 @@ impl Token2022TokenAccountExtension for App {
             .map_err(TestError::from_raw_error)?;
 
         // Create the token account keypair
-        let token_account_keypair = Keypair::new();
+        let token_account_keypair = new_keypair();
         let signers = &[&sender.keypair(), &token_account_keypair];
 
         let lamports = self
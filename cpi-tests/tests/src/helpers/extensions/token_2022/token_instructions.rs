@@ -0,0 +1,641 @@
+use {
+    crate::helpers::suite::{
+        core::{extension::send_tx, App, ProgramId},
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, SolPubkey, Target, TestError,
+            TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    spl_token_2022_interface::instruction::AuthorityType,
+};
+
+pub trait Token2022TokenInstructionsExtension {
+    fn token_2022_try_transfer_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        from: &Pubkey,
+        mint: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_mint_to(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_mint_to_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_burn(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_burn_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_approve(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        delegate: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_approve_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        mint: &Pubkey,
+        delegate: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_revoke(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_set_authority(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        authority_type: AuthorityType,
+        new_authority: Option<&Pubkey>,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_close_account(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        destination: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_freeze_account(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+
+    fn token_2022_try_thaw_account(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata>;
+}
+
+impl Token2022TokenInstructionsExtension for App {
+    fn token_2022_try_transfer_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        from: &Pubkey,
+        mint: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::transfer_checked(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(from),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(to),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_mint_to(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::mint_to(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(account),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_mint_to_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::mint_to_checked(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(account),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_burn(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::burn(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_burn_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::burn_checked(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_approve(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        delegate: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::approve(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(source),
+            &pin_pubkey_to_addr(delegate),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_approve_checked(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+        mint: &Pubkey,
+        delegate: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::approve_checked(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(source),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(delegate),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_revoke(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        source: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::revoke(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(source),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_set_authority(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        authority_type: AuthorityType,
+        new_authority: Option<&Pubkey>,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::set_authority(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            new_authority.map(pin_pubkey_to_addr).as_ref(),
+            authority_type,
+            &sender.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_close_account(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        destination: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::close_account(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(destination),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_freeze_account(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::freeze_account(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_thaw_account(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        account: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_2022_interface::instruction::thaw_account(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(account),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_ix(self, target, sender, ix_legacy)
+    }
+}
+
+/// Send a base token instruction signed by `sender`, routing it through the proxy if required
+fn send_token_ix(
+    app: &mut App,
+    target: Target,
+    sender: AppUser,
+    mut ix_legacy: solana_instruction::Instruction,
+) -> TestResult<TransactionMetadata> {
+    let ProgramId {
+        token_2022_program,
+        token_2022_proxy,
+        ..
+    } = app.program_id;
+
+    let signers = &[&sender.keypair()];
+
+    // required by runtime to validate programs
+    let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+        token_2022_program,
+        false,
+    )];
+
+    if let Target::Proxy = target {
+        ix_legacy.program_id = token_2022_proxy;
+        ix_legacy.accounts.extend_from_slice(&additional_accounts);
+    }
+
+    send_tx(
+        &mut app.litesvm,
+        &[ix_legacy],
+        signers,
+        app.is_log_displayed,
+    )
+}
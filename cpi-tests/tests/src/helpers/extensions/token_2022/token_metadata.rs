@@ -0,0 +1,362 @@
+use {
+    crate::helpers::suite::{
+        core::{
+            extension::{get_account_data, send_tx},
+            App, ProgramId,
+        },
+        types::{
+            addr_to_sol_pubkey, pin_pubkey_to_addr, pin_to_sol_pubkey, to_optional_non_zero_pubkey,
+            AppUser, SolPubkey, Target, TestError, TestResult,
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    solana_program::native_token::LAMPORTS_PER_SOL,
+    spl_token_2022_interface::{
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint,
+    },
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+    token_2022_proxy::INITIALIZE_METADATA_WITH_POINTER,
+};
+
+pub trait Token2022TokenMetadataExtension {
+    /// Initializes the token metadata stored in the mint itself, the mint must have
+    /// the metadata pointer set to itself. `sender` is the mint and update authority
+    fn token_2022_try_initialize_token_metadata(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Initializes the mint created with the space of the metadata pointer only, the metadata
+    /// pointer set to itself and its metadata through the proxy. `sender` is the mint and
+    /// update authority and pays the rent of the metadata
+    fn token_2022_try_initialize_metadata_with_pointer(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        decimals: u8,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Updates a base field or adds/updates a custom key. `sender` is the update authority
+    fn token_2022_try_update_token_metadata_field(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        field: Field,
+        value: &str,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Removes a custom key. `sender` is the update authority
+    fn token_2022_try_remove_token_metadata_key(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        key: &str,
+        idempotent: bool,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Sets or clears the update authority. `sender` is the current update authority
+    fn token_2022_try_update_token_metadata_authority(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        new_authority: Option<&Pubkey>,
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Parses the token metadata stored in the mint with SPL parser for `Target::Spl`
+    /// and with pinocchio parser for `Target::Proxy`
+    fn token_2022_query_token_metadata(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<TokenMetadata>;
+}
+
+impl Token2022TokenMetadataExtension for App {
+    fn token_2022_try_initialize_token_metadata(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        // the mint is reallocated to store the metadata, top up its balance to stay rent exempt
+        self.litesvm
+            .airdrop(&pin_to_sol_pubkey(mint), LAMPORTS_PER_SOL)
+            .map_err(TestError::from_raw_error)?;
+
+        let ix = spl_token_metadata_interface::instruction::initialize(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            name.to_string(),
+            symbol.to_string(),
+            uri.to_string(),
+        );
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_metadata_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_initialize_metadata_with_pointer(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        decimals: u8,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            system_program,
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let mut data = vec![INITIALIZE_METADATA_WITH_POINTER, decimals];
+        for value in [name, symbol] {
+            data.push(value.len() as u8);
+            data.extend_from_slice(value.as_bytes());
+        }
+        data.extend_from_slice(uri.as_bytes());
+
+        let ix = solana_instruction::Instruction {
+            program_id: token_2022_proxy,
+            accounts: vec![
+                solana_instruction::AccountMeta::new(pin_to_sol_pubkey(mint), false),
+                solana_instruction::AccountMeta::new_readonly(sender.pubkey(), false),
+                solana_instruction::AccountMeta::new_readonly(sender.pubkey(), true),
+                solana_instruction::AccountMeta::new(sender.pubkey(), true),
+                solana_instruction::AccountMeta::new_readonly(system_program, false),
+                solana_instruction::AccountMeta::new_readonly(token_2022_program, false),
+            ],
+            data,
+        };
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix],
+            &[&sender.keypair()],
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_try_update_token_metadata_field(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        field: Field,
+        value: &str,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_metadata_interface::instruction::update_field(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            field,
+            value.to_string(),
+        );
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_metadata_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_remove_token_metadata_key(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        key: &str,
+        idempotent: bool,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_metadata_interface::instruction::remove_key(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            key.to_string(),
+            idempotent,
+        );
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_metadata_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_try_update_token_metadata_authority(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        mint: &Pubkey,
+        new_authority: Option<&Pubkey>,
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program, ..
+        } = self.program_id;
+
+        let ix = spl_token_metadata_interface::instruction::update_authority(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(mint),
+            &sender.pubkey().to_bytes().into(),
+            to_optional_non_zero_pubkey(new_authority),
+        );
+
+        // convert Instruction v3.0.0 to Instruction v2.3.0
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        send_token_metadata_ix(self, target, sender, ix_legacy)
+    }
+
+    fn token_2022_query_token_metadata(
+        &self,
+        target: Target,
+        mint: &Pubkey,
+    ) -> TestResult<TokenMetadata> {
+        let data = &get_account_data(self, mint)?;
+
+        match target {
+            Target::Spl => {
+                // parse the mint with extensions
+                let mint_with_extensions =
+                    StateWithExtensions::<Mint>::unpack(data).map_err(TestError::from_raw_error)?;
+
+                mint_with_extensions
+                    .get_variable_len_extension::<TokenMetadata>()
+                    .map_err(TestError::from_raw_error)
+            }
+            Target::Proxy => {
+                use pinocchio_token_2022::extension::token_metadata::state::TokenMetadata as PinocchioTokenMetadata;
+
+                let state =
+                    PinocchioTokenMetadata::from_bytes(data).map_err(TestError::from_raw_error)?;
+
+                Ok(TokenMetadata {
+                    update_authority: to_optional_non_zero_pubkey(state.update_authority()),
+                    mint: pin_pubkey_to_addr(state.mint),
+                    name: state.name.to_string(),
+                    symbol: state.symbol.to_string(),
+                    uri: state.uri.to_string(),
+                    additional_metadata: state
+                        .additional_metadata()
+                        .map(|(key, value)| (key.to_string(), value.to_string()))
+                        .collect(),
+                })
+            }
+        }
+    }
+}
+
+/// Send a token metadata instruction signed by `sender`, routing it through the proxy if required
+fn send_token_metadata_ix(
+    app: &mut App,
+    target: Target,
+    sender: AppUser,
+    mut ix_legacy: solana_instruction::Instruction,
+) -> TestResult<TransactionMetadata> {
+    let ProgramId {
+        token_2022_program,
+        token_2022_proxy,
+        ..
+    } = app.program_id;
+
+    let signers = &[&sender.keypair()];
+
+    // required by runtime to validate programs
+    let additional_accounts = [solana_instruction::AccountMeta::new_readonly(
+        token_2022_program,
+        false,
+    )];
+
+    if let Target::Proxy = target {
+        ix_legacy.program_id = token_2022_proxy;
+        ix_legacy.accounts.extend_from_slice(&additional_accounts);
+    }
+
+    send_tx(
+        &mut app.litesvm,
+        &[ix_legacy],
+        signers,
+        app.is_log_displayed,
+    )
+}
@@ -5,16 +5,22 @@ use {
             App, ProgramId,
         },
         types::{
-            addr_to_sol_pubkey, pin_pubkey_to_addr, to_optional_non_zero_pubkey, AppUser,
-            SolPubkey, Target, TestError, TestResult,
+            addr_to_sol_pubkey, pin_pubkey_to_addr, pin_to_sol_pubkey, sol_to_pin_pubkey,
+            to_optional_non_zero_pubkey, AppUser, SolPubkey, Target, TestError, TestResult,
         },
     },
     litesvm::types::TransactionMetadata,
     pinocchio::pubkey::Pubkey,
+    pinocchio_token_2022::transfer_hook_interface::{
+        initialize_extra_account_meta_list_instruction_data, ExtraAccountMeta,
+        ExtraAccountMetaList, EXTRA_ACCOUNT_METAS_SEED,
+        MAX_EXTRA_ACCOUNT_METAS_INSTRUCTION_DATA_LEN,
+    },
     spl_token_2022_interface::{
         extension::{transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions},
         state::Mint,
     },
+    token_2022_proxy::TRANSFER_CHECKED_WITH_HOOK,
 };
 
 pub trait Token2022TransferHookExtension {
@@ -50,6 +56,33 @@ pub trait Token2022TransferHookExtension {
         target: Target,
         mint: &Pubkey,
     ) -> TestResult<TransferHook>;
+
+    /// Transfers tokens of a mint with the `TransferHook` extension. The Spl target appends
+    /// `additional_accounts` to the transfer as they are, while the proxy resolves the extra
+    /// accounts from the validation account and picks them from `additional_accounts`
+    fn token_2022_try_transfer_checked_with_hook(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        from: &Pubkey,
+        mint: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+        decimals: u8,
+        additional_accounts: &[solana_instruction::AccountMeta],
+    ) -> TestResult<TransactionMetadata>;
+
+    /// Address of the validation account of `mint` for the dummy transfer hook program
+    fn token_2022_query_extra_account_metas_address(&self, mint: &Pubkey) -> Pubkey;
+
+    /// Funds the validation account of `mint` and initializes it with `metas` via the dummy
+    /// transfer hook program, `sender` being the mint authority
+    fn token_2022_try_initialize_extra_account_meta_list(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        metas: &[ExtraAccountMeta],
+    ) -> TestResult<TransactionMetadata>;
 }
 
 impl Token2022TransferHookExtension for App {
@@ -263,6 +296,149 @@ impl Token2022TransferHookExtension for App {
             }
         }
     }
+
+    fn token_2022_try_transfer_checked_with_hook(
+        &mut self,
+        target: Target,
+        sender: AppUser,
+        from: &Pubkey,
+        mint: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+        decimals: u8,
+        additional_accounts: &[solana_instruction::AccountMeta],
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let signers = &[&sender.keypair()];
+
+        let ix = spl_token_2022_interface::instruction::transfer_checked(
+            &token_2022_program.to_bytes().into(),
+            &pin_pubkey_to_addr(from),
+            &pin_pubkey_to_addr(mint),
+            &pin_pubkey_to_addr(to),
+            &sender.pubkey().to_bytes().into(),
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        let mut ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        };
+
+        if let Target::Proxy = target {
+            ix_legacy.program_id = token_2022_proxy;
+            ix_legacy
+                .accounts
+                .push(solana_instruction::AccountMeta::new_readonly(
+                    token_2022_program,
+                    false,
+                ));
+            ix_legacy.data = [
+                &[TRANSFER_CHECKED_WITH_HOOK][..],
+                &amount.to_le_bytes(),
+                &[decimals],
+            ]
+            .concat();
+        }
+
+        ix_legacy.accounts.extend_from_slice(additional_accounts);
+
+        send_tx(
+            &mut self.litesvm,
+            &[ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
+
+    fn token_2022_query_extra_account_metas_address(&self, mint: &Pubkey) -> Pubkey {
+        let (address, _) = solana_pubkey::Pubkey::find_program_address(
+            &[EXTRA_ACCOUNT_METAS_SEED, mint],
+            &self.program_id.dummy_transfer_hook,
+        );
+
+        sol_to_pin_pubkey(&address)
+    }
+
+    fn token_2022_try_initialize_extra_account_meta_list(
+        &mut self,
+        sender: AppUser,
+        mint: &Pubkey,
+        metas: &[ExtraAccountMeta],
+    ) -> TestResult<TransactionMetadata> {
+        let ProgramId {
+            system_program,
+            dummy_transfer_hook,
+            ..
+        } = self.program_id;
+
+        let extra_account_metas =
+            pin_to_sol_pubkey(&self.token_2022_query_extra_account_metas_address(mint));
+        let signers = &[&sender.keypair()];
+
+        // the hook program only allocates and assigns the validation account
+        let lamports = self
+            .litesvm
+            .minimum_balance_for_rent_exemption(ExtraAccountMetaList::size_of(metas.len()));
+        let fund_ix = solana_system_interface::instruction::transfer(
+            &sender.pubkey().to_bytes().into(),
+            &extra_account_metas.to_bytes().into(),
+            lamports,
+        );
+
+        let fund_ix_legacy = solana_instruction::Instruction {
+            program_id: addr_to_sol_pubkey(&fund_ix.program_id),
+            accounts: fund_ix
+                .accounts
+                .into_iter()
+                .map(|x| solana_instruction::AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: fund_ix.data,
+        };
+
+        let mut buffer = [0; MAX_EXTRA_ACCOUNT_METAS_INSTRUCTION_DATA_LEN];
+        let data = initialize_extra_account_meta_list_instruction_data(&mut buffer, metas)
+            .map_err(TestError::from_raw_error)?;
+
+        let ix_legacy = solana_instruction::Instruction {
+            program_id: dummy_transfer_hook,
+            accounts: vec![
+                solana_instruction::AccountMeta::new(extra_account_metas, false),
+                solana_instruction::AccountMeta::new_readonly(pin_to_sol_pubkey(mint), false),
+                solana_instruction::AccountMeta::new_readonly(sender.pubkey(), true),
+                solana_instruction::AccountMeta::new_readonly(system_program, false),
+            ],
+            data: data.to_vec(),
+        };
+
+        send_tx(
+            &mut self.litesvm,
+            &[fund_ix_legacy, ix_legacy],
+            signers,
+            self.is_log_displayed,
+        )
+    }
 }
 
 
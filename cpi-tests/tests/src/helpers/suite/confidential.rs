@@ -0,0 +1,154 @@
+use {
+    crate::helpers::suite::types::{AppUser, TestError, TestResult},
+    pinocchio::pubkey::Pubkey,
+    solana_seed_derivable::SeedDerivable,
+    solana_zk_sdk::{
+        encryption::{
+            auth_encryption::AeKey,
+            elgamal::{ElGamalCiphertext, ElGamalKeypair, ElGamalPubkey},
+            grouped_elgamal::{GroupedElGamal, GroupedElGamalCiphertext},
+            pedersen::{Pedersen, PedersenCommitment, PedersenOpening},
+        },
+        zk_elgamal_proof_program::proof_data::{
+            BatchedRangeProofU64Data, CiphertextCommitmentEqualityProofData,
+            GroupedCiphertext2HandlesValidityProofData, PubkeyValidityProofData,
+            ZeroCiphertextProofData,
+        },
+    },
+};
+
+/// Keys of a confidential token account, derived from the owner keypair and the account
+/// address, so the same user gets the same keys for the account in every test
+pub struct ConfidentialKeys {
+    pub elgamal: ElGamalKeypair,
+    /// Encrypts the decryptable available balance
+    pub ae_key: AeKey,
+}
+
+/// Amount committed to with a random opening, along with the proof about it
+pub struct CommitmentProof<T> {
+    pub commitment: PedersenCommitment,
+    pub opening: PedersenOpening,
+    pub proof: T,
+}
+
+impl<T> CommitmentProof<T> {
+    /// Prove that the committed `amount` is a 64 bit value, e.g. the remaining balance of a
+    /// withdrawal, whose equality and range proofs share the commitment
+    pub fn range_proof(&self, amount: u64) -> TestResult<BatchedRangeProofU64Data> {
+        BatchedRangeProofU64Data::new(
+            vec![&self.commitment],
+            vec![amount],
+            vec![64],
+            vec![&self.opening],
+        )
+        .map_err(TestError::from_raw_error)
+    }
+}
+
+/// Amount encrypted under 2 ElGamal pubkeys with a random opening, along with the proof
+/// of the ciphertext validity
+pub struct GroupedCiphertextProof {
+    pub grouped_ciphertext: GroupedElGamalCiphertext<2>,
+    pub opening: PedersenOpening,
+    pub proof: GroupedCiphertext2HandlesValidityProofData,
+}
+
+impl ConfidentialKeys {
+    pub fn new(owner: AppUser, token_account: &Pubkey) -> TestResult<Self> {
+        let seed = [&owner.keypair().to_bytes()[..], &token_account[..]].concat();
+
+        Ok(Self {
+            elgamal: ElGamalKeypair::from_seed(&seed).map_err(TestError::from_raw_error)?,
+            ae_key: AeKey::from_seed(&seed).map_err(TestError::from_raw_error)?,
+        })
+    }
+
+    pub fn pubkey(&self) -> &ElGamalPubkey {
+        self.elgamal.pubkey()
+    }
+
+    /// Decrypt a balance encrypted under the ElGamal pubkey, `None` if it's out of the
+    /// u32 range the discrete log is solved for
+    pub fn decrypt(&self, ciphertext: &ElGamalCiphertext) -> Option<u64> {
+        self.elgamal.secret().decrypt_u32(ciphertext)
+    }
+
+    /// Prove that the ElGamal pubkey is well-formed, required to configure the account
+    pub fn pubkey_validity_proof(&self) -> TestResult<PubkeyValidityProofData> {
+        PubkeyValidityProofData::new(&self.elgamal).map_err(TestError::from_raw_error)
+    }
+
+    /// Prove that `ciphertext` encrypts 0, e.g. the available balance of an account being
+    /// closed
+    pub fn zero_balance_proof(
+        &self,
+        ciphertext: &ElGamalCiphertext,
+    ) -> TestResult<ZeroCiphertextProofData> {
+        ZeroCiphertextProofData::new(&self.elgamal, ciphertext).map_err(TestError::from_raw_error)
+    }
+
+    /// Prove that `ciphertext` encrypts the same `amount` as a new Pedersen commitment,
+    /// e.g. the remaining balance after a withdrawal
+    pub fn equality_proof(
+        &self,
+        ciphertext: &ElGamalCiphertext,
+        amount: u64,
+    ) -> TestResult<CommitmentProof<CiphertextCommitmentEqualityProofData>> {
+        let (commitment, opening) = Pedersen::new(amount);
+        let proof = CiphertextCommitmentEqualityProofData::new(
+            &self.elgamal,
+            ciphertext,
+            &commitment,
+            &opening,
+            amount,
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        Ok(CommitmentProof {
+            commitment,
+            opening,
+            proof,
+        })
+    }
+}
+
+/// Encrypt `amount` for the first and the second pubkey, e.g. the destination and the
+/// auditor of a transfer, and prove the ciphertext is well-formed
+pub fn ciphertext_validity_proof(
+    first_pubkey: &ElGamalPubkey,
+    second_pubkey: &ElGamalPubkey,
+    amount: u64,
+) -> TestResult<GroupedCiphertextProof> {
+    let opening = PedersenOpening::new_rand();
+    let grouped_ciphertext =
+        GroupedElGamal::encrypt_with([first_pubkey, second_pubkey], amount, &opening);
+    let proof = GroupedCiphertext2HandlesValidityProofData::new(
+        first_pubkey,
+        second_pubkey,
+        &grouped_ciphertext,
+        amount,
+        &opening,
+    )
+    .map_err(TestError::from_raw_error)?;
+
+    Ok(GroupedCiphertextProof {
+        grouped_ciphertext,
+        opening,
+        proof,
+    })
+}
+
+/// Commit to `amount` and prove it's a 64 bit value
+pub fn range_proof(amount: u64) -> TestResult<CommitmentProof<BatchedRangeProofU64Data>> {
+    let (commitment, opening) = Pedersen::new(amount);
+    let proof =
+        BatchedRangeProofU64Data::new(vec![&commitment], vec![amount], vec![64], vec![&opening])
+            .map_err(TestError::from_raw_error)?;
+
+    Ok(CommitmentProof {
+        commitment,
+        opening,
+        proof,
+    })
+}
@@ -1,5 +1,7 @@
 use {
     crate::helpers::suite::{
+        coverage,
+        rng::new_keypair,
         solana_kite::{
             create_associated_token_account, create_token_mint, deploy_program,
             get_token_account_balance, mint_tokens_to_account,
@@ -10,19 +12,28 @@ use {
         },
     },
     litesvm::{types::TransactionMetadata, LiteSVM},
+    pinocchio_token_2022::extension::tlv::{extensions_from_bytes, EXTENSIONS_START},
+    solana_account::Account,
+    solana_address_lookup_table_interface::{
+        program as address_lookup_table_program,
+        state::{AddressLookupTable, LookupTableMeta, LOOKUP_TABLE_MAX_ADDRESSES},
+    },
     solana_compute_budget::compute_budget::ComputeBudget,
+    solana_compute_budget_interface::ComputeBudgetInstruction,
     solana_instruction::{AccountMeta, Instruction},
     solana_keypair::Keypair,
+    solana_message::{v0, AddressLookupTableAccount, VersionedMessage},
     solana_program::native_token::LAMPORTS_PER_SOL,
     solana_pubkey::Pubkey,
     solana_signer::{signers::Signers, Signer},
     solana_system_interface,
-    solana_transaction::Transaction,
+    solana_transaction::{versioned::VersionedTransaction, Transaction},
     spl_associated_token_account::get_associated_token_address,
     strum::IntoEnumIterator,
 };
 
 pub const PROGRAM_NAME_TOKEN_2022_PROXY: &str = "token_2022_proxy";
+pub const PROGRAM_NAME_DUMMY_TRANSFER_HOOK: &str = "dummy_transfer_hook";
 
 pub struct ProgramId {
     // 3rd party
@@ -33,6 +44,35 @@ pub struct ProgramId {
 
     // custom
     pub token_2022_proxy: Pubkey,
+    pub dummy_transfer_hook: Pubkey,
+}
+
+impl Default for ProgramId {
+    fn default() -> Self {
+        Self {
+            // 3rd party
+            system_program: addr_to_sol_pubkey(&solana_system_interface::program::ID),
+            token_program: spl_token::ID,
+            token_2022_program: addr_to_sol_pubkey(&spl_token_2022_interface::ID),
+            associated_token_program: spl_associated_token_account::ID,
+
+            // custom
+            token_2022_proxy: token_2022_proxy::ID.into(),
+            dummy_transfer_hook: dummy_transfer_hook::ID.into(),
+        }
+    }
+}
+
+impl ProgramId {
+    /// Program ids with the proxy deployed under a random id instead of its declared one,
+    /// so that tests running in parallel don't share a proxy program id. The id is drawn
+    /// from the test RNG
+    pub fn with_random_proxy() -> Self {
+        Self {
+            token_2022_proxy: new_keypair().pubkey(),
+            ..Self::default()
+        }
+    }
 }
 
 pub struct App {
@@ -40,42 +80,95 @@ pub struct App {
     pub is_log_displayed: bool,
 
     pub program_id: ProgramId,
+    /// Labels of the users created with `create_user`
+    pub user_labels: Vec<String>,
+    /// Tables created with `create_address_lookup_table`, resolving the accounts of the
+    /// transactions sent by `send_tx_with_ix` and the transfer fee helpers
+    pub address_lookup_tables: Vec<AddressLookupTableAccount>,
+    /// Compute budget instructions prepended to the same transactions
+    pub compute_budget: ComputeBudgetOptions,
+}
+
+/// Compute budget instructions prepended to a transaction, none by default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComputeBudgetOptions {
+    /// `SetComputeUnitLimit`, the compute units of the whole transaction
+    pub compute_unit_limit: Option<u32>,
+    /// `RequestHeapFrame`, a multiple of 1 KiB up to 256 KiB
+    pub heap_frame_bytes: Option<u32>,
+    /// `SetComputeUnitPrice`, the priority fee in micro-lamports per compute unit
+    pub compute_unit_price: Option<u64>,
+}
+
+impl ComputeBudgetOptions {
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let mut instructions = vec![];
+
+        if let Some(units) = self.compute_unit_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+        }
+        if let Some(bytes) = self.heap_frame_bytes {
+            instructions.push(ComputeBudgetInstruction::request_heap_frame(bytes));
+        }
+        if let Some(micro_lamports) = self.compute_unit_price {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+                micro_lamports,
+            ));
+        }
+
+        instructions
+    }
+}
+
+/// How `extension::send_tx_with_options` builds the transaction
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TxOptions<'a> {
+    /// A v0 transaction is built if there are any, resolving its accounts through them
+    pub address_lookup_tables: &'a [AddressLookupTableAccount],
+    pub compute_budget: ComputeBudgetOptions,
+}
+
+/// Copy of the chain state and the created users, see `App::snapshot`
+#[derive(Clone)]
+pub struct AppSnapshot {
+    litesvm: LiteSVM,
+    user_labels: Vec<String>,
+    address_lookup_tables: Vec<AddressLookupTableAccount>,
 }
 
 impl App {
-    pub fn create_app_with_programs(is_log_displayed: bool) -> Self {
+    pub fn create_app_with_programs(is_log_displayed: bool, program_id: ProgramId) -> Self {
         // prepare environment with balances
         let mut litesvm = Self::init_env_with_balances();
 
-        // specify programs
-        let program_id = ProgramId {
-            // 3rd party
-            system_program: addr_to_sol_pubkey(&solana_system_interface::program::ID),
-            token_program: spl_token::ID,
-            token_2022_program: addr_to_sol_pubkey(&spl_token_2022_interface::ID),
-            associated_token_program: spl_associated_token_account::ID,
-
-            // custom
-            token_2022_proxy: token_2022_proxy::ID.into(),
-        };
-
         // upload custom programs
         upload_program(
             &mut litesvm,
             PROGRAM_NAME_TOKEN_2022_PROXY,
             &program_id.token_2022_proxy,
         );
+        coverage::register_proxy_program_id(&program_id.token_2022_proxy);
+        upload_program(
+            &mut litesvm,
+            PROGRAM_NAME_DUMMY_TRANSFER_HOOK,
+            &program_id.dummy_transfer_hook,
+        );
 
         Self {
             litesvm,
             is_log_displayed,
 
             program_id,
+            user_labels: vec![],
+            address_lookup_tables: vec![],
+            compute_budget: ComputeBudgetOptions::default(),
         }
     }
 
+    /// Each app deploys the proxy under its own random program id, see
+    /// `ProgramId::with_random_proxy`
     pub fn new(is_log_displayed: bool) -> Self {
-        Self::create_app_with_programs(is_log_displayed)
+        Self::create_app_with_programs(is_log_displayed, ProgramId::with_random_proxy())
     }
 
     fn init_env_with_balances() -> LiteSVM {
@@ -142,6 +235,133 @@ impl App {
 
     // utils
 
+    /// Create a user funded with `sol_amount` SOL
+    pub fn create_user(&mut self, label: &str, sol_amount: u64) -> AppUser {
+        let user = AppUser::Persona(self.user_labels.len() as u16);
+        self.user_labels.push(label.to_string());
+
+        self.litesvm
+            .airdrop(&user.pubkey(), sol_amount * LAMPORTS_PER_SOL)
+            .unwrap();
+
+        user
+    }
+
+    /// Create `count` users funded with `sol_amount` SOL each, labeled as `{label} {index}`
+    pub fn create_users(&mut self, label: &str, count: usize, sol_amount: u64) -> Vec<AppUser> {
+        (0..count)
+            .map(|i| self.create_user(&format!("{} {}", label, i), sol_amount))
+            .collect()
+    }
+
+    pub fn get_user_label(&self, user: AppUser) -> String {
+        match user {
+            AppUser::Persona(index) => self.user_labels[index as usize].clone(),
+            _ => user.to_string(),
+        }
+    }
+
+    /// Save the current state to set up an expensive fixture once and `restore` it
+    /// before each case
+    pub fn snapshot(&self) -> AppSnapshot {
+        AppSnapshot {
+            litesvm: self.litesvm.clone(),
+            user_labels: self.user_labels.clone(),
+            address_lookup_tables: self.address_lookup_tables.clone(),
+        }
+    }
+
+    /// Return to the state saved with `snapshot`, the snapshot can be restored again
+    pub fn restore(&mut self, snapshot: &AppSnapshot) {
+        self.litesvm = snapshot.litesvm.clone();
+        self.user_labels = snapshot.user_labels.clone();
+        self.address_lookup_tables = snapshot.address_lookup_tables.clone();
+    }
+
+    /// Store a frozen address lookup table holding `addresses` at a random address and
+    /// register it in `address_lookup_tables`. Unlike the ones extended via the lookup
+    /// table program, its addresses can be looked up in the current slot already, except
+    /// for a full table at slot 0, which moves the clock to slot 1
+    pub fn create_address_lookup_table(
+        &mut self,
+        addresses: &[Pubkey],
+    ) -> TestResult<AddressLookupTableAccount> {
+        if addresses.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+            Err(TestError::from_raw_error("Too many addresses"))?;
+        }
+
+        // up to `last_extended_slot` (0), only the addresses before
+        // `last_extended_slot_start_index` are active and all of them after it, see
+        // `LookupTableMeta::get_active_addresses_len`. The u8 can't count a full table,
+        // which is active from slot 1 on
+        let last_extended_slot_start_index = match u8::try_from(addresses.len()) {
+            Ok(x) => x,
+            Err(_) => {
+                let clock = self
+                    .litesvm
+                    .get_sysvar::<solana_program::sysvar::clock::Clock>();
+                if clock.slot == 0 {
+                    self.litesvm.warp_to_slot(1);
+                }
+
+                0
+            }
+        };
+
+        let data = AddressLookupTable {
+            meta: LookupTableMeta {
+                last_extended_slot_start_index,
+                ..LookupTableMeta::default()
+            },
+            addresses: addresses.into(),
+        }
+        .serialize_for_tests()
+        .map_err(TestError::from_raw_error)?;
+
+        let table = AddressLookupTableAccount {
+            key: new_keypair().pubkey(),
+            addresses: addresses.to_vec(),
+        };
+
+        self.litesvm
+            .set_account(
+                table.key,
+                Account {
+                    lamports: self.litesvm.minimum_balance_for_rent_exemption(data.len()),
+                    data,
+                    owner: address_lookup_table_program::ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .map_err(TestError::from_raw_error)?;
+        self.address_lookup_tables.push(table.clone());
+
+        Ok(table)
+    }
+
+    /// Simulate the instructions with the lookup tables and the compute budget of the app,
+    /// see `extension::simulate_tx_with_options`
+    pub fn simulate_tx<S>(
+        &self,
+        instructions: &[Instruction],
+        signers: &S,
+    ) -> TestResult<TransactionMetadata>
+    where
+        S: Signers + ?Sized,
+    {
+        extension::simulate_tx_with_options(
+            &self.litesvm,
+            instructions,
+            signers,
+            &TxOptions {
+                address_lookup_tables: &self.address_lookup_tables,
+                compute_budget: self.compute_budget,
+            },
+            self.is_log_displayed,
+        )
+    }
+
     pub fn transfer_sol(
         &mut self,
         sender: AppUser,
@@ -237,7 +457,7 @@ impl App {
         space: usize,
         owner: &Pubkey,
     ) -> TestResult<(TransactionMetadata, Keypair)> {
-        let account_keypair = new_account.unwrap_or(Keypair::new());
+        let account_keypair = new_account.unwrap_or_else(new_keypair);
         let signers = &[&sender.keypair(), &account_keypair];
 
         let lamports = self
@@ -276,6 +496,41 @@ impl App {
 
         Ok((tx_metadata, account_keypair))
     }
+
+    /// Move the litesvm clock to the first slot of `epoch`
+    pub fn warp_to_epoch(&mut self, epoch: u64) {
+        let slot = self
+            .litesvm
+            .get_sysvar::<solana_program::sysvar::epoch_schedule::EpochSchedule>()
+            .get_first_slot_in_epoch(epoch);
+        self.litesvm.warp_to_slot(slot);
+
+        // warping updates the slot only
+        let mut clock = self
+            .litesvm
+            .get_sysvar::<solana_program::sysvar::clock::Clock>();
+        clock.epoch = epoch;
+        clock.leader_schedule_epoch = epoch + 1;
+        self.litesvm.set_sysvar(&clock);
+    }
+
+    /// Move the litesvm clock forward by `seconds`
+    pub fn advance_clock(&mut self, seconds: i64) {
+        let unix_timestamp = self
+            .litesvm
+            .get_sysvar::<solana_program::sysvar::clock::Clock>()
+            .unix_timestamp;
+        self.set_unix_timestamp(unix_timestamp + seconds);
+    }
+
+    /// Set the litesvm clock to the given unix timestamp
+    pub fn set_unix_timestamp(&mut self, unix_timestamp: i64) {
+        let mut clock = self
+            .litesvm
+            .get_sysvar::<solana_program::sysvar::clock::Clock>();
+        clock.unix_timestamp = unix_timestamp;
+        self.litesvm.set_sysvar(&clock);
+    }
 }
 
 impl Default for App {
@@ -304,7 +559,7 @@ pub fn get_program_size(program_name: &str) -> TestResult<u64> {
         })
 }
 
-fn get_program_path(program_name: &str) -> String {
+pub fn get_program_path(program_name: &str) -> String {
     const PROGRAM_PATH: &str = "../../target/deploy/";
     format!("{}{}.so", PROGRAM_PATH, program_name)
 }
@@ -329,18 +584,35 @@ pub mod extension {
         signers: &S,
         is_log_displayed: bool,
     ) -> TestResult<TransactionMetadata>
+    where
+        S: Signers + ?Sized,
+    {
+        send_tx_with_options(
+            litesvm,
+            instructions,
+            signers,
+            &TxOptions::default(),
+            is_log_displayed,
+        )
+    }
+
+    /// Like `send_tx`, but prepends the compute budget instructions and builds a v0
+    /// transaction if there are address lookup tables, for account lists exceeding the
+    /// legacy transaction size
+    pub fn send_tx_with_options<S>(
+        litesvm: &mut LiteSVM,
+        instructions: &[Instruction],
+        signers: &S,
+        options: &TxOptions,
+        is_log_displayed: bool,
+    ) -> TestResult<TransactionMetadata>
     where
         S: Signers + ?Sized,
     {
         // to avoid AlreadyProcessed error
         litesvm.expire_blockhash();
 
-        let transaction = Transaction::new_signed_with_payer(
-            instructions,
-            signers.pubkeys().first(),
-            signers,
-            litesvm.latest_blockhash(),
-        );
+        let transaction = build_tx(litesvm, instructions, signers, options)?;
 
         match litesvm.send_transaction(transaction) {
             Ok(x) => {
@@ -350,6 +622,8 @@ pub mod extension {
                     println!("Transaction logs: {:#?}\n", logs);
                 }
 
+                coverage::record_proxy_instructions(instructions);
+
                 Ok(x)
             }
             Err(e) => {
@@ -364,6 +638,78 @@ pub mod extension {
         }
     }
 
+    /// Like `send_tx_with_options`, but the transaction is only simulated: the logs and the
+    /// consumed CU are returned and the state is left untouched, so the same instructions
+    /// can be measured repeatedly. The blockhash isn't expired, a transaction identical to
+    /// an already sent one fails with AlreadyProcessed
+    pub fn simulate_tx_with_options<S>(
+        litesvm: &LiteSVM,
+        instructions: &[Instruction],
+        signers: &S,
+        options: &TxOptions,
+        is_log_displayed: bool,
+    ) -> TestResult<TransactionMetadata>
+    where
+        S: Signers + ?Sized,
+    {
+        let transaction = build_tx(litesvm, instructions, signers, options)?;
+
+        match litesvm.simulate_transaction(transaction) {
+            Ok(x) => {
+                if is_log_displayed {
+                    println!("Simulated transaction logs: {:#?}\n", &x.meta.logs);
+                }
+
+                Ok(x.meta)
+            }
+            Err(e) => {
+                let logs = &e.meta.logs;
+
+                if is_log_displayed {
+                    println!("Simulated transaction logs: {:#?}\n", logs);
+                }
+
+                Err(get_test_error_from_logs(logs))
+            }
+        }
+    }
+
+    fn build_tx<S>(
+        litesvm: &LiteSVM,
+        instructions: &[Instruction],
+        signers: &S,
+        options: &TxOptions,
+    ) -> TestResult<VersionedTransaction>
+    where
+        S: Signers + ?Sized,
+    {
+        let payer = signers.pubkeys().first().copied();
+        let all_instructions =
+            [options.compute_budget.instructions(), instructions.to_vec()].concat();
+
+        if options.address_lookup_tables.is_empty() {
+            return Ok(Transaction::new_signed_with_payer(
+                &all_instructions,
+                payer.as_ref(),
+                signers,
+                litesvm.latest_blockhash(),
+            )
+            .into());
+        }
+
+        let payer = payer.ok_or(TestError::from_raw_error("The payer isn't found"))?;
+        let message = v0::Message::try_compile(
+            &payer,
+            &all_instructions,
+            options.address_lookup_tables,
+            litesvm.latest_blockhash(),
+        )
+        .map_err(TestError::from_raw_error)?;
+
+        VersionedTransaction::try_new(VersionedMessage::V0(message), signers)
+            .map_err(TestError::from_raw_error)
+    }
+
     pub fn send_tx_with_ix<S>(
         app: &mut App,
         program_id: &Pubkey,
@@ -381,7 +727,16 @@ pub mod extension {
             data: instruction_data.to_vec(),
         };
 
-        send_tx(&mut app.litesvm, &[ix], signers, app.is_log_displayed)
+        send_tx_with_options(
+            &mut app.litesvm,
+            &[ix],
+            signers,
+            &TxOptions {
+                address_lookup_tables: &app.address_lookup_tables,
+                compute_budget: app.compute_budget,
+            },
+            app.is_log_displayed,
+        )
     }
 }
 
@@ -399,3 +754,59 @@ pub fn assert_error<T: Sized + std::fmt::Debug>(err: TestError, expected: T) {
         error
     );
 }
+
+/// Assert that both accounts have the same owner, lamports and byte-for-byte the same data.
+/// On mismatch the base state and each TLV entry are diffed as hex, 32 bytes per line
+pub fn assert_accounts_equal(
+    app: &App,
+    spl_account: &pinocchio::pubkey::Pubkey,
+    proxy_account: &pinocchio::pubkey::Pubkey,
+) {
+    let [spl, proxy] = [spl_account, proxy_account].map(|x| {
+        app.litesvm
+            .get_account(&pin_to_sol_pubkey(x))
+            .unwrap_or_else(|| panic!("The account {} isn't found", pin_to_sol_pubkey(x)))
+    });
+
+    pretty_assertions::assert_eq!(spl.owner, proxy.owner, "Owners don't match");
+    pretty_assertions::assert_eq!(spl.lamports, proxy.lamports, "Lamports don't match");
+
+    if spl.data == proxy.data {
+        return;
+    }
+
+    let [mut spl_dump, mut proxy_dump] = [&spl.data, &proxy.data].map(|x| dump_account_data(x));
+    // the difference is in bytes the TLV walk doesn't reach, e.g. after an Uninitialized
+    // or a truncated entry, diff the whole data instead
+    if spl_dump == proxy_dump {
+        [spl_dump, proxy_dump] = [&spl.data, &proxy.data].map(|x| dump_bytes("data", x).join("\n"));
+    }
+
+    pretty_assertions::assert_eq!(spl_dump, proxy_dump, "Account data doesn't match");
+}
+
+/// Hex dump of the base state followed by the TLV entries
+fn dump_account_data(data: &[u8]) -> String {
+    let mut lines = vec![format!("length: {}", data.len())];
+    lines.extend(dump_bytes(
+        "base",
+        &data[..data.len().min(EXTENSIONS_START)],
+    ));
+    for (tlv_type, value) in extensions_from_bytes(data) {
+        lines.extend(dump_bytes(&format!("extension {}", tlv_type), value));
+    }
+
+    lines.join("\n")
+}
+
+/// `bytes` as hex, 32 bytes per line prefixed with `label` and the offset
+fn dump_bytes(label: &str, bytes: &[u8]) -> Vec<String> {
+    bytes
+        .chunks(32)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let hex: String = chunk.iter().map(|x| format!("{:02x}", x)).collect();
+            format!("{}[{}]: {}", label, i * 32, hex)
+        })
+        .collect()
+}
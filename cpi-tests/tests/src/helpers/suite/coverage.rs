@@ -0,0 +1,381 @@
+use {
+    crate::helpers::suite::types::{TestError, TestResult},
+    pinocchio_token_2022::{
+        extension::{
+            confidential_transfer::InstructionDiscriminatorConfidentialTransfer,
+            confidential_transfer_fee::InstructionDiscriminatorConfidentialTransferFee,
+            cpi_guard::CpiGuardInstruction,
+            default_account_state::state::DefaultAccountStateInstruction,
+            group_member_pointer::InstructionDiscriminatorGroupMemberPointer,
+            group_pointer::InstructionDiscriminatorGroupPointer,
+            interest_bearing_mint::InterestBearingMintInstruction,
+            memo_transfer::InstructionDiscriminatorMemoTransfer,
+            metadata_pointer::InstructionDiscriminatorMetadataPointer,
+            pausable::PausableInstruction, scaled_ui_amount::ScaledUiAmountInstruction,
+            transfer_fee::InstructionDiscriminatorTransferFee,
+            transfer_hook::TransferHookInstruction,
+        },
+        instruction::TokenInstructionDiscriminator,
+    },
+    solana_instruction::Instruction,
+    solana_pubkey::Pubkey,
+    spl_token_2022_interface::instruction::TokenInstruction,
+    std::{collections::BTreeSet, fmt, fs, io::Write, sync::Mutex},
+};
+
+/// Instructions sent to the proxy by successful transactions, one per line, appended by
+/// `send_tx`. Relative to the `tests` crate like the deployed programs
+pub const COVERAGE_LOG_PATH: &str = "../../target/proxy-coverage.log";
+
+/// Ids the proxy is deployed under, a random one per `App`
+static PROXY_PROGRAM_IDS: Mutex<BTreeSet<Pubkey>> = Mutex::new(BTreeSet::new());
+
+/// Token-2022 instructions without proxy coverage yet, `None` standing for all the
+/// instructions of an extension
+pub const KNOWN_UNCOVERED: &[(TokenInstructionDiscriminator, Option<u8>)] = &[
+    // deprecated in favor of `TransferChecked`
+    (TokenInstructionDiscriminator::Transfer, None),
+    // forwarded as is by the proxy, not tested yet
+    (TokenInstructionDiscriminator::InitializeAccount2, None),
+    (TokenInstructionDiscriminator::SyncNative, None),
+    (TokenInstructionDiscriminator::InitializeAccount3, None),
+    (TokenInstructionDiscriminator::InitializeMultisig2, None),
+    (TokenInstructionDiscriminator::InitializeMint2, None),
+    (TokenInstructionDiscriminator::Reallocate, None),
+    (TokenInstructionDiscriminator::CreateNativeMint, None),
+    (TokenInstructionDiscriminator::WithdrawExcessLamports, None),
+    // no wrappers yet, forwarded as is by the proxy
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::UpdateMint as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::ApproveAccount as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::EmptyAccount as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::Transfer as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::EnableConfidentialCredits as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::DisableConfidentialCredits as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::EnableNonConfidentialCredits as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::DisableNonConfidentialCredits as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::TransferWithFee as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferExtension,
+        Some(InstructionDiscriminatorConfidentialTransfer::ConfigureAccountWithRegistry as u8),
+    ),
+    // require client-side ElGamal keys and zk proofs, see the README
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferFeeExtension,
+        Some(InstructionDiscriminatorConfidentialTransferFee::WithdrawWithheldTokensFromMint as u8),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialTransferFeeExtension,
+        Some(
+            InstructionDiscriminatorConfidentialTransferFee::WithdrawWithheldTokensFromAccounts
+                as u8,
+        ),
+    ),
+    (
+        TokenInstructionDiscriminator::ConfidentialMintBurnExtension,
+        None,
+    ),
+];
+
+/// Token-2022 instruction, identified by its discriminator and, for the extension
+/// instructions, by the extension instruction discriminator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CoveredInstruction {
+    pub discriminator: u8,
+    pub extension_discriminator: Option<u8>,
+}
+
+impl CoveredInstruction {
+    /// Parse the instruction data sent to the proxy the same way the proxy does, `None`
+    /// for the token group, token metadata and proxy's own instructions
+    pub fn from_instruction_data(data: &[u8]) -> Option<Self> {
+        TokenInstruction::unpack(data).ok()?;
+
+        let discriminator = TokenInstructionDiscriminator::try_from(data[0]).ok()?;
+
+        Some(Self {
+            discriminator: discriminator as u8,
+            extension_discriminator: if discriminator.is_extension() {
+                data.get(1).copied()
+            } else {
+                None
+            },
+        })
+    }
+
+    fn to_line(self) -> String {
+        match self.extension_discriminator {
+            Some(extension_discriminator) => {
+                format!("{} {}", self.discriminator, extension_discriminator)
+            }
+            None => self.discriminator.to_string(),
+        }
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut words = line.split_whitespace();
+
+        Some(Self {
+            discriminator: words.next()?.parse().ok()?,
+            extension_discriminator: match words.next() {
+                Some(word) => Some(word.parse().ok()?),
+                None => None,
+            },
+        })
+    }
+}
+
+/// Record the id the proxy is deployed under, for `record_proxy_instructions` to tell the
+/// instructions sent to the proxy apart
+pub fn register_proxy_program_id(program_id: &Pubkey) {
+    PROXY_PROGRAM_IDS.lock().unwrap().insert(*program_id);
+}
+
+/// Append the Token-2022 instructions of a successful transaction sent to the proxy to
+/// the coverage log
+pub fn record_proxy_instructions(instructions: &[Instruction]) {
+    let proxy_program_ids = PROXY_PROGRAM_IDS.lock().unwrap();
+
+    let lines: String = instructions
+        .iter()
+        .filter(|ix| proxy_program_ids.contains(&ix.program_id))
+        .filter_map(|ix| CoveredInstruction::from_instruction_data(&ix.data))
+        .map(|x| format!("{}\n", x.to_line()))
+        .collect();
+
+    if lines.is_empty() {
+        return;
+    }
+
+    // tests run in parallel, the lock held above keeps their lines apart
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(COVERAGE_LOG_PATH)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .unwrap_or_else(|e| panic!("{} can't be written: {}", COVERAGE_LOG_PATH, e));
+}
+
+/// Row of the coverage matrix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageRow {
+    pub instruction: CoveredInstruction,
+    /// e.g. `TransferFeeExtension::TransferCheckedWithFee`
+    pub name: String,
+    pub is_covered: bool,
+    pub is_known_uncovered: bool,
+}
+
+impl CoverageRow {
+    fn discriminator_label(&self) -> String {
+        match self.instruction.extension_discriminator {
+            Some(extension_discriminator) => format!(
+                "{}/{}",
+                self.instruction.discriminator, extension_discriminator
+            ),
+            None => self.instruction.discriminator.to_string(),
+        }
+    }
+}
+
+/// Coverage matrix of all the Token-2022 instructions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageReport {
+    pub rows: Vec<CoverageRow>,
+}
+
+impl CoverageReport {
+    /// Instructions neither exercised via the proxy nor listed in `KNOWN_UNCOVERED`
+    pub fn missing(&self) -> Vec<&CoverageRow> {
+        self.rows
+            .iter()
+            .filter(|x| !x.is_covered && !x.is_known_uncovered)
+            .collect()
+    }
+
+    /// Instructions listed in `KNOWN_UNCOVERED` that are exercised via the proxy now
+    pub fn stale(&self) -> Vec<&CoverageRow> {
+        self.rows
+            .iter()
+            .filter(|x| x.is_covered && x.is_known_uncovered)
+            .collect()
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let covered = self.rows.iter().filter(|x| x.is_covered).count();
+
+        writeln!(
+            f,
+            "Proxy coverage: {}/{} Token-2022 instructions",
+            covered,
+            self.rows.len()
+        )?;
+
+        for row in &self.rows {
+            let status = match (row.is_covered, row.is_known_uncovered) {
+                (true, false) => "covered",
+                (true, true) => "covered, remove from KNOWN_UNCOVERED",
+                (false, true) => "known uncovered",
+                (false, false) => "MISSING",
+            };
+
+            writeln!(
+                f,
+                "{:>4}  {:<64} {}",
+                row.discriminator_label(),
+                row.name,
+                status
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Names of the instructions of the extension routed by `discriminator`, in extension
+/// instruction discriminator order. Empty for the extensions without wrappers
+fn extension_instruction_names(discriminator: TokenInstructionDiscriminator) -> Vec<String> {
+    macro_rules! names {
+        ($instruction:ty) => {
+            (0..=u8::MAX)
+                .map_while(|x| <$instruction>::try_from(x).ok())
+                .map(|x| format!("{:?}", x))
+                .collect()
+        };
+    }
+
+    match discriminator {
+        TokenInstructionDiscriminator::TransferFeeExtension => {
+            names!(InstructionDiscriminatorTransferFee)
+        }
+        TokenInstructionDiscriminator::DefaultAccountStateExtension => {
+            names!(DefaultAccountStateInstruction)
+        }
+        TokenInstructionDiscriminator::MemoTransferExtension => {
+            names!(InstructionDiscriminatorMemoTransfer)
+        }
+        TokenInstructionDiscriminator::InterestBearingMintExtension => {
+            names!(InterestBearingMintInstruction)
+        }
+        TokenInstructionDiscriminator::CpiGuardExtension => names!(CpiGuardInstruction),
+        TokenInstructionDiscriminator::TransferHookExtension => names!(TransferHookInstruction),
+        TokenInstructionDiscriminator::ConfidentialTransferExtension => {
+            names!(InstructionDiscriminatorConfidentialTransfer)
+        }
+        TokenInstructionDiscriminator::ConfidentialTransferFeeExtension => {
+            names!(InstructionDiscriminatorConfidentialTransferFee)
+        }
+        TokenInstructionDiscriminator::MetadataPointerExtension => {
+            names!(InstructionDiscriminatorMetadataPointer)
+        }
+        TokenInstructionDiscriminator::GroupPointerExtension => {
+            names!(InstructionDiscriminatorGroupPointer)
+        }
+        TokenInstructionDiscriminator::GroupMemberPointerExtension => {
+            names!(InstructionDiscriminatorGroupMemberPointer)
+        }
+        TokenInstructionDiscriminator::ScaledUiAmountExtension => {
+            names!(ScaledUiAmountInstruction)
+        }
+        TokenInstructionDiscriminator::PausableExtension => names!(PausableInstruction),
+        _ => vec![],
+    }
+}
+
+/// All the Token-2022 instructions with their names, an extension without wrappers
+/// being a single instruction
+pub fn token_2022_instructions() -> Vec<(CoveredInstruction, String)> {
+    let mut instructions = vec![];
+
+    for discriminator in
+        (0..=u8::MAX).map_while(|x| TokenInstructionDiscriminator::try_from(x).ok())
+    {
+        let names = extension_instruction_names(discriminator);
+
+        if names.is_empty() {
+            instructions.push((
+                CoveredInstruction {
+                    discriminator: discriminator as u8,
+                    extension_discriminator: None,
+                },
+                format!("{:?}", discriminator),
+            ));
+            continue;
+        }
+
+        for (extension_discriminator, name) in names.into_iter().enumerate() {
+            instructions.push((
+                CoveredInstruction {
+                    discriminator: discriminator as u8,
+                    extension_discriminator: Some(extension_discriminator as u8),
+                },
+                format!("{:?}::{}", discriminator, name),
+            ));
+        }
+    }
+
+    instructions
+}
+
+/// Build the coverage matrix from the instructions recorded in the coverage log
+pub fn get_proxy_coverage_report() -> TestResult<CoverageReport> {
+    let log = fs::read_to_string(COVERAGE_LOG_PATH).map_err(|e| TestError {
+        info: format!(
+            "{} isn't found, run the test suite first: {}",
+            COVERAGE_LOG_PATH, e
+        ),
+        index: None,
+    })?;
+
+    let recorded: BTreeSet<CoveredInstruction> = log
+        .lines()
+        .filter_map(CoveredInstruction::from_line)
+        .collect();
+
+    let rows = token_2022_instructions()
+        .into_iter()
+        .map(|(instruction, name)| CoverageRow {
+            is_covered: recorded.iter().any(|x| {
+                x.discriminator == instruction.discriminator
+                    && (instruction.extension_discriminator.is_none()
+                        || x.extension_discriminator == instruction.extension_discriminator)
+            }),
+            is_known_uncovered: KNOWN_UNCOVERED.iter().any(|(discriminator, extension)| {
+                *discriminator as u8 == instruction.discriminator
+                    && (extension.is_none() || *extension == instruction.extension_discriminator)
+            }),
+            instruction,
+            name,
+        })
+        .collect();
+
+    Ok(CoverageReport { rows })
+}
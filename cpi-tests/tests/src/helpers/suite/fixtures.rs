@@ -0,0 +1,157 @@
+use {
+    crate::helpers::suite::{
+        core::App,
+        types::{TestError, TestResult},
+    },
+    base64::{engine::general_purpose::STANDARD as BASE64, Engine},
+    serde::{Deserialize, Serialize},
+    solana_account::Account,
+    solana_pubkey::Pubkey,
+    std::{
+        fs,
+        path::{Path, PathBuf},
+        str::FromStr,
+    },
+};
+
+/// Dumped mainnet accounts, relative to the `tests` crate like the deployed programs
+pub const MAINNET_FIXTURES_DIR: &str = "fixtures/mainnet";
+
+/// Account file written by `solana account <ADDRESS> --output json`, the format
+/// `solana-test-validator --account` reads
+#[derive(Debug, Deserialize, Serialize)]
+struct JsonAccountFile {
+    pubkey: String,
+    account: JsonAccount,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonAccount {
+    lamports: u64,
+    /// base64 encoded data followed by its encoding
+    data: (String, String),
+    owner: String,
+    executable: bool,
+    rent_epoch: u64,
+}
+
+/// Account loaded from a JSON account file or from a bincode serialized `Account`
+/// named `<ADDRESS>.bin`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountFixture {
+    pub pubkey: Pubkey,
+    pub account: Account,
+}
+
+impl AccountFixture {
+    pub fn load(path: &Path) -> TestResult<Self> {
+        let error = |e: &dyn std::fmt::Debug| TestError {
+            info: format!("{} can't be loaded: {:?}", path.display(), e),
+            index: None,
+        };
+
+        let bytes = fs::read(path).map_err(|e| error(&e))?;
+
+        match path.extension().and_then(|x| x.to_str()) {
+            Some("json") => {
+                let file: JsonAccountFile =
+                    serde_json::from_slice(&bytes).map_err(|e| error(&e))?;
+                let (data, encoding) = &file.account.data;
+
+                if encoding != "base64" {
+                    return Err(error(&format!("unsupported encoding {}", encoding)));
+                }
+
+                Ok(Self {
+                    pubkey: Pubkey::from_str(&file.pubkey).map_err(|e| error(&e))?,
+                    account: Account {
+                        lamports: file.account.lamports,
+                        data: BASE64.decode(data).map_err(|e| error(&e))?,
+                        owner: Pubkey::from_str(&file.account.owner).map_err(|e| error(&e))?,
+                        executable: file.account.executable,
+                        rent_epoch: file.account.rent_epoch,
+                    },
+                })
+            }
+            Some("bin") => {
+                let pubkey = path
+                    .file_stem()
+                    .and_then(|x| x.to_str())
+                    .ok_or_else(|| error(&"the file isn't named after the address"))?;
+
+                Ok(Self {
+                    pubkey: Pubkey::from_str(pubkey).map_err(|e| error(&e))?,
+                    account: bincode::deserialize(&bytes).map_err(|e| error(&e))?,
+                })
+            }
+            _ => Err(error(&"only .json and .bin account files are supported")),
+        }
+    }
+
+    /// Write the account in the format of `solana account <ADDRESS> --output json`
+    pub fn save_json(&self, path: &Path) -> TestResult<()> {
+        let file = JsonAccountFile {
+            pubkey: self.pubkey.to_string(),
+            account: JsonAccount {
+                lamports: self.account.lamports,
+                data: (BASE64.encode(&self.account.data), "base64".to_string()),
+                owner: self.account.owner.to_string(),
+                executable: self.account.executable,
+                rent_epoch: self.account.rent_epoch,
+            },
+        };
+
+        let json = serde_json::to_vec_pretty(&file).map_err(TestError::from_raw_error)?;
+        fs::write(path, json).map_err(TestError::from_raw_error)
+    }
+
+    /// Write the bincode serialized account to `<ADDRESS>.bin` in `dir` and return its path
+    pub fn save_bincode(&self, dir: &Path) -> TestResult<PathBuf> {
+        let path = dir.join(format!("{}.bin", self.pubkey));
+        let bytes = bincode::serialize(&self.account).map_err(TestError::from_raw_error)?;
+
+        fs::write(&path, bytes).map_err(TestError::from_raw_error)?;
+
+        Ok(path)
+    }
+}
+
+/// Load the `.json` and `.bin` account files of `dir` in file name order, none if the
+/// directory doesn't exist
+pub fn load_account_fixtures(dir: &Path) -> TestResult<Vec<AccountFixture>> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(vec![]);
+    };
+
+    let mut paths = entries
+        .map(|x| x.map(|x| x.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(TestError::from_raw_error)?;
+    paths.retain(|x| matches!(x.extension().and_then(|x| x.to_str()), Some("json" | "bin")));
+    paths.sort();
+
+    paths.iter().map(|x| AccountFixture::load(x)).collect()
+}
+
+impl App {
+    /// Store the fixture account in litesvm, replacing the account at its address
+    pub fn set_account_fixture(&mut self, fixture: &AccountFixture) -> TestResult<()> {
+        self.litesvm
+            .set_account(fixture.pubkey, fixture.account.clone())
+            .map_err(TestError::from_raw_error)
+    }
+
+    /// Dump an account of litesvm as a fixture
+    pub fn get_account_fixture(&self, pubkey: &Pubkey) -> TestResult<AccountFixture> {
+        let account = self
+            .litesvm
+            .get_account(pubkey)
+            .ok_or(TestError::from_raw_error("The account isn't found"))?;
+
+        Ok(AccountFixture {
+            pubkey: *pubkey,
+            account,
+        })
+    }
+}
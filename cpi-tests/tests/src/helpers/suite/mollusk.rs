@@ -0,0 +1,102 @@
+// instruction level harness, an alternative to the litesvm based `App` for parity checks
+// which don't need signatures, blockhashes and account creation transactions
+
+use {
+    crate::helpers::suite::{
+        core::{get_program_path, ProgramId, PROGRAM_NAME_TOKEN_2022_PROXY},
+        types::Target,
+    },
+    mollusk_svm::{program::loader_keys::LOADER_V3, result::InstructionResult, Mollusk},
+    mollusk_svm_programs_token::token2022,
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    std::fs,
+};
+
+pub struct MolluskApp {
+    pub mollusk: Mollusk,
+
+    pub program_id: ProgramId,
+}
+
+impl MolluskApp {
+    pub fn new() -> Self {
+        let program_id = ProgramId::default();
+        let mut mollusk = Mollusk::default();
+
+        let elf = fs::read(get_program_path(PROGRAM_NAME_TOKEN_2022_PROXY)).unwrap();
+        mollusk.add_program_with_elf_and_loader(&program_id.token_2022_proxy, &elf, &LOADER_V3);
+        token2022::add_program(&mut mollusk);
+
+        Self {
+            mollusk,
+            program_id,
+        }
+    }
+
+    /// Rent exempt account owned by Token-2022, e.g. a mint before `InitializeMint`
+    pub fn token_2022_account(&self, data: Vec<u8>) -> Account {
+        Account {
+            lamports: self.mollusk.sysvars.rent.minimum_balance(data.len()),
+            data,
+            owner: self.program_id.token_2022_program,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    /// Send a Token-2022 instruction directly or through the proxy. The Token-2022 program
+    /// account is added to `accounts` in both cases
+    pub fn process(
+        &self,
+        target: Target,
+        ix: &Instruction,
+        accounts: &[(Pubkey, Account)],
+    ) -> InstructionResult {
+        let ProgramId {
+            token_2022_program,
+            token_2022_proxy,
+            ..
+        } = self.program_id;
+
+        let mut ix = ix.clone();
+
+        if let Target::Proxy = target {
+            ix.program_id = token_2022_proxy;
+            ix.accounts
+                .push(AccountMeta::new_readonly(token_2022_program, false));
+        }
+
+        let accounts = [accounts, &[token2022::keyed_account()]].concat();
+
+        self.mollusk.process_instruction(&ix, &accounts)
+    }
+
+    /// Process the instruction with both targets and assert they end the same way
+    pub fn assert_parity(&self, ix: &Instruction, accounts: &[(Pubkey, Account)]) {
+        let [spl, proxy] = [Target::Spl, Target::Proxy].map(|x| self.process(x, ix, accounts));
+
+        pretty_assertions::assert_eq!(spl.raw_result, proxy.raw_result);
+
+        for ((spl_address, spl_account), (proxy_address, proxy_account)) in spl
+            .resulting_accounts
+            .iter()
+            .zip(proxy.resulting_accounts.iter())
+        {
+            pretty_assertions::assert_eq!(spl_address, proxy_address);
+            pretty_assertions::assert_eq!(
+                spl_account,
+                proxy_account,
+                "\n\n❌ Account {} differs",
+                spl_address
+            );
+        }
+    }
+}
+
+impl Default for MolluskApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
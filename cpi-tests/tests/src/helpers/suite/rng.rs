@@ -0,0 +1,75 @@
+use {
+    proptest::test_runner::{Config as ProptestConfig, RngSeed},
+    rand::{rngs::StdRng, RngCore, SeedableRng},
+    solana_keypair::Keypair,
+    std::{
+        cell::{Cell, RefCell},
+        env,
+        sync::OnceLock,
+    },
+};
+
+/// Env var fixing the seed of the test RNGs, e.g. `TEST_SEED=42 cargo test` reproduces
+/// the keypairs and the generated inputs of a failed run
+pub const TEST_SEED_ENV: &str = "TEST_SEED";
+
+thread_local! {
+    static TEST_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+    static IS_SEED_PRINTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Seed of the run: `TEST_SEED` if it's set, a random one otherwise. It's printed on the
+/// first call of each test thread, so a failed test shows it along with its captured output
+pub fn test_seed() -> u64 {
+    static SEED: OnceLock<u64> = OnceLock::new();
+
+    let seed = *SEED.get_or_init(|| match env::var(TEST_SEED_ENV) {
+        Ok(x) => x
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be a u64, got {:?}", TEST_SEED_ENV, x)),
+        Err(_) => rand::random(),
+    });
+    if !IS_SEED_PRINTED.replace(true) {
+        println!("{}={}", TEST_SEED_ENV, seed);
+    }
+
+    seed
+}
+
+/// Run `f` with the RNG of the current test. Tests run on threads named after them, so
+/// each one gets its own stream derived from the seed of the run and doesn't depend on
+/// the order the tests are scheduled in
+pub fn with_test_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    TEST_RNG.with_borrow_mut(|rng| {
+        let rng = rng.get_or_insert_with(|| {
+            // FNV-1a of the test name
+            let seed = std::thread::current()
+                .name()
+                .unwrap_or_default()
+                .bytes()
+                .fold(test_seed() ^ 0xcbf2_9ce4_8422_2325, |hash, x| {
+                    (hash ^ x as u64).wrapping_mul(0x100_0000_01b3)
+                });
+
+            StdRng::seed_from_u64(seed)
+        });
+
+        f(rng)
+    })
+}
+
+/// Keypair drawn from the test RNG, use it instead of `Keypair::new`
+pub fn new_keypair() -> Keypair {
+    let mut secret_key = [0; 32];
+    with_test_rng(|rng| rng.fill_bytes(&mut secret_key));
+
+    Keypair::new_from_array(secret_key)
+}
+
+/// Default proptest config with the runner seeded from the seed of the run
+pub fn proptest_config() -> ProptestConfig {
+    ProptestConfig {
+        rng_seed: RngSeed::Fixed(test_seed()),
+        ..ProptestConfig::default()
+    }
+}
@@ -0,0 +1,211 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            cpi_guard::Token2022CpiGuardExtension,
+            default_account_state::Token2022DefaultAccountStateExtension,
+            group_member_pointer::Token2022GroupMemberPointerExtension,
+            group_pointer::Token2022GroupPointerExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            interest_bearing_mint::Token2022InterestBearingMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            mint_close_authority::Token2022MintCloseAuthorityExtension,
+            mint_factory::{ExtensionSpec, Token2022MintFactoryExtension},
+            pausable::Token2022PausableExtension,
+            permanent_delegate::Token2022PermanentDelegateExtension,
+            scaled_ui_amount::Token2022ScaledUiAmountExtension,
+            token_account::Token2022TokenAccountExtension,
+            token_group::Token2022TokenGroupExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+            token_metadata::Token2022TokenMetadataExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+            transfer_hook::Token2022TransferHookExtension,
+        },
+        suite::{
+            core::{extension::get_account_data, App},
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    solana_signer::Signer,
+    spl_token_2022_interface::{
+        extension::{
+            cpi_guard::CpiGuard, default_account_state::DefaultAccountState,
+            group_member_pointer::GroupMemberPointer, group_pointer::GroupPointer,
+            interest_bearing_mint::InterestBearingConfig, metadata_pointer::MetadataPointer,
+            mint_close_authority::MintCloseAuthority, pausable::PausableConfig,
+            permanent_delegate::PermanentDelegate, scaled_ui_amount::ScaledUiAmountConfig,
+            transfer_fee::TransferFeeConfig, transfer_hook::TransferHook, BaseStateWithExtensions,
+            ExtensionType, StateWithExtensions,
+        },
+        state::{Account, Mint},
+    },
+    spl_token_group_interface::state::{TokenGroup, TokenGroupMember},
+    spl_token_metadata_interface::state::TokenMetadata,
+};
+
+/// State of a mint or token account extension, read by the query helper of the extension
+/// with the SPL parser or the pinocchio one depending on the target
+pub trait ExtensionState: Sized {
+    fn query(app: &App, target: Target, address: &Pubkey) -> TestResult<Self>;
+}
+
+macro_rules! impl_extension_state {
+    ($($state:ty => $query:ident,)*) => {
+        $(
+            impl ExtensionState for $state {
+                fn query(app: &App, target: Target, address: &Pubkey) -> TestResult<Self> {
+                    app.$query(target, address)
+                }
+            }
+        )*
+    };
+}
+
+impl_extension_state! {
+    CpiGuard => token_2022_query_cpi_guard,
+    DefaultAccountState => token_2022_query_default_account_state,
+    GroupMemberPointer => token_2022_query_group_member_pointer,
+    GroupPointer => token_2022_query_group_pointer,
+    InterestBearingConfig => token_2022_query_interest_bearing_mint,
+    MetadataPointer => token_2022_query_metadata_pointer,
+    MintCloseAuthority => token_2022_query_mint_close_authority,
+    PausableConfig => token_2022_query_pausable_config,
+    PermanentDelegate => token_2022_query_permanent_delegate,
+    ScaledUiAmountConfig => token_2022_query_scaled_ui_amount,
+    TokenGroup => token_2022_query_token_group,
+    TokenGroupMember => token_2022_query_token_group_member,
+    TokenMetadata => token_2022_query_token_metadata,
+    TransferFeeConfig => token_2022_query_transfer_fee_config,
+    TransferHook => token_2022_query_transfer_hook,
+}
+
+/// Facade over the per-extension helper traits: the instructions are sent to `target`
+/// and the state is read with its parser, so a test needs a single import for the
+/// common flow. Anything else is still available on `app`
+pub struct TokenClient<'a> {
+    pub app: &'a mut App,
+    pub target: Target,
+}
+
+impl App {
+    pub fn token_client(&mut self, target: Target) -> TokenClient<'_> {
+        TokenClient { app: self, target }
+    }
+}
+
+impl TokenClient<'_> {
+    /// Create a mint with `extensions` initialized in the given order, Admin pays for it
+    pub fn create_mint(
+        &mut self,
+        extensions: &[ExtensionSpec],
+        decimals: u8,
+        mint_authority: AppUser,
+        freeze_authority: Option<AppUser>,
+    ) -> TestResult<Pubkey> {
+        self.app.token_2022_try_create_mint_with_extensions(
+            self.target,
+            extensions,
+            decimals,
+            &mint_authority.pubkey(),
+            freeze_authority.map(|x| x.pubkey()).as_ref(),
+        )
+    }
+
+    /// Create a token account of `owner` with `extensions` along with the ones required
+    /// by the mint extensions, Admin pays for it
+    pub fn create_token_account(
+        &mut self,
+        owner: AppUser,
+        mint: &Pubkey,
+        extensions: &[ExtensionType],
+    ) -> TestResult<Pubkey> {
+        let mint_extensions =
+            StateWithExtensions::<Mint>::unpack(&get_account_data(self.app, mint)?)
+                .and_then(|x| x.get_extension_types())
+                .map_err(TestError::from_raw_error)?;
+
+        let mut account_extensions =
+            ExtensionType::get_required_init_account_extensions(&mint_extensions);
+        for extension in extensions {
+            if !account_extensions.contains(extension) {
+                account_extensions.push(*extension);
+            }
+        }
+
+        let (_, keypair) = self.app.token_2022_try_create_and_init_token_account(
+            AppUser::Admin,
+            &owner.pubkey(),
+            mint,
+            &account_extensions,
+        )?;
+
+        Ok(keypair.pubkey().to_bytes())
+    }
+
+    pub fn mint_to(
+        &mut self,
+        mint_authority: AppUser,
+        mint: &Pubkey,
+        account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        self.app
+            .token_2022_try_mint_to(self.target, mint_authority, mint, account, amount)
+    }
+
+    /// `TransferChecked` with the mint and the decimals read from the source account
+    pub fn transfer(
+        &mut self,
+        owner: AppUser,
+        from: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let mint = self.token_account(from)?.mint.to_bytes();
+        let decimals = self.mint(&mint)?.decimals;
+
+        self.app.token_2022_try_transfer_checked(
+            self.target,
+            owner,
+            from,
+            &mint,
+            to,
+            amount,
+            decimals,
+        )
+    }
+
+    /// `Burn` with the mint read from the account
+    pub fn burn(
+        &mut self,
+        owner: AppUser,
+        account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let mint = self.token_account(account)?.mint.to_bytes();
+
+        self.app
+            .token_2022_try_burn(self.target, owner, account, &mint, amount)
+    }
+
+    pub fn mint(&self, mint: &Pubkey) -> TestResult<Mint> {
+        self.app.token_2022_query_mint(self.target, mint)
+    }
+
+    pub fn token_account(&self, account: &Pubkey) -> TestResult<Account> {
+        self.app
+            .token_2022_query_token_account(self.target, account)
+    }
+
+    pub fn balance(&self, account: &Pubkey) -> TestResult<u64> {
+        Ok(self.token_account(account)?.amount)
+    }
+
+    /// Extension state of the mint or the token account at `address`, e.g.
+    /// `client.extension::<TransferHook>(&mint)`
+    pub fn extension<T: ExtensionState>(&self, address: &Pubkey) -> TestResult<T> {
+        T::query(self.app, self.target, address)
+    }
+}
@@ -0,0 +1,116 @@
+use {litesvm::types::TransactionMetadata, solana_pubkey::Pubkey, std::str::FromStr};
+
+const LOG_PREFIX: &str = "Program log: ";
+
+/// Program invocation parsed from the transaction logs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramInvocation {
+    pub program_id: Pubkey,
+    /// 1 for top-level instructions, 2 and more for CPIs
+    pub stack_height: usize,
+    /// Compute units consumed by the invocation, including its CPIs
+    pub compute_units: Option<u64>,
+    /// Messages logged by the program itself
+    pub logs: Vec<String>,
+}
+
+/// All program invocations in execution order
+pub fn get_invocations(tx: &TransactionMetadata) -> Vec<ProgramInvocation> {
+    let mut invocations: Vec<ProgramInvocation> = vec![];
+    // indices of the invocations being executed
+    let mut stack: Vec<usize> = vec![];
+
+    for log in &tx.logs {
+        if let Some(message) = log.strip_prefix(LOG_PREFIX) {
+            if let Some(&i) = stack.last() {
+                invocations[i].logs.push(message.to_string());
+            }
+            continue;
+        }
+
+        let words: Vec<&str> = log.split_whitespace().collect();
+        match words[..] {
+            ["Program", program_id, "invoke", stack_height] => {
+                let (Ok(program_id), Ok(stack_height)) = (
+                    Pubkey::from_str(program_id),
+                    stack_height.trim_matches(['[', ']']).parse(),
+                ) else {
+                    continue;
+                };
+
+                stack.push(invocations.len());
+                invocations.push(ProgramInvocation {
+                    program_id,
+                    stack_height,
+                    compute_units: None,
+                    logs: vec![],
+                });
+            }
+            ["Program", _, "consumed", compute_units, "of", _, "compute", "units"] => {
+                if let Some(&i) = stack.last() {
+                    invocations[i].compute_units = compute_units.parse().ok();
+                }
+            }
+            ["Program", _, "success"] | ["Program", _, "failed:", ..] => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    invocations
+}
+
+/// Invocations made by other programs
+pub fn get_cpis(tx: &TransactionMetadata) -> Vec<ProgramInvocation> {
+    get_invocations(tx)
+        .into_iter()
+        .filter(|x| x.stack_height > 1)
+        .collect()
+}
+
+/// Messages logged by all programs, in execution order
+pub fn get_program_logs(tx: &TransactionMetadata) -> Vec<String> {
+    tx.logs
+        .iter()
+        .filter_map(|x| x.strip_prefix(LOG_PREFIX))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Compute units consumed by each top-level instruction
+pub fn get_instruction_compute_units(tx: &TransactionMetadata) -> Vec<u64> {
+    get_invocations(tx)
+        .into_iter()
+        .filter(|x| x.stack_height == 1)
+        .map(|x| x.compute_units.unwrap_or_default())
+        .collect()
+}
+
+/// Number of inner instructions recorded by the runtime
+pub fn get_inner_instruction_count(tx: &TransactionMetadata) -> usize {
+    tx.inner_instructions.iter().map(|x| x.len()).sum()
+}
+
+/// Assert that the transaction invoked `program_id` through CPI
+pub fn assert_cpi_to(tx: &TransactionMetadata, program_id: &Pubkey) {
+    let cpis = get_cpis(tx);
+
+    assert!(
+        cpis.iter().any(|x| &x.program_id == program_id),
+        "\n\n❌ Expected CPI to {}, received: {:#?}",
+        program_id,
+        cpis.iter().map(|x| x.program_id).collect::<Vec<_>>()
+    );
+}
+
+/// Assert that the transaction made no CPI at all
+pub fn assert_no_cpi(tx: &TransactionMetadata) {
+    let cpis = get_cpis(tx);
+
+    assert!(
+        cpis.is_empty(),
+        "\n\n❌ Expected no CPI, received: {:#?}",
+        cpis.iter().map(|x| x.program_id).collect::<Vec<_>>()
+    );
+}
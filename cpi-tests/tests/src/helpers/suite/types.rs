@@ -1,6 +1,8 @@
 use {
+    solana_instruction::error::InstructionError,
     solana_keypair::Keypair,
     solana_program_option::COption,
+    solana_signer::Signer,
     std::fmt::Debug,
     strum::IntoEnumIterator,
     strum_macros::{Display, EnumIter, IntoStaticStr},
@@ -52,31 +54,45 @@ pub trait PinPubkey {
     fn pubkey(&self) -> pinocchio::pubkey::Pubkey;
 }
 
-#[derive(Debug, Clone, Copy, Display, IntoStaticStr, EnumIter, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Display, IntoStaticStr, PartialEq, Eq)]
 pub enum AppUser {
     Admin,
     Alice,
     Bob,
+    /// User created with `App::create_user`, the keypair is derived from the index
+    Persona(u16),
 }
 
 impl SolPubkey for AppUser {
     fn pubkey(&self) -> solana_pubkey::Pubkey {
-        solana_pubkey::Pubkey::from_str_const(self.get_pubkey_str())
+        match self.get_pubkey_str() {
+            Some(x) => solana_pubkey::Pubkey::from_str_const(x),
+            None => self.keypair().pubkey(),
+        }
     }
 }
 
 impl PinPubkey for AppUser {
     fn pubkey(&self) -> pinocchio::pubkey::Pubkey {
-        pinocchio_pubkey::from_str(self.get_pubkey_str())
+        match self.get_pubkey_str() {
+            Some(x) => pinocchio_pubkey::from_str(x),
+            None => self.keypair().pubkey().to_bytes(),
+        }
     }
 }
 
 impl AppUser {
-    fn get_pubkey_str(&self) -> &str {
+    /// Predefined users, funded on the app creation
+    pub fn iter() -> impl Iterator<Item = Self> {
+        [Self::Admin, Self::Alice, Self::Bob].into_iter()
+    }
+
+    fn get_pubkey_str(&self) -> Option<&str> {
         match self {
-            Self::Admin => PUBKEY_ADMIN,
-            Self::Alice => PUBKEY_ALICE,
-            Self::Bob => PUBKEY_BOB,
+            Self::Admin => Some(PUBKEY_ADMIN),
+            Self::Alice => Some(PUBKEY_ALICE),
+            Self::Bob => Some(PUBKEY_BOB),
+            Self::Persona(_) => None,
         }
     }
 
@@ -85,6 +101,12 @@ impl AppUser {
             Self::Admin => KEYPAIR_ADMIN,
             Self::Alice => KEYPAIR_ALICE,
             Self::Bob => KEYPAIR_BOB,
+            Self::Persona(index) => {
+                let mut secret_key = [u8::MAX; 32];
+                secret_key[..2].copy_from_slice(&index.to_le_bytes());
+
+                return Keypair::new_from_array(secret_key);
+            }
         };
 
         Keypair::from_base58_string(base58_string)
@@ -239,6 +261,24 @@ impl TestError {
         }
     }
 
+    /// Expected error of a failed instruction, as it's reported in the transaction logs
+    pub fn from_instruction_error(error: InstructionError) -> Self {
+        Self {
+            info: error.to_string(),
+            index: None,
+        }
+    }
+
+    /// Expected error of a failed instruction returning `ProgramError::Custom(code)`
+    pub fn from_custom_error(code: u32) -> Self {
+        Self::from_instruction_error(InstructionError::Custom(code))
+    }
+
+    /// Expected error of a failed token-2022 instruction
+    pub fn from_token_error(error: spl_token_2022_interface::error::TokenError) -> Self {
+        Self::from_custom_error(error as u32)
+    }
+
     /// Parses custom program error from a vector of log strings
     /// Returns the error code as u32 if found, otherwise returns None
     pub fn parse_custom_program_error(logs: &[String]) -> Option<u32> {
@@ -279,6 +319,7 @@ impl TestError {
 }
 
 /// to switch between SPL and Proxy programs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Target {
     /// execute token-2022 instruction directly, read token-2022 state using spl interface
     Spl,
@@ -286,6 +327,16 @@ pub enum Target {
     Proxy,
 }
 
+/// Authority of an instruction executed using proxy program, to exercise both the `invoke`
+/// and the `invoke_signed` paths of the wrappers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyAuthority {
+    /// the user signs the transaction, the proxy calls `invoke`
+    User(AppUser),
+    /// the proxy PDA, the proxy calls `invoke_signed` with its seeds
+    Pda,
+}
+
 pub fn sol_to_pin_pubkey(sol_pubkey: &solana_pubkey::Pubkey) -> pinocchio::pubkey::Pubkey {
     pinocchio::pubkey::Pubkey::from(sol_pubkey.to_bytes())
 }
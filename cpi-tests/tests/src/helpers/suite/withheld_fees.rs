@@ -0,0 +1,136 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+        },
+        suite::{
+            core::{extension::get_account_data, App},
+            types::{AppUser, Target, TestError, TestResult},
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    pinocchio_token_2022::extension::transfer_fee::state::expected_transfer_fee_from_bytes,
+    pretty_assertions::assert_eq,
+    solana_program::clock::Clock,
+    std::collections::BTreeMap,
+};
+
+/// Expected withheld amounts of a transfer fee mint and of the token accounts receiving
+/// its transfers, kept in sync with the transfers and the withdraw/harvest instructions
+/// sent by a test and compared with the on-chain state in one call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithheldFeeTracker {
+    pub mint: Pubkey,
+    /// Withheld amount of the mint `TransferFeeConfig`
+    pub mint_withheld: u64,
+    /// Withheld amount of the `TransferFeeAmount` of each account
+    pub accounts: BTreeMap<Pubkey, u64>,
+}
+
+impl WithheldFeeTracker {
+    pub fn new(mint: &Pubkey) -> Self {
+        Self {
+            mint: *mint,
+            mint_withheld: 0,
+            accounts: BTreeMap::new(),
+        }
+    }
+
+    /// Fee of a transfer of `amount` in the current epoch, calculated from the mint state
+    /// with the pinocchio `expected_transfer_fee_from_bytes`, wherever the mint holds its
+    /// `TransferFeeConfig`
+    pub fn fee(&self, app: &App, amount: u64) -> TestResult<u64> {
+        let epoch = app.litesvm.get_sysvar::<Clock>().epoch;
+        let data = get_account_data(app, &self.mint)?;
+
+        expected_transfer_fee_from_bytes(&data, amount, epoch).map_err(TestError::from_raw_error)
+    }
+
+    /// Record a transfer of `amount` to `destination` and return the fee withheld in it
+    pub fn record_transfer(
+        &mut self,
+        app: &App,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> TestResult<u64> {
+        let fee = self.fee(app, amount)?;
+        *self.accounts.entry(*destination).or_default() += fee;
+
+        Ok(fee)
+    }
+
+    /// Send `TransferCheckedWithFee` with the expected fee, recording it if the transfer
+    /// succeeds
+    pub fn try_transfer_checked_with_fee(
+        &mut self,
+        app: &mut App,
+        target: Target,
+        sender: AppUser,
+        from: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+    ) -> TestResult<TransactionMetadata> {
+        let decimals = app.token_2022_query_mint(target, &self.mint)?.decimals;
+        let fee = self.fee(app, amount)?;
+
+        let tx_metadata = app.token_2022_try_transfer_checked_with_fee(
+            target, sender, from, &self.mint, to, amount, decimals, fee,
+        )?;
+        *self.accounts.entry(*to).or_default() += fee;
+
+        Ok(tx_metadata)
+    }
+
+    /// Record `WithdrawWithheldTokensFromAccounts` and return the withdrawn amount
+    pub fn record_withdraw_from_accounts(&mut self, sources: &[Pubkey]) -> u64 {
+        sources.iter().map(|x| self.take_account_withheld(x)).sum()
+    }
+
+    /// Record `HarvestWithheldTokensToMint`
+    pub fn record_harvest_to_mint(&mut self, sources: &[Pubkey]) {
+        self.mint_withheld += self.record_withdraw_from_accounts(sources);
+    }
+
+    /// Record `WithdrawWithheldTokensFromMint` and return the withdrawn amount
+    pub fn record_withdraw_from_mint(&mut self) -> u64 {
+        std::mem::take(&mut self.mint_withheld)
+    }
+
+    /// Total amount withheld in the mint and the accounts
+    pub fn total_withheld(&self) -> u64 {
+        self.mint_withheld + self.accounts.values().sum::<u64>()
+    }
+
+    /// Assert the withheld amounts of the mint and of every recorded account, read with
+    /// the parser of `target`
+    pub fn assert_withheld(&self, app: &App, target: Target) -> TestResult<()> {
+        let accounts = self
+            .accounts
+            .keys()
+            .map(|x| Ok((*x, app.token_2022_query_withheld_amount(target, x)?)))
+            .collect::<TestResult<BTreeMap<_, _>>>()?;
+        let mint_withheld = app
+            .token_2022_query_transfer_fee_config(target, &self.mint)?
+            .withheld_amount;
+
+        assert_eq!(
+            Self {
+                mint: self.mint,
+                mint_withheld: mint_withheld.into(),
+                accounts,
+            },
+            *self
+        );
+
+        Ok(())
+    }
+
+    fn take_account_withheld(&mut self, account: &Pubkey) -> u64 {
+        self.accounts
+            .get_mut(account)
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+}
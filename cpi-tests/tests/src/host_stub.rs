@@ -0,0 +1,1339 @@
+use {
+    crate::helpers::suite::types::pin_pubkey_to_addr,
+    pinocchio::{
+        instruction::{Seed, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::rent::{
+            Rent, DEFAULT_BURN_PERCENT, DEFAULT_EXEMPTION_THRESHOLD, DEFAULT_LAMPORTS_PER_BYTE_YEAR,
+        },
+    },
+    pinocchio_token_2022::{
+        error::TokenError,
+        extension::{
+            consts::ExtensionType,
+            cpi_guard::EnableCpiGuard,
+            default_account_state::instructions::initialize_account::InitializeAccountThawed,
+            immutable_owner::InitializeImmutableOwner,
+            memo_transfer::Enable,
+            non_transferable::InitializeNonTransferableMint,
+            pausable::InitializePausable,
+            permanent_delegate,
+            token_group::{
+                InitializeGroupWithPointer, InitializeMemberWithPointer, TokenGroupError,
+                TOKEN_GROUP_DISCRIMINATOR,
+            },
+            token_metadata::InitializeMetadataWithPointer,
+            transfer_fee::{InitializeTransferFeeConfig, TransferCheckedWithFee},
+        },
+        host_stub::{take_invoked, HostAccount, InvokedAccountMeta, InvokedInstruction},
+        instructions::{
+            create_mint_with_extensions, AccountBuilder, Approve, Burn, CloseAccount, MintBuilder,
+            ReallocateForMint, TransferChecked, WithdrawExcessLamports,
+        },
+        native_mint::{self, UnwrapSol, WrapSol},
+        state::{Mint, TokenAccount},
+        test_utils::{TlvBuilder, ACCOUNT_TYPE_ACCOUNT, ACCOUNT_TYPE_MINT},
+        ID,
+    },
+    pretty_assertions::assert_eq,
+    spl_token_2022_interface::{
+        extension::{
+            cpi_guard::instruction::enable_cpi_guard,
+            group_member_pointer::instruction::initialize as group_member_pointer_initialize,
+            group_pointer::instruction::initialize as group_pointer_initialize,
+            memo_transfer::instruction::enable_required_transfer_memos,
+            metadata_pointer::instruction::initialize as metadata_pointer_initialize,
+            pausable::instruction::initialize as pausable_initialize,
+            transfer_fee::instruction::{
+                initialize_transfer_fee_config, transfer_checked_with_fee,
+            },
+            ExtensionType as SplExtensionType,
+        },
+        instruction::{
+            burn, close_account, initialize_account3, initialize_immutable_owner, initialize_mint2,
+            initialize_non_transferable_mint, sync_native, thaw_account, transfer_checked,
+        },
+    },
+};
+
+const SOURCE: Pubkey = [1; 32];
+const DESTINATION: Pubkey = [2; 32];
+const AUTHORITY: Pubkey = [3; 32];
+const MINT: Pubkey = [4; 32];
+const PAYER: Pubkey = [5; 32];
+const SYSTEM_PROGRAM: Pubkey = [0; 32];
+
+/// The instruction the stub records for the given SPL instruction, with an
+/// account info for each account
+macro_rules! invoked {
+    ($spl:expr) => {{
+        let spl = $spl;
+        let accounts: Vec<_> = spl
+            .accounts
+            .iter()
+            .map(|x| InvokedAccountMeta {
+                pubkey: x.pubkey.to_bytes(),
+                is_writable: x.is_writable,
+                is_signer: x.is_signer,
+            })
+            .collect();
+
+        InvokedInstruction {
+            program_id: spl.program_id.to_bytes(),
+            account_infos: accounts.iter().map(|x| x.pubkey).collect(),
+            accounts,
+            data: spl.data,
+            signers_len: 0,
+        }
+    }};
+}
+
+#[test]
+fn host_stub_records_single_authority() {
+    let mut account = HostAccount::new(SOURCE, ID, &[]);
+    let mut authority = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    authority.set_signer(true);
+
+    Enable {
+        token_account: &account.info(),
+        authority: &authority.info(),
+        signers: &[],
+        token_program: &ID,
+    }
+    .invoke()
+    .unwrap();
+
+    let spl = enable_required_transfer_memos(
+        &spl_token_2022_interface::id(),
+        &pin_pubkey_to_addr(&SOURCE),
+        &pin_pubkey_to_addr(&AUTHORITY),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(take_invoked(), [invoked!(spl)]);
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_records_multisig_authority() {
+    let mut source = HostAccount::new(SOURCE, ID, &[]);
+    let mut destination = HostAccount::new(DESTINATION, ID, &[]);
+    let mut authority = HostAccount::new(AUTHORITY, ID, &[]);
+    let mut signers: Vec<_> = (4..7)
+        .map(|x| HostAccount::new([x; 32], [0; 32], &[]))
+        .collect();
+    let signers: Vec<_> = signers.iter_mut().map(HostAccount::info).collect();
+
+    WithdrawExcessLamports {
+        source: &source.info(),
+        destination: &destination.info(),
+        authority: &authority.info(),
+        signers: &signers,
+        token_program: &ID,
+    }
+    .invoke()
+    .unwrap();
+
+    let signer_addresses: Vec<_> = signers
+        .iter()
+        .map(|x| pin_pubkey_to_addr(x.key()))
+        .collect();
+    let spl = spl_token_2022_interface::instruction::withdraw_excess_lamports(
+        &spl_token_2022_interface::id(),
+        &pin_pubkey_to_addr(&SOURCE),
+        &pin_pubkey_to_addr(&DESTINATION),
+        &pin_pubkey_to_addr(&AUTHORITY),
+        &signer_addresses.iter().collect::<Vec<_>>(),
+    )
+    .unwrap();
+    assert_eq!(take_invoked(), [invoked!(spl)]);
+
+    // the wrapper rejects more signers than a multisig can have, nothing is invoked
+    let signers = [signers[0]; 12];
+    assert_eq!(
+        WithdrawExcessLamports {
+            source: &source.info(),
+            destination: &destination.info(),
+            authority: &authority.info(),
+            signers: &signers,
+            token_program: &ID,
+        }
+        .invoke(),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_generated_authority_matches_hand_written() {
+    let mut account = HostAccount::new(SOURCE, ID, &[]);
+    let mut authority = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    authority.set_signer(true);
+    let mut signers: Vec<_> = (6..9)
+        .map(|x| HostAccount::new([x; 32], [0; 32], &[]))
+        .collect();
+    let signers: Vec<_> = signers.iter_mut().map(HostAccount::info).collect();
+    let signer_addresses: Vec<_> = signers
+        .iter()
+        .map(|x| pin_pubkey_to_addr(x.key()))
+        .collect();
+
+    for signers in [&[][..], &signers[..]] {
+        let account = account.info();
+        let authority = authority.info();
+
+        // `memo_transfer::Enable` is generated by `define_extension_instruction!`,
+        // `EnableCpiGuard` writes its metas by hand for the same accounts
+        Enable {
+            token_account: &account,
+            authority: &authority,
+            signers,
+            token_program: &ID,
+        }
+        .invoke()
+        .unwrap();
+        EnableCpiGuard {
+            token_account: &account,
+            owner: &authority,
+            signers,
+            token_program: &ID,
+        }
+        .invoke()
+        .unwrap();
+
+        let signer_addresses: Vec<_> = signer_addresses[..signers.len()].iter().collect();
+        let spl_memo = enable_required_transfer_memos(
+            &spl_token_2022_interface::id(),
+            &pin_pubkey_to_addr(&SOURCE),
+            &pin_pubkey_to_addr(&AUTHORITY),
+            &signer_addresses,
+        )
+        .unwrap();
+        let spl_cpi_guard = enable_cpi_guard(
+            &spl_token_2022_interface::id(),
+            &pin_pubkey_to_addr(&SOURCE),
+            &pin_pubkey_to_addr(&AUTHORITY),
+            &signer_addresses,
+        )
+        .unwrap();
+
+        let [generated, hand_written] = <[_; 2]>::try_from(take_invoked()).unwrap();
+        // the authority signs only without multisig signers
+        assert_eq!(generated.accounts[1].is_signer, signers.is_empty());
+        assert_eq!(generated.accounts, hand_written.accounts);
+        assert_eq!(generated.account_infos, hand_written.account_infos);
+        assert_eq!(
+            [generated, hand_written],
+            [invoked!(spl_memo), invoked!(spl_cpi_guard)]
+        );
+    }
+}
+
+#[test]
+fn host_stub_reallocates_for_mint() {
+    let mint_extensions = [
+        ExtensionType::MetadataPointer,
+        ExtensionType::TransferFeeConfig,
+        ExtensionType::TransferHook,
+        ExtensionType::NonTransferable,
+        ExtensionType::Pausable,
+    ];
+    let mint = mint_extensions
+        .iter()
+        .fold(TlvBuilder::mint(), |builder, &x| builder.extension(x, &[]))
+        .build();
+
+    // the account of the mint already has the `TransferHookAccount` extension
+    let mut base = [0; TokenAccount::BASE_LEN];
+    base[..32].copy_from_slice(&MINT);
+    let account = TlvBuilder::new(&base, ACCOUNT_TYPE_ACCOUNT)
+        .extension(ExtensionType::TransferHookAccount, &[0])
+        .build();
+
+    let mut mint = HostAccount::new(MINT, ID, &mint);
+    let mut account = HostAccount::new(SOURCE, ID, &account);
+    let mut payer = HostAccount::new(PAYER, [0; 32], &[]);
+    payer.set_signer(true);
+    let mut system_program = HostAccount::new(SYSTEM_PROGRAM, [0; 32], &[]);
+    let mut owner = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    owner.set_signer(true);
+
+    let reallocate = ReallocateForMint {
+        account: &account.info(),
+        mint: &mint.info(),
+        payer: &payer.info(),
+        system_program: &system_program.info(),
+        owner: &owner.info(),
+        token_program: &ID,
+    };
+    reallocate.invoke().unwrap();
+
+    let required = SplExtensionType::get_required_init_account_extensions(
+        &mint_extensions
+            .iter()
+            .map(|&x| SplExtensionType::try_from(x as u16).unwrap())
+            .collect::<Vec<_>>(),
+    );
+    let missing: Vec<_> = required
+        .into_iter()
+        .filter(|x| *x != SplExtensionType::TransferHookAccount)
+        .collect();
+    let spl = spl_token_2022_interface::instruction::reallocate(
+        &spl_token_2022_interface::id(),
+        &pin_pubkey_to_addr(&SOURCE),
+        &pin_pubkey_to_addr(&PAYER),
+        &pin_pubkey_to_addr(&AUTHORITY),
+        &[],
+        &missing,
+    )
+    .unwrap();
+    assert_eq!(take_invoked(), [invoked!(spl)]);
+
+    // nothing is missing from an account of a mint without extensions
+    let mut plain_mint = HostAccount::new(MINT, ID, &TlvBuilder::mint().build());
+    ReallocateForMint {
+        mint: &plain_mint.info(),
+        ..reallocate
+    }
+    .invoke()
+    .unwrap();
+    assert!(take_invoked().is_empty());
+
+    // the account must belong to the mint
+    let mut other_mint = HostAccount::new(DESTINATION, ID, &TlvBuilder::mint().build());
+    assert_eq!(
+        ReallocateForMint {
+            mint: &other_mint.info(),
+            ..reallocate
+        }
+        .invoke(),
+        Err(TokenError::MintMismatch.into())
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_initializes_mint_with_extensions() {
+    let mut mint = HostAccount::new(MINT, ID, &[]);
+    let mint = mint.info();
+    let mut other_mint = HostAccount::new(DESTINATION, ID, &[]);
+    let other_mint = other_mint.info();
+
+    let transfer_fee_config = InitializeTransferFeeConfig {
+        mint: &mint,
+        transfer_fee_config_authority: Some(&AUTHORITY),
+        withdraw_withheld_authority: None,
+        transfer_fee_basis_points: 50,
+        maximum_fee: 1_000,
+        token_program: &ID,
+    };
+    let non_transferable = InitializeNonTransferableMint {
+        mint: &mint,
+        token_program: &ID,
+    };
+    let pausable = InitializePausable {
+        mint_account: &mint,
+        authority: AUTHORITY,
+        token_program: &ID,
+    };
+    let builder = MintBuilder {
+        mint: &mint,
+        decimals: 6,
+        mint_authority: &AUTHORITY,
+        freeze_authority: Some(&AUTHORITY),
+        extensions: &[&transfer_fee_config, &non_transferable, &pausable],
+        token_program: &ID,
+    };
+    builder.invoke().unwrap();
+
+    let program_id = spl_token_2022_interface::id();
+    let mint_address = pin_pubkey_to_addr(&MINT);
+    let authority = pin_pubkey_to_addr(&AUTHORITY);
+    assert_eq!(
+        take_invoked(),
+        [
+            invoked!(initialize_transfer_fee_config(
+                &program_id,
+                &mint_address,
+                Some(&authority),
+                None,
+                50,
+                1_000,
+            )
+            .unwrap()),
+            invoked!(initialize_non_transferable_mint(&program_id, &mint_address).unwrap()),
+            invoked!(pausable_initialize(&program_id, &mint_address, &authority).unwrap()),
+            invoked!(
+                initialize_mint2(&program_id, &mint_address, &authority, Some(&authority), 6)
+                    .unwrap()
+            ),
+        ]
+    );
+
+    // every extension must be initialized on the mint
+    let other_non_transferable = InitializeNonTransferableMint {
+        mint: &other_mint,
+        token_program: &ID,
+    };
+    assert_eq!(
+        MintBuilder {
+            extensions: &[&transfer_fee_config, &other_non_transferable],
+            ..builder
+        }
+        .invoke(),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_initializes_account_with_extensions() {
+    let mut account = HostAccount::new(SOURCE, ID, &[]);
+    let account = account.info();
+    let mut mint = HostAccount::new(MINT, ID, &[]);
+    let mint = mint.info();
+    let mut owner = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    let owner = owner.info();
+
+    let immutable_owner = InitializeImmutableOwner {
+        token_account: &account,
+        token_program: &ID,
+    };
+    let memo_transfer = Enable {
+        token_account: &account,
+        authority: &owner,
+        signers: &[],
+        token_program: &ID,
+    };
+    let cpi_guard = EnableCpiGuard {
+        token_account: &account,
+        owner: &owner,
+        signers: &[],
+        token_program: &ID,
+    };
+    let builder = AccountBuilder {
+        account: &account,
+        mint: &mint,
+        owner: &AUTHORITY,
+        extensions: &[&immutable_owner],
+        enabled_extensions: &[&memo_transfer, &cpi_guard],
+        token_program: &ID,
+    };
+
+    // a PDA owner signs the enablers only
+    let seeds = [Seed::from(b"owner")];
+    builder.invoke_signed(&[Signer::from(&seeds)]).unwrap();
+
+    let program_id = spl_token_2022_interface::id();
+    let account_address = pin_pubkey_to_addr(&SOURCE);
+    let owner_address = pin_pubkey_to_addr(&AUTHORITY);
+    let mut expected = [
+        invoked!(initialize_immutable_owner(&program_id, &account_address).unwrap()),
+        invoked!(initialize_account3(
+            &program_id,
+            &account_address,
+            &pin_pubkey_to_addr(&MINT),
+            &owner_address,
+        )
+        .unwrap()),
+        invoked!(enable_required_transfer_memos(
+            &program_id,
+            &account_address,
+            &owner_address,
+            &[]
+        )
+        .unwrap()),
+        invoked!(enable_cpi_guard(&program_id, &account_address, &owner_address, &[]).unwrap()),
+    ];
+    for instruction in &mut expected[2..] {
+        instruction.signers_len = 1;
+    }
+    assert_eq!(take_invoked(), expected);
+
+    // every extension must target the account
+    let other_cpi_guard = EnableCpiGuard {
+        token_account: &mint,
+        ..cpi_guard
+    };
+    assert_eq!(
+        AccountBuilder {
+            enabled_extensions: &[&memo_transfer, &other_cpi_guard],
+            ..builder
+        }
+        .invoke(),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_creates_mint_with_extensions() {
+    let mut payer = HostAccount::new(PAYER, [0; 32], &[]);
+    payer.set_signer(true);
+    let payer = payer.info();
+    let mut mint = HostAccount::new(MINT, [0; 32], &[]);
+    mint.set_signer(true);
+    let mint = mint.info();
+
+    let non_transferable = InitializeNonTransferableMint {
+        mint: &mint,
+        token_program: &ID,
+    };
+    let pausable = InitializePausable {
+        mint_account: &mint,
+        authority: AUTHORITY,
+        token_program: &ID,
+    };
+    let builder = MintBuilder {
+        mint: &mint,
+        decimals: 6,
+        mint_authority: &AUTHORITY,
+        freeze_authority: None,
+        extensions: &[&non_transferable, &pausable],
+        token_program: &ID,
+    };
+    let rent = Rent {
+        lamports_per_byte_year: DEFAULT_LAMPORTS_PER_BYTE_YEAR,
+        exemption_threshold: DEFAULT_EXEMPTION_THRESHOLD,
+        burn_percent: DEFAULT_BURN_PERCENT,
+    };
+
+    let len = create_mint_with_extensions(&payer, &rent, &builder, &[]).unwrap();
+    assert_eq!(
+        Ok(len),
+        SplExtensionType::try_calculate_account_len::<spl_token_2022_interface::state::Mint>(&[
+            SplExtensionType::NonTransferable,
+            SplExtensionType::Pausable,
+        ])
+    );
+    assert_eq!(builder.try_calculate_mint_len(), Ok(len));
+
+    let invoked = take_invoked();
+    let create_account = solana_system_interface::instruction::create_account(
+        &PAYER.into(),
+        &MINT.into(),
+        solana_program::rent::Rent::default().minimum_balance(len),
+        len as u64,
+        &ID.into(),
+    );
+    assert_eq!(invoked[0], invoked!(create_account));
+    assert_eq!(invoked.len(), 1 + builder.extensions.len() + 1);
+
+    // a mint without extensions only holds the base state
+    let len = create_mint_with_extensions(
+        &payer,
+        &rent,
+        &MintBuilder {
+            extensions: &[],
+            ..builder
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(len, Mint::BASE_LEN);
+    assert_eq!(take_invoked().len(), 2);
+}
+
+#[test]
+fn host_stub_wraps_and_unwraps_sol() {
+    assert_eq!(
+        native_mint::ID,
+        spl_token_2022_interface::native_mint::id().to_bytes()
+    );
+
+    let mut data = TlvBuilder::token_account().build();
+    data[..32].copy_from_slice(&native_mint::ID);
+    let mut native_token = HostAccount::new(SOURCE, ID, &data);
+    let native_token = native_token.info();
+    let mut payer = HostAccount::new(PAYER, [0; 32], &[]);
+    payer.set_signer(true);
+    let payer = payer.info();
+    let mut owner = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    owner.set_signer(true);
+    let owner = owner.info();
+
+    let program_id = spl_token_2022_interface::id();
+    let native_token_address = pin_pubkey_to_addr(&SOURCE);
+
+    WrapSol {
+        from: &payer,
+        native_token: &native_token,
+        amount: 1_000_000,
+        token_program: &ID,
+    }
+    .invoke()
+    .unwrap();
+    assert_eq!(
+        take_invoked(),
+        [
+            invoked!(solana_system_interface::instruction::transfer(
+                &PAYER.into(),
+                &SOURCE.into(),
+                1_000_000,
+            )),
+            invoked!(sync_native(&program_id, &native_token_address).unwrap()),
+        ]
+    );
+
+    UnwrapSol {
+        native_token: &native_token,
+        destination: &payer,
+        authority: &owner,
+        token_program: &ID,
+    }
+    .invoke()
+    .unwrap();
+    let spl = close_account(
+        &program_id,
+        &native_token_address,
+        &pin_pubkey_to_addr(&PAYER),
+        &pin_pubkey_to_addr(&AUTHORITY),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(take_invoked(), [invoked!(spl)]);
+
+    // the account must be of the native mint
+    let mut token = HostAccount::new(DESTINATION, ID, &TlvBuilder::token_account().build());
+    assert_eq!(
+        WrapSol {
+            from: &payer,
+            native_token: &token.info(),
+            amount: 1_000_000,
+            token_program: &ID,
+        }
+        .invoke(),
+        Err(TokenError::NonNativeNotSupported.into())
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_transfers_and_burns_as_permanent_delegate() {
+    // the permanent delegate isn't the first extension of the mint
+    let data = TlvBuilder::mint()
+        .extension(ExtensionType::MintCloseAuthority, &[0; 32])
+        .extension(ExtensionType::PermanentDelegate, &AUTHORITY)
+        .build();
+    let mut mint = HostAccount::new(MINT, ID, &data);
+    let mint = mint.info();
+    let mut source = HostAccount::new(SOURCE, ID, &[]);
+    let source = source.info();
+    let mut destination = HostAccount::new(DESTINATION, ID, &[]);
+    let destination = destination.info();
+    let mut delegate = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    delegate.set_signer(true);
+    let delegate = delegate.info();
+
+    let program_id = spl_token_2022_interface::id();
+    let transfer = permanent_delegate::TransferChecked {
+        from: &source,
+        mint: &mint,
+        to: &destination,
+        delegate: &delegate,
+        amount: 100,
+        decimals: 6,
+        token_program: &ID,
+    };
+    transfer.invoke().unwrap();
+    let spl = transfer_checked(
+        &program_id,
+        &pin_pubkey_to_addr(&SOURCE),
+        &pin_pubkey_to_addr(&MINT),
+        &pin_pubkey_to_addr(&DESTINATION),
+        &pin_pubkey_to_addr(&AUTHORITY),
+        &[],
+        100,
+        6,
+    )
+    .unwrap();
+    assert_eq!(take_invoked(), [invoked!(spl)]);
+
+    let burn_as_delegate = permanent_delegate::Burn {
+        account: &source,
+        mint: &mint,
+        delegate: &delegate,
+        amount: 100,
+        token_program: &ID,
+    };
+    burn_as_delegate.invoke().unwrap();
+    let spl = burn(
+        &program_id,
+        &pin_pubkey_to_addr(&SOURCE),
+        &pin_pubkey_to_addr(&MINT),
+        &pin_pubkey_to_addr(&AUTHORITY),
+        &[],
+        100,
+    )
+    .unwrap();
+    assert_eq!(take_invoked(), [invoked!(spl)]);
+
+    // the delegate must be the permanent delegate of the mint
+    let mut other = HostAccount::new(PAYER, [0; 32], &[]);
+    other.set_signer(true);
+    let other = other.info();
+    assert_eq!(
+        permanent_delegate::TransferChecked {
+            delegate: &other,
+            ..transfer
+        }
+        .invoke(),
+        Err(TokenError::OwnerMismatch.into())
+    );
+    let mut plain_mint = HostAccount::new(MINT, ID, &TlvBuilder::mint().build());
+    assert_eq!(
+        permanent_delegate::Burn {
+            mint: &plain_mint.info(),
+            ..burn_as_delegate
+        }
+        .invoke(),
+        Err(TokenError::OwnerMismatch.into())
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_initializes_group_with_pointer() {
+    let mut mint = HostAccount::new(MINT, ID, &[]);
+    let mint = mint.info();
+    let mut other_mint = HostAccount::new(DESTINATION, ID, &[]);
+    let other_mint = other_mint.info();
+    let mut mint_authority = HostAccount::new(PAYER, [0; 32], &[]);
+    let mint_authority = mint_authority.info();
+
+    let non_transferable = InitializeNonTransferableMint {
+        mint: &mint,
+        token_program: &ID,
+    };
+    let initialize = InitializeGroupWithPointer {
+        mint: &mint,
+        decimals: 0,
+        mint_authority: &mint_authority,
+        freeze_authority: None,
+        update_authority: Some(&AUTHORITY),
+        max_size: 10,
+        extensions: &[&non_transferable],
+        token_program: &ID,
+    };
+
+    // a PDA mint authority signs `InitializeGroup`
+    let seeds = [Seed::from(b"mint_authority")];
+    initialize.invoke_signed(&[Signer::from(&seeds)]).unwrap();
+
+    let program_id = spl_token_2022_interface::id();
+    let mint_address = pin_pubkey_to_addr(&MINT);
+    let authority = pin_pubkey_to_addr(&AUTHORITY);
+    let mint_authority_address = pin_pubkey_to_addr(&PAYER);
+    let mut expected = [
+        invoked!(group_pointer_initialize(
+            &program_id,
+            &mint_address,
+            Some(authority),
+            Some(mint_address),
+        )
+        .unwrap()),
+        invoked!(initialize_non_transferable_mint(&program_id, &mint_address).unwrap()),
+        invoked!(
+            initialize_mint2(&program_id, &mint_address, &mint_authority_address, None, 0,)
+                .unwrap()
+        ),
+        invoked!(spl_token_group_interface::instruction::initialize_group(
+            &program_id,
+            &mint_address,
+            &mint_address,
+            &mint_authority_address,
+            Some(authority),
+            10,
+        )),
+    ];
+    expected[3].signers_len = 1;
+    assert_eq!(take_invoked(), expected);
+
+    // the group pointer is initialized once, on the mint
+    let group_pointer = pinocchio_token_2022::extension::group_pointer::Initialize {
+        mint: &mint,
+        authority: None,
+        group_address: None,
+        token_program: &ID,
+    };
+    assert_eq!(
+        InitializeGroupWithPointer {
+            extensions: &[&group_pointer],
+            ..initialize
+        }
+        .invoke(),
+        Err(ProgramError::InvalidArgument)
+    );
+    let other_non_transferable = InitializeNonTransferableMint {
+        mint: &other_mint,
+        token_program: &ID,
+    };
+    assert_eq!(
+        InitializeGroupWithPointer {
+            extensions: &[&other_non_transferable],
+            ..initialize
+        }
+        .invoke(),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_initializes_member_with_pointer() {
+    let group_value = |size: u64, max_size: u64| {
+        let mut value = [[3; 32], [1; 32]].concat();
+        value.extend_from_slice(&size.to_le_bytes());
+        value.extend_from_slice(&max_size.to_le_bytes());
+        value
+    };
+    // the group isn't the first extension of its mint
+    let group_data = |size: u64, max_size: u64| {
+        TlvBuilder::mint()
+            .extension(ExtensionType::NonTransferable, &[])
+            .extension(ExtensionType::TokenGroup, &group_value(size, max_size))
+            .build()
+    };
+    // a program implementing the token group interface stores the group as an
+    // interface TLV entry
+    let interface_group_data = |size: u64, max_size: u64| {
+        [
+            &TOKEN_GROUP_DISCRIMINATOR[..],
+            &80u32.to_le_bytes(),
+            &group_value(size, max_size),
+        ]
+        .concat()
+    };
+    let mut group = HostAccount::new(SOURCE, ID, &group_data(1, 2));
+    let group = group.info();
+    let mut full_group = HostAccount::new(SOURCE, ID, &group_data(2, 2));
+    let full_group = full_group.info();
+    let mut foreign_group = HostAccount::new(SOURCE, [6; 32], &interface_group_data(1, 2));
+    let foreign_group = foreign_group.info();
+    let mut full_foreign_group = HostAccount::new(SOURCE, [6; 32], &interface_group_data(2, 2));
+    let full_foreign_group = full_foreign_group.info();
+    let mut member_mint = HostAccount::new(MINT, ID, &[]);
+    let member_mint = member_mint.info();
+    let mut member_mint_authority = HostAccount::new(PAYER, [0; 32], &[]);
+    let member_mint_authority = member_mint_authority.info();
+    let mut group_update_authority = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    let group_update_authority = group_update_authority.info();
+
+    let non_transferable = InitializeNonTransferableMint {
+        mint: &member_mint,
+        token_program: &ID,
+    };
+    let initialize = InitializeMemberWithPointer {
+        member_mint: &member_mint,
+        decimals: 0,
+        member_mint_authority: &member_mint_authority,
+        freeze_authority: None,
+        pointer_authority: Some(&AUTHORITY),
+        group: &group,
+        group_update_authority: &group_update_authority,
+        extensions: &[&non_transferable],
+        token_program: &ID,
+    };
+
+    // a PDA group update authority signs `InitializeMember`
+    let seeds = [Seed::from(b"update_authority")];
+    initialize.invoke_signed(&[Signer::from(&seeds)]).unwrap();
+
+    let program_id = spl_token_2022_interface::id();
+    let mint_address = pin_pubkey_to_addr(&MINT);
+    let authority = pin_pubkey_to_addr(&AUTHORITY);
+    let mint_authority_address = pin_pubkey_to_addr(&PAYER);
+    let mut expected = [
+        invoked!(group_member_pointer_initialize(
+            &program_id,
+            &mint_address,
+            Some(authority),
+            Some(mint_address),
+        )
+        .unwrap()),
+        invoked!(initialize_non_transferable_mint(&program_id, &mint_address).unwrap()),
+        invoked!(
+            initialize_mint2(&program_id, &mint_address, &mint_authority_address, None, 0,)
+                .unwrap()
+        ),
+        invoked!(spl_token_group_interface::instruction::initialize_member(
+            &program_id,
+            &mint_address,
+            &mint_address,
+            &mint_authority_address,
+            &pin_pubkey_to_addr(&SOURCE),
+            &authority,
+        )),
+    ];
+    expected[3].signers_len = 1;
+    assert_eq!(take_invoked(), expected);
+
+    // a group owned by another program is read with the interface layout
+    InitializeMemberWithPointer {
+        group: &foreign_group,
+        ..initialize
+    }
+    .invoke()
+    .unwrap();
+    assert_eq!(take_invoked().len(), 4);
+
+    // the group must have room for the member
+    for group in [&full_group, &full_foreign_group] {
+        assert_eq!(
+            InitializeMemberWithPointer {
+                group,
+                ..initialize
+            }
+            .invoke(),
+            Err(ProgramError::Custom(
+                TokenGroupError::SizeExceedsMaxSize as u32
+            ))
+        );
+    }
+    assert_eq!(
+        InitializeMemberWithPointer {
+            group: &member_mint,
+            ..initialize
+        }
+        .invoke(),
+        Err(ProgramError::InvalidAccountData)
+    );
+
+    // the member pointer is initialized once, on the member mint
+    let member_pointer = pinocchio_token_2022::extension::group_member_pointer::Initialize {
+        mint: &member_mint,
+        authority: None,
+        member_address: None,
+        token_program: &ID,
+    };
+    assert_eq!(
+        InitializeMemberWithPointer {
+            extensions: &[&member_pointer],
+            ..initialize
+        }
+        .invoke(),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_initializes_metadata_with_pointer() {
+    let mint_len = SplExtensionType::try_calculate_account_len::<
+        spl_token_2022_interface::state::Mint,
+    >(&[SplExtensionType::MetadataPointer])
+    .unwrap();
+    let mut mint = HostAccount::new(MINT, ID, &vec![0; mint_len]);
+    let mint = mint.info();
+    let mut mint_authority = HostAccount::new(PAYER, [0; 32], &[]);
+    let mint_authority = mint_authority.info();
+    let mut update_authority = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    let update_authority = update_authority.info();
+    let mut payer = HostAccount::new(SOURCE, [0; 32], &[]);
+    payer.set_signer(true);
+    let payer = payer.info();
+    let rent = Rent {
+        lamports_per_byte_year: DEFAULT_LAMPORTS_PER_BYTE_YEAR,
+        exemption_threshold: DEFAULT_EXEMPTION_THRESHOLD,
+        burn_percent: DEFAULT_BURN_PERCENT,
+    };
+
+    let initialize = InitializeMetadataWithPointer {
+        mint: &mint,
+        decimals: 6,
+        mint_authority: &mint_authority,
+        freeze_authority: None,
+        update_authority: &update_authority,
+        name: "Token",
+        symbol: "TKN",
+        uri: "https://example.com/token.json",
+        rent_payer: Some((&payer, &rent)),
+        extensions: &[],
+        token_program: &ID,
+    };
+
+    // the length of the mint once reallocated for the metadata, which
+    // Token-2022 stores behind its 4-byte TLV header instead of the 12-byte
+    // header of the interface
+    let metadata = spl_token_metadata_interface::state::TokenMetadata {
+        name: "Token".to_string(),
+        symbol: "TKN".to_string(),
+        uri: "https://example.com/token.json".to_string(),
+        ..Default::default()
+    };
+    let len = mint_len + metadata.tlv_size_of().unwrap() - 8;
+    assert_eq!(initialize.mint_len(), len);
+
+    initialize.invoke().unwrap();
+
+    let program_id = spl_token_2022_interface::id();
+    let mint_address = pin_pubkey_to_addr(&MINT);
+    let authority = pin_pubkey_to_addr(&AUTHORITY);
+    let mint_authority_address = pin_pubkey_to_addr(&PAYER);
+    assert_eq!(
+        take_invoked(),
+        [
+            invoked!(metadata_pointer_initialize(
+                &program_id,
+                &mint_address,
+                Some(authority),
+                Some(mint_address),
+            )
+            .unwrap()),
+            invoked!(initialize_mint2(
+                &program_id,
+                &mint_address,
+                &mint_authority_address,
+                None,
+                6,
+            )
+            .unwrap()),
+            invoked!(solana_system_interface::instruction::transfer(
+                &SOURCE.into(),
+                &MINT.into(),
+                rent.minimum_balance(len),
+            )),
+            invoked!(spl_token_metadata_interface::instruction::initialize(
+                &program_id,
+                &mint_address,
+                &authority,
+                &mint_address,
+                &mint_authority_address,
+                "Token".to_string(),
+                "TKN".to_string(),
+                "https://example.com/token.json".to_string(),
+            )),
+        ]
+    );
+
+    // without a rent payer, the mint must already hold the lamports
+    InitializeMetadataWithPointer {
+        rent_payer: None,
+        ..initialize
+    }
+    .invoke()
+    .unwrap();
+    assert_eq!(take_invoked().len(), 3);
+
+    // the metadata pointer is initialized once, on the mint
+    let metadata_pointer = pinocchio_token_2022::extension::metadata_pointer::Initialize {
+        mint: &mint,
+        authority: None,
+        metadata_address: None,
+        token_program: &ID,
+    };
+    assert_eq!(
+        InitializeMetadataWithPointer {
+            extensions: &[&metadata_pointer],
+            ..initialize
+        }
+        .invoke(),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert!(take_invoked().is_empty());
+}
+
+#[test]
+fn host_stub_preflight_checks_cpi_guard() {
+    // a token account of `AUTHORITY` with the given `CpiGuard` value
+    let account_data = |lock_cpi: u8| {
+        let mut base = [0; TokenAccount::BASE_LEN];
+        base[..32].copy_from_slice(&MINT);
+        base[32..64].copy_from_slice(&AUTHORITY);
+        TlvBuilder::new(&base, ACCOUNT_TYPE_ACCOUNT)
+            .extension(ExtensionType::ImmutableOwner, &[])
+            .extension(ExtensionType::CpiGuard, &[lock_cpi])
+            .build()
+    };
+    let mut guarded = HostAccount::new(SOURCE, ID, &account_data(1));
+    let guarded = guarded.info();
+    let mut unguarded = HostAccount::new(SOURCE, ID, &account_data(0));
+    let unguarded = unguarded.info();
+    let mut destination = HostAccount::new(DESTINATION, ID, &[]);
+    let destination = destination.info();
+    let mut owner = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    owner.set_signer(true);
+    let owner = owner.info();
+    let mut delegate = HostAccount::new(PAYER, [0; 32], &[]);
+    delegate.set_signer(true);
+    let delegate = delegate.info();
+    let mut mint = HostAccount::new(MINT, ID, &[]);
+    let mint = mint.info();
+
+    let transfer = TransferChecked {
+        from: &guarded,
+        mint: &mint,
+        to: &destination,
+        authority: &owner,
+        amount: 10,
+        decimals: 6,
+        token_program: &ID,
+    };
+    let burn_as_owner = Burn {
+        account: &guarded,
+        mint: &mint,
+        authority: &owner,
+        amount: 10,
+        token_program: &ID,
+    };
+    let close = CloseAccount {
+        account: &guarded,
+        destination: &destination,
+        authority: &owner,
+        token_program: &ID,
+    };
+
+    // the owner can't move the funds of a guarded account through a CPI
+    assert_eq!(
+        transfer.invoke(),
+        Err(TokenError::CpiGuardTransferBlocked.into())
+    );
+    assert_eq!(
+        burn_as_owner.invoke(),
+        Err(TokenError::CpiGuardBurnBlocked.into())
+    );
+    assert_eq!(
+        Approve {
+            source: &guarded,
+            delegate: &delegate,
+            authority: &owner,
+            amount: 10,
+            token_program: &ID,
+        }
+        .invoke(),
+        Err(TokenError::CpiGuardApproveBlocked.into())
+    );
+    assert_eq!(
+        close.invoke(),
+        Err(TokenError::CpiGuardCloseAccountBlocked.into())
+    );
+    assert!(take_invoked().is_empty());
+
+    // a delegate can, the lamports can go back to the owner, and the owner of
+    // an account whose guard is disabled can
+    TransferChecked {
+        authority: &delegate,
+        ..transfer
+    }
+    .invoke()
+    .unwrap();
+    Burn {
+        authority: &delegate,
+        ..burn_as_owner
+    }
+    .invoke()
+    .unwrap();
+    CloseAccount {
+        destination: &owner,
+        ..close
+    }
+    .invoke()
+    .unwrap();
+    TransferChecked {
+        from: &unguarded,
+        ..transfer
+    }
+    .invoke()
+    .unwrap();
+
+    let program_id = spl_token_2022_interface::id();
+    let account_address = pin_pubkey_to_addr(&SOURCE);
+    let mint_address = pin_pubkey_to_addr(&MINT);
+    let destination_address = pin_pubkey_to_addr(&DESTINATION);
+    let owner_address = pin_pubkey_to_addr(&AUTHORITY);
+    let delegate_address = pin_pubkey_to_addr(&PAYER);
+    assert_eq!(
+        take_invoked(),
+        [
+            invoked!(transfer_checked(
+                &program_id,
+                &account_address,
+                &mint_address,
+                &destination_address,
+                &delegate_address,
+                &[],
+                10,
+                6,
+            )
+            .unwrap()),
+            invoked!(burn(
+                &program_id,
+                &account_address,
+                &mint_address,
+                &delegate_address,
+                &[],
+                10,
+            )
+            .unwrap()),
+            invoked!(close_account(
+                &program_id,
+                &account_address,
+                &owner_address,
+                &owner_address,
+                &[],
+            )
+            .unwrap()),
+            invoked!(transfer_checked(
+                &program_id,
+                &account_address,
+                &mint_address,
+                &destination_address,
+                &owner_address,
+                &[],
+                10,
+                6,
+            )
+            .unwrap()),
+        ]
+    );
+}
+
+#[test]
+fn host_stub_preflight_checks_cpi_guard_on_fee_transfer() {
+    let mut base = [0; TokenAccount::BASE_LEN];
+    base[..32].copy_from_slice(&MINT);
+    base[32..64].copy_from_slice(&AUTHORITY);
+    let account_data = TlvBuilder::new(&base, ACCOUNT_TYPE_ACCOUNT)
+        .extension(ExtensionType::TransferFeeAmount, &[0; 8])
+        .extension(ExtensionType::CpiGuard, &[1])
+        .build();
+
+    let mut source = HostAccount::new(SOURCE, ID, &account_data);
+    let source = source.info();
+    let mut destination = HostAccount::new(DESTINATION, ID, &[]);
+    let destination = destination.info();
+    let mut owner = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    owner.set_signer(true);
+    let owner = owner.info();
+    let mut delegate = HostAccount::new(PAYER, [0; 32], &[]);
+    delegate.set_signer(true);
+    let delegate = delegate.info();
+    let mut mint = HostAccount::new(MINT, ID, &[]);
+    let mint = mint.info();
+
+    let transfer = TransferCheckedWithFee {
+        source: &source,
+        mint: &mint,
+        destination: &destination,
+        authority: &owner,
+        signers: &[],
+        amount: 100,
+        decimals: 6,
+        fee: 1,
+        token_program: &ID,
+    };
+
+    // the fee transfer is guarded like `TransferChecked`
+    assert_eq!(
+        transfer.invoke(),
+        Err(TokenError::CpiGuardTransferBlocked.into())
+    );
+    assert!(take_invoked().is_empty());
+
+    TransferCheckedWithFee {
+        authority: &delegate,
+        ..transfer
+    }
+    .invoke()
+    .unwrap();
+
+    let spl = transfer_checked_with_fee(
+        &spl_token_2022_interface::id(),
+        &pin_pubkey_to_addr(&SOURCE),
+        &pin_pubkey_to_addr(&MINT),
+        &pin_pubkey_to_addr(&DESTINATION),
+        &pin_pubkey_to_addr(&PAYER),
+        &[],
+        100,
+        6,
+        1,
+    )
+    .unwrap();
+    assert_eq!(take_invoked(), [invoked!(spl)]);
+}
+
+#[test]
+fn host_stub_initializes_account_thawed() {
+    // a mint frozen by default whose freeze authority is `AUTHORITY`, the
+    // `DefaultAccountState` not being its first extension
+    let mint_data = |state: u8| {
+        let mut base = [0; Mint::BASE_LEN];
+        base[45] = 1;
+        base[46] = 1;
+        base[50..82].copy_from_slice(&AUTHORITY);
+        TlvBuilder::new(&base, ACCOUNT_TYPE_MINT)
+            .extension(ExtensionType::NonTransferable, &[])
+            .extension(ExtensionType::DefaultAccountState, &[state])
+            .build()
+    };
+    let mut frozen_mint = HostAccount::new(MINT, ID, &mint_data(2));
+    let frozen_mint = frozen_mint.info();
+    let mut initialized_mint = HostAccount::new(MINT, ID, &mint_data(1));
+    let initialized_mint = initialized_mint.info();
+    let mut account = HostAccount::new(SOURCE, ID, &[]);
+    let account = account.info();
+    let mut freeze_authority = HostAccount::new(AUTHORITY, [0; 32], &[]);
+    let freeze_authority = freeze_authority.info();
+    let mut other_authority = HostAccount::new(PAYER, [0; 32], &[]);
+    let other_authority = other_authority.info();
+
+    let immutable_owner = InitializeImmutableOwner {
+        token_account: &account,
+        token_program: &ID,
+    };
+    let initialize = InitializeAccountThawed {
+        account: &account,
+        mint: &frozen_mint,
+        owner: &DESTINATION,
+        freeze_authority: &freeze_authority,
+        extensions: &[&immutable_owner],
+        token_program: &ID,
+    };
+
+    // a PDA freeze authority signs `ThawAccount`
+    let seeds = [Seed::from(b"freeze_authority")];
+    initialize.invoke_signed(&[Signer::from(&seeds)]).unwrap();
+
+    let program_id = spl_token_2022_interface::id();
+    let account_address = pin_pubkey_to_addr(&SOURCE);
+    let mint_address = pin_pubkey_to_addr(&MINT);
+    let initialized = [
+        invoked!(initialize_immutable_owner(&program_id, &account_address).unwrap()),
+        invoked!(initialize_account3(
+            &program_id,
+            &account_address,
+            &mint_address,
+            &pin_pubkey_to_addr(&DESTINATION),
+        )
+        .unwrap()),
+    ];
+    let mut expected = initialized.to_vec();
+    expected.push(invoked!(thaw_account(
+        &program_id,
+        &account_address,
+        &mint_address,
+        &pin_pubkey_to_addr(&AUTHORITY),
+        &[],
+    )
+    .unwrap()));
+    expected[2].signers_len = 1;
+    assert_eq!(take_invoked(), expected);
+
+    // the account stays frozen without the freeze authority, and isn't
+    // thawed when it isn't frozen by default
+    InitializeAccountThawed {
+        freeze_authority: &other_authority,
+        ..initialize
+    }
+    .invoke()
+    .unwrap();
+    assert_eq!(take_invoked(), initialized);
+    InitializeAccountThawed {
+        mint: &initialized_mint,
+        ..initialize
+    }
+    .invoke()
+    .unwrap();
+    assert_eq!(take_invoked(), initialized);
+}
@@ -0,0 +1,1633 @@
+use {
+    crate::helpers::suite::types::{pin_pubkey_to_addr, to_c_option, to_optional_non_zero_pubkey},
+    bytemuck::Pod,
+    core::mem::MaybeUninit,
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+    pinocchio_token_2022::extension::{
+        confidential_transfer::{
+            apply_pending_balance_instruction_data, configure_account_instruction_data,
+            deposit_instruction_data, initialize_confidential_transfer_mint_instruction_data,
+            offset_confidential_transfer_apply_pending_balance,
+            offset_confidential_transfer_configure_account, offset_confidential_transfer_deposit,
+            offset_confidential_transfer_initialize_mint, offset_confidential_transfer_withdraw,
+            withdraw_instruction_data, DecryptableBalance,
+            InstructionDiscriminatorConfidentialTransfer, PodElGamalPubkey,
+        },
+        confidential_transfer_fee::{
+            disable_harvest_to_mint_instruction_data, enable_harvest_to_mint_instruction_data,
+            harvest_withheld_tokens_to_mint_instruction_data as confidential_harvest_instruction_data,
+            offset_confidential_transfer_fee_no_data,
+        },
+        consts::ExtensionDiscriminator,
+        cpi_guard::{cpi_guard_instruction_data, CpiGuardInstruction},
+        default_account_state::state::{
+            default_account_state_instruction_data, DefaultAccountStateInstruction,
+        },
+        group_member_pointer::{
+            self, offset_group_member_pointer_initialize, offset_group_member_pointer_update,
+        },
+        group_pointer::{self, offset_group_pointer_initialize, offset_group_pointer_update},
+        immutable_owner::{self, offset_immutable_owner_initialize},
+        interest_bearing_mint::{
+            interest_bearing_mint_initialize_instruction_data,
+            interest_bearing_mint_update_rate_instruction_data,
+        },
+        memo_transfer::{disable_instruction_data, enable_instruction_data, offset_memo_transfer},
+        metadata_pointer::{
+            self, offset_metadata_pointer_initialize, offset_metadata_pointer_update,
+        },
+        mint_close_authority::{self, offset_mint_close_authority_initialize},
+        non_transferable::{self, offset_non_transferable_initialize},
+        pausable::{
+            pausable_initialize_instruction_data, pausable_instruction_data, PausableInstruction,
+        },
+        permanent_delegate::{self, offset_permanent_delegate_initialize},
+        scaled_ui_amount::{
+            scaled_ui_amount_initialize_instruction_data,
+            scaled_ui_amount_update_multiplier_instruction_data, ScaledUiAmountInstruction,
+        },
+        token_group::{
+            initialize_group_instruction_data, initialize_member_instruction_data,
+            offset_token_group_initialize_group, offset_token_group_initialize_member,
+            offset_token_group_update_authority, offset_token_group_update_max_size,
+            update_group_authority_instruction_data, update_group_max_size_instruction_data,
+            InstructionDiscriminatorTokenGroup,
+        },
+        token_metadata::{
+            emit_token_metadata_instruction_data, initialize_token_metadata_instruction_data,
+            remove_token_metadata_key_instruction_data,
+            update_token_metadata_authority_instruction_data,
+            update_token_metadata_field_instruction_data, Field,
+            EMIT_TOKEN_METADATA_INSTRUCTION_DATA_LEN, MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+            UPDATE_TOKEN_METADATA_AUTHORITY_INSTRUCTION_DATA_LEN,
+        },
+        transfer_fee::{
+            harvest_withheld_tokens_to_mint_instruction_data,
+            initialize_transfer_fee_config_instruction_data,
+            offset_transfer_fee_harvest_withheld_tokens_to_mint, offset_transfer_fee_initialize,
+            offset_transfer_fee_set_transfer_fee, offset_transfer_fee_transfer_checked_with_fee,
+            offset_transfer_fee_withdraw_withheld_tokens_from_accounts,
+            offset_transfer_fee_withdraw_withheld_tokens_from_mint,
+            set_transfer_fee_instruction_data, transfer_checked_with_fee_instruction_data,
+            withdraw_withheld_tokens_from_accounts_instruction_data,
+            withdraw_withheld_tokens_from_mint_instruction_data,
+            InstructionDiscriminatorTransferFee,
+        },
+        transfer_hook::{self, transfer_hook_initialize_instruction_data, TransferHookInstruction},
+    },
+    pinocchio_token_2022::instruction::{
+        TokenInstruction as PinTokenInstruction, TokenInstructionDiscriminator,
+    },
+    pinocchio_token_2022::instructions::withdraw_excess_lamports_instruction_data,
+    pinocchio_token_2022::transfer_hook_interface::{
+        execute_instruction_data, initialize_extra_account_meta_list_instruction_data,
+        update_extra_account_meta_list_instruction_data, ExtraAccountMeta, ExtraAccountMetaList,
+        MAX_EXTRA_ACCOUNT_METAS_INSTRUCTION_DATA_LEN,
+    },
+    pretty_assertions::assert_eq,
+    solana_address::Address,
+    solana_zk_sdk::encryption::pod::{
+        auth_encryption::PodAeCiphertext as SplPodAeCiphertext,
+        elgamal::PodElGamalPubkey as SplPodElGamalPubkey,
+    },
+    spl_token_2022_interface::{
+        extension as spl,
+        instruction::{decode_instruction_data, decode_instruction_type, TokenInstruction},
+        state::AccountState,
+    },
+    spl_token_confidential_transfer_proof_extraction::instruction::ProofLocation,
+};
+
+// the encoders only produce the data, so the accounts are arbitrary
+const MINT: Pubkey = [1; 32];
+const ACCOUNT: Pubkey = [2; 32];
+const AUTHORITY: Pubkey = [3; 32];
+const ADDRESS: Pubkey = [4; 32];
+
+fn program_id() -> Address {
+    spl_token_2022_interface::id()
+}
+
+fn mint() -> Address {
+    pin_pubkey_to_addr(&MINT)
+}
+
+fn account() -> Address {
+    pin_pubkey_to_addr(&ACCOUNT)
+}
+
+fn authority() -> Address {
+    pin_pubkey_to_addr(&AUTHORITY)
+}
+
+fn address() -> Address {
+    pin_pubkey_to_addr(&ADDRESS)
+}
+
+/// Data of the fixed size encoders is fully written before it's returned
+fn init<const N: usize>(data: [MaybeUninit<u8>; N]) -> Vec<u8> {
+    data.map(|x| unsafe { x.assume_init() }).to_vec()
+}
+
+#[test]
+fn cpi_guard_encoders() {
+    let spl =
+        spl::cpi_guard::instruction::enable_cpi_guard(&program_id(), &account(), &authority(), &[])
+            .unwrap();
+    assert_eq!(
+        init(cpi_guard_instruction_data(CpiGuardInstruction::Enable)),
+        spl.data
+    );
+
+    let spl = spl::cpi_guard::instruction::disable_cpi_guard(
+        &program_id(),
+        &account(),
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        init(cpi_guard_instruction_data(CpiGuardInstruction::Disable)),
+        spl.data
+    );
+}
+
+#[test]
+fn default_account_state_encoders() {
+    let spl = spl::default_account_state::instruction::initialize_default_account_state(
+        &program_id(),
+        &mint(),
+        &AccountState::Frozen,
+    )
+    .unwrap();
+    assert_eq!(
+        init(default_account_state_instruction_data(
+            DefaultAccountStateInstruction::Initialize,
+            AccountState::Frozen as u8
+        )),
+        spl.data
+    );
+
+    let spl = spl::default_account_state::instruction::update_default_account_state(
+        &program_id(),
+        &mint(),
+        &authority(),
+        &[],
+        &AccountState::Initialized,
+    )
+    .unwrap();
+    assert_eq!(
+        init(default_account_state_instruction_data(
+            DefaultAccountStateInstruction::Update,
+            AccountState::Initialized as u8
+        )),
+        spl.data
+    );
+}
+
+#[test]
+fn group_member_pointer_encoders() {
+    for (authority, member_address) in [
+        (None, None),
+        (Some(&AUTHORITY), None),
+        (None, Some(&ADDRESS)),
+        (Some(&AUTHORITY), Some(&ADDRESS)),
+    ] {
+        let spl = spl::group_member_pointer::instruction::initialize(
+            &program_id(),
+            &mint(),
+            authority.map(pin_pubkey_to_addr),
+            member_address.map(pin_pubkey_to_addr),
+        )
+        .unwrap();
+        let mut buffer = [0; offset_group_member_pointer_initialize::END as usize];
+        assert_eq!(
+            group_member_pointer::initialize_instruction_data(
+                &mut buffer,
+                authority,
+                member_address
+            ),
+            spl.data
+        );
+    }
+
+    for member_address in [None, Some(&ADDRESS)] {
+        let spl = spl::group_member_pointer::instruction::update(
+            &program_id(),
+            &mint(),
+            &authority(),
+            &[],
+            member_address.map(pin_pubkey_to_addr),
+        )
+        .unwrap();
+        let mut buffer = [0; offset_group_member_pointer_update::END as usize];
+        assert_eq!(
+            group_member_pointer::update_instruction_data(&mut buffer, member_address),
+            spl.data
+        );
+    }
+}
+
+#[test]
+fn group_pointer_encoders() {
+    for (authority, group_address) in [
+        (None, None),
+        (Some(&AUTHORITY), None),
+        (None, Some(&ADDRESS)),
+        (Some(&AUTHORITY), Some(&ADDRESS)),
+    ] {
+        let spl = spl::group_pointer::instruction::initialize(
+            &program_id(),
+            &mint(),
+            authority.map(pin_pubkey_to_addr),
+            group_address.map(pin_pubkey_to_addr),
+        )
+        .unwrap();
+        let mut buffer = [0; offset_group_pointer_initialize::END as usize];
+        assert_eq!(
+            group_pointer::initialize_instruction_data(&mut buffer, authority, group_address),
+            spl.data
+        );
+    }
+
+    for group_address in [None, Some(&ADDRESS)] {
+        let spl = spl::group_pointer::instruction::update(
+            &program_id(),
+            &mint(),
+            &authority(),
+            &[],
+            group_address.map(pin_pubkey_to_addr),
+        )
+        .unwrap();
+        let mut buffer = [0; offset_group_pointer_update::END as usize];
+        assert_eq!(
+            group_pointer::update_instruction_data(&mut buffer, group_address),
+            spl.data
+        );
+    }
+}
+
+#[test]
+fn immutable_owner_encoders() {
+    let spl = spl_token_2022_interface::instruction::initialize_immutable_owner(
+        &program_id(),
+        &account(),
+    )
+    .unwrap();
+    let mut buffer = [0; offset_immutable_owner_initialize::END as usize];
+    assert_eq!(
+        immutable_owner::initialize_instruction_data(&mut buffer),
+        spl.data
+    );
+}
+
+#[test]
+fn interest_bearing_mint_encoders() {
+    for (rate_authority, rate) in [(None, 0), (Some(&AUTHORITY), 500), (Some(&AUTHORITY), -250)] {
+        let spl = spl::interest_bearing_mint::instruction::initialize(
+            &program_id(),
+            &mint(),
+            rate_authority.map(pin_pubkey_to_addr),
+            rate,
+        )
+        .unwrap();
+        assert_eq!(
+            init(interest_bearing_mint_initialize_instruction_data(
+                rate_authority,
+                rate
+            )),
+            spl.data
+        );
+    }
+
+    for rate in [i16::MIN, 0, i16::MAX] {
+        let spl = spl::interest_bearing_mint::instruction::update_rate(
+            &program_id(),
+            &mint(),
+            &authority(),
+            &[],
+            rate,
+        )
+        .unwrap();
+        assert_eq!(
+            init(interest_bearing_mint_update_rate_instruction_data(rate)),
+            spl.data
+        );
+    }
+}
+
+#[test]
+fn memo_transfer_encoders() {
+    let spl = spl::memo_transfer::instruction::enable_required_transfer_memos(
+        &program_id(),
+        &account(),
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = [0; offset_memo_transfer::END as usize];
+    assert_eq!(enable_instruction_data(&mut buffer), spl.data);
+
+    let spl = spl::memo_transfer::instruction::disable_required_transfer_memos(
+        &program_id(),
+        &account(),
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = [0; offset_memo_transfer::END as usize];
+    assert_eq!(disable_instruction_data(&mut buffer), spl.data);
+}
+
+#[test]
+fn metadata_pointer_encoders() {
+    for (authority, metadata_address) in [
+        (None, None),
+        (Some(&AUTHORITY), None),
+        (None, Some(&ADDRESS)),
+        (Some(&AUTHORITY), Some(&ADDRESS)),
+    ] {
+        let spl = spl::metadata_pointer::instruction::initialize(
+            &program_id(),
+            &mint(),
+            authority.map(pin_pubkey_to_addr),
+            metadata_address.map(pin_pubkey_to_addr),
+        )
+        .unwrap();
+        let mut buffer = [0; offset_metadata_pointer_initialize::END as usize];
+        assert_eq!(
+            metadata_pointer::initialize_instruction_data(&mut buffer, authority, metadata_address),
+            spl.data
+        );
+    }
+
+    for metadata_address in [None, Some(&ADDRESS)] {
+        let spl = spl::metadata_pointer::instruction::update(
+            &program_id(),
+            &mint(),
+            &authority(),
+            &[],
+            metadata_address.map(pin_pubkey_to_addr),
+        )
+        .unwrap();
+        let mut buffer = [0; offset_metadata_pointer_update::END as usize];
+        assert_eq!(
+            metadata_pointer::update_instruction_data(&mut buffer, metadata_address),
+            spl.data
+        );
+    }
+}
+
+#[test]
+fn mint_close_authority_encoders() {
+    for close_authority in [None, Some(&AUTHORITY)] {
+        let spl = spl_token_2022_interface::instruction::initialize_mint_close_authority(
+            &program_id(),
+            &mint(),
+            close_authority.map(pin_pubkey_to_addr).as_ref(),
+        )
+        .unwrap();
+        let mut buffer = [0; offset_mint_close_authority_initialize::END as usize];
+        assert_eq!(
+            mint_close_authority::initialize_instruction_data(&mut buffer, close_authority),
+            spl.data
+        );
+    }
+}
+
+#[test]
+fn non_transferable_encoders() {
+    let spl = spl_token_2022_interface::instruction::initialize_non_transferable_mint(
+        &program_id(),
+        &mint(),
+    )
+    .unwrap();
+    let mut buffer = [0; offset_non_transferable_initialize::END as usize];
+    assert_eq!(
+        non_transferable::initialize_instruction_data(&mut buffer),
+        spl.data
+    );
+}
+
+#[test]
+fn pausable_encoders() {
+    let spl = spl::pausable::instruction::initialize(&program_id(), &mint(), &authority()).unwrap();
+    assert_eq!(
+        init(pausable_initialize_instruction_data(
+            PausableInstruction::Initialize,
+            AUTHORITY
+        )),
+        spl.data
+    );
+
+    let spl = spl::pausable::instruction::pause(&program_id(), &mint(), &authority(), &[]).unwrap();
+    assert_eq!(
+        init(pausable_instruction_data(PausableInstruction::Pause)),
+        spl.data
+    );
+
+    let spl =
+        spl::pausable::instruction::resume(&program_id(), &mint(), &authority(), &[]).unwrap();
+    assert_eq!(
+        init(pausable_instruction_data(PausableInstruction::Resume)),
+        spl.data
+    );
+}
+
+#[test]
+fn permanent_delegate_encoders() {
+    let spl = spl_token_2022_interface::instruction::initialize_permanent_delegate(
+        &program_id(),
+        &mint(),
+        &authority(),
+    )
+    .unwrap();
+    let mut buffer = [0; offset_permanent_delegate_initialize::END as usize];
+    assert_eq!(
+        permanent_delegate::initialize_instruction_data(&mut buffer, &AUTHORITY),
+        spl.data
+    );
+}
+
+#[test]
+fn scaled_ui_amount_encoders() {
+    for multiplier in [1.0, 0.5, 1_000.125] {
+        let spl = spl::scaled_ui_amount::instruction::initialize(
+            &program_id(),
+            &mint(),
+            Some(authority()),
+            multiplier,
+        )
+        .unwrap();
+        assert_eq!(
+            init(scaled_ui_amount_initialize_instruction_data(
+                ScaledUiAmountInstruction::Initialize,
+                AUTHORITY,
+                multiplier
+            )),
+            spl.data
+        );
+
+        let spl = spl::scaled_ui_amount::instruction::update_multiplier(
+            &program_id(),
+            &mint(),
+            &authority(),
+            &[],
+            multiplier,
+            1_700_000_000,
+        )
+        .unwrap();
+        assert_eq!(
+            init(scaled_ui_amount_update_multiplier_instruction_data(
+                ScaledUiAmountInstruction::UpdateMultiplier,
+                multiplier,
+                1_700_000_000
+            )),
+            spl.data
+        );
+    }
+}
+
+#[test]
+fn token_group_encoders() {
+    for update_authority in [None, Some(&AUTHORITY)] {
+        let spl = spl_token_group_interface::instruction::initialize_group(
+            &program_id(),
+            &address(),
+            &mint(),
+            &authority(),
+            update_authority.map(pin_pubkey_to_addr),
+            10,
+        );
+        let mut buffer = [0; offset_token_group_initialize_group::END as usize];
+        assert_eq!(
+            initialize_group_instruction_data(&mut buffer, update_authority, 10),
+            spl.data
+        );
+    }
+
+    let spl = spl_token_group_interface::instruction::update_group_max_size(
+        &program_id(),
+        &address(),
+        &authority(),
+        u64::MAX,
+    );
+    let mut buffer = [0; offset_token_group_update_max_size::END as usize];
+    assert_eq!(
+        update_group_max_size_instruction_data(&mut buffer, u64::MAX),
+        spl.data
+    );
+
+    for new_authority in [None, Some(&ADDRESS)] {
+        let spl = spl_token_group_interface::instruction::update_group_authority(
+            &program_id(),
+            &address(),
+            &authority(),
+            new_authority.map(pin_pubkey_to_addr),
+        );
+        let mut buffer = [0; offset_token_group_update_authority::END as usize];
+        assert_eq!(
+            update_group_authority_instruction_data(&mut buffer, new_authority),
+            spl.data
+        );
+    }
+
+    let spl = spl_token_group_interface::instruction::initialize_member(
+        &program_id(),
+        &account(),
+        &mint(),
+        &authority(),
+        &address(),
+        &authority(),
+    );
+    let mut buffer = [0; offset_token_group_initialize_member::END as usize];
+    assert_eq!(initialize_member_instruction_data(&mut buffer), spl.data);
+}
+
+#[test]
+fn token_metadata_encoders() {
+    use spl_token_metadata_interface::state::Field as SplField;
+
+    let mut buffer = [0; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+
+    let spl = spl_token_metadata_interface::instruction::initialize(
+        &program_id(),
+        &address(),
+        &authority(),
+        &mint(),
+        &authority(),
+        "Name".to_string(),
+        "".to_string(),
+        "https://example.com".to_string(),
+    );
+    assert_eq!(
+        initialize_token_metadata_instruction_data(&mut buffer, "Name", "", "https://example.com")
+            .unwrap(),
+        spl.data
+    );
+    // the strings don't fit
+    assert!(initialize_token_metadata_instruction_data(&mut buffer[..20], "Name", "", "").is_err());
+
+    for (field, spl_field) in [
+        (Field::Name, SplField::Name),
+        (Field::Symbol, SplField::Symbol),
+        (Field::Uri, SplField::Uri),
+        (Field::Key("key"), SplField::Key("key".to_string())),
+    ] {
+        let spl = spl_token_metadata_interface::instruction::update_field(
+            &program_id(),
+            &address(),
+            &authority(),
+            spl_field,
+            "value".to_string(),
+        );
+        assert_eq!(
+            update_token_metadata_field_instruction_data(&mut buffer, field, "value").unwrap(),
+            spl.data
+        );
+    }
+
+    for idempotent in [false, true] {
+        let spl = spl_token_metadata_interface::instruction::remove_key(
+            &program_id(),
+            &address(),
+            &authority(),
+            "key".to_string(),
+            idempotent,
+        );
+        assert_eq!(
+            remove_token_metadata_key_instruction_data(&mut buffer, idempotent, "key").unwrap(),
+            spl.data
+        );
+    }
+
+    for new_authority in [None, Some(&ADDRESS)] {
+        let spl = spl_token_metadata_interface::instruction::update_authority(
+            &program_id(),
+            &address(),
+            &authority(),
+            to_optional_non_zero_pubkey(new_authority),
+        );
+        let mut buffer = [0; UPDATE_TOKEN_METADATA_AUTHORITY_INSTRUCTION_DATA_LEN];
+        assert_eq!(
+            update_token_metadata_authority_instruction_data(&mut buffer, new_authority).unwrap(),
+            spl.data
+        );
+    }
+
+    for (start, end) in [
+        (None, None),
+        (Some(1), None),
+        (None, Some(u64::MAX)),
+        (Some(2), Some(3)),
+    ] {
+        let spl =
+            spl_token_metadata_interface::instruction::emit(&program_id(), &address(), start, end);
+        let mut buffer = [0; EMIT_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+        assert_eq!(
+            emit_token_metadata_instruction_data(&mut buffer, start, end).unwrap(),
+            spl.data
+        );
+    }
+}
+
+#[test]
+fn transfer_fee_encoders() {
+    for (transfer_fee_config_authority, withdraw_withheld_authority) in [
+        (None, None),
+        (Some(&AUTHORITY), None),
+        (None, Some(&ADDRESS)),
+        (Some(&AUTHORITY), Some(&ADDRESS)),
+    ] {
+        let spl = spl::transfer_fee::instruction::initialize_transfer_fee_config(
+            &program_id(),
+            &mint(),
+            transfer_fee_config_authority
+                .map(pin_pubkey_to_addr)
+                .as_ref(),
+            withdraw_withheld_authority.map(pin_pubkey_to_addr).as_ref(),
+            100,
+            5_000,
+        )
+        .unwrap();
+        let mut buffer = [0; offset_transfer_fee_initialize::END as usize];
+        assert_eq!(
+            initialize_transfer_fee_config_instruction_data(
+                &mut buffer,
+                transfer_fee_config_authority,
+                withdraw_withheld_authority,
+                100,
+                5_000
+            ),
+            spl.data
+        );
+    }
+
+    let spl = spl::transfer_fee::instruction::transfer_checked_with_fee(
+        &program_id(),
+        &account(),
+        &mint(),
+        &address(),
+        &authority(),
+        &[],
+        1_000_000,
+        6,
+        10_000,
+    )
+    .unwrap();
+    let mut buffer = [0; offset_transfer_fee_transfer_checked_with_fee::END as usize];
+    assert_eq!(
+        transfer_checked_with_fee_instruction_data(&mut buffer, 1_000_000, 6, 10_000),
+        spl.data
+    );
+
+    let spl = spl::transfer_fee::instruction::set_transfer_fee(
+        &program_id(),
+        &mint(),
+        &authority(),
+        &[],
+        10_000,
+        u64::MAX,
+    )
+    .unwrap();
+    let mut buffer = [0; offset_transfer_fee_set_transfer_fee::END as usize];
+    assert_eq!(
+        set_transfer_fee_instruction_data(&mut buffer, 10_000, u64::MAX),
+        spl.data
+    );
+
+    let spl = spl::transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+        &program_id(),
+        &mint(),
+        &address(),
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = [0; offset_transfer_fee_withdraw_withheld_tokens_from_mint::END as usize];
+    assert_eq!(
+        withdraw_withheld_tokens_from_mint_instruction_data(&mut buffer),
+        spl.data
+    );
+
+    let sources = [account(), address()];
+    let spl = spl::transfer_fee::instruction::withdraw_withheld_tokens_from_accounts(
+        &program_id(),
+        &mint(),
+        &address(),
+        &authority(),
+        &[],
+        &sources.iter().collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let mut buffer = [0; offset_transfer_fee_withdraw_withheld_tokens_from_accounts::END as usize];
+    assert_eq!(
+        withdraw_withheld_tokens_from_accounts_instruction_data(&mut buffer, sources.len() as u8),
+        spl.data
+    );
+
+    let spl = spl::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        &program_id(),
+        &mint(),
+        &sources.iter().collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let mut buffer = [0; offset_transfer_fee_harvest_withheld_tokens_to_mint::END as usize];
+    assert_eq!(
+        harvest_withheld_tokens_to_mint_instruction_data(&mut buffer),
+        spl.data
+    );
+}
+
+#[test]
+fn confidential_transfer_encoders() {
+    // the ciphertexts are arbitrary bytes, the encoders don't check them
+    let auditor = PodElGamalPubkey([5; 32]);
+    let balance = DecryptableBalance([6; 36]);
+    let spl_balance = bytemuck::cast::<_, SplPodAeCiphertext>(balance.0);
+
+    for (authority, auditor_elgamal_pubkey) in [
+        (None, None),
+        (Some(&AUTHORITY), None),
+        (None, Some(&auditor)),
+        (Some(&AUTHORITY), Some(&auditor)),
+    ] {
+        for auto_approve_new_accounts in [false, true] {
+            let spl = spl::confidential_transfer::instruction::initialize_mint(
+                &program_id(),
+                &mint(),
+                authority.map(pin_pubkey_to_addr),
+                auto_approve_new_accounts,
+                auditor_elgamal_pubkey.map(|x| bytemuck::cast::<_, SplPodElGamalPubkey>(x.0)),
+            )
+            .unwrap();
+            let mut buffer = [0; offset_confidential_transfer_initialize_mint::END as usize];
+            assert_eq!(
+                initialize_confidential_transfer_mint_instruction_data(
+                    &mut buffer,
+                    authority,
+                    auto_approve_new_accounts,
+                    auditor_elgamal_pubkey
+                ),
+                spl.data
+            );
+        }
+    }
+
+    // proofs in context state accounts, so the instruction offsets are 0
+    let context_state = address();
+    let spl = spl::confidential_transfer::instruction::configure_account(
+        &program_id(),
+        &account(),
+        &mint(),
+        &spl_balance,
+        65_536,
+        &authority(),
+        &[],
+        ProofLocation::ContextStateAccount(&context_state),
+    )
+    .unwrap();
+    let mut buffer = [0; offset_confidential_transfer_configure_account::END as usize];
+    assert_eq!(
+        configure_account_instruction_data(&mut buffer, &balance, 65_536, 0),
+        spl[0].data
+    );
+
+    let spl = spl::confidential_transfer::instruction::deposit(
+        &program_id(),
+        &account(),
+        &mint(),
+        1_000_000,
+        6,
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = [0; offset_confidential_transfer_deposit::END as usize];
+    assert_eq!(
+        deposit_instruction_data(&mut buffer, 1_000_000, 6),
+        spl.data
+    );
+
+    let spl = spl::confidential_transfer::instruction::withdraw(
+        &program_id(),
+        &account(),
+        &mint(),
+        1_000_000,
+        6,
+        &spl_balance,
+        &authority(),
+        &[],
+        ProofLocation::ContextStateAccount(&context_state),
+        ProofLocation::ContextStateAccount(&context_state),
+    )
+    .unwrap();
+    let mut buffer = [0; offset_confidential_transfer_withdraw::END as usize];
+    assert_eq!(
+        withdraw_instruction_data(&mut buffer, 1_000_000, 6, &balance, 0, 0),
+        spl[0].data
+    );
+
+    let spl = spl::confidential_transfer::instruction::apply_pending_balance(
+        &program_id(),
+        &account(),
+        3,
+        &spl_balance,
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = [0; offset_confidential_transfer_apply_pending_balance::END as usize];
+    assert_eq!(
+        apply_pending_balance_instruction_data(&mut buffer, 3, &balance),
+        spl.data
+    );
+}
+
+#[test]
+fn confidential_transfer_fee_encoders() {
+    // the encoders carrying ElGamal keys and ciphertexts are left out, the interface
+    // builders for them require proofs
+    let spl = spl::confidential_transfer_fee::instruction::enable_harvest_to_mint(
+        &program_id(),
+        &mint(),
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = [0; offset_confidential_transfer_fee_no_data::END as usize];
+    assert_eq!(
+        enable_harvest_to_mint_instruction_data(&mut buffer),
+        spl.data
+    );
+
+    let spl = spl::confidential_transfer_fee::instruction::disable_harvest_to_mint(
+        &program_id(),
+        &mint(),
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = [0; offset_confidential_transfer_fee_no_data::END as usize];
+    assert_eq!(
+        disable_harvest_to_mint_instruction_data(&mut buffer),
+        spl.data
+    );
+
+    let sources = [account()];
+    let spl = spl::confidential_transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        &program_id(),
+        &mint(),
+        &sources.iter().collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let mut buffer = [0; offset_confidential_transfer_fee_no_data::END as usize];
+    assert_eq!(confidential_harvest_instruction_data(&mut buffer), spl.data);
+}
+
+#[test]
+fn transfer_hook_encoders() {
+    for (authority, hook_program_id) in [
+        (None, None),
+        (Some(&AUTHORITY), None),
+        (None, Some(&ADDRESS)),
+        (Some(&AUTHORITY), Some(&ADDRESS)),
+    ] {
+        let spl = spl::transfer_hook::instruction::initialize(
+            &program_id(),
+            &mint(),
+            authority.map(pin_pubkey_to_addr),
+            hook_program_id.map(pin_pubkey_to_addr),
+        )
+        .unwrap();
+        let mut buffer = [0; 66];
+        assert_eq!(
+            transfer_hook_initialize_instruction_data(
+                &mut buffer,
+                TransferHookInstruction::Initialize,
+                authority,
+                hook_program_id
+            ),
+            spl.data
+        );
+    }
+
+    for hook_program_id in [None, Some(&ADDRESS)] {
+        let spl = spl::transfer_hook::instruction::update(
+            &program_id(),
+            &mint(),
+            &authority(),
+            &[],
+            hook_program_id.map(pin_pubkey_to_addr),
+        )
+        .unwrap();
+        let mut buffer = [0; 34];
+        assert_eq!(
+            transfer_hook::update_instruction_data(&mut buffer, hook_program_id),
+            spl.data
+        );
+    }
+}
+
+#[test]
+fn transfer_hook_interface_encoders() {
+    // the interface discriminators are the first 8 bytes of the namespaced instruction hash
+    let discriminator = solana_program::hash::hash(b"spl-transfer-hook-interface:execute");
+
+    for amount in [0, 1_000, u64::MAX] {
+        assert_eq!(
+            init(execute_instruction_data(amount)),
+            [&discriminator.to_bytes()[..8], &amount.to_le_bytes()].concat()
+        );
+    }
+
+    let metas = [
+        ExtraAccountMeta::new_with_pubkey(&ADDRESS, false, true),
+        ExtraAccountMeta::new_with_pubkey(&AUTHORITY, true, false),
+    ];
+    let mut list = vec![0; ExtraAccountMetaList::size_of(metas.len())];
+    ExtraAccountMetaList::write(&mut list, &metas).unwrap();
+
+    let mut buffer = [0; MAX_EXTRA_ACCOUNT_METAS_INSTRUCTION_DATA_LEN];
+    for (encode, name) in [
+        (
+            initialize_extra_account_meta_list_instruction_data
+                as for<'a> fn(&'a mut [u8], &[ExtraAccountMeta]) -> Result<&'a [u8], _>,
+            "initialize-extra-account-metas",
+        ),
+        (
+            update_extra_account_meta_list_instruction_data,
+            "update-extra-account-metas",
+        ),
+    ] {
+        let discriminator =
+            solana_program::hash::hash(format!("spl-transfer-hook-interface:{name}").as_bytes());
+
+        // the metas follow the discriminator like in the validation account, without the length
+        assert_eq!(
+            encode(&mut buffer, &metas).unwrap(),
+            [&discriminator.to_bytes()[..8], &list[12..]].concat()
+        );
+        assert!(encode(&mut buffer[..list.len() - 5], &metas).is_err());
+    }
+}
+
+#[test]
+fn withdraw_excess_lamports_encoder() {
+    let spl = spl_token_2022_interface::instruction::withdraw_excess_lamports(
+        &program_id(),
+        &account(),
+        &address(),
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    let mut buffer = [0; 1];
+    assert_eq!(
+        withdraw_excess_lamports_instruction_data(&mut buffer),
+        spl.data
+    );
+}
+
+// the round trip tests decode the pinocchio data with the SPL decoders and compare the
+// decoded fields with the encoder arguments
+
+/// Check the extension prefix and decode the instruction type that follows
+fn decode_type<T: TryFrom<u8>>(data: &[u8], prefix: TokenInstruction) -> T {
+    assert_eq!(TokenInstruction::unpack(data).unwrap(), prefix);
+    decode_instruction_type(&data[1..]).unwrap()
+}
+
+/// Check the extension prefix and decode the instruction data that follows the type
+fn decode_data<T: Pod>(data: &[u8], prefix: TokenInstruction) -> &T {
+    assert_eq!(TokenInstruction::unpack(data).unwrap(), prefix);
+    decode_instruction_data(&data[1..]).unwrap()
+}
+
+#[test]
+fn cpi_guard_round_trip() {
+    use spl::cpi_guard::instruction::CpiGuardInstruction as Spl;
+
+    for (instruction, expected) in [
+        (CpiGuardInstruction::Enable, Spl::Enable),
+        (CpiGuardInstruction::Disable, Spl::Disable),
+    ] {
+        let data = init(cpi_guard_instruction_data(instruction));
+        assert_eq!(
+            decode_type::<Spl>(&data, TokenInstruction::CpiGuardExtension),
+            expected
+        );
+    }
+}
+
+#[test]
+fn default_account_state_round_trip() {
+    use spl::default_account_state::instruction::DefaultAccountStateInstruction as Spl;
+    let prefix = TokenInstruction::DefaultAccountStateExtension;
+
+    for (instruction, expected, state) in [
+        (
+            DefaultAccountStateInstruction::Initialize,
+            Spl::Initialize,
+            AccountState::Frozen,
+        ),
+        (
+            DefaultAccountStateInstruction::Update,
+            Spl::Update,
+            AccountState::Initialized,
+        ),
+    ] {
+        let data = init(default_account_state_instruction_data(
+            instruction,
+            state as u8,
+        ));
+        assert_eq!(decode_type::<Spl>(&data, prefix.clone()), expected);
+        assert_eq!(*decode_data::<u8>(&data, prefix.clone()), state as u8);
+    }
+}
+
+#[test]
+fn group_member_pointer_round_trip() {
+    use spl::group_member_pointer::instruction::{
+        GroupMemberPointerInstruction, InitializeInstructionData, UpdateInstructionData,
+    };
+    let prefix = TokenInstruction::GroupMemberPointerExtension;
+
+    for (authority, member_address) in [(None, None), (Some(&AUTHORITY), Some(&ADDRESS))] {
+        let mut buffer = [0; offset_group_member_pointer_initialize::END as usize];
+        let data = group_member_pointer::initialize_instruction_data(
+            &mut buffer,
+            authority,
+            member_address,
+        );
+        assert_eq!(
+            decode_type::<GroupMemberPointerInstruction>(data, prefix.clone()),
+            GroupMemberPointerInstruction::Initialize
+        );
+        let decoded = decode_data::<InitializeInstructionData>(data, prefix.clone());
+        assert_eq!(decoded.authority, to_optional_non_zero_pubkey(authority));
+        assert_eq!(
+            decoded.member_address,
+            to_optional_non_zero_pubkey(member_address)
+        );
+    }
+
+    let mut buffer = [0; offset_group_member_pointer_update::END as usize];
+    let data = group_member_pointer::update_instruction_data(&mut buffer, Some(&ADDRESS));
+    assert_eq!(
+        decode_type::<GroupMemberPointerInstruction>(data, prefix.clone()),
+        GroupMemberPointerInstruction::Update
+    );
+    assert_eq!(
+        decode_data::<UpdateInstructionData>(data, prefix).member_address,
+        to_optional_non_zero_pubkey(Some(&ADDRESS))
+    );
+}
+
+#[test]
+fn group_pointer_round_trip() {
+    use spl::group_pointer::instruction::{
+        GroupPointerInstruction, InitializeInstructionData, UpdateInstructionData,
+    };
+    let prefix = TokenInstruction::GroupPointerExtension;
+
+    for (authority, group_address) in [(None, None), (Some(&AUTHORITY), Some(&ADDRESS))] {
+        let mut buffer = [0; offset_group_pointer_initialize::END as usize];
+        let data =
+            group_pointer::initialize_instruction_data(&mut buffer, authority, group_address);
+        assert_eq!(
+            decode_type::<GroupPointerInstruction>(data, prefix.clone()),
+            GroupPointerInstruction::Initialize
+        );
+        let decoded = decode_data::<InitializeInstructionData>(data, prefix.clone());
+        assert_eq!(decoded.authority, to_optional_non_zero_pubkey(authority));
+        assert_eq!(
+            decoded.group_address,
+            to_optional_non_zero_pubkey(group_address)
+        );
+    }
+
+    let mut buffer = [0; offset_group_pointer_update::END as usize];
+    let data = group_pointer::update_instruction_data(&mut buffer, Some(&ADDRESS));
+    assert_eq!(
+        decode_type::<GroupPointerInstruction>(data, prefix.clone()),
+        GroupPointerInstruction::Update
+    );
+    assert_eq!(
+        decode_data::<UpdateInstructionData>(data, prefix).group_address,
+        to_optional_non_zero_pubkey(Some(&ADDRESS))
+    );
+}
+
+#[test]
+fn interest_bearing_mint_round_trip() {
+    use spl::interest_bearing_mint::{
+        instruction::{InitializeInstructionData, InterestBearingMintInstruction},
+        BasisPoints,
+    };
+    let prefix = TokenInstruction::InterestBearingMintExtension;
+
+    for (rate_authority, rate) in [(None, 0), (Some(&AUTHORITY), i16::MIN)] {
+        let data = init(interest_bearing_mint_initialize_instruction_data(
+            rate_authority,
+            rate,
+        ));
+        assert_eq!(
+            decode_type::<InterestBearingMintInstruction>(&data, prefix.clone()),
+            InterestBearingMintInstruction::Initialize
+        );
+        let decoded = decode_data::<InitializeInstructionData>(&data, prefix.clone());
+        assert_eq!(
+            decoded.rate_authority,
+            to_optional_non_zero_pubkey(rate_authority)
+        );
+        assert_eq!(i16::from(decoded.rate), rate);
+    }
+
+    let data = init(interest_bearing_mint_update_rate_instruction_data(i16::MAX));
+    assert_eq!(
+        decode_type::<InterestBearingMintInstruction>(&data, prefix.clone()),
+        InterestBearingMintInstruction::UpdateRate
+    );
+    assert_eq!(
+        i16::from(*decode_data::<BasisPoints>(&data, prefix)),
+        i16::MAX
+    );
+}
+
+#[test]
+fn memo_transfer_round_trip() {
+    use spl::memo_transfer::instruction::RequiredMemoTransfersInstruction;
+    let prefix = TokenInstruction::MemoTransferExtension;
+
+    let mut buffer = [0; offset_memo_transfer::END as usize];
+    assert_eq!(
+        decode_type::<RequiredMemoTransfersInstruction>(
+            enable_instruction_data(&mut buffer),
+            prefix.clone()
+        ),
+        RequiredMemoTransfersInstruction::Enable
+    );
+
+    let mut buffer = [0; offset_memo_transfer::END as usize];
+    assert_eq!(
+        decode_type::<RequiredMemoTransfersInstruction>(
+            disable_instruction_data(&mut buffer),
+            prefix
+        ),
+        RequiredMemoTransfersInstruction::Disable
+    );
+}
+
+#[test]
+fn metadata_pointer_round_trip() {
+    use spl::metadata_pointer::instruction::{
+        InitializeInstructionData, MetadataPointerInstruction, UpdateInstructionData,
+    };
+    let prefix = TokenInstruction::MetadataPointerExtension;
+
+    for (authority, metadata_address) in [(None, None), (Some(&AUTHORITY), Some(&ADDRESS))] {
+        let mut buffer = [0; offset_metadata_pointer_initialize::END as usize];
+        let data =
+            metadata_pointer::initialize_instruction_data(&mut buffer, authority, metadata_address);
+        assert_eq!(
+            decode_type::<MetadataPointerInstruction>(data, prefix.clone()),
+            MetadataPointerInstruction::Initialize
+        );
+        let decoded = decode_data::<InitializeInstructionData>(data, prefix.clone());
+        assert_eq!(decoded.authority, to_optional_non_zero_pubkey(authority));
+        assert_eq!(
+            decoded.metadata_address,
+            to_optional_non_zero_pubkey(metadata_address)
+        );
+    }
+
+    let mut buffer = [0; offset_metadata_pointer_update::END as usize];
+    let data = metadata_pointer::update_instruction_data(&mut buffer, Some(&ADDRESS));
+    assert_eq!(
+        decode_type::<MetadataPointerInstruction>(data, prefix.clone()),
+        MetadataPointerInstruction::Update
+    );
+    assert_eq!(
+        decode_data::<UpdateInstructionData>(data, prefix).metadata_address,
+        to_optional_non_zero_pubkey(Some(&ADDRESS))
+    );
+}
+
+#[test]
+fn mint_extensions_round_trip() {
+    for close_authority in [None, Some(&AUTHORITY)] {
+        let mut buffer = [0; offset_mint_close_authority_initialize::END as usize];
+        assert_eq!(
+            TokenInstruction::unpack(mint_close_authority::initialize_instruction_data(
+                &mut buffer,
+                close_authority
+            ))
+            .unwrap(),
+            TokenInstruction::InitializeMintCloseAuthority {
+                close_authority: to_c_option(close_authority.map(pin_pubkey_to_addr)),
+            }
+        );
+    }
+
+    let mut buffer = [0; offset_permanent_delegate_initialize::END as usize];
+    assert_eq!(
+        TokenInstruction::unpack(permanent_delegate::initialize_instruction_data(
+            &mut buffer,
+            &AUTHORITY
+        ))
+        .unwrap(),
+        TokenInstruction::InitializePermanentDelegate {
+            delegate: authority()
+        }
+    );
+
+    let mut buffer = [0; offset_non_transferable_initialize::END as usize];
+    assert_eq!(
+        TokenInstruction::unpack(non_transferable::initialize_instruction_data(&mut buffer))
+            .unwrap(),
+        TokenInstruction::InitializeNonTransferableMint
+    );
+}
+
+#[test]
+fn pausable_round_trip() {
+    use spl::pausable::instruction::{InitializeInstructionData, PausableInstruction as Spl};
+    let prefix = TokenInstruction::PausableExtension;
+
+    let data = init(pausable_initialize_instruction_data(
+        PausableInstruction::Initialize,
+        AUTHORITY,
+    ));
+    assert_eq!(decode_type::<Spl>(&data, prefix.clone()), Spl::Initialize);
+    assert_eq!(
+        decode_data::<InitializeInstructionData>(&data, prefix.clone()).authority,
+        authority()
+    );
+
+    for (instruction, expected) in [
+        (PausableInstruction::Pause, Spl::Pause),
+        (PausableInstruction::Resume, Spl::Resume),
+    ] {
+        let data = init(pausable_instruction_data(instruction));
+        assert_eq!(decode_type::<Spl>(&data, prefix.clone()), expected);
+    }
+}
+
+#[test]
+fn scaled_ui_amount_round_trip() {
+    use spl::scaled_ui_amount::instruction::{
+        InitializeInstructionData, ScaledUiAmountMintInstruction, UpdateMultiplierInstructionData,
+    };
+    let prefix = TokenInstruction::ScaledUiAmountExtension;
+
+    let data = init(scaled_ui_amount_initialize_instruction_data(
+        ScaledUiAmountInstruction::Initialize,
+        AUTHORITY,
+        1_000.125,
+    ));
+    assert_eq!(
+        decode_type::<ScaledUiAmountMintInstruction>(&data, prefix.clone()),
+        ScaledUiAmountMintInstruction::Initialize
+    );
+    let decoded = decode_data::<InitializeInstructionData>(&data, prefix.clone());
+    assert_eq!(
+        decoded.authority,
+        to_optional_non_zero_pubkey(Some(&AUTHORITY))
+    );
+    assert_eq!(f64::from(decoded.multiplier), 1_000.125);
+
+    let data = init(scaled_ui_amount_update_multiplier_instruction_data(
+        ScaledUiAmountInstruction::UpdateMultiplier,
+        0.5,
+        -1,
+    ));
+    assert_eq!(
+        decode_type::<ScaledUiAmountMintInstruction>(&data, prefix.clone()),
+        ScaledUiAmountMintInstruction::UpdateMultiplier
+    );
+    let decoded = decode_data::<UpdateMultiplierInstructionData>(&data, prefix);
+    assert_eq!(f64::from(decoded.multiplier), 0.5);
+    assert_eq!(i64::from(decoded.effective_timestamp), -1);
+}
+
+#[test]
+fn token_group_round_trip() {
+    use spl_token_group_interface::instruction::{
+        InitializeGroup, TokenGroupInstruction, UpdateGroupAuthority, UpdateGroupMaxSize,
+    };
+
+    for update_authority in [None, Some(&AUTHORITY)] {
+        let mut buffer = [0; offset_token_group_initialize_group::END as usize];
+        assert_eq!(
+            TokenGroupInstruction::unpack(initialize_group_instruction_data(
+                &mut buffer,
+                update_authority,
+                u64::MAX
+            ))
+            .unwrap(),
+            TokenGroupInstruction::InitializeGroup(InitializeGroup {
+                update_authority: to_optional_non_zero_pubkey(update_authority),
+                max_size: u64::MAX.into(),
+            })
+        );
+    }
+
+    let mut buffer = [0; offset_token_group_update_max_size::END as usize];
+    assert_eq!(
+        TokenGroupInstruction::unpack(update_group_max_size_instruction_data(&mut buffer, 1))
+            .unwrap(),
+        TokenGroupInstruction::UpdateGroupMaxSize(UpdateGroupMaxSize { max_size: 1.into() })
+    );
+
+    let mut buffer = [0; offset_token_group_update_authority::END as usize];
+    assert_eq!(
+        TokenGroupInstruction::unpack(update_group_authority_instruction_data(
+            &mut buffer,
+            Some(&ADDRESS)
+        ))
+        .unwrap(),
+        TokenGroupInstruction::UpdateGroupAuthority(UpdateGroupAuthority {
+            new_authority: to_optional_non_zero_pubkey(Some(&ADDRESS)),
+        })
+    );
+}
+
+#[test]
+fn transfer_fee_round_trip() {
+    use spl::transfer_fee::instruction::TransferFeeInstruction;
+
+    fn decode(data: &[u8]) -> TransferFeeInstruction {
+        assert_eq!(
+            TokenInstruction::unpack(data).unwrap(),
+            TokenInstruction::TransferFeeExtension
+        );
+        TransferFeeInstruction::unpack(&data[1..]).unwrap()
+    }
+
+    // the maximum fee is u64, values wider than u32 catch a truncated field
+    for (transfer_fee_config_authority, withdraw_withheld_authority, maximum_fee) in [
+        (None, None, 0),
+        (Some(&AUTHORITY), Some(&ADDRESS), u64::MAX),
+        (None, Some(&ADDRESS), u32::MAX as u64 + 1),
+    ] {
+        let mut buffer = [0; offset_transfer_fee_initialize::END as usize];
+        assert_eq!(
+            decode(initialize_transfer_fee_config_instruction_data(
+                &mut buffer,
+                transfer_fee_config_authority,
+                withdraw_withheld_authority,
+                10_000,
+                maximum_fee
+            )),
+            TransferFeeInstruction::InitializeTransferFeeConfig {
+                transfer_fee_config_authority: to_c_option(
+                    transfer_fee_config_authority.map(pin_pubkey_to_addr)
+                ),
+                withdraw_withheld_authority: to_c_option(
+                    withdraw_withheld_authority.map(pin_pubkey_to_addr)
+                ),
+                transfer_fee_basis_points: 10_000,
+                maximum_fee,
+            }
+        );
+
+        let mut buffer = [0; offset_transfer_fee_set_transfer_fee::END as usize];
+        assert_eq!(
+            decode(set_transfer_fee_instruction_data(
+                &mut buffer,
+                u16::MAX,
+                maximum_fee
+            )),
+            TransferFeeInstruction::SetTransferFee {
+                transfer_fee_basis_points: u16::MAX,
+                maximum_fee,
+            }
+        );
+    }
+
+    let mut buffer = [0; offset_transfer_fee_transfer_checked_with_fee::END as usize];
+    assert_eq!(
+        decode(transfer_checked_with_fee_instruction_data(
+            &mut buffer,
+            u64::MAX,
+            9,
+            u64::MAX - 1
+        )),
+        TransferFeeInstruction::TransferCheckedWithFee {
+            amount: u64::MAX,
+            decimals: 9,
+            fee: u64::MAX - 1,
+        }
+    );
+
+    let mut buffer = [0; offset_transfer_fee_withdraw_withheld_tokens_from_mint::END as usize];
+    assert_eq!(
+        decode(withdraw_withheld_tokens_from_mint_instruction_data(
+            &mut buffer
+        )),
+        TransferFeeInstruction::WithdrawWithheldTokensFromMint
+    );
+
+    let mut buffer = [0; offset_transfer_fee_withdraw_withheld_tokens_from_accounts::END as usize];
+    assert_eq!(
+        decode(withdraw_withheld_tokens_from_accounts_instruction_data(
+            &mut buffer,
+            u8::MAX
+        )),
+        TransferFeeInstruction::WithdrawWithheldTokensFromAccounts {
+            num_token_accounts: u8::MAX,
+        }
+    );
+
+    let mut buffer = [0; offset_transfer_fee_harvest_withheld_tokens_to_mint::END as usize];
+    assert_eq!(
+        decode(harvest_withheld_tokens_to_mint_instruction_data(
+            &mut buffer
+        )),
+        TransferFeeInstruction::HarvestWithheldTokensToMint
+    );
+}
+
+#[test]
+fn transfer_hook_round_trip() {
+    use spl::transfer_hook::instruction::{
+        InitializeInstructionData, TransferHookInstruction as Spl, UpdateInstructionData,
+    };
+    let prefix = TokenInstruction::TransferHookExtension;
+
+    for (authority, hook_program_id) in [(None, None), (Some(&AUTHORITY), Some(&ADDRESS))] {
+        let mut buffer = [0; 66];
+        let data = transfer_hook_initialize_instruction_data(
+            &mut buffer,
+            TransferHookInstruction::Initialize,
+            authority,
+            hook_program_id,
+        );
+        assert_eq!(decode_type::<Spl>(data, prefix.clone()), Spl::Initialize);
+        let decoded = decode_data::<InitializeInstructionData>(data, prefix.clone());
+        assert_eq!(decoded.authority, to_optional_non_zero_pubkey(authority));
+        assert_eq!(
+            decoded.program_id,
+            to_optional_non_zero_pubkey(hook_program_id)
+        );
+    }
+
+    let mut buffer = [0; 34];
+    let data = transfer_hook::update_instruction_data(&mut buffer, Some(&ADDRESS));
+    assert_eq!(decode_type::<Spl>(data, prefix.clone()), Spl::Update);
+    assert_eq!(
+        decode_data::<UpdateInstructionData>(data, prefix).program_id,
+        to_optional_non_zero_pubkey(Some(&ADDRESS))
+    );
+}
+
+#[test]
+fn extension_instruction_discriminators() {
+    // the discriminators start the data of the SPL instructions
+    let spl = spl::pausable::instruction::pause(&program_id(), &mint(), &authority(), &[]).unwrap();
+    assert_eq!(PausableInstruction::Pause.as_bytes(), spl.data[..]);
+    assert_eq!(
+        ExtensionDiscriminator::try_from(spl.data[0]),
+        Ok(ExtensionDiscriminator::Pausable)
+    );
+    assert_eq!(
+        PausableInstruction::try_from(spl.data[1]),
+        Ok(PausableInstruction::Pause)
+    );
+
+    let spl = spl::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        &program_id(),
+        &mint(),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        InstructionDiscriminatorTransferFee::HarvestWithheldTokensToMint.as_bytes(),
+        spl.data[..]
+    );
+    assert_eq!(
+        InstructionDiscriminatorTransferFee::try_from(spl.data[1]),
+        Ok(InstructionDiscriminatorTransferFee::HarvestWithheldTokensToMint)
+    );
+
+    let spl = spl::confidential_transfer::instruction::deposit(
+        &program_id(),
+        &account(),
+        &mint(),
+        1_000_000,
+        6,
+        &authority(),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        InstructionDiscriminatorConfidentialTransfer::Deposit.as_bytes(),
+        spl.data[..2]
+    );
+    assert_eq!(
+        ExtensionDiscriminator::try_from(spl.data[0]),
+        Ok(ExtensionDiscriminator::ConfidentialTransfer)
+    );
+    assert_eq!(
+        InstructionDiscriminatorConfidentialTransfer::try_from(spl.data[1]),
+        Ok(InstructionDiscriminatorConfidentialTransfer::Deposit)
+    );
+
+    let spl = spl_token_group_interface::instruction::update_group_max_size(
+        &program_id(),
+        &address(),
+        &authority(),
+        10,
+    );
+    assert_eq!(
+        InstructionDiscriminatorTokenGroup::UpdateGroupMaxSize.as_bytes(),
+        spl.data[..8]
+    );
+    assert_eq!(
+        InstructionDiscriminatorTokenGroup::try_from(&spl.data[..]),
+        Ok(InstructionDiscriminatorTokenGroup::UpdateGroupMaxSize)
+    );
+
+    // unknown discriminators
+    assert_eq!(
+        ExtensionDiscriminator::try_from(42),
+        Err(ProgramError::InvalidInstructionData)
+    );
+    assert_eq!(
+        InstructionDiscriminatorTransferFee::try_from(6),
+        Err(ProgramError::InvalidInstructionData)
+    );
+    assert_eq!(
+        InstructionDiscriminatorTokenGroup::try_from(&[0; 8][..]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+    assert_eq!(
+        InstructionDiscriminatorTokenGroup::try_from(&spl.data[..7]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+}
+
+#[test]
+fn token_instruction_unpack() {
+    // a zeroed payload is long enough for the arguments of every instruction and
+    // decodes to valid SPL arguments, the variants are compared by name
+    let payload = [0; 66];
+
+    for discriminator in 0..=44u8 {
+        let data = [&[discriminator][..], &payload].concat();
+        let spl = format!("{:?}", TokenInstruction::unpack(&data).unwrap());
+        let instruction = PinTokenInstruction::unpack(&data).unwrap();
+
+        assert_eq!(
+            TokenInstructionDiscriminator::try_from(discriminator),
+            Ok(instruction.discriminator)
+        );
+        assert_eq!(instruction.discriminator as u8, discriminator);
+        assert_eq!(instruction.data, &payload[..]);
+        assert_eq!(
+            spl.split([' ', '(']).next().unwrap(),
+            format!("{:?}", instruction.discriminator)
+        );
+
+        // the SPL extension instructions are the `*Extension` variants
+        assert_eq!(
+            instruction.discriminator.is_extension(),
+            spl.ends_with("Extension")
+        );
+        if instruction.discriminator.is_extension() {
+            assert_eq!(instruction.extension_instruction(), Ok((0, &payload[1..])));
+            assert_eq!(
+                PinTokenInstruction::unpack(&[discriminator])
+                    .unwrap()
+                    .extension_instruction(),
+                Err(ProgramError::InvalidInstructionData)
+            );
+        } else {
+            assert_eq!(
+                instruction.extension_instruction(),
+                Err(ProgramError::InvalidInstructionData)
+            );
+        }
+    }
+
+    // empty data and unknown discriminators
+    assert!(TokenInstruction::unpack(&[]).is_err());
+    assert_eq!(
+        PinTokenInstruction::unpack(&[]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+    for discriminator in 45..=u8::MAX {
+        let data = [&[discriminator][..], &payload].concat();
+
+        assert!(TokenInstruction::unpack(&data).is_err());
+        assert_eq!(
+            PinTokenInstruction::unpack(&data),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            TokenInstructionDiscriminator::try_from(discriminator),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+}
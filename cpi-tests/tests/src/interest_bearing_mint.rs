@@ -4,13 +4,16 @@ use {
             initialize_mint::Token2022InitializeMintExtension,
             initialize_multisig::Token2022InitializeMultisigExtension,
             interest_bearing_mint::Token2022InterestBearingMintExtension,
+            return_data::Token2022ReturnDataExtension,
         },
         suite::{
-            core::App,
+            core::{extension::get_account_data, App},
             types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestResult},
         },
     },
+    pinocchio_token_2022::extension::interest_bearing_mint::state::InterestBearingConfig as PinocchioInterestBearingConfig,
     pretty_assertions::assert_eq,
+    solana_program::clock::Clock,
     solana_signer::Signer,
     spl_pod::optional_keys::OptionalNonZeroPubkey,
     spl_token_2022_interface::extension::{
@@ -366,3 +369,203 @@ fn proxy_initialize_and_update_interest_bearing_mint_with_multisig() -> TestResu
 
     Ok(())
 }
+
+const DECIMALS: u8 = 6;
+/// Same as the Token-2022 year length used for the interest accrual
+const SECONDS_PER_YEAR: i64 = 31_556_736;
+
+/// Create a mint with interest bearing config controlled by `rate_authority`
+fn prepare_interest_bearing_mint(
+    app: &mut App,
+    target: Target,
+    rate_authority: &[u8; 32],
+    rate: i16,
+) -> TestResult<[u8; 32]> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::InterestBearingConfig]),
+    )?;
+    let mint_pubkey = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_interest_bearing_mint(
+        target,
+        AppUser::Admin,
+        &mint_pubkey,
+        Some(rate_authority),
+        rate,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint_pubkey,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    Ok(mint_pubkey)
+}
+
+/// Both parsers must read the same config, and the pinocchio accrual helper must match
+/// `AmountToUiAmount` of Token-2022 at the current clock. Returns the UI amount
+fn assert_accrued_interest(
+    app: &mut App,
+    target: Target,
+    mint_pubkey: &[u8; 32],
+    amount: u64,
+) -> TestResult<f64> {
+    assert_eq!(
+        app.token_2022_query_interest_bearing_mint(Target::Proxy, mint_pubkey)?,
+        app.token_2022_query_interest_bearing_mint(Target::Spl, mint_pubkey)?
+    );
+
+    let unix_timestamp = app.litesvm.get_sysvar::<Clock>().unix_timestamp;
+    let data = get_account_data(app, mint_pubkey)?;
+    let ui_amount = PinocchioInterestBearingConfig::from_bytes(&data)
+        .unwrap()
+        .amount_to_ui_amount(amount, DECIMALS, unix_timestamp)
+        .unwrap();
+
+    let return_data = app
+        .token_2022_try_amount_to_ui_amount(target, AppUser::Admin, mint_pubkey, amount)?
+        .return_data
+        .data;
+    let expected_ui_amount: f64 = String::from_utf8(return_data).unwrap().parse().unwrap();
+
+    // Token-2022 rounds the UI amount to the mint decimals
+    assert!(
+        (ui_amount - expected_ui_amount).abs() < 1e-6,
+        "{ui_amount} != {expected_ui_amount}"
+    );
+
+    Ok(ui_amount)
+}
+
+fn accrue_interest_with_clock_warp(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let amount = 1_000_000_000;
+    let mint_pubkey =
+        &prepare_interest_bearing_mint(&mut app, target, &AppUser::Admin.pubkey(), 500)?;
+
+    // no interest without time passing
+    assert_eq!(
+        assert_accrued_interest(&mut app, target, mint_pubkey, amount)?,
+        1_000.
+    );
+
+    // 5% continuously compounded for a year
+    app.advance_clock(SECONDS_PER_YEAR);
+    let ui_amount = assert_accrued_interest(&mut app, target, mint_pubkey, amount)?;
+    assert!((ui_amount - 1_051.271_096).abs() < 1e-6);
+
+    app.token_2022_try_update_interest_bearing_mint_rate(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        1_000,
+    )?;
+
+    let config = app.token_2022_query_interest_bearing_mint(target, mint_pubkey)?;
+    assert_eq!(i64::from(config.last_update_timestamp), SECONDS_PER_YEAR);
+    assert_eq!(i16::from(config.pre_update_average_rate), 500);
+    assert_eq!(i16::from(config.current_rate), 1_000);
+
+    // the rate update doesn't change the accrued interest
+    assert_eq!(
+        assert_accrued_interest(&mut app, target, mint_pubkey, amount)?,
+        ui_amount
+    );
+
+    // then 10% for another year
+    app.advance_clock(SECONDS_PER_YEAR);
+    let ui_amount = assert_accrued_interest(&mut app, target, mint_pubkey, amount)?;
+    assert!((ui_amount - 1_161.834_243).abs() < 1e-6);
+
+    // the time-weighted average rate matches the one Token-2022 stores on the
+    // next update
+    let unix_timestamp = app.litesvm.get_sysvar::<Clock>().unix_timestamp;
+    let data = get_account_data(&app, mint_pubkey)?;
+    let pinocchio_config = PinocchioInterestBearingConfig::from_bytes(&data).unwrap();
+    assert_eq!(
+        pinocchio_config.total_elapsed(unix_timestamp),
+        Some(2 * SECONDS_PER_YEAR)
+    );
+    let average_rate = pinocchio_config.average_rate(unix_timestamp);
+    assert_eq!(average_rate, Some(750));
+
+    app.token_2022_try_update_interest_bearing_mint_rate(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        1_000,
+    )?;
+    let config = app.token_2022_query_interest_bearing_mint(target, mint_pubkey)?;
+    assert_eq!(
+        Some(i16::from(config.pre_update_average_rate)),
+        average_rate
+    );
+
+    Ok(())
+}
+
+fn accrue_interest_with_clock_warp_and_multisig(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let amount = 1_000_000_000;
+
+    let (_, multisig_kp) = app.token_2022_try_create_multisig(AppUser::Admin, None)?;
+    let multisig_pubkey = &multisig_kp.pubkey().to_bytes();
+    app.token_2022_try_initialize_multisig(
+        Target::Spl,
+        AppUser::Admin,
+        multisig_pubkey,
+        2,
+        &[
+            AppUser::Admin.pubkey(),
+            AppUser::Alice.pubkey(),
+            AppUser::Bob.pubkey(),
+        ],
+    )?;
+
+    let mint_pubkey = &prepare_interest_bearing_mint(&mut app, target, multisig_pubkey, 500)?;
+
+    app.advance_clock(SECONDS_PER_YEAR);
+
+    app.token_2022_try_update_interest_bearing_mint_rate_multisig(
+        target,
+        mint_pubkey,
+        multisig_pubkey,
+        &[AppUser::Alice, AppUser::Bob],
+        -500,
+    )?;
+
+    // the negative rate cancels out the accrued interest
+    app.advance_clock(SECONDS_PER_YEAR);
+    let ui_amount = assert_accrued_interest(&mut app, target, mint_pubkey, amount)?;
+    assert!((ui_amount - 1_000.).abs() < 1e-6);
+
+    Ok(())
+}
+
+#[test]
+fn accrue_interest_with_clock_warp_spl() -> TestResult<()> {
+    accrue_interest_with_clock_warp(Target::Spl)
+}
+
+#[test]
+fn accrue_interest_with_clock_warp_proxy() -> TestResult<()> {
+    accrue_interest_with_clock_warp(Target::Proxy)
+}
+
+#[test]
+fn accrue_interest_with_clock_warp_and_multisig_spl() -> TestResult<()> {
+    accrue_interest_with_clock_warp_and_multisig(Target::Spl)
+}
+
+#[test]
+fn accrue_interest_with_clock_warp_and_multisig_proxy() -> TestResult<()> {
+    accrue_interest_with_clock_warp_and_multisig(Target::Proxy)
+}
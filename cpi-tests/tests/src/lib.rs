@@ -1,59 +1,145 @@
 #[cfg(test)]
+pub mod associated_token_account;
+#[cfg(test)]
+pub mod builders;
+#[cfg(test)]
+pub mod compute_units;
+#[cfg(test)]
+pub mod confidential_proofs;
+#[cfg(test)]
+pub mod confidential_transfer;
+#[cfg(test)]
+pub mod confidential_transfer_fee;
+#[cfg(test)]
+pub mod corrupted_tlv;
+#[cfg(test)]
 pub mod cpi_guard;
 #[cfg(test)]
 pub mod default_account_state;
 #[cfg(test)]
+pub mod error_context;
+#[cfg(test)]
+pub mod extra_account_meta_list;
+#[cfg(test)]
+pub mod fixtures;
+#[cfg(test)]
 pub mod group_member_pointer;
 #[cfg(test)]
 pub mod group_pointer;
 #[cfg(test)]
+pub mod host_stub;
+#[cfg(test)]
 pub mod initialize_mint;
 #[cfg(test)]
 pub mod initialize_token_account;
 #[cfg(test)]
+pub mod instruction_encoders;
+#[cfg(test)]
 pub mod memo_transfer;
 #[cfg(test)]
 pub mod metadata_pointer;
 #[cfg(test)]
+pub mod mint_close_authority;
+#[cfg(test)]
+pub mod mint_factory;
+#[cfg(all(test, feature = "mollusk"))]
+pub mod mollusk_parity;
+#[cfg(test)]
+pub mod multisig;
+#[cfg(test)]
+pub mod negative_paths;
+#[cfg(test)]
+pub mod non_transferable;
+#[cfg(test)]
+pub mod one_shot_extensions;
+#[cfg(test)]
+pub mod owner;
+#[cfg(test)]
+pub mod pass_through;
+#[cfg(test)]
+pub mod pda_authority;
+#[cfg(test)]
 pub mod permanent_delegate;
 #[cfg(test)]
 pub mod pausable;
 #[cfg(test)]
+pub mod pod;
+#[cfg(test)]
+pub mod proxy_coverage;
+#[cfg(test)]
+pub mod return_data;
+#[cfg(test)]
 pub mod scaled_ui_amount;
 #[cfg(test)]
+pub mod state_layout;
+#[cfg(test)]
+pub mod tlv;
+#[cfg(test)]
+pub mod token_client;
+#[cfg(test)]
 pub mod token_group;
 #[cfg(test)]
 pub mod token_group_member;
 #[cfg(test)]
+pub mod token_instructions;
+#[cfg(test)]
+pub mod token_metadata;
+#[cfg(test)]
+pub mod transfer_fee;
+#[cfg(test)]
 pub mod transfer_hook;
 #[cfg(test)]
+pub mod tx_metadata;
+#[cfg(test)]
 pub mod interest_bearing_mint;
 
 pub mod helpers {
     pub mod extensions {
         pub mod token_2022 {
+            pub mod associated_token_account;
+            pub mod builders;
+            pub mod confidential_transfer;
+            pub mod confidential_transfer_fee;
             pub mod cpi_guard;
             pub mod default_account_state;
             pub mod group_member_pointer;
             pub mod group_pointer;
+            pub mod immutable_owner;
             pub mod initialize_mint;
             pub mod initialize_multisig;
             pub mod initialize_token_account;
             pub mod memo_transfer;
             pub mod metadata_pointer;
+            pub mod mint_close_authority;
+            pub mod mint_factory;
+            pub mod non_transferable;
             pub mod permanent_delegate;
             pub mod pausable;
+            pub mod pda_authority;
+            pub mod return_data;
             pub mod scaled_ui_amount;
             pub mod token_account;
             pub mod token_group;
+            pub mod token_instructions;
+            pub mod token_metadata;
+            pub mod transfer_fee;
             pub mod transfer_hook;
             pub mod interest_bearing_mint;
         }
     }
 
     pub mod suite {
+        pub mod confidential;
         pub mod core;
+        pub mod coverage;
+        pub mod fixtures;
+        #[cfg(feature = "mollusk")]
+        pub mod mollusk;
+        pub mod rng;
         pub mod solana_kite;
+        pub mod token_client;
+        pub mod tx_metadata;
         pub mod types;
+        pub mod withheld_fees;
     }
 }
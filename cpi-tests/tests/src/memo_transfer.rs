@@ -4,10 +4,12 @@ use {
             initialize_multisig::Token2022InitializeMultisigExtension,
             initialize_token_account::Token2022InitializeAccountExtension,
             memo_transfer::{MemoStatus, Token2022MemoTransferExtension},
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
         },
         suite::{
             core::{extension::get_account_data, App},
-            types::{AppUser, PinPubkey, Target, TestResult},
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
         },
     },
     pretty_assertions::assert_eq,
@@ -15,9 +17,6 @@ use {
     spl_token_2022_interface::extension::ExtensionType,
 };
 
-//// chore: maybe add tests to check transfer behavior across different MemoStatuses;
-//// like make sure transfer fails when memo is enabled but the instruction doesn’t include one.
-//// not really necessary though — fine to skip,  since memo extension data validation (data[165..171]) is enough.
 #[test]
 fn enable_memo_transfer_with_eoa() -> TestResult<()> {
     let mut app = App::new(false);
@@ -400,3 +399,101 @@ fn proxy_disable_memo_transfer_with_multisig() -> TestResult<()> {
 
     Ok(())
 }
+
+fn transfer_with_required_memo(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let (_, alice_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint,
+        &[],
+    )?;
+    let alice_account = alice_keypair.pubkey().to_bytes();
+
+    let (_, bob_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        &mint,
+        &[ExtensionType::MemoTransfer],
+    )?;
+    let bob_account = bob_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(Target::Spl, AppUser::Admin, &mint, &alice_account, 1_000)?;
+
+    app.token_2022_try_enable_memo_transfer(
+        target,
+        &bob_account,
+        &AppUser::Bob.pubkey(),
+        AppUser::Bob,
+    )?;
+
+    // incoming transfer without memo
+    let res = app
+        .token_2022_try_transfer_checked_with_memo(
+            target,
+            AppUser::Alice,
+            &alice_account,
+            &mint,
+            &bob_account,
+            400,
+            6,
+            None,
+        )
+        .unwrap_err();
+
+    // TokenError::NoMemo
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x24".to_string(),
+            index: None,
+        }
+    );
+
+    app.token_2022_try_transfer_checked_with_memo(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        400,
+        6,
+        Some("payment for order 42"),
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .amount,
+        600
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &bob_account)?
+            .amount,
+        400
+    );
+
+    // outgoing transfers don't require memo
+    app.token_2022_try_transfer_checked(
+        Target::Spl,
+        AppUser::Bob,
+        &bob_account,
+        &mint,
+        &alice_account,
+        100,
+        6,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn transfer_with_required_memo_spl() -> TestResult<()> {
+    transfer_with_required_memo(Target::Spl)
+}
+
+#[test]
+fn transfer_with_required_memo_proxy() -> TestResult<()> {
+    transfer_with_required_memo(Target::Proxy)
+}
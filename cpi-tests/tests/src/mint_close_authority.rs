@@ -0,0 +1,151 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            mint_close_authority::Token2022MintCloseAuthorityExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+#[test]
+fn initialize_mint_close_authority() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::MintCloseAuthority]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+    let mint_authority = AppUser::Admin;
+    let freeze_authority = Some(AppUser::Admin.pubkey());
+    let close_authority = &AppUser::Alice.pubkey();
+
+    app.token_2022_try_initialize_mint_close_authority(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        Some(close_authority),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &mint_authority.pubkey(),
+        freeze_authority.as_ref(),
+    )?;
+
+    assert_eq!(
+        &app.token_2022_query_mint_close_authority(Target::Spl, mint_pubkey)
+            .map(|x| x.close_authority.0.to_bytes())?,
+        close_authority
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_initialize_mint_close_authority() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::MintCloseAuthority]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+    let mint_authority = AppUser::Admin;
+    let freeze_authority = Some(AppUser::Admin.pubkey());
+    let close_authority = &AppUser::Alice.pubkey();
+
+    app.token_2022_try_initialize_mint_close_authority(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        Some(close_authority),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &mint_authority.pubkey(),
+        freeze_authority.as_ref(),
+    )?;
+
+    // execute 2nd time to run internal checks
+    app.token_2022_try_initialize_mint_close_authority(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        Some(close_authority),
+    )?;
+
+    assert_eq!(
+        &app.token_2022_query_mint_close_authority(Target::Proxy, mint_pubkey)
+            .map(|x| x.close_authority.0.to_bytes())?,
+        close_authority
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_initialize_mint_close_authority_without_authority() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::MintCloseAuthority]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+    let mint_authority = AppUser::Admin;
+
+    app.token_2022_try_initialize_mint_close_authority(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        None,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &mint_authority.pubkey(),
+        None,
+    )?;
+
+    // execute 2nd time to run internal checks
+    app.token_2022_try_initialize_mint_close_authority(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        None,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_mint_close_authority(Target::Proxy, mint_pubkey)?,
+        app.token_2022_query_mint_close_authority(Target::Spl, mint_pubkey)?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            interest_bearing_mint::Token2022InterestBearingMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            mint_close_authority::Token2022MintCloseAuthorityExtension,
+            mint_factory::{ExtensionSpec, Token2022MintFactoryExtension},
+            pausable::Token2022PausableExtension,
+            permanent_delegate::Token2022PermanentDelegateExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+        },
+        suite::{
+            core::{assert_accounts_equal, extension::get_account_data, App},
+            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022_interface::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::{AccountState, Mint},
+    },
+};
+
+const DECIMALS: u8 = 6;
+
+fn create_mint_with_extensions(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+    let extensions = [
+        ExtensionSpec::TransferFeeConfig {
+            transfer_fee_config_authority: Some(admin),
+            withdraw_withheld_authority: Some(admin),
+            transfer_fee_basis_points: 100,
+            maximum_fee: 5_000,
+        },
+        ExtensionSpec::InterestBearingConfig {
+            rate_authority: Some(admin),
+            rate: 500,
+        },
+        ExtensionSpec::MintCloseAuthority {
+            close_authority: Some(admin),
+        },
+        ExtensionSpec::PermanentDelegate {
+            delegate: AppUser::Bob.pubkey(),
+        },
+        ExtensionSpec::MetadataPointer {
+            authority: None,
+            metadata_address: Some(AppUser::Alice.pubkey()),
+        },
+        ExtensionSpec::Pausable { authority: admin },
+    ];
+
+    let mint = &app.token_2022_try_create_mint_with_extensions(
+        target,
+        &extensions,
+        DECIMALS,
+        &admin,
+        Some(&admin),
+    )?;
+
+    // the extensions are laid out in the initialization order
+    let data = get_account_data(&app, mint)?;
+    let state = StateWithExtensions::<Mint>::unpack(&data).unwrap();
+    assert_eq!(
+        state.get_extension_types().unwrap(),
+        extensions
+            .iter()
+            .map(|x| x.extension_type())
+            .collect::<Vec<ExtensionType>>()
+    );
+    assert_eq!(
+        data.len(),
+        ExtensionType::try_calculate_account_len::<Mint>(&state.get_extension_types().unwrap())
+            .unwrap()
+    );
+
+    let mint_state = app.token_2022_query_mint(Target::Spl, mint)?;
+    assert!(mint_state.is_initialized);
+    assert_eq!(mint_state.decimals, DECIMALS);
+
+    // only the first extension is found at the fixed offset, so the rest are read with
+    // the TLV aware SPL parser
+    let admin_key = OptionalNonZeroPubkey::try_from(Some(pin_pubkey_to_addr(&admin))).unwrap();
+    assert_eq!(
+        app.token_2022_query_transfer_fee_config(Target::Proxy, mint)?,
+        app.token_2022_query_transfer_fee_config(Target::Spl, mint)?
+    );
+    assert_eq!(
+        app.token_2022_query_interest_bearing_mint(Target::Spl, mint)?
+            .rate_authority,
+        admin_key
+    );
+    assert_eq!(
+        app.token_2022_query_mint_close_authority(Target::Proxy, mint)?
+            .close_authority,
+        admin_key
+    );
+    assert_eq!(
+        app.token_2022_query_permanent_delegate(Target::Spl, mint)?
+            .delegate,
+        OptionalNonZeroPubkey::try_from(Some(pin_pubkey_to_addr(&AppUser::Bob.pubkey()))).unwrap()
+    );
+    assert_eq!(
+        app.token_2022_query_metadata_pointer(Target::Spl, mint)?
+            .metadata_address,
+        OptionalNonZeroPubkey::try_from(Some(pin_pubkey_to_addr(&AppUser::Alice.pubkey())))
+            .unwrap()
+    );
+    assert_eq!(
+        app.token_2022_query_pausable_config(Target::Spl, mint)?
+            .authority,
+        admin_key
+    );
+
+    Ok(())
+}
+
+#[test]
+fn create_mint_with_extensions_parity() -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+    let extensions = [
+        ExtensionSpec::TransferFeeConfig {
+            transfer_fee_config_authority: Some(admin),
+            withdraw_withheld_authority: None,
+            transfer_fee_basis_points: 250,
+            maximum_fee: 1_000,
+        },
+        ExtensionSpec::DefaultAccountState {
+            state: AccountState::Frozen,
+        },
+        ExtensionSpec::TransferHook {
+            authority: Some(admin),
+            program_id: Some(AppUser::Bob.pubkey()),
+        },
+        ExtensionSpec::GroupMemberPointer {
+            authority: None,
+            member_address: Some(AppUser::Alice.pubkey()),
+        },
+        ExtensionSpec::NonTransferable,
+    ];
+
+    let [spl_mint, proxy_mint] = [Target::Spl, Target::Proxy].map(|target| {
+        app.token_2022_try_create_mint_with_extensions(
+            target,
+            &extensions,
+            DECIMALS,
+            &admin,
+            Some(&admin),
+        )
+    });
+
+    // the proxy flow must produce the same on-chain state
+    assert_accounts_equal(&app, &spl_mint?, &proxy_mint?);
+
+    Ok(())
+}
+
+#[test]
+fn create_mint_with_extensions_snapshot() -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+    let users = app.create_users("fixture user", 2, 1);
+
+    // the fixture is set up once and shared by both targets
+    let mint = &app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[
+            ExtensionSpec::Pausable { authority: admin },
+            ExtensionSpec::TransferFeeConfig {
+                transfer_fee_config_authority: Some(admin),
+                withdraw_withheld_authority: Some(admin),
+                transfer_fee_basis_points: 100,
+                maximum_fee: 5_000,
+            },
+            ExtensionSpec::PermanentDelegate {
+                delegate: users[0].pubkey(),
+            },
+        ],
+        DECIMALS,
+        &admin,
+        None,
+    )?;
+    let snapshot = app.snapshot();
+
+    for target in [Target::Spl, Target::Proxy] {
+        app.restore(&snapshot);
+        assert!(!bool::from(
+            app.token_2022_query_pausable_config(Target::Spl, mint)?
+                .paused
+        ));
+
+        app.token_2022_try_pause(target, AppUser::Admin, mint, &admin)?;
+        assert!(bool::from(
+            app.token_2022_query_pausable_config(Target::Spl, mint)?
+                .paused
+        ));
+
+        // users created after the snapshot are dropped on restore
+        app.create_user("temporary user", 1);
+        assert_eq!(app.user_labels.len(), 3);
+    }
+
+    app.restore(&snapshot);
+    assert_eq!(app.user_labels.len(), 2);
+    assert_eq!(app.get_user_label(users[1]), "fixture user 1");
+    assert!(!bool::from(
+        app.token_2022_query_pausable_config(Target::Spl, mint)?
+            .paused
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn create_mint_with_extensions_spl() -> TestResult<()> {
+    create_mint_with_extensions(Target::Spl)
+}
+
+#[test]
+fn create_mint_with_extensions_proxy() -> TestResult<()> {
+    create_mint_with_extensions(Target::Proxy)
+}
@@ -0,0 +1,114 @@
+use {
+    crate::helpers::suite::{
+        mollusk::MolluskApp,
+        types::{addr_to_sol_pubkey, pin_pubkey_to_addr, AppUser, PinPubkey, Target},
+    },
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    spl_token_2022_interface::{
+        extension::{metadata_pointer, transfer_hook, ExtensionType},
+        instruction,
+        state::Mint,
+    },
+};
+
+/// Convert an instruction built by `spl_token_2022_interface`
+macro_rules! to_legacy {
+    ($ix:expr) => {{
+        let ix = $ix.unwrap();
+
+        Instruction {
+            program_id: addr_to_sol_pubkey(&ix.program_id),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|x| AccountMeta {
+                    pubkey: addr_to_sol_pubkey(&x.pubkey),
+                    is_signer: x.is_signer,
+                    is_writable: x.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        }
+    }};
+}
+
+/// Uninitialized mint with space for `extensions`
+fn mint_account(app: &MolluskApp, extensions: &[ExtensionType]) -> (Pubkey, Account) {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+
+    (Pubkey::new_unique(), app.token_2022_account(vec![0; len]))
+}
+
+#[test]
+fn mollusk_initialize_mint_extensions_parity() {
+    let app = MolluskApp::new();
+
+    let cases: [(ExtensionType, fn(&Pubkey) -> Instruction); 4] = [
+        (ExtensionType::MintCloseAuthority, |mint| {
+            to_legacy!(instruction::initialize_mint_close_authority(
+                &spl_token_2022_interface::ID,
+                &mint.to_bytes().into(),
+                Some(&pin_pubkey_to_addr(&AppUser::Admin.pubkey())),
+            ))
+        }),
+        (ExtensionType::PermanentDelegate, |mint| {
+            to_legacy!(instruction::initialize_permanent_delegate(
+                &spl_token_2022_interface::ID,
+                &mint.to_bytes().into(),
+                &pin_pubkey_to_addr(&AppUser::Bob.pubkey()),
+            ))
+        }),
+        (ExtensionType::NonTransferable, |mint| {
+            to_legacy!(instruction::initialize_non_transferable_mint(
+                &spl_token_2022_interface::ID,
+                &mint.to_bytes().into(),
+            ))
+        }),
+        (ExtensionType::TransferHook, |mint| {
+            to_legacy!(transfer_hook::instruction::initialize(
+                &spl_token_2022_interface::ID,
+                &mint.to_bytes().into(),
+                Some(pin_pubkey_to_addr(&AppUser::Admin.pubkey())),
+                Some(pin_pubkey_to_addr(&AppUser::Bob.pubkey())),
+            ))
+        }),
+    ];
+
+    for (extension, build_ix) in cases {
+        let mint = mint_account(&app, &[extension]);
+        app.assert_parity(&build_ix(&mint.0), &[mint]);
+    }
+
+    let mint = mint_account(&app, &[ExtensionType::MetadataPointer]);
+    let ix = to_legacy!(metadata_pointer::instruction::initialize(
+        &spl_token_2022_interface::ID,
+        &mint.0.to_bytes().into(),
+        Some(pin_pubkey_to_addr(&AppUser::Admin.pubkey())),
+        Some(pin_pubkey_to_addr(&AppUser::Alice.pubkey())),
+    ));
+
+    // the proxy forwards the instruction instead of failing early
+    let result = app.process(Target::Proxy, &ix, &[mint.clone()]);
+    assert!(result.raw_result.is_ok());
+    app.assert_parity(&ix, &[mint]);
+}
+
+#[test]
+fn mollusk_initialize_wrong_extension_parity() {
+    let app = MolluskApp::new();
+
+    // the account has no space for the metadata pointer
+    let mint = mint_account(&app, &[ExtensionType::GroupPointer]);
+    let ix = to_legacy!(metadata_pointer::instruction::initialize(
+        &spl_token_2022_interface::ID,
+        &mint.0.to_bytes().into(),
+        Some(pin_pubkey_to_addr(&AppUser::Admin.pubkey())),
+        None,
+    ));
+
+    let result = app.process(Target::Proxy, &ix, &[mint.clone()]);
+    assert!(result.raw_result.is_err());
+    app.assert_parity(&ix, &[mint]);
+}
@@ -0,0 +1,215 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            default_account_state::Token2022DefaultAccountStateExtension,
+            group_member_pointer::Token2022GroupMemberPointerExtension,
+            group_pointer::Token2022GroupPointerExtension,
+            initialize_multisig::{MultisigConfig, Token2022InitializeMultisigExtension},
+            interest_bearing_mint::Token2022InterestBearingMintExtension,
+            memo_transfer::Token2022MemoTransferExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            mint_factory::{ExtensionSpec, Token2022MintFactoryExtension},
+            pausable::Token2022PausableExtension,
+            scaled_ui_amount::Token2022ScaledUiAmountExtension,
+            token_account::Token2022TokenAccountExtension,
+            transfer_hook::Token2022TransferHookExtension,
+        },
+        suite::{
+            core::App,
+            rng::proptest_config,
+            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    proptest::prelude::*,
+    solana_instruction::error::InstructionError,
+    solana_signer::Signer,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022_interface::{
+        extension::ExtensionType, instruction::MAX_SIGNERS, state::AccountState,
+    },
+};
+
+const DECIMALS: u8 = 6;
+
+/// Run every multisig-capable wrapper instruction with the multisig described by `config`
+/// as the authority
+fn multisig_instructions(target: Target, config: &MultisigConfig) -> TestResult<()> {
+    let mut app = App::new(false);
+    let multisig =
+        &app.token_2022_try_create_and_initialize_multisig(Target::Spl, AppUser::Admin, config)?;
+    let signers = &config.signers;
+    let address = Some(&AppUser::Alice.pubkey());
+
+    let mint = &app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[
+            ExtensionSpec::MetadataPointer {
+                authority: Some(*multisig),
+                metadata_address: None,
+            },
+            ExtensionSpec::GroupPointer {
+                authority: Some(*multisig),
+                group_address: None,
+            },
+            ExtensionSpec::GroupMemberPointer {
+                authority: Some(*multisig),
+                member_address: None,
+            },
+            ExtensionSpec::TransferHook {
+                authority: Some(*multisig),
+                program_id: None,
+            },
+            ExtensionSpec::InterestBearingConfig {
+                rate_authority: Some(*multisig),
+                rate: 0,
+            },
+            ExtensionSpec::Pausable {
+                authority: *multisig,
+            },
+            ExtensionSpec::DefaultAccountState {
+                state: AccountState::Initialized,
+            },
+        ],
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        Some(multisig),
+    )?;
+    // the scaled UI amount can't be combined with the interest bearing config
+    let scaled_mint = &app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[ExtensionSpec::ScaledUiAmount {
+            authority: *multisig,
+            multiplier: 1.0,
+        }],
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+    let (_, token_account) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        multisig,
+        mint,
+        &[ExtensionType::MemoTransfer],
+    )?;
+    let token_account = &token_account.pubkey().to_bytes();
+
+    app.token_2022_try_update_metadata_pointer_multisig(target, mint, multisig, signers, address)?;
+    app.token_2022_try_update_group_pointer_multisig(target, mint, multisig, signers, address)?;
+    app.token_2022_try_update_group_member_pointer_multisig(
+        target, mint, multisig, signers, address,
+    )?;
+    app.token_2022_try_update_transfer_hook_multisig(target, mint, multisig, signers, address)?;
+    app.token_2022_try_update_interest_bearing_mint_rate_multisig(
+        target, mint, multisig, signers, 100,
+    )?;
+    app.token_2022_try_update_multiplier_multisig(target, scaled_mint, multisig, signers, 2.0, 0)?;
+    app.token_2022_try_pause_multisig(target, mint, multisig, signers)?;
+    app.token_2022_try_resume_multisig(target, mint, multisig, signers)?;
+    app.token_2022_try_update_default_account_state_multisig(
+        target,
+        mint,
+        multisig,
+        signers,
+        AccountState::Frozen,
+    )?;
+    app.token_2022_try_enable_memo_transfer_multisig(target, token_account, multisig, signers)?;
+    app.token_2022_try_disable_memo_transfer_multisig(target, token_account, multisig, signers)?;
+
+    assert_eq!(
+        app.token_2022_query_metadata_pointer(Target::Spl, mint)?
+            .metadata_address,
+        OptionalNonZeroPubkey::try_from(Some(pin_pubkey_to_addr(&AppUser::Alice.pubkey())))
+            .unwrap()
+    );
+    assert_eq!(
+        i16::from(
+            app.token_2022_query_interest_bearing_mint(Target::Spl, mint)?
+                .current_rate
+        ),
+        100
+    );
+    assert_eq!(
+        f64::from(
+            app.token_2022_query_scaled_ui_amount(Target::Spl, scaled_mint)?
+                .multiplier
+        ),
+        2.0
+    );
+
+    // a signer short of the threshold
+    if signers.len() > 1 {
+        assert_eq!(
+            app.token_2022_try_pause_multisig(target, mint, multisig, &signers[1..])
+                .unwrap_err(),
+            TestError::from_instruction_error(InstructionError::MissingRequiredSignature)
+        );
+    }
+
+    Ok(())
+}
+
+fn multisig_with_personas(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let users = app.create_users("signer", MAX_SIGNERS, 1);
+    assert_eq!(app.get_user_label(users[3]), "signer 3");
+
+    // 6 of 11 signing in reverse order
+    let config = MultisigConfig {
+        members: users.clone(),
+        signers: users.into_iter().rev().take(6).collect(),
+    };
+    let multisig =
+        &app.token_2022_try_create_and_initialize_multisig(Target::Spl, AppUser::Admin, &config)?;
+
+    let mint = &app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[ExtensionSpec::Pausable {
+            authority: *multisig,
+        }],
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.token_2022_try_pause_multisig(target, mint, multisig, &config.signers)?;
+    assert!(bool::from(
+        app.token_2022_query_pausable_config(Target::Spl, mint)?
+            .paused
+    ));
+
+    assert_eq!(
+        app.token_2022_try_resume_multisig(target, mint, multisig, &config.signers[1..])
+            .unwrap_err(),
+        TestError::from_instruction_error(InstructionError::MissingRequiredSignature)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn multisig_with_personas_spl() -> TestResult<()> {
+    multisig_with_personas(Target::Spl)
+}
+
+#[test]
+fn multisig_with_personas_proxy() -> TestResult<()> {
+    multisig_with_personas(Target::Proxy)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 8,
+        ..proptest_config()
+    })]
+
+    #[test]
+    fn multisig_instructions_spl(config in MultisigConfig::strategy()) {
+        multisig_instructions(Target::Spl, &config).unwrap();
+    }
+
+    #[test]
+    fn multisig_instructions_proxy(config in MultisigConfig::strategy()) {
+        multisig_instructions(Target::Proxy, &config).unwrap();
+    }
+}
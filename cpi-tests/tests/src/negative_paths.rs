@@ -0,0 +1,491 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            default_account_state::Token2022DefaultAccountStateExtension,
+            group_member_pointer::Token2022GroupMemberPointerExtension,
+            group_pointer::Token2022GroupPointerExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            interest_bearing_mint::Token2022InterestBearingMintExtension,
+            memo_transfer::Token2022MemoTransferExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            pausable::Token2022PausableExtension,
+            scaled_ui_amount::Token2022ScaledUiAmountExtension,
+            token_account::Token2022TokenAccountExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+            transfer_hook::Token2022TransferHookExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::pubkey::Pubkey,
+    pretty_assertions::assert_eq,
+    solana_instruction::error::InstructionError,
+    solana_signer::Signer,
+    spl_token_2022_interface::{error::TokenError, extension::ExtensionType, state::AccountState},
+};
+
+const DECIMALS: u8 = 6;
+
+/// Create a mint with the given extension set up by `initialize`. Admin is the mint and
+/// freeze authority
+fn create_mint(
+    app: &mut App,
+    extension_type: ExtensionType,
+    initialize: impl Fn(&mut App, &Pubkey) -> TestResult<TransactionMetadata>,
+) -> TestResult<Pubkey> {
+    let (_, mint_keypair) =
+        app.token_2022_try_create_mint_account(AppUser::Admin, None, Some(&[extension_type]))?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    initialize(app, &mint)?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        Some(&AppUser::Admin.pubkey()),
+    )?;
+
+    Ok(mint)
+}
+
+/// The mint extensions can't be initialized once the mint is
+fn assert_already_in_use(result: TestResult<TransactionMetadata>) {
+    assert_eq!(
+        result.unwrap_err(),
+        TestError::from_token_error(TokenError::AlreadyInUse)
+    );
+}
+
+/// Bob signs as the authority instead of Admin
+fn assert_owner_mismatch(result: TestResult<TransactionMetadata>) {
+    assert_eq!(
+        result.unwrap_err(),
+        TestError::from_token_error(TokenError::OwnerMismatch)
+    );
+}
+
+/// Admin is passed as the authority, but Bob signs the transaction
+fn assert_missing_signature(result: TestResult<TransactionMetadata>) {
+    assert_eq!(
+        result.unwrap_err(),
+        TestError::from_instruction_error(InstructionError::MissingRequiredSignature)
+    );
+}
+
+fn metadata_pointer(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_metadata_pointer(
+            target,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+            Some(mint),
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::MetadataPointer, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    assert_owner_mismatch(app.token_2022_try_update_metadata_pointer(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Bob.pubkey(),
+        None,
+    ));
+    assert_missing_signature(app.token_2022_try_update_metadata_pointer(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Admin.pubkey(),
+        None,
+    ));
+
+    Ok(())
+}
+
+fn group_pointer(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_group_pointer(
+            target,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+            Some(mint),
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::GroupPointer, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    assert_owner_mismatch(app.token_2022_try_update_group_pointer(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Bob.pubkey(),
+        None,
+    ));
+    assert_missing_signature(app.token_2022_try_update_group_pointer(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Admin.pubkey(),
+        None,
+    ));
+
+    Ok(())
+}
+
+fn group_member_pointer(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_group_member_pointer(
+            target,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+            Some(mint),
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::GroupMemberPointer, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    assert_owner_mismatch(app.token_2022_try_update_group_member_pointer(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Bob.pubkey(),
+        None,
+    ));
+    assert_missing_signature(app.token_2022_try_update_group_member_pointer(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Admin.pubkey(),
+        None,
+    ));
+
+    Ok(())
+}
+
+fn transfer_hook(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_transfer_hook(
+            target,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+            None,
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::TransferHook, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    assert_owner_mismatch(app.token_2022_try_update_transfer_hook(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Bob.pubkey(),
+        None,
+    ));
+    assert_missing_signature(app.token_2022_try_update_transfer_hook(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Admin.pubkey(),
+        None,
+    ));
+
+    Ok(())
+}
+
+fn interest_bearing_mint(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_interest_bearing_mint(
+            target,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+            500,
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::InterestBearingConfig, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    assert_owner_mismatch(app.token_2022_try_update_interest_bearing_mint_rate(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Bob.pubkey(),
+        1_000,
+    ));
+    assert_missing_signature(app.token_2022_try_update_interest_bearing_mint_rate(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Admin.pubkey(),
+        1_000,
+    ));
+
+    Ok(())
+}
+
+fn scaled_ui_amount(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_scaled_ui_amount(
+            target,
+            AppUser::Admin,
+            mint,
+            &AppUser::Admin.pubkey(),
+            1.0,
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::ScaledUiAmount, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    assert_owner_mismatch(app.token_2022_try_update_multiplier(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Bob.pubkey(),
+        2.0,
+        0,
+    ));
+    assert_missing_signature(app.token_2022_try_update_multiplier(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Admin.pubkey(),
+        2.0,
+        0,
+    ));
+
+    Ok(())
+}
+
+fn pausable(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_pausable(
+            target,
+            AppUser::Admin,
+            mint,
+            &AppUser::Admin.pubkey(),
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::Pausable, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    assert_owner_mismatch(app.token_2022_try_pause(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Bob.pubkey(),
+    ));
+    assert_missing_signature(app.token_2022_try_pause(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Admin.pubkey(),
+    ));
+
+    Ok(())
+}
+
+fn default_account_state(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_default_account_state(
+            target,
+            AppUser::Admin,
+            mint,
+            AccountState::Frozen,
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::DefaultAccountState, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    // the freeze authority controls the default state
+    assert_owner_mismatch(app.token_2022_try_update_default_account_state(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Bob.pubkey(),
+        AccountState::Initialized,
+    ));
+    assert_missing_signature(app.token_2022_try_update_default_account_state(
+        target,
+        AppUser::Bob,
+        mint,
+        &AppUser::Admin.pubkey(),
+        AccountState::Initialized,
+    ));
+
+    Ok(())
+}
+
+fn transfer_fee(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let initialize = |app: &mut App, mint: &Pubkey| {
+        app.token_2022_try_initialize_transfer_fee_config(
+            target,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+            Some(&AppUser::Admin.pubkey()),
+            100,
+            5_000,
+        )
+    };
+    let mint = &create_mint(&mut app, ExtensionType::TransferFeeConfig, initialize)?;
+
+    assert_already_in_use(initialize(&mut app, mint));
+    // the sender is used as the authority
+    assert_owner_mismatch(app.token_2022_try_set_transfer_fee(
+        target,
+        AppUser::Bob,
+        mint,
+        200,
+        10_000,
+    ));
+
+    Ok(())
+}
+
+fn memo_transfer(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let (_, token_account) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Admin.pubkey(),
+        &mint,
+        &[ExtensionType::MemoTransfer],
+    )?;
+    let token_account = &token_account.pubkey().to_bytes();
+
+    // the account owner is the authority
+    assert_owner_mismatch(app.token_2022_try_enable_memo_transfer(
+        target,
+        token_account,
+        &AppUser::Bob.pubkey(),
+        AppUser::Bob,
+    ));
+    assert_missing_signature(app.token_2022_try_enable_memo_transfer(
+        target,
+        token_account,
+        &AppUser::Admin.pubkey(),
+        AppUser::Bob,
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn metadata_pointer_negative_paths_spl() -> TestResult<()> {
+    metadata_pointer(Target::Spl)
+}
+
+#[test]
+fn metadata_pointer_negative_paths_proxy() -> TestResult<()> {
+    metadata_pointer(Target::Proxy)
+}
+
+#[test]
+fn group_pointer_negative_paths_spl() -> TestResult<()> {
+    group_pointer(Target::Spl)
+}
+
+#[test]
+fn group_pointer_negative_paths_proxy() -> TestResult<()> {
+    group_pointer(Target::Proxy)
+}
+
+#[test]
+fn group_member_pointer_negative_paths_spl() -> TestResult<()> {
+    group_member_pointer(Target::Spl)
+}
+
+#[test]
+fn group_member_pointer_negative_paths_proxy() -> TestResult<()> {
+    group_member_pointer(Target::Proxy)
+}
+
+#[test]
+fn transfer_hook_negative_paths_spl() -> TestResult<()> {
+    transfer_hook(Target::Spl)
+}
+
+#[test]
+fn transfer_hook_negative_paths_proxy() -> TestResult<()> {
+    transfer_hook(Target::Proxy)
+}
+
+#[test]
+fn interest_bearing_mint_negative_paths_spl() -> TestResult<()> {
+    interest_bearing_mint(Target::Spl)
+}
+
+#[test]
+fn interest_bearing_mint_negative_paths_proxy() -> TestResult<()> {
+    interest_bearing_mint(Target::Proxy)
+}
+
+#[test]
+fn scaled_ui_amount_negative_paths_spl() -> TestResult<()> {
+    scaled_ui_amount(Target::Spl)
+}
+
+#[test]
+fn scaled_ui_amount_negative_paths_proxy() -> TestResult<()> {
+    scaled_ui_amount(Target::Proxy)
+}
+
+#[test]
+fn pausable_negative_paths_spl() -> TestResult<()> {
+    pausable(Target::Spl)
+}
+
+#[test]
+fn pausable_negative_paths_proxy() -> TestResult<()> {
+    pausable(Target::Proxy)
+}
+
+#[test]
+fn default_account_state_negative_paths_spl() -> TestResult<()> {
+    default_account_state(Target::Spl)
+}
+
+#[test]
+fn default_account_state_negative_paths_proxy() -> TestResult<()> {
+    default_account_state(Target::Proxy)
+}
+
+#[test]
+fn transfer_fee_negative_paths_spl() -> TestResult<()> {
+    transfer_fee(Target::Spl)
+}
+
+#[test]
+fn transfer_fee_negative_paths_proxy() -> TestResult<()> {
+    transfer_fee(Target::Proxy)
+}
+
+#[test]
+fn memo_transfer_negative_paths_spl() -> TestResult<()> {
+    memo_transfer(Target::Spl)
+}
+
+#[test]
+fn memo_transfer_negative_paths_proxy() -> TestResult<()> {
+    memo_transfer(Target::Proxy)
+}
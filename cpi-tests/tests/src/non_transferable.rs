@@ -0,0 +1,185 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            non_transferable::Token2022NonTransferableExtension,
+            token_account::Token2022TokenAccountExtension,
+        },
+        suite::{
+            core::{extension::get_account_data, App},
+            types::{AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pinocchio_token_2022::{error::TokenError, guards::assert_transferable_from_bytes},
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+#[test]
+fn initialize_non_transferable_mint() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::NonTransferable]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+    let mint_authority = AppUser::Admin;
+    let freeze_authority = Some(AppUser::Admin.pubkey());
+
+    app.token_2022_try_initialize_non_transferable_mint(Target::Spl, AppUser::Admin, mint_pubkey)?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &mint_authority.pubkey(),
+        freeze_authority.as_ref(),
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_non_transferable(Target::Spl, mint_pubkey)?,
+        true
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_initialize_non_transferable_mint() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::NonTransferable]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+    let mint_authority = AppUser::Admin;
+    let freeze_authority = Some(AppUser::Admin.pubkey());
+
+    app.token_2022_try_initialize_non_transferable_mint(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &mint_authority.pubkey(),
+        freeze_authority.as_ref(),
+    )?;
+
+    // execute 2nd time to run internal checks
+    app.token_2022_try_initialize_non_transferable_mint(
+        Target::Proxy,
+        AppUser::Admin,
+        mint_pubkey,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_non_transferable(Target::Proxy, mint_pubkey)?,
+        true
+    );
+
+    // token accounts of a non-transferable mint get `NonTransferableAccount` on initialization
+    let (_, token_account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[
+            ExtensionType::ImmutableOwner,
+            ExtensionType::NonTransferableAccount,
+        ],
+    )?;
+    let token_account_pubkey = &token_account_keypair.pubkey().to_bytes();
+
+    assert_eq!(
+        app.token_2022_query_non_transferable_account(Target::Spl, token_account_pubkey)?,
+        true
+    );
+    assert_eq!(
+        app.token_2022_query_non_transferable_account(Target::Proxy, token_account_pubkey)?,
+        true
+    );
+
+    Ok(())
+}
+
+#[test]
+fn assert_transferable_guard() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::NonTransferable]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_non_transferable_mint(Target::Spl, AppUser::Admin, mint_pubkey)?;
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+    assert_eq!(
+        assert_transferable_from_bytes(&get_account_data(&app, mint_pubkey)?, None),
+        Err(TokenError::NonTransferable.into())
+    );
+
+    // mints without the extension pass
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+    let mint_data = get_account_data(&app, mint_pubkey)?;
+
+    let (_, token_account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[],
+    )?;
+    let token_account_data = get_account_data(&app, &token_account_keypair.pubkey().to_bytes())?;
+    assert_eq!(
+        assert_transferable_from_bytes(&mint_data, Some(&token_account_data)),
+        Ok(())
+    );
+
+    // a token account in the middle of a transfer: account type followed by the
+    // `TransferHookAccount` entry with the `transferring` flag set
+    let mut token_account_data = token_account_data[..165].to_vec();
+    token_account_data.extend_from_slice(&[2, 15, 0, 1, 0, 1]);
+    assert_eq!(
+        assert_transferable_from_bytes(&mint_data, Some(&token_account_data)),
+        Err(TokenError::InvalidState.into())
+    );
+
+    token_account_data[170] = 0;
+    assert_eq!(
+        assert_transferable_from_bytes(&mint_data, Some(&token_account_data)),
+        Ok(())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,313 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            immutable_owner::Token2022ImmutableOwnerExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            mint_close_authority::Token2022MintCloseAuthorityExtension,
+            non_transferable::Token2022NonTransferableExtension,
+            permanent_delegate::Token2022PermanentDelegateExtension,
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+        },
+        suite::{
+            core::App,
+            types::{pin_to_sol_pubkey, AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::{extension::ExtensionType, instruction::AuthorityType},
+};
+
+const DECIMALS: u8 = 6;
+
+/// Create a mint with `extension` initialized by `initialize`, controlled by Admin
+fn prepare_mint(
+    app: &mut App,
+    extension: ExtensionType,
+    initialize: impl FnOnce(&mut App, &[u8; 32]) -> TestResult<()>,
+) -> TestResult<[u8; 32]> {
+    let (_, mint_keypair) =
+        app.token_2022_try_create_mint_account(AppUser::Admin, None, Some(&[extension]))?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    initialize(app, &mint)?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    Ok(mint)
+}
+
+fn close_mint_with_close_authority(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = prepare_mint(&mut app, ExtensionType::MintCloseAuthority, |app, mint| {
+        app.token_2022_try_initialize_mint_close_authority(
+            target,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+        )?;
+        Ok(())
+    })?;
+
+    // only the close authority can close the mint
+    let res = app
+        .token_2022_try_close_account(target, AppUser::Alice, &mint, &AppUser::Alice.pubkey())
+        .unwrap_err();
+
+    // TokenError::OwnerMismatch
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x4".to_string(),
+            index: None,
+        }
+    );
+
+    app.token_2022_try_close_account(target, AppUser::Admin, &mint, &AppUser::Admin.pubkey())?;
+
+    assert!(app
+        .litesvm
+        .get_account(&pin_to_sol_pubkey(&mint))
+        .is_none_or(|x| x.lamports == 0));
+
+    Ok(())
+}
+
+fn transfer_non_transferable_mint(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = prepare_mint(&mut app, ExtensionType::NonTransferable, |app, mint| {
+        app.token_2022_try_initialize_non_transferable_mint(target, AppUser::Admin, mint)?;
+        Ok(())
+    })?;
+
+    // the accounts of a non-transferable mint must have immutable owners
+    let mut token_accounts = vec![];
+    for owner in [AppUser::Alice, AppUser::Bob] {
+        let (_, keypair) = app.token_2022_try_create_and_init_token_account(
+            AppUser::Admin,
+            &owner.pubkey(),
+            &mint,
+            &[
+                ExtensionType::ImmutableOwner,
+                ExtensionType::NonTransferableAccount,
+            ],
+        )?;
+        token_accounts.push(keypair.pubkey().to_bytes());
+    }
+    let [alice_account, bob_account] = token_accounts[..] else {
+        unreachable!()
+    };
+
+    assert!(app.token_2022_query_non_transferable(Target::Spl, &mint)?);
+    assert!(app.token_2022_query_non_transferable(Target::Proxy, &mint)?);
+    assert!(app.token_2022_query_non_transferable_account(Target::Spl, &alice_account)?);
+    assert!(app.token_2022_query_non_transferable_account(Target::Proxy, &alice_account)?);
+
+    app.token_2022_try_mint_to(target, AppUser::Admin, &mint, &alice_account, 1_000)?;
+
+    let res = app
+        .token_2022_try_transfer_checked(
+            target,
+            AppUser::Alice,
+            &alice_account,
+            &mint,
+            &bob_account,
+            400,
+            DECIMALS,
+        )
+        .unwrap_err();
+
+    // TokenError::NonTransferable
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x25".to_string(),
+            index: None,
+        }
+    );
+
+    // burning is still allowed
+    app.token_2022_try_burn(target, AppUser::Alice, &alice_account, &mint, 100)?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .amount,
+        900
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &bob_account)?
+            .amount,
+        0
+    );
+
+    Ok(())
+}
+
+fn transfer_and_burn_with_permanent_delegate(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = prepare_mint(&mut app, ExtensionType::PermanentDelegate, |app, mint| {
+        app.token_2022_try_initialize_permanent_delegate(
+            target,
+            AppUser::Admin,
+            mint,
+            &AppUser::Bob.pubkey(),
+        )?;
+        Ok(())
+    })?;
+
+    let mut token_accounts = vec![];
+    for owner in [AppUser::Alice, AppUser::Bob] {
+        let (_, keypair) = app.token_2022_try_create_and_init_token_account(
+            AppUser::Admin,
+            &owner.pubkey(),
+            &mint,
+            &[],
+        )?;
+        token_accounts.push(keypair.pubkey().to_bytes());
+    }
+    let [alice_account, bob_account] = token_accounts[..] else {
+        unreachable!()
+    };
+
+    app.token_2022_try_mint_to(target, AppUser::Admin, &mint, &alice_account, 1_000)?;
+
+    // the delegate moves and burns tokens without the owner approval
+    app.token_2022_try_transfer_checked(
+        target,
+        AppUser::Bob,
+        &alice_account,
+        &mint,
+        &bob_account,
+        400,
+        DECIMALS,
+    )?;
+    app.token_2022_try_burn(target, AppUser::Bob, &alice_account, &mint, 100)?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .amount,
+        500
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &bob_account)?
+            .amount,
+        400
+    );
+
+    // anyone else still needs to be the owner
+    let res = app
+        .token_2022_try_burn(target, AppUser::Admin, &alice_account, &mint, 100)
+        .unwrap_err();
+
+    // TokenError::OwnerMismatch
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x4".to_string(),
+            index: None,
+        }
+    );
+
+    Ok(())
+}
+
+fn change_immutable_owner(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let (_, token_account_keypair) = app.token_2022_try_create_token_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ImmutableOwner]),
+    )?;
+    let token_account = token_account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_immutable_owner(target, AppUser::Admin, &token_account)?;
+    app.token_2022_try_initialize_token_account(
+        target,
+        AppUser::Admin,
+        &token_account,
+        &mint,
+        &AppUser::Alice.pubkey(),
+    )?;
+
+    assert!(app.token_2022_query_immutable_owner(Target::Spl, &token_account)?);
+    assert!(app.token_2022_query_immutable_owner(Target::Proxy, &token_account)?);
+
+    let res = app
+        .token_2022_try_set_authority(
+            target,
+            AppUser::Alice,
+            &token_account,
+            AuthorityType::AccountOwner,
+            Some(&AppUser::Bob.pubkey()),
+        )
+        .unwrap_err();
+
+    // TokenError::ImmutableOwner
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x22".to_string(),
+            index: None,
+        }
+    );
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &token_account)?
+            .owner
+            .to_bytes(),
+        AppUser::Alice.pubkey()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn close_mint_with_close_authority_spl() -> TestResult<()> {
+    close_mint_with_close_authority(Target::Spl)
+}
+
+#[test]
+fn close_mint_with_close_authority_proxy() -> TestResult<()> {
+    close_mint_with_close_authority(Target::Proxy)
+}
+
+#[test]
+fn transfer_non_transferable_mint_spl() -> TestResult<()> {
+    transfer_non_transferable_mint(Target::Spl)
+}
+
+#[test]
+fn transfer_non_transferable_mint_proxy() -> TestResult<()> {
+    transfer_non_transferable_mint(Target::Proxy)
+}
+
+#[test]
+fn transfer_and_burn_with_permanent_delegate_spl() -> TestResult<()> {
+    transfer_and_burn_with_permanent_delegate(Target::Spl)
+}
+
+#[test]
+fn transfer_and_burn_with_permanent_delegate_proxy() -> TestResult<()> {
+    transfer_and_burn_with_permanent_delegate(Target::Proxy)
+}
+
+#[test]
+fn change_immutable_owner_spl() -> TestResult<()> {
+    change_immutable_owner(Target::Spl)
+}
+
+#[test]
+fn change_immutable_owner_proxy() -> TestResult<()> {
+    change_immutable_owner(Target::Proxy)
+}
@@ -0,0 +1,63 @@
+use {
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+    pinocchio_token_2022::{
+        extension::{
+            consts::ExtensionType, mint_close_authority::MintCloseAuthority,
+            non_transferable::NonTransferable,
+        },
+        host_stub::HostAccount,
+        state::Mint,
+        test_utils::TlvBuilder,
+        ID,
+    },
+};
+
+const MINT: Pubkey = [1; 32];
+/// Address of a fork of Token-2022
+const FORK_ID: Pubkey = [7; 32];
+
+#[test]
+fn from_account_info_with_owner_accepts_forks() {
+    let data = TlvBuilder::mint()
+        .extension(ExtensionType::MintCloseAuthority, &[2; 32])
+        .extension(ExtensionType::NonTransferable, &[])
+        .build();
+    let mut mint = HostAccount::new(MINT, FORK_ID, &data);
+    let mint = mint.info();
+
+    assert_eq!(
+        Mint::from_account_info(&mint).err(),
+        Some(ProgramError::InvalidAccountOwner)
+    );
+    assert!(Mint::from_account_info_with_owner(&mint, &FORK_ID)
+        .unwrap()
+        .is_initialized());
+
+    // a set of program ids
+    assert_eq!(
+        MintCloseAuthority::from_account_info_with_owner(&mint, &[ID, FORK_ID])
+            .unwrap()
+            .close_authority(),
+        Some(&[2; 32])
+    );
+    assert!(NonTransferable::from_account_info_with_owner(&mint, &[ID, FORK_ID][..]).is_ok());
+
+    assert_eq!(
+        Mint::from_account_info_with_owner(&mint, &ID).err(),
+        Some(ProgramError::InvalidAccountOwner)
+    );
+    assert_eq!(
+        MintCloseAuthority::from_account_info_with_owner(&mint, &[ID]).err(),
+        Some(ProgramError::InvalidAccountOwner)
+    );
+}
+
+#[test]
+fn from_account_info_with_owner_validates_data() {
+    let mut mint = HostAccount::new(MINT, FORK_ID, &[0; 10]);
+
+    assert_eq!(
+        Mint::from_account_info_with_owner(&mint.info(), &FORK_ID).err(),
+        Some(ProgramError::InvalidAccountData)
+    );
+}
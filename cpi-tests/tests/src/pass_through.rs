@@ -0,0 +1,61 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            immutable_owner::Token2022ImmutableOwnerExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_program_pack::IsInitialized,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+/// `InitializeImmutableOwner` has no dedicated proxy handler and is forwarded as is
+fn initialize_immutable_owner(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let (_, token_account_keypair) = app.token_2022_try_create_token_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ImmutableOwner]),
+    )?;
+    let token_account = token_account_keypair.pubkey().to_bytes();
+    let alice = AppUser::Alice.pubkey();
+
+    app.token_2022_try_initialize_immutable_owner(target, AppUser::Admin, &token_account)?;
+
+    app.token_2022_try_initialize_token_account(
+        Target::Spl,
+        AppUser::Admin,
+        &token_account,
+        &mint,
+        &alice,
+    )?;
+
+    assert!(app
+        .token_2022_query_token_account(target, &token_account)?
+        .is_initialized());
+    assert_eq!(
+        app.token_2022_query_immutable_owner(target, &token_account)?,
+        true
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pass_through_initialize_immutable_owner() -> TestResult<()> {
+    initialize_immutable_owner(Target::Spl)
+}
+
+#[test]
+fn proxy_pass_through_initialize_immutable_owner() -> TestResult<()> {
+    initialize_immutable_owner(Target::Proxy)
+}
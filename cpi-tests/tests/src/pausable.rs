@@ -4,12 +4,17 @@ use {
             pausable::Token2022PausableExtension,
             initialize_mint::Token2022InitializeMintExtension,
             initialize_multisig::Token2022InitializeMultisigExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
         },
         suite::{
-            core::App,
+            core::{extension::get_account_data, App},
             types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestResult},
         },
     },
+    pinocchio::program_error::ProgramError,
+    pinocchio_token_2022::{error::TokenError, guards::assert_not_paused_from_bytes},
     pretty_assertions::assert_eq,
     solana_signer::Signer,
     spl_token_2022_interface::extension::{pausable::PausableConfig, ExtensionType},
@@ -386,3 +391,191 @@ fn pause_and_resume_multisig_proxy() -> TestResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn assert_not_paused_guard() -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::Pausable]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let pause_authority = AppUser::Admin;
+
+    app.token_2022_try_initialize_pausable(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        &pause_authority.pubkey(),
+    )?;
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+    assert_eq!(
+        assert_not_paused_from_bytes(&get_account_data(&app, mint_pubkey)?),
+        Ok(())
+    );
+
+    app.token_2022_try_pause(
+        Target::Spl,
+        pause_authority,
+        mint_pubkey,
+        &pause_authority.pubkey(),
+    )?;
+
+    // the guard fails with the error code of Token-2022
+    let error = assert_not_paused_from_bytes(&get_account_data(&app, mint_pubkey)?).unwrap_err();
+    assert_eq!(error, TokenError::MintPaused.into());
+    assert_eq!(
+        error,
+        ProgramError::Custom(spl_token_2022_interface::error::TokenError::MintPaused as u32)
+    );
+
+    // mints without the extension pass
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+    assert_eq!(
+        assert_not_paused_from_bytes(&get_account_data(&app, mint_pubkey)?),
+        Ok(())
+    );
+
+    Ok(())
+}
+
+/// Query the pausable config through both the SPL and the pinocchio parsers
+fn assert_paused(app: &App, mint: &[u8; 32], paused: bool) -> TestResult<()> {
+    let spl_config = app.token_2022_query_pausable_config(Target::Spl, mint)?;
+    let pinocchio_config = app.token_2022_query_pausable_config(Target::Proxy, mint)?;
+
+    assert_eq!(spl_config.paused, paused.into());
+    assert_eq!(pinocchio_config, spl_config);
+
+    Ok(())
+}
+
+fn pause_transfer_and_resume(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::Pausable]),
+    )?;
+
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let decimals: u8 = 6;
+    let pause_authority = AppUser::Admin;
+
+    app.token_2022_try_initialize_pausable(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        &pause_authority.pubkey(),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        decimals,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    // token accounts of a pausable mint require the PausableAccount extension
+    let (_, alice_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::PausableAccount],
+    )?;
+    let alice_account = &alice_keypair.pubkey().to_bytes();
+
+    let (_, bob_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::PausableAccount],
+    )?;
+    let bob_account = &bob_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(target, AppUser::Admin, mint_pubkey, alice_account, 1_000)?;
+    assert_paused(&app, mint_pubkey, false)?;
+
+    // transfers are rejected while the mint is paused
+    app.token_2022_try_pause(
+        target,
+        pause_authority,
+        mint_pubkey,
+        &pause_authority.pubkey(),
+    )?;
+    assert_paused(&app, mint_pubkey, true)?;
+
+    assert!(app
+        .token_2022_try_transfer_checked(
+            target,
+            AppUser::Alice,
+            alice_account,
+            mint_pubkey,
+            bob_account,
+            400,
+            decimals,
+        )
+        .is_err());
+
+    // and allowed again after resuming
+    app.token_2022_try_resume(
+        target,
+        pause_authority,
+        mint_pubkey,
+        &pause_authority.pubkey(),
+    )?;
+    assert_paused(&app, mint_pubkey, false)?;
+
+    app.token_2022_try_transfer_checked(
+        target,
+        AppUser::Alice,
+        alice_account,
+        mint_pubkey,
+        bob_account,
+        400,
+        decimals,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, alice_account)?
+            .amount,
+        600
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, bob_account)?
+            .amount,
+        400
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pause_transfer_and_resume_spl() -> TestResult<()> {
+    pause_transfer_and_resume(Target::Spl)
+}
+
+#[test]
+fn pause_transfer_and_resume_proxy() -> TestResult<()> {
+    pause_transfer_and_resume(Target::Proxy)
+}
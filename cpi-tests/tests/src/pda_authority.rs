@@ -0,0 +1,298 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            pausable::Token2022PausableExtension,
+            pda_authority::{Token2022PdaAuthorityExtension, Token2022ProxyAuthorityExtension},
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, PinPubkey, ProxyAuthority, Target, TestResult},
+        },
+    },
+    pretty_assertions::{assert_eq, assert_ne},
+    solana_signer::Signer,
+    spl_token_2022_interface::{extension::ExtensionType, state::AccountState},
+};
+
+fn mint_and_freeze_authority(authority: ProxyAuthority) -> TestResult<()> {
+    let mut app = App::new(false);
+    let authority_pubkey = app.token_2022_query_authority_pubkey(authority);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &authority_pubkey,
+        Some(&authority_pubkey),
+    )?;
+
+    let (_, token_account_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[],
+    )?;
+    let token_account = &token_account_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to_with_authority(
+        AppUser::Admin,
+        authority,
+        mint_pubkey,
+        token_account,
+        1_000,
+    )?;
+    app.token_2022_try_mint_to_checked_with_authority(
+        AppUser::Admin,
+        authority,
+        mint_pubkey,
+        token_account,
+        500,
+        6,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Proxy, token_account)?
+            .amount,
+        1_500
+    );
+
+    app.token_2022_try_freeze_account_with_authority(
+        AppUser::Admin,
+        authority,
+        token_account,
+        mint_pubkey,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Proxy, token_account)?
+            .state,
+        AccountState::Frozen
+    );
+
+    app.token_2022_try_thaw_account_with_authority(
+        AppUser::Admin,
+        authority,
+        token_account,
+        mint_pubkey,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Proxy, token_account)?
+            .state,
+        AccountState::Initialized
+    );
+
+    Ok(())
+}
+
+fn pause_authority(authority: ProxyAuthority) -> TestResult<()> {
+    let mut app = App::new(false);
+    let authority_pubkey = app.token_2022_query_authority_pubkey(authority);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::Pausable]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_pausable(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        &authority_pubkey,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.token_2022_try_pause_with_authority(AppUser::Admin, authority, mint_pubkey)?;
+
+    assert_eq!(
+        app.token_2022_query_pausable_config(Target::Proxy, mint_pubkey)
+            .map(|x| bool::from(x.paused))?,
+        true
+    );
+
+    app.token_2022_try_resume_with_authority(AppUser::Admin, authority, mint_pubkey)?;
+
+    assert_eq!(
+        app.token_2022_query_pausable_config(Target::Proxy, mint_pubkey)
+            .map(|x| bool::from(x.paused))?,
+        false
+    );
+
+    Ok(())
+}
+
+fn transfer_fee_config_authority(authority: ProxyAuthority) -> TestResult<()> {
+    let mut app = App::new(false);
+    let authority_pubkey = app.token_2022_query_authority_pubkey(authority);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::TransferFeeConfig]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_transfer_fee_config(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        Some(&authority_pubkey),
+        Some(&authority_pubkey),
+        100,
+        1_000,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let (_, alice_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::TransferFeeAmount],
+    )?;
+    let alice_account = &alice_keypair.pubkey().to_bytes();
+
+    let (_, bob_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        mint_pubkey,
+        &[ExtensionType::TransferFeeAmount],
+    )?;
+    let bob_account = &bob_keypair.pubkey().to_bytes();
+
+    // withhold a 1% fee and harvest it to the mint
+    app.token_2022_try_mint_to(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        alice_account,
+        10_000,
+    )?;
+    app.token_2022_try_transfer_checked_with_fee(
+        Target::Spl,
+        AppUser::Alice,
+        alice_account,
+        mint_pubkey,
+        bob_account,
+        1_000,
+        6,
+        10,
+    )?;
+    app.token_2022_try_harvest_withheld_tokens_to_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        &[*bob_account],
+    )?;
+
+    app.token_2022_try_withdraw_withheld_tokens_from_mint_with_authority(
+        AppUser::Admin,
+        authority,
+        mint_pubkey,
+        alice_account,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Proxy, alice_account)?
+            .amount,
+        9_010
+    );
+
+    app.token_2022_try_set_transfer_fee_with_authority(
+        AppUser::Admin,
+        authority,
+        mint_pubkey,
+        200,
+        2_000,
+    )?;
+
+    let config = app.token_2022_query_transfer_fee_config(Target::Proxy, mint_pubkey)?;
+
+    assert_eq!(u64::from(config.withheld_amount), 0);
+    assert_eq!(
+        u16::from(config.newer_transfer_fee.transfer_fee_basis_points),
+        200
+    );
+    assert_eq!(u64::from(config.newer_transfer_fee.maximum_fee), 2_000);
+
+    Ok(())
+}
+
+#[test]
+fn proxy_user_mint_and_freeze_authority() -> TestResult<()> {
+    mint_and_freeze_authority(ProxyAuthority::User(AppUser::Admin))
+}
+
+#[test]
+fn proxy_pda_mint_and_freeze_authority() -> TestResult<()> {
+    mint_and_freeze_authority(ProxyAuthority::Pda)
+}
+
+#[test]
+fn proxy_user_pause_authority() -> TestResult<()> {
+    pause_authority(ProxyAuthority::User(AppUser::Admin))
+}
+
+#[test]
+fn proxy_pda_pause_authority() -> TestResult<()> {
+    pause_authority(ProxyAuthority::Pda)
+}
+
+#[test]
+fn proxy_user_transfer_fee_config_authority() -> TestResult<()> {
+    transfer_fee_config_authority(ProxyAuthority::User(AppUser::Admin))
+}
+
+#[test]
+fn proxy_pda_transfer_fee_config_authority() -> TestResult<()> {
+    transfer_fee_config_authority(ProxyAuthority::Pda)
+}
+
+#[test]
+fn proxy_program_id_is_isolated_per_app() {
+    let [app, other_app] = [App::new(false), App::new(false)];
+
+    // each app deploys the proxy under its own random id, with its own PDA authority
+    assert_ne!(
+        app.program_id.token_2022_proxy,
+        other_app.program_id.token_2022_proxy
+    );
+    assert_ne!(
+        app.program_id.token_2022_proxy,
+        solana_pubkey::Pubkey::from(token_2022_proxy::ID)
+    );
+    assert_ne!(
+        app.token_2022_query_proxy_authority(),
+        other_app.token_2022_query_proxy_authority()
+    );
+    assert!(app
+        .litesvm
+        .get_account(&other_app.program_id.token_2022_proxy)
+        .is_none());
+}
@@ -0,0 +1,188 @@
+use {
+    crate::helpers::suite::{
+        confidential::ConfidentialKeys,
+        rng::proptest_config,
+        types::{to_optional_non_zero_pubkey, AppUser},
+    },
+    core::mem::size_of,
+    pinocchio::program_error::ProgramError,
+    pinocchio_token_2022::{
+        extension::confidential::{
+            EncryptedBalance, OptionalNonZeroElGamalPubkey, PodAeCiphertext, PodElGamalCiphertext,
+            PodElGamalPubkey,
+        },
+        pod::{
+            pod_from_bytes, pod_slice_from_bytes, read_pod_slice, read_pod_str,
+            OptionalNonZeroPubkey, PodOption,
+        },
+    },
+    pretty_assertions::assert_eq,
+    proptest::{collection::vec, prelude::*},
+    solana_zk_sdk::encryption::pod::elgamal::PodElGamalPubkey as ZkPodElGamalPubkey,
+};
+
+/// Length-prefixed (borsh) encoding of the given bytes
+fn encode_prefixed(value: &[u8], count: usize) -> Vec<u8> {
+    [&(count as u32).to_le_bytes()[..], value].concat()
+}
+
+#[test]
+fn optional_non_zero_pubkey() {
+    for address in [None, Some([7; 32])] {
+        let spl = to_optional_non_zero_pubkey(address.as_ref());
+
+        // same layout as the SPL type
+        let parsed: &OptionalNonZeroPubkey = pod_from_bytes(bytemuck::bytes_of(&spl)).unwrap();
+        assert_eq!(parsed.get(), address.as_ref());
+        assert_eq!(parsed.is_some(), address.is_some());
+        assert_eq!(Option::from(*parsed), address);
+        assert_eq!(OptionalNonZeroPubkey::try_from(address).unwrap(), *parsed);
+    }
+
+    assert_eq!(OptionalNonZeroPubkey::default(), PodOption::none());
+    assert_eq!(OptionalNonZeroPubkey::some([0; 32]), None);
+    assert_eq!(
+        OptionalNonZeroPubkey::try_from(Some([0; 32])).unwrap_err(),
+        ProgramError::InvalidArgument
+    );
+
+    // exactly 32 bytes are expected
+    assert!(pod_from_bytes::<OptionalNonZeroPubkey>(&[1; 31]).is_err());
+    assert!(pod_from_bytes::<OptionalNonZeroPubkey>(&[1; 33]).is_err());
+}
+
+#[test]
+fn pod_slice() {
+    let bytes = [1, 2, 3, 4, 5, 6];
+    assert_eq!(
+        pod_slice_from_bytes::<[u8; 2]>(&bytes).unwrap(),
+        &[[1, 2], [3, 4], [5, 6]]
+    );
+    assert_eq!(
+        pod_slice_from_bytes::<[u8; 4]>(&bytes).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    assert!(pod_slice_from_bytes::<[u8; 0]>(&bytes).is_err());
+
+    // the rest follows the elements
+    let data = encode_prefixed(&bytes, 2);
+    assert_eq!(
+        read_pod_slice::<[u8; 2]>(&data).unwrap(),
+        (&[[1, 2], [3, 4]][..], &[5, 6][..])
+    );
+
+    // the count exceeds the data
+    let data = encode_prefixed(&bytes, 4);
+    assert_eq!(
+        read_pod_slice::<[u8; 2]>(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    let data = encode_prefixed(&bytes, u32::MAX as usize);
+    assert!(read_pod_slice::<[u8; 32]>(&data).is_err());
+
+    // truncated length
+    assert!(read_pod_slice::<u8>(&[0; 3]).is_err());
+}
+
+#[test]
+fn pod_str() {
+    let data = [encode_prefixed(b"name", 4), encode_prefixed(b"", 0)].concat();
+
+    let (name, rest) = read_pod_str(&data).unwrap();
+    assert_eq!(name, "name");
+    assert_eq!(read_pod_str(rest).unwrap(), ("", &[][..]));
+
+    // invalid UTF-8
+    let data = encode_prefixed(&[0xff, 0xfe], 2);
+    assert_eq!(
+        read_pod_str(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+}
+
+#[test]
+fn confidential_pod_types() {
+    assert_eq!(size_of::<PodElGamalPubkey>(), PodElGamalPubkey::LEN);
+    assert_eq!(size_of::<PodElGamalCiphertext>(), 64);
+    assert_eq!(size_of::<PodAeCiphertext>(), 36);
+
+    let ciphertext = PodElGamalCiphertext::new([3; 64]);
+    assert_eq!(ciphertext, [3; 64]);
+    assert_eq!(ciphertext.as_ref(), &[3; 64]);
+    assert_eq!(<[u8; 64]>::from(ciphertext), [3; 64]);
+    assert_eq!(
+        PodElGamalCiphertext::from_bytes(&[3; 64]).unwrap(),
+        &ciphertext
+    );
+    assert!(PodAeCiphertext::from_bytes(&[0; 35]).is_err());
+
+    // an empty balance is the zeroed ciphertext
+    assert_eq!(EncryptedBalance::default(), EncryptedBalance::zeroed());
+    assert!(EncryptedBalance::default().is_zeroed());
+    assert!(!ciphertext.is_zeroed());
+
+    let pubkey = PodElGamalPubkey::from([7; 32]);
+    assert_eq!(
+        OptionalNonZeroElGamalPubkey::try_from(Some(pubkey))
+            .unwrap()
+            .get(),
+        Some(&pubkey)
+    );
+    assert!(OptionalNonZeroElGamalPubkey::some(PodElGamalPubkey::zeroed()).is_none());
+}
+
+#[test]
+fn elgamal_pubkey_base64() {
+    let keys = ConfidentialKeys::new(AppUser::Alice, &[1; 32]).unwrap();
+    let zk_pubkey = ZkPodElGamalPubkey::from(*keys.pubkey());
+    let encoded = zk_pubkey.to_string();
+
+    // the encoding of the zk SDK is parsed to the same bytes and written back
+    let pubkey = PodElGamalPubkey::from_base64(&encoded).unwrap();
+    assert_eq!(pubkey.as_ref(), bytemuck::bytes_of(&zk_pubkey));
+    assert_eq!(pubkey.to_base64(), encoded);
+    assert_eq!(encoded.len(), PodElGamalPubkey::BASE64_LEN);
+    assert_eq!(encoded.parse::<PodElGamalPubkey>(), Ok(pubkey));
+
+    // compared with the encoding directly
+    assert_eq!(pubkey, encoded.as_str());
+    assert_ne!(PodElGamalPubkey::zeroed(), encoded.as_str());
+    assert_eq!(
+        PodElGamalPubkey::zeroed(),
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+    );
+
+    // not an encoding of 32 bytes, or not the canonical one
+    for invalid in [
+        "",
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAB=",
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA*=",
+    ] {
+        assert_eq!(
+            PodElGamalPubkey::from_base64(invalid),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
+
+proptest! {
+    #![proptest_config(proptest_config())]
+
+    #[test]
+    fn pod_str_round_trip(values in vec(".*", 0..8)) {
+        let data: Vec<u8> = values
+            .iter()
+            .flat_map(|x| encode_prefixed(x.as_bytes(), x.len()))
+            .collect();
+
+        let mut rest = data.as_slice();
+        for value in &values {
+            let (parsed, next) = read_pod_str(rest).unwrap();
+            prop_assert_eq!(parsed, value.as_str());
+            rest = next;
+        }
+        prop_assert!(rest.is_empty());
+    }
+}
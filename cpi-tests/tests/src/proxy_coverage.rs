@@ -0,0 +1,87 @@
+use {
+    crate::helpers::suite::{
+        coverage::{get_proxy_coverage_report, token_2022_instructions, CoveredInstruction},
+        types::TestResult,
+    },
+    pinocchio_token_2022::{
+        extension::transfer_fee::InstructionDiscriminatorTransferFee,
+        instruction::TokenInstructionDiscriminator,
+    },
+    pretty_assertions::assert_eq,
+};
+
+#[test]
+fn token_2022_instructions_are_enumerated() {
+    let instructions = token_2022_instructions();
+
+    // every top-level discriminator is listed
+    for discriminator in 0..=TokenInstructionDiscriminator::PausableExtension as u8 {
+        assert!(instructions
+            .iter()
+            .any(|(x, _)| x.discriminator == discriminator));
+    }
+
+    // extension instructions are listed one by one
+    let transfer_fee: Vec<_> = instructions
+        .iter()
+        .filter(|(x, _)| {
+            x.discriminator == TokenInstructionDiscriminator::TransferFeeExtension as u8
+        })
+        .collect();
+    assert_eq!(
+        transfer_fee.len(),
+        InstructionDiscriminatorTransferFee::SetTransferFee as usize + 1
+    );
+    assert_eq!(
+        transfer_fee[1],
+        &(
+            CoveredInstruction {
+                discriminator: TokenInstructionDiscriminator::TransferFeeExtension as u8,
+                extension_discriminator: Some(1),
+            },
+            "TransferFeeExtension::TransferCheckedWithFee".to_string()
+        )
+    );
+
+    // instruction data is parsed the way the proxy does
+    assert_eq!(
+        CoveredInstruction::from_instruction_data(&[
+            TokenInstructionDiscriminator::TransferFeeExtension as u8,
+            InstructionDiscriminatorTransferFee::HarvestWithheldTokensToMint as u8,
+        ]),
+        Some(CoveredInstruction {
+            discriminator: TokenInstructionDiscriminator::TransferFeeExtension as u8,
+            extension_discriminator: Some(4),
+        })
+    );
+    assert_eq!(
+        CoveredInstruction::from_instruction_data(&[token_2022_proxy::TRANSFER_CHECKED_WITH_HOOK]),
+        None
+    );
+}
+
+/// Report which Token-2022 instructions the suite exercised via `Target::Proxy`. Run it
+/// after the whole suite, which records them, see `test.sh`:
+/// `cargo test -- --ignored --show-output proxy_coverage_report`
+#[test]
+#[ignore]
+fn proxy_coverage_report() -> TestResult<()> {
+    let report = get_proxy_coverage_report()?;
+    println!("{}", report);
+
+    for row in report.stale() {
+        println!(
+            "warning: {} is covered now, remove it from KNOWN_UNCOVERED",
+            row.name
+        );
+    }
+
+    let missing: Vec<_> = report.missing().into_iter().map(|x| &x.name).collect();
+    assert!(
+        missing.is_empty(),
+        "Instructions without proxy coverage: {:?}",
+        missing
+    );
+
+    Ok(())
+}
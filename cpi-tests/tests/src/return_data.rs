@@ -0,0 +1,296 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            return_data::Token2022ReturnDataExtension,
+            token_metadata::Token2022TokenMetadataExtension,
+        },
+        suite::{
+            core::App,
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
+        },
+    },
+    litesvm::types::TransactionMetadata,
+    pinocchio::program_error::ProgramError,
+    pinocchio_token_2022::ui_amount::{format_ui_amount, parse_ui_amount, MAX_UI_AMOUNT_LEN},
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::{extension::ExtensionType, state::Account},
+};
+
+/// The proxied call must return the same data as the direct one, set by the proxy itself
+fn assert_return_data(app: &App, spl: &TransactionMetadata, proxy: &TransactionMetadata) {
+    assert_eq!(
+        spl.return_data.program_id,
+        app.program_id.token_2022_program
+    );
+    assert_eq!(
+        proxy.return_data.program_id,
+        app.program_id.token_2022_proxy
+    );
+    assert_eq!(proxy.return_data.data, spl.return_data.data);
+}
+
+#[test]
+fn proxy_get_account_data_size() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+    let extension_types = [ExtensionType::ImmutableOwner, ExtensionType::MemoTransfer];
+
+    let spl = app.token_2022_try_get_account_data_size(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        &extension_types,
+    )?;
+    let proxy = app.token_2022_try_get_account_data_size(
+        Target::Proxy,
+        AppUser::Admin,
+        &mint,
+        &extension_types,
+    )?;
+
+    assert_return_data(&app, &spl, &proxy);
+    assert_eq!(
+        proxy.return_data.data,
+        (ExtensionType::try_calculate_account_len::<Account>(&extension_types).unwrap() as u64)
+            .to_le_bytes()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_amount_to_ui_amount() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let spl = app.token_2022_try_amount_to_ui_amount(Target::Spl, AppUser::Admin, &mint, 1_500)?;
+    let proxy =
+        app.token_2022_try_amount_to_ui_amount(Target::Proxy, AppUser::Admin, &mint, 1_500)?;
+
+    assert_return_data(&app, &spl, &proxy);
+
+    Ok(())
+}
+
+#[test]
+fn format_ui_amount_matches_amount_to_ui_amount() -> TestResult<()> {
+    let mut app = App::new(false);
+    let mut buf = [0; MAX_UI_AMOUNT_LEN];
+
+    // 6 decimals
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    for amount in [0, 1, 10, 1_500, 1_000_000, 1_500_000, 123_456_789, u64::MAX] {
+        let spl =
+            app.token_2022_try_amount_to_ui_amount(Target::Spl, AppUser::Admin, &mint, amount)?;
+
+        assert_eq!(
+            format_ui_amount(amount, 6, &mut buf).as_bytes(),
+            spl.return_data.data
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn proxy_ui_amount_to_amount() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    let spl = app.token_2022_try_ui_amount_to_amount(Target::Spl, AppUser::Admin, &mint, "1.5")?;
+    let proxy =
+        app.token_2022_try_ui_amount_to_amount(Target::Proxy, AppUser::Admin, &mint, "1.5")?;
+
+    assert_return_data(&app, &spl, &proxy);
+
+    Ok(())
+}
+
+#[test]
+fn parse_ui_amount_matches_ui_amount_to_amount() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    // 6 decimals
+    let (_, mint) = app.token2022_try_create_and_try_initialize_mint(Target::Spl)?;
+
+    for ui_amount in [
+        "0",
+        "1",
+        "1.5",
+        "+1.5",
+        ".5",
+        "1.",
+        "0.000001",
+        "1.500000000",
+        "18446744073709.551615",
+    ] {
+        let spl =
+            app.token_2022_try_ui_amount_to_amount(Target::Spl, AppUser::Admin, &mint, ui_amount)?;
+
+        assert_eq!(
+            parse_ui_amount(ui_amount, 6)
+                .map_err(TestError::from_raw_error)?
+                .to_le_bytes()
+                .as_slice(),
+            spl.return_data.data
+        );
+    }
+
+    // invalid, too precise or overflowing amounts
+    for ui_amount in [
+        "",
+        ".",
+        "-1",
+        "1e6",
+        "1.2.3",
+        "0.0000001",
+        "18446744073709.551616",
+    ] {
+        assert!(app
+            .token_2022_try_ui_amount_to_amount(Target::Spl, AppUser::Admin, &mint, ui_amount)
+            .is_err());
+        assert_eq!(
+            parse_ui_amount(ui_amount, 6),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    Ok(())
+}
+
+/// Create a mint without extensions with the given decimals
+fn create_mint(app: &mut App, decimals: u8) -> TestResult<PinPubkey> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        decimals,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    Ok(mint)
+}
+
+#[test]
+fn ui_amount_conversions_match_spl_for_any_decimals() -> TestResult<()> {
+    let mut app = App::new(false);
+    let mut buf = [0; MAX_UI_AMOUNT_LEN];
+
+    for decimals in [0, 9, u8::MAX] {
+        let mint = create_mint(&mut app, decimals)?;
+
+        for amount in [0, 1, 10, 1_000_000_000, 1_500_000_000, u64::MAX] {
+            let spl =
+                app.token_2022_try_amount_to_ui_amount(Target::Spl, AppUser::Admin, &mint, amount)?;
+            let ui_amount = format_ui_amount(amount, decimals, &mut buf);
+            assert_eq!(ui_amount.as_bytes(), spl.return_data.data);
+
+            // the UI amount is parsed back to the same amount
+            let spl = app.token_2022_try_ui_amount_to_amount(
+                Target::Spl,
+                AppUser::Admin,
+                &mint,
+                ui_amount,
+            )?;
+            assert_eq!(amount.to_le_bytes().as_slice(), spl.return_data.data);
+            assert_eq!(parse_ui_amount(ui_amount, decimals), Ok(amount));
+        }
+
+        // trailing zeros of the decimals are accepted
+        let spl =
+            app.token_2022_try_ui_amount_to_amount(Target::Spl, AppUser::Admin, &mint, "1.000")?;
+        assert_eq!(
+            parse_ui_amount("1.000", decimals)
+                .map_err(TestError::from_raw_error)?
+                .to_le_bytes()
+                .as_slice(),
+            spl.return_data.data
+        );
+
+        // empty, or with more fractional digits than the decimals
+        let too_precise = format!("0.{}", "1".repeat(decimals as usize + 1));
+        for ui_amount in ["", ".", too_precise.as_str()] {
+            assert!(app
+                .token_2022_try_ui_amount_to_amount(Target::Spl, AppUser::Admin, &mint, ui_amount)
+                .is_err());
+            assert_eq!(
+                parse_ui_amount(ui_amount, decimals),
+                Err(ProgramError::InvalidArgument)
+            );
+        }
+    }
+
+    // the trailing zeros of the decimals and an unneeded decimal point are trimmed,
+    // the zeros of the integer part are kept
+    assert_eq!(format_ui_amount(10, 0, &mut buf), "10");
+    assert_eq!(format_ui_amount(1_000_000_000, 9, &mut buf), "1");
+    assert_eq!(format_ui_amount(1_500_000_000, 9, &mut buf), "1.5");
+    assert_eq!(format_ui_amount(10, 9, &mut buf), "0.00000001");
+    assert_eq!(
+        format_ui_amount(u64::MAX, u8::MAX, &mut buf),
+        format!("0.{}{}", "0".repeat(255 - 20), u64::MAX)
+    );
+    assert_eq!(
+        parse_ui_amount(&format!("0.{}1", "0".repeat(254)), u8::MAX),
+        Ok(1)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_emit_token_metadata() -> TestResult<()> {
+    let mut app = App::new(false);
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::MetadataPointer]),
+    )?;
+    let mint = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_metadata_pointer(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        None,
+        Some(mint),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.token_2022_try_initialize_token_metadata(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        "Proxy Token",
+        "PRX",
+        "https://example.com/proxy.json",
+    )?;
+
+    let spl = app.token_2022_try_emit_token_metadata(Target::Spl, AppUser::Admin, mint)?;
+    let proxy = app.token_2022_try_emit_token_metadata(Target::Proxy, AppUser::Admin, mint)?;
+
+    assert_return_data(&app, &spl, &proxy);
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use {
+    crate::helpers::suite::rng::new_keypair, pretty_assertions::assert_eq, solana_pubkey::Pubkey,
+    solana_signer::Signer, std::thread,
+};
+
+/// Draw 2 keypairs on a new thread named `name`, like a test named `name`
+fn draw_keypairs(name: &str) -> [Pubkey; 2] {
+    thread::Builder::new()
+        .name(name.to_string())
+        .spawn(|| [new_keypair().pubkey(), new_keypair().pubkey()])
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+#[test]
+fn keypairs_are_reproducible() {
+    let keypairs = draw_keypairs("rng::first");
+
+    // the stream of a test doesn't repeat
+    assert_ne!(keypairs[0], keypairs[1]);
+    // and is the same for the same test in the same run
+    assert_eq!(draw_keypairs("rng::first"), keypairs);
+    // but not for another test
+    assert_ne!(draw_keypairs("rng::second"), keypairs);
+}
@@ -4,13 +4,16 @@ use {
             scaled_ui_amount::Token2022ScaledUiAmountExtension,
             initialize_mint::Token2022InitializeMintExtension,
             initialize_multisig::Token2022InitializeMultisigExtension,
+            return_data::Token2022ReturnDataExtension,
         },
         suite::{
             core::App,
-            types::{AppUser, PinPubkey, Target, TestResult},
+            types::{AppUser, PinPubkey, Target, TestError, TestResult},
         },
     },
     pretty_assertions::assert_eq,
+    solana_instruction::error::InstructionError,
+    solana_program::clock::Clock,
     solana_signer::Signer,
     spl_token_2022_interface::extension::{scaled_ui_amount::ScaledUiAmountConfig, ExtensionType},
 };
@@ -272,3 +275,223 @@ fn initialize_and_update_scaled_ui_amount_multisig() -> TestResult<()> {
 
     Ok(())
 }
+
+/// `AmountToUiAmount` of Token-2022 must match the SPL config math at the current clock
+fn assert_ui_amount(
+    app: &mut App,
+    target: Target,
+    mint_pubkey: &[u8; 32],
+    amount: u64,
+) -> TestResult<f64> {
+    let unix_timestamp = app.litesvm.get_sysvar::<Clock>().unix_timestamp;
+    let expected_ui_amount = app
+        .token_2022_query_scaled_ui_amount(Target::Spl, mint_pubkey)?
+        .amount_to_ui_amount(amount, 6, unix_timestamp)
+        .unwrap();
+
+    let return_data = app
+        .token_2022_try_amount_to_ui_amount(target, AppUser::Admin, mint_pubkey, amount)?
+        .return_data
+        .data;
+    let ui_amount = String::from_utf8(return_data).unwrap();
+    assert_eq!(ui_amount, expected_ui_amount);
+
+    Ok(ui_amount.parse().unwrap())
+}
+
+fn update_multiplier_with_effective_timestamp(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ScaledUiAmount]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+    let amount = 1_500_000;
+
+    app.token_2022_try_initialize_scaled_ui_amount(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        1.0,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.set_unix_timestamp(1_000);
+    app.token_2022_try_update_multiplier(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        2.0,
+        2_000,
+    )?;
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 1.5);
+
+    // the old multiplier is kept until the new one is effective
+    let config = app.token_2022_query_scaled_ui_amount(target, mint_pubkey)?;
+    assert_eq!(f64::from(config.multiplier), 1.0);
+    assert_eq!(f64::from(config.new_multiplier), 2.0);
+    assert_eq!(i64::from(config.new_multiplier_effective_timestamp), 2_000);
+
+    // the new multiplier isn't effective yet
+    app.advance_clock(999);
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 1.5);
+
+    app.advance_clock(1);
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 3.0);
+
+    // an effective timestamp in the past replaces both multipliers at once
+    app.token_2022_try_update_multiplier(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        &AppUser::Admin.pubkey(),
+        4.0,
+        1_500,
+    )?;
+    let config = app.token_2022_query_scaled_ui_amount(target, mint_pubkey)?;
+    assert_eq!(f64::from(config.multiplier), 4.0);
+    assert_eq!(f64::from(config.new_multiplier), 4.0);
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 6.0);
+
+    Ok(())
+}
+
+fn update_multiplier_with_effective_timestamp_and_multisig(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let amount = 1_500_000;
+
+    let (_, multisig_kp) = app.token_2022_try_create_multisig(AppUser::Admin, None)?;
+    let multisig_pubkey = &multisig_kp.pubkey().to_bytes();
+    app.token_2022_try_initialize_multisig(
+        Target::Spl,
+        AppUser::Admin,
+        multisig_pubkey,
+        2,
+        &[
+            AppUser::Admin.pubkey(),
+            AppUser::Alice.pubkey(),
+            AppUser::Bob.pubkey(),
+        ],
+    )?;
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::ScaledUiAmount]),
+    )?;
+    let mint_pubkey = &mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_scaled_ui_amount(
+        target,
+        AppUser::Admin,
+        mint_pubkey,
+        multisig_pubkey,
+        1.0,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.set_unix_timestamp(1_000);
+
+    // a signer short of the threshold
+    assert_eq!(
+        app.token_2022_try_update_multiplier_multisig(
+            target,
+            mint_pubkey,
+            multisig_pubkey,
+            &[AppUser::Alice],
+            2.0,
+            2_000,
+        )
+        .unwrap_err(),
+        TestError::from_instruction_error(InstructionError::MissingRequiredSignature)
+    );
+
+    app.token_2022_try_update_multiplier_multisig(
+        target,
+        mint_pubkey,
+        multisig_pubkey,
+        &[AppUser::Alice, AppUser::Bob],
+        2.0,
+        2_000,
+    )?;
+    let config = app.token_2022_query_scaled_ui_amount(target, mint_pubkey)?;
+    assert_eq!(f64::from(config.multiplier), 1.0);
+    assert_eq!(f64::from(config.new_multiplier), 2.0);
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 1.5);
+
+    app.advance_clock(1_000);
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 3.0);
+
+    // Token-2022 doesn't move an effective new multiplier to `multiplier`, so
+    // scheduling another one brings the old multiplier back until then
+    app.token_2022_try_update_multiplier_multisig(
+        target,
+        mint_pubkey,
+        multisig_pubkey,
+        &[AppUser::Admin, AppUser::Bob],
+        0.5,
+        3_000,
+    )?;
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 1.5);
+
+    // and a pending multiplier can be replaced before it's effective
+    app.token_2022_try_update_multiplier_multisig(
+        target,
+        mint_pubkey,
+        multisig_pubkey,
+        &[AppUser::Bob, AppUser::Alice],
+        4.0,
+        4_000,
+    )?;
+    let config = app.token_2022_query_scaled_ui_amount(target, mint_pubkey)?;
+    assert_eq!(f64::from(config.multiplier), 1.0);
+    assert_eq!(f64::from(config.new_multiplier), 4.0);
+    assert_eq!(i64::from(config.new_multiplier_effective_timestamp), 4_000);
+
+    app.advance_clock(1_000);
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 1.5);
+
+    app.advance_clock(1_000);
+    assert_eq!(assert_ui_amount(&mut app, target, mint_pubkey, amount)?, 6.0);
+
+    Ok(())
+}
+
+#[test]
+fn update_multiplier_with_effective_timestamp_spl() -> TestResult<()> {
+    update_multiplier_with_effective_timestamp(Target::Spl)
+}
+
+#[test]
+fn update_multiplier_with_effective_timestamp_proxy() -> TestResult<()> {
+    update_multiplier_with_effective_timestamp(Target::Proxy)
+}
+
+#[test]
+fn update_multiplier_with_effective_timestamp_and_multisig_spl() -> TestResult<()> {
+    update_multiplier_with_effective_timestamp_and_multisig(Target::Spl)
+}
+
+#[test]
+fn update_multiplier_with_effective_timestamp_and_multisig_proxy() -> TestResult<()> {
+    update_multiplier_with_effective_timestamp_and_multisig(Target::Proxy)
+}
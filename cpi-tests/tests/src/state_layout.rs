@@ -0,0 +1,777 @@
+use {
+    crate::helpers::suite::types::pin_pubkey_to_addr,
+    core::mem::{offset_of, size_of},
+    pinocchio::pubkey::Pubkey,
+    pinocchio_token_2022::extension::{
+        self as pin, consts::ExtensionType as PinExtensionType, tlv::Extension as _,
+    },
+    pretty_assertions::assert_eq,
+    solana_program_pack::Pack,
+    spl_pod::{
+        optional_keys::OptionalNonZeroPubkey,
+        primitives::{PodBool, PodI16, PodI64, PodU16, PodU64},
+    },
+    spl_token_2022_interface::{
+        extension::{
+            self as spl, BaseState, BaseStateWithExtensionsMut, ExtensionType,
+            StateWithExtensionsMut,
+        },
+        state::{Account, AccountState, Mint},
+    },
+    spl_token_group_interface::state::{TokenGroup, TokenGroupMember},
+};
+
+// every field gets a distinct value, so a misplaced field can't go unnoticed
+const AUTHORITY: Pubkey = [1; 32];
+const ADDRESS: Pubkey = [2; 32];
+const MINT: Pubkey = [3; 32];
+
+fn some_key(pubkey: &Pubkey) -> OptionalNonZeroPubkey {
+    OptionalNonZeroPubkey(pin_pubkey_to_addr(pubkey))
+}
+
+/// Account data with the given extensions, written by the SPL crate in the given order
+fn with_extensions<S: BaseState + Pack>(
+    base: S,
+    extension_types: &[ExtensionType],
+    init: impl FnOnce(&mut StateWithExtensionsMut<S>),
+) -> Vec<u8> {
+    let len = ExtensionType::try_calculate_account_len::<S>(extension_types).unwrap();
+    let mut data = vec![0; len];
+
+    let mut state = StateWithExtensionsMut::<S>::unpack_uninitialized(&mut data).unwrap();
+    init(&mut state);
+    state.base = base;
+    state.pack_base();
+    state.init_account_type().unwrap();
+
+    data
+}
+
+fn mint_with(
+    extension_types: &[ExtensionType],
+    init: impl FnOnce(&mut StateWithExtensionsMut<Mint>),
+) -> Vec<u8> {
+    let mint = Mint {
+        is_initialized: true,
+        ..Default::default()
+    };
+
+    with_extensions(mint, extension_types, init)
+}
+
+fn account_with(
+    extension_types: &[ExtensionType],
+    init: impl FnOnce(&mut StateWithExtensionsMut<Account>),
+) -> Vec<u8> {
+    let account = Account {
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    with_extensions(account, extension_types, init)
+}
+
+#[test]
+fn extension_types_match_spl() {
+    for (pin_type, spl_type) in [
+        (
+            PinExtensionType::Uninitialized,
+            ExtensionType::Uninitialized,
+        ),
+        (
+            PinExtensionType::TransferFeeConfig,
+            ExtensionType::TransferFeeConfig,
+        ),
+        (
+            PinExtensionType::TransferFeeAmount,
+            ExtensionType::TransferFeeAmount,
+        ),
+        (
+            PinExtensionType::MintCloseAuthority,
+            ExtensionType::MintCloseAuthority,
+        ),
+        (
+            PinExtensionType::ConfidentialTransferMint,
+            ExtensionType::ConfidentialTransferMint,
+        ),
+        (
+            PinExtensionType::ConfidentialTransferAccount,
+            ExtensionType::ConfidentialTransferAccount,
+        ),
+        (
+            PinExtensionType::DefaultAccountState,
+            ExtensionType::DefaultAccountState,
+        ),
+        (
+            PinExtensionType::ImmutableOwner,
+            ExtensionType::ImmutableOwner,
+        ),
+        (PinExtensionType::MemoTransfer, ExtensionType::MemoTransfer),
+        (
+            PinExtensionType::NonTransferable,
+            ExtensionType::NonTransferable,
+        ),
+        (
+            PinExtensionType::InterestBearingConfig,
+            ExtensionType::InterestBearingConfig,
+        ),
+        (PinExtensionType::CpiGuard, ExtensionType::CpiGuard),
+        (
+            PinExtensionType::PermanentDelegate,
+            ExtensionType::PermanentDelegate,
+        ),
+        (
+            PinExtensionType::NonTransferableAccount,
+            ExtensionType::NonTransferableAccount,
+        ),
+        (PinExtensionType::TransferHook, ExtensionType::TransferHook),
+        (
+            PinExtensionType::TransferHookAccount,
+            ExtensionType::TransferHookAccount,
+        ),
+        (
+            PinExtensionType::ConfidentialTransferFeeConfig,
+            ExtensionType::ConfidentialTransferFeeConfig,
+        ),
+        (
+            PinExtensionType::ConfidentialTransferFeeAmount,
+            ExtensionType::ConfidentialTransferFeeAmount,
+        ),
+        (
+            PinExtensionType::MetadataPointer,
+            ExtensionType::MetadataPointer,
+        ),
+        (
+            PinExtensionType::TokenMetadata,
+            ExtensionType::TokenMetadata,
+        ),
+        (PinExtensionType::GroupPointer, ExtensionType::GroupPointer),
+        (PinExtensionType::TokenGroup, ExtensionType::TokenGroup),
+        (
+            PinExtensionType::GroupMemberPointer,
+            ExtensionType::GroupMemberPointer,
+        ),
+        (
+            PinExtensionType::TokenGroupMember,
+            ExtensionType::TokenGroupMember,
+        ),
+        (
+            PinExtensionType::ConfidentialMintBurn,
+            ExtensionType::ConfidentialMintBurn,
+        ),
+        (
+            PinExtensionType::ScaledUiAmount,
+            ExtensionType::ScaledUiAmount,
+        ),
+        (PinExtensionType::Pausable, ExtensionType::Pausable),
+        (
+            PinExtensionType::PausableAccount,
+            ExtensionType::PausableAccount,
+        ),
+    ] {
+        assert_eq!(pin_type as u16, spl_type as u16, "{pin_type:?}");
+    }
+
+    assert_eq!(
+        pin::mint_close_authority::MintCloseAuthority::TYPE as u16,
+        ExtensionType::MintCloseAuthority as u16
+    );
+    assert_eq!(
+        pin::non_transferable::NonTransferable::TYPE as u16,
+        ExtensionType::NonTransferable as u16
+    );
+    assert_eq!(
+        pin::non_transferable::NonTransferableAccount::TYPE as u16,
+        ExtensionType::NonTransferableAccount as u16
+    );
+    assert_eq!(
+        pin::confidential_transfer::ConfidentialTransferMint::TYPE as u16,
+        ExtensionType::ConfidentialTransferMint as u16
+    );
+    assert_eq!(
+        pin::confidential_transfer::ConfidentialTransferAccount::TYPE as u16,
+        ExtensionType::ConfidentialTransferAccount as u16
+    );
+    assert_eq!(
+        pin::confidential_transfer_fee::ConfidentialTransferFeeConfig::TYPE as u16,
+        ExtensionType::ConfidentialTransferFeeConfig as u16
+    );
+    assert_eq!(
+        pin::confidential_transfer_fee::ConfidentialTransferFeeAmount::TYPE as u16,
+        ExtensionType::ConfidentialTransferFeeAmount as u16
+    );
+}
+
+#[test]
+fn base_len_matches_spl() {
+    for (base_len, spl_len) in [
+        (
+            pin::transfer_fee::TransferFeeConfig::BASE_LEN,
+            size_of::<spl::transfer_fee::TransferFeeConfig>(),
+        ),
+        (
+            pin::transfer_fee::TransferFeeAmount::BASE_LEN,
+            size_of::<spl::transfer_fee::TransferFeeAmount>(),
+        ),
+        (
+            pin::mint_close_authority::MintCloseAuthority::BASE_LEN,
+            size_of::<spl::mint_close_authority::MintCloseAuthority>(),
+        ),
+        (
+            pin::default_account_state::state::DefaultAccountStateConfig::BASE_LEN,
+            size_of::<spl::default_account_state::DefaultAccountState>(),
+        ),
+        (
+            pin::interest_bearing_mint::InterestBearingConfig::BASE_LEN,
+            size_of::<spl::interest_bearing_mint::InterestBearingConfig>(),
+        ),
+        (
+            pin::cpi_guard::CpiGuard::BASE_LEN,
+            size_of::<spl::cpi_guard::CpiGuard>(),
+        ),
+        (
+            pin::permanent_delegate::PermanentDelegate::BASE_LEN,
+            size_of::<spl::permanent_delegate::PermanentDelegate>(),
+        ),
+        (
+            pin::transfer_hook::TransferHook::BASE_LEN,
+            size_of::<spl::transfer_hook::TransferHook>(),
+        ),
+        (
+            pin::confidential_transfer::ConfidentialTransferMint::BASE_LEN,
+            size_of::<spl::confidential_transfer::ConfidentialTransferMint>(),
+        ),
+        (
+            pin::confidential_transfer::ConfidentialTransferAccount::BASE_LEN,
+            size_of::<spl::confidential_transfer::ConfidentialTransferAccount>(),
+        ),
+        (
+            pin::confidential_transfer_fee::ConfidentialTransferFeeConfig::BASE_LEN,
+            size_of::<spl::confidential_transfer_fee::ConfidentialTransferFeeConfig>(),
+        ),
+        (
+            pin::confidential_transfer_fee::ConfidentialTransferFeeAmount::BASE_LEN,
+            size_of::<spl::confidential_transfer_fee::ConfidentialTransferFeeAmount>(),
+        ),
+        (
+            pin::metadata_pointer::MetadataPointer::BASE_LEN,
+            size_of::<spl::metadata_pointer::MetadataPointer>(),
+        ),
+        (
+            pin::group_pointer::GroupPointer::BASE_LEN,
+            size_of::<spl::group_pointer::GroupPointer>(),
+        ),
+        (
+            pin::token_group::TokenGroup::BASE_LEN,
+            size_of::<TokenGroup>(),
+        ),
+        (
+            pin::group_member_pointer::GroupMemberPointer::BASE_LEN,
+            size_of::<spl::group_member_pointer::GroupMemberPointer>(),
+        ),
+        (
+            pin::token_group::TokenGroupMember::BASE_LEN,
+            size_of::<TokenGroupMember>(),
+        ),
+        (
+            pin::scaled_ui_amount::ScaledUiAmountConfig::BASE_LEN,
+            size_of::<spl::scaled_ui_amount::ScaledUiAmountConfig>(),
+        ),
+        (
+            pin::pausable::PausableConfig::BASE_LEN,
+            size_of::<spl::pausable::PausableConfig>(),
+        ),
+    ] {
+        assert_eq!(base_len, spl_len);
+    }
+}
+
+#[test]
+fn public_field_offsets_match_spl() {
+    use {pin::transfer_fee as pin_fee, spl::transfer_fee as spl_fee};
+
+    assert_eq!(
+        offset_of!(pin_fee::TransferFee, epoch),
+        offset_of!(spl_fee::TransferFee, epoch)
+    );
+    assert_eq!(
+        offset_of!(pin_fee::TransferFee, maximum_fee),
+        offset_of!(spl_fee::TransferFee, maximum_fee)
+    );
+    assert_eq!(
+        offset_of!(pin_fee::TransferFee, transfer_fee_basis_points),
+        offset_of!(spl_fee::TransferFee, transfer_fee_basis_points)
+    );
+    assert_eq!(
+        offset_of!(pin_fee::TransferFeeConfig, transfer_fee_config_authority),
+        offset_of!(spl_fee::TransferFeeConfig, transfer_fee_config_authority)
+    );
+    assert_eq!(
+        offset_of!(pin_fee::TransferFeeConfig, withdraw_withheld_authority),
+        offset_of!(spl_fee::TransferFeeConfig, withdraw_withheld_authority)
+    );
+    assert_eq!(
+        offset_of!(pin_fee::TransferFeeConfig, withheld_amount),
+        offset_of!(spl_fee::TransferFeeConfig, withheld_amount)
+    );
+    assert_eq!(
+        offset_of!(pin_fee::TransferFeeConfig, older_transfer_fee),
+        offset_of!(spl_fee::TransferFeeConfig, older_transfer_fee)
+    );
+    assert_eq!(
+        offset_of!(pin_fee::TransferFeeConfig, newer_transfer_fee),
+        offset_of!(spl_fee::TransferFeeConfig, newer_transfer_fee)
+    );
+
+    assert_eq!(
+        offset_of!(pin::metadata_pointer::MetadataPointer, authority),
+        offset_of!(spl::metadata_pointer::MetadataPointer, authority)
+    );
+    assert_eq!(
+        offset_of!(pin::metadata_pointer::MetadataPointer, metadata_address),
+        offset_of!(spl::metadata_pointer::MetadataPointer, metadata_address)
+    );
+
+    assert_eq!(
+        offset_of!(pin::scaled_ui_amount::ScaledUiAmountConfig, authority),
+        offset_of!(spl::scaled_ui_amount::ScaledUiAmountConfig, authority)
+    );
+    assert_eq!(
+        offset_of!(pin::scaled_ui_amount::ScaledUiAmountConfig, multiplier),
+        offset_of!(spl::scaled_ui_amount::ScaledUiAmountConfig, multiplier)
+    );
+    assert_eq!(
+        offset_of!(
+            pin::scaled_ui_amount::ScaledUiAmountConfig,
+            new_multiplier_effective_timestamp
+        ),
+        offset_of!(
+            spl::scaled_ui_amount::ScaledUiAmountConfig,
+            new_multiplier_effective_timestamp
+        )
+    );
+    assert_eq!(
+        offset_of!(pin::scaled_ui_amount::ScaledUiAmountConfig, new_multiplier),
+        offset_of!(spl::scaled_ui_amount::ScaledUiAmountConfig, new_multiplier)
+    );
+}
+
+#[test]
+fn transfer_fee_layout() {
+    let fee = |epoch: u64, maximum_fee: u64, basis_points: u16| spl::transfer_fee::TransferFee {
+        epoch: PodU64::from(epoch),
+        maximum_fee: PodU64::from(maximum_fee),
+        transfer_fee_basis_points: PodU16::from(basis_points),
+    };
+    let data = mint_with(&[ExtensionType::TransferFeeConfig], |state| {
+        *state
+            .init_extension::<spl::transfer_fee::TransferFeeConfig>(true)
+            .unwrap() = spl::transfer_fee::TransferFeeConfig {
+            transfer_fee_config_authority: some_key(&AUTHORITY),
+            withdraw_withheld_authority: some_key(&ADDRESS),
+            withheld_amount: PodU64::from(11),
+            older_transfer_fee: fee(12, 13, 14),
+            newer_transfer_fee: fee(15, 16, 17),
+        };
+    });
+
+    let config = pin::transfer_fee::TransferFeeConfig::from_bytes(&data).unwrap();
+    assert_eq!(config.transfer_fee_config_authority(), Some(&AUTHORITY));
+    assert_eq!(config.withdraw_withheld_authority(), Some(&ADDRESS));
+    assert_eq!(config.withheld_amount, 11u64.to_le_bytes());
+    assert_eq!(config.older_transfer_fee.epoch, 12u64.to_le_bytes());
+    assert_eq!(config.older_transfer_fee.maximum_fee, 13u64.to_le_bytes());
+    assert_eq!(
+        config.older_transfer_fee.transfer_fee_basis_points,
+        14u16.to_le_bytes()
+    );
+    assert_eq!(config.newer_transfer_fee.epoch, 15u64.to_le_bytes());
+    assert_eq!(config.newer_transfer_fee.maximum_fee, 16u64.to_le_bytes());
+    assert_eq!(
+        config.newer_transfer_fee.transfer_fee_basis_points,
+        17u16.to_le_bytes()
+    );
+
+    let data = account_with(&[ExtensionType::TransferFeeAmount], |state| {
+        state
+            .init_extension::<spl::transfer_fee::TransferFeeAmount>(true)
+            .unwrap()
+            .withheld_amount = PodU64::from(18);
+    });
+
+    let amount = pin::transfer_fee::TransferFeeAmount::from_bytes(&data).unwrap();
+    assert_eq!(amount.withheld_amount, 18u64.to_le_bytes());
+}
+
+#[test]
+fn transfer_fee_after_another_extension() {
+    // the fee extensions are looked up in the TLV data, not read at a fixed offset
+    let data = mint_with(
+        &[
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::TransferFeeConfig,
+        ],
+        |state| {
+            state
+                .init_extension::<spl::mint_close_authority::MintCloseAuthority>(true)
+                .unwrap()
+                .close_authority = some_key(&ADDRESS);
+            let config = state
+                .init_extension::<spl::transfer_fee::TransferFeeConfig>(true)
+                .unwrap();
+            config.transfer_fee_config_authority = some_key(&AUTHORITY);
+            config.withheld_amount = PodU64::from(11);
+        },
+    );
+
+    let config = pin::transfer_fee::TransferFeeConfig::from_bytes(&data).unwrap();
+    assert_eq!(config.transfer_fee_config_authority(), Some(&AUTHORITY));
+    assert_eq!(config.withdraw_withheld_authority(), None);
+    assert_eq!(config.withheld_amount, 11u64.to_le_bytes());
+
+    // associated token accounts hold `ImmutableOwner` first
+    let data = account_with(
+        &[
+            ExtensionType::ImmutableOwner,
+            ExtensionType::TransferFeeAmount,
+        ],
+        |state| {
+            state
+                .init_extension::<spl::immutable_owner::ImmutableOwner>(true)
+                .unwrap();
+            state
+                .init_extension::<spl::transfer_fee::TransferFeeAmount>(true)
+                .unwrap()
+                .withheld_amount = PodU64::from(18);
+        },
+    );
+
+    let amount = pin::transfer_fee::TransferFeeAmount::from_bytes(&data).unwrap();
+    assert_eq!(amount.withheld_amount, 18u64.to_le_bytes());
+}
+
+#[test]
+fn mint_close_authority_layout() {
+    let data = mint_with(&[ExtensionType::MintCloseAuthority], |state| {
+        state
+            .init_extension::<spl::mint_close_authority::MintCloseAuthority>(true)
+            .unwrap()
+            .close_authority = some_key(&AUTHORITY);
+    });
+
+    let extension = pin::mint_close_authority::MintCloseAuthority::from_bytes(&data).unwrap();
+    assert_eq!(extension.close_authority(), Some(&AUTHORITY));
+}
+
+#[test]
+fn default_account_state_layout() {
+    let data = mint_with(&[ExtensionType::DefaultAccountState], |state| {
+        state
+            .init_extension::<spl::default_account_state::DefaultAccountState>(true)
+            .unwrap()
+            .state = AccountState::Frozen as u8;
+    });
+
+    let extension =
+        pin::default_account_state::state::DefaultAccountStateConfig::from_bytes(&data).unwrap();
+    assert_eq!(extension.state(), AccountState::Frozen as u8);
+}
+
+#[test]
+fn memo_transfer_layout() {
+    let data = account_with(&[ExtensionType::MemoTransfer], |state| {
+        state
+            .init_extension::<spl::memo_transfer::MemoTransfer>(true)
+            .unwrap()
+            .require_incoming_transfer_memos = PodBool::from(true);
+    });
+
+    let extension = pin::memo_transfer::MemoTransfer::from_bytes(&data).unwrap();
+    assert!(extension.is_enabled());
+}
+
+#[test]
+fn non_transferable_layout() {
+    let data = mint_with(&[ExtensionType::NonTransferable], |state| {
+        state
+            .init_extension::<spl::non_transferable::NonTransferable>(true)
+            .unwrap();
+    });
+    assert!(pin::non_transferable::NonTransferable::from_bytes(&data).is_ok());
+
+    let data = account_with(&[ExtensionType::NonTransferableAccount], |state| {
+        state
+            .init_extension::<spl::non_transferable::NonTransferableAccount>(true)
+            .unwrap();
+    });
+    assert!(pin::non_transferable::NonTransferableAccount::from_bytes(&data).is_ok());
+}
+
+#[test]
+fn interest_bearing_mint_layout() {
+    let data = mint_with(&[ExtensionType::InterestBearingConfig], |state| {
+        *state
+            .init_extension::<spl::interest_bearing_mint::InterestBearingConfig>(true)
+            .unwrap() = spl::interest_bearing_mint::InterestBearingConfig {
+            rate_authority: some_key(&AUTHORITY),
+            initialization_timestamp: PodI64::from(1_600_000_000),
+            pre_update_average_rate: PodI16::from(-250),
+            last_update_timestamp: PodI64::from(1_700_000_000),
+            current_rate: PodI16::from(500),
+        };
+    });
+
+    let config = pin::interest_bearing_mint::InterestBearingConfig::from_bytes(&data).unwrap();
+    assert_eq!(config.rate_authority(), Some(&AUTHORITY));
+    assert_eq!(config.initialization_timestamp(), 1_600_000_000);
+    assert_eq!(config.pre_update_average_rate(), -250);
+    assert_eq!(config.last_update_timestamp(), 1_700_000_000);
+    assert_eq!(config.current_rate(), 500);
+}
+
+#[test]
+fn cpi_guard_layout() {
+    let data = account_with(&[ExtensionType::CpiGuard], |state| {
+        state
+            .init_extension::<spl::cpi_guard::CpiGuard>(true)
+            .unwrap()
+            .lock_cpi = PodBool::from(true);
+    });
+
+    let extension = pin::cpi_guard::CpiGuard::from_bytes(&data).unwrap();
+    assert!(extension.lock_cpi());
+}
+
+#[test]
+fn permanent_delegate_layout() {
+    let data = mint_with(&[ExtensionType::PermanentDelegate], |state| {
+        state
+            .init_extension::<spl::permanent_delegate::PermanentDelegate>(true)
+            .unwrap()
+            .delegate = some_key(&AUTHORITY);
+    });
+
+    let extension = pin::permanent_delegate::PermanentDelegate::from_bytes(&data).unwrap();
+    assert_eq!(extension.delegate(), Some(&AUTHORITY));
+}
+
+#[test]
+fn transfer_hook_layout() {
+    let data = mint_with(&[ExtensionType::TransferHook], |state| {
+        *state
+            .init_extension::<spl::transfer_hook::TransferHook>(true)
+            .unwrap() = spl::transfer_hook::TransferHook {
+            authority: some_key(&AUTHORITY),
+            program_id: some_key(&ADDRESS),
+        };
+    });
+
+    let extension = pin::transfer_hook::TransferHook::from_bytes(&data).unwrap();
+    assert_eq!(extension.authority(), Some(&AUTHORITY));
+    assert_eq!(extension.program_id(), Some(&ADDRESS));
+}
+
+#[test]
+fn confidential_transfer_layout() {
+    let data = mint_with(&[ExtensionType::ConfidentialTransferMint], |state| {
+        let extension = state
+            .init_extension::<spl::confidential_transfer::ConfidentialTransferMint>(true)
+            .unwrap();
+        extension.authority = some_key(&AUTHORITY);
+        extension.auto_approve_new_accounts = PodBool::from(true);
+        bytemuck::bytes_of_mut(&mut extension.auditor_elgamal_pubkey).fill(4);
+    });
+
+    let mint = pin::confidential_transfer::ConfidentialTransferMint::from_bytes(&data).unwrap();
+    assert_eq!(mint.authority(), Some(&AUTHORITY));
+    assert!(mint.auto_approve_new_accounts());
+    assert_eq!(mint.auditor_elgamal_pubkey().unwrap(), &[4; 32]);
+
+    let data = account_with(&[ExtensionType::ConfidentialTransferAccount], |state| {
+        let extension = state
+            .init_extension::<spl::confidential_transfer::ConfidentialTransferAccount>(true)
+            .unwrap();
+        extension.approved = PodBool::from(true);
+        bytemuck::bytes_of_mut(&mut extension.elgamal_pubkey).fill(5);
+        bytemuck::bytes_of_mut(&mut extension.pending_balance_lo).fill(6);
+        bytemuck::bytes_of_mut(&mut extension.pending_balance_hi).fill(7);
+        bytemuck::bytes_of_mut(&mut extension.available_balance).fill(8);
+        bytemuck::bytes_of_mut(&mut extension.decryptable_available_balance).fill(9);
+        extension.allow_confidential_credits = PodBool::from(false);
+        extension.allow_non_confidential_credits = PodBool::from(true);
+        extension.pending_balance_credit_counter = PodU64::from(10);
+        extension.maximum_pending_balance_credit_counter = PodU64::from(11);
+        extension.expected_pending_balance_credit_counter = PodU64::from(12);
+        extension.actual_pending_balance_credit_counter = PodU64::from(13);
+    });
+
+    let account =
+        pin::confidential_transfer::ConfidentialTransferAccount::from_bytes(&data).unwrap();
+    assert!(account.approved());
+    assert_eq!(account.elgamal_pubkey(), &[5; 32]);
+    assert_eq!(account.pending_balance_lo(), &[6; 64]);
+    assert_eq!(account.pending_balance_hi(), &[7; 64]);
+    assert_eq!(account.available_balance(), &[8; 64]);
+    assert_eq!(account.decryptable_available_balance(), &[9; 36]);
+    assert!(!account.allow_confidential_credits());
+    assert!(account.allow_non_confidential_credits());
+    assert_eq!(account.pending_balance_credit_counter(), 10);
+    assert_eq!(account.maximum_pending_balance_credit_counter(), 11);
+    assert_eq!(account.expected_pending_balance_credit_counter(), 12);
+    assert_eq!(account.actual_pending_balance_credit_counter(), 13);
+}
+
+#[test]
+fn confidential_transfer_fee_layout() {
+    let data = mint_with(&[ExtensionType::ConfidentialTransferFeeConfig], |state| {
+        let extension = state
+            .init_extension::<spl::confidential_transfer_fee::ConfidentialTransferFeeConfig>(true)
+            .unwrap();
+        extension.authority = some_key(&AUTHORITY);
+        bytemuck::bytes_of_mut(&mut extension.withdraw_withheld_authority_elgamal_pubkey).fill(4);
+        extension.harvest_to_mint_enabled = PodBool::from(true);
+        bytemuck::bytes_of_mut(&mut extension.withheld_amount).fill(5);
+    });
+
+    let config =
+        pin::confidential_transfer_fee::ConfidentialTransferFeeConfig::from_bytes(&data).unwrap();
+    assert_eq!(config.authority(), Some(&AUTHORITY));
+    assert_eq!(
+        config.withdraw_withheld_authority_elgamal_pubkey(),
+        &[4; 32]
+    );
+    assert!(config.harvest_to_mint_enabled());
+    assert_eq!(config.withheld_amount(), &[5; 64]);
+
+    let data = account_with(&[ExtensionType::ConfidentialTransferFeeAmount], |state| {
+        let extension = state
+            .init_extension::<spl::confidential_transfer_fee::ConfidentialTransferFeeAmount>(true)
+            .unwrap();
+        bytemuck::bytes_of_mut(&mut extension.withheld_amount).fill(6);
+    });
+
+    let amount =
+        pin::confidential_transfer_fee::ConfidentialTransferFeeAmount::from_bytes(&data).unwrap();
+    assert_eq!(amount.withheld_amount(), &[6; 64]);
+}
+
+#[test]
+fn metadata_pointer_layout() {
+    let data = mint_with(&[ExtensionType::MetadataPointer], |state| {
+        *state
+            .init_extension::<spl::metadata_pointer::MetadataPointer>(true)
+            .unwrap() = spl::metadata_pointer::MetadataPointer {
+            authority: some_key(&AUTHORITY),
+            metadata_address: some_key(&ADDRESS),
+        };
+    });
+
+    let extension = pin::metadata_pointer::MetadataPointer::from_bytes(&data).unwrap();
+    assert_eq!(extension.authority(), Some(&AUTHORITY));
+    assert_eq!(extension.metadata_address(), Some(&ADDRESS));
+}
+
+#[test]
+fn token_group_layout() {
+    // the pinocchio parsers expect the group right after its pointer
+    let data = mint_with(
+        &[ExtensionType::GroupPointer, ExtensionType::TokenGroup],
+        |state| {
+            *state
+                .init_extension::<spl::group_pointer::GroupPointer>(true)
+                .unwrap() = spl::group_pointer::GroupPointer {
+                authority: some_key(&AUTHORITY),
+                group_address: some_key(&MINT),
+            };
+            *state.init_extension::<TokenGroup>(true).unwrap() = TokenGroup {
+                update_authority: some_key(&AUTHORITY),
+                mint: pin_pubkey_to_addr(&MINT),
+                size: PodU64::from(7),
+                max_size: PodU64::from(8),
+            };
+        },
+    );
+
+    let pointer = pin::group_pointer::GroupPointer::from_bytes(&data).unwrap();
+    assert_eq!(pointer.authority(), Some(&AUTHORITY));
+    assert_eq!(pointer.group_address(), Some(&MINT));
+
+    let group = pin::token_group::TokenGroup::from_bytes(&data).unwrap();
+    assert_eq!(group.update_authority(), Some(&AUTHORITY));
+    assert_eq!(group.mint(), &MINT);
+    assert_eq!(group.size(), 7);
+    assert_eq!(group.max_size(), 8);
+
+    let data = mint_with(
+        &[
+            ExtensionType::GroupMemberPointer,
+            ExtensionType::TokenGroupMember,
+        ],
+        |state| {
+            *state
+                .init_extension::<spl::group_member_pointer::GroupMemberPointer>(true)
+                .unwrap() = spl::group_member_pointer::GroupMemberPointer {
+                authority: some_key(&AUTHORITY),
+                member_address: some_key(&ADDRESS),
+            };
+            *state.init_extension::<TokenGroupMember>(true).unwrap() = TokenGroupMember {
+                mint: pin_pubkey_to_addr(&ADDRESS),
+                group: pin_pubkey_to_addr(&MINT),
+                member_number: PodU64::from(9),
+            };
+        },
+    );
+
+    let pointer = pin::group_member_pointer::GroupMemberPointer::from_bytes(&data).unwrap();
+    assert_eq!(pointer.authority(), Some(&AUTHORITY));
+    assert_eq!(pointer.member_address(), Some(&ADDRESS));
+
+    let member = pin::token_group::TokenGroupMember::from_bytes(&data).unwrap();
+    assert_eq!(member.mint(), &ADDRESS);
+    assert_eq!(member.group(), &MINT);
+    assert_eq!(member.member_number(), 9);
+}
+
+#[test]
+fn scaled_ui_amount_layout() {
+    let data = mint_with(&[ExtensionType::ScaledUiAmount], |state| {
+        *state
+            .init_extension::<spl::scaled_ui_amount::ScaledUiAmountConfig>(true)
+            .unwrap() = spl::scaled_ui_amount::ScaledUiAmountConfig {
+            authority: some_key(&AUTHORITY),
+            multiplier: spl::scaled_ui_amount::PodF64::from(1.5),
+            new_multiplier_effective_timestamp: PodI64::from(1_700_000_000),
+            new_multiplier: spl::scaled_ui_amount::PodF64::from(2.25),
+        };
+    });
+
+    // the config holds `f64` fields, which can't be referenced in place at this offset,
+    // copy it out instead
+    assert!(data.len() >= pin::scaled_ui_amount::ScaledUiAmountConfig::LEN);
+    let config = unsafe {
+        (data[pin::scaled_ui_amount::ScaledUiAmountConfig::AUTHORITY_START..].as_ptr()
+            as *const pin::scaled_ui_amount::ScaledUiAmountConfig)
+            .read_unaligned()
+    };
+    assert_eq!(config.authority, AUTHORITY);
+    assert_eq!(config.multiplier, 1.5);
+    assert_eq!(config.new_multiplier_effective_timestamp, 1_700_000_000);
+    assert_eq!(config.new_multiplier, 2.25);
+}
+
+#[test]
+fn pausable_layout() {
+    let data = mint_with(&[ExtensionType::Pausable], |state| {
+        *state
+            .init_extension::<spl::pausable::PausableConfig>(true)
+            .unwrap() = spl::pausable::PausableConfig {
+            authority: some_key(&AUTHORITY),
+            paused: PodBool::from(true),
+        };
+    });
+
+    let config = pin::pausable::PausableConfig::from_bytes(&data).unwrap();
+    assert_eq!(config.authority(), &AUTHORITY);
+    assert!(config.is_paused());
+}
@@ -0,0 +1,558 @@
+use {
+    crate::helpers::suite::{rng::proptest_config, types::pin_pubkey_to_addr},
+    pinocchio::program_error::ProgramError,
+    pinocchio_token_2022::{
+        client::{account_type, extension_types, AccountType},
+        extension::{
+            consts::ExtensionType,
+            mint_close_authority::MintCloseAuthority,
+            pausable::PausableConfig,
+            tlv::{
+                extensions_from_bytes, get_extension_data_from_bytes, get_extension_from_bytes,
+                get_extensions_from_bytes, get_interface_value_from_bytes, has_extension,
+                has_extension_type, BASE_ACCOUNT_LENGTH,
+            },
+            token_group::{
+                TokenGroup, TokenGroupMember, TOKEN_GROUP_DISCRIMINATOR,
+                TOKEN_GROUP_MEMBER_DISCRIMINATOR,
+            },
+            token_metadata::{TokenMetadata, TOKEN_METADATA_DISCRIMINATOR},
+            transfer_hook::TransferHook,
+        },
+        state::{Mint, TokenAccount},
+        test_utils::TlvBuilder,
+    },
+    proptest::{collection::vec, prelude::*, sample::Index},
+    solana_program_pack::Pack,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022_interface::{
+        extension::{
+            BaseStateWithExtensions, ExtensionType as SplExtensionType, StateWithExtensions,
+        },
+        state::{Account, Mint as SplMint, Multisig},
+    },
+};
+
+/// Known extension types
+const KNOWN_TYPES: core::ops::RangeInclusive<u16> = 1..=27;
+
+type Entry = (u16, Vec<u8>);
+
+/// Initialized token account followed by the TLV entries
+fn encode(entries: &[Entry]) -> Vec<u8> {
+    let mut data = entries
+        .iter()
+        .fold(TlvBuilder::token_account(), |builder, (tlv_type, value)| {
+            builder.entry(*tlv_type, value)
+        })
+        .build();
+
+    // multisig length is rejected by SPL, terminate the TLV area explicitly
+    if data.len() == Multisig::LEN {
+        data.extend_from_slice(&[0; 4]);
+    }
+
+    data
+}
+
+fn parse(data: &[u8]) -> Vec<Entry> {
+    extensions_from_bytes(data)
+        .map(|(tlv_type, value)| (tlv_type, value.to_vec()))
+        .collect()
+}
+
+fn entry(types: impl Strategy<Value = u16>) -> impl Strategy<Value = Entry> {
+    (types, vec(any::<u8>(), 0..64))
+}
+
+/// Entries of unique known types in random order
+fn valid_entries() -> impl Strategy<Value = Vec<Entry>> {
+    proptest::collection::btree_map(KNOWN_TYPES, vec(any::<u8>(), 0..64), 0..8)
+        .prop_map(|entries| entries.into_iter().collect::<Vec<_>>())
+        .prop_shuffle()
+}
+
+proptest! {
+    #![proptest_config(proptest_config())]
+
+    #[test]
+    fn tlv_never_panics_on_arbitrary_bytes(data in vec(any::<u8>(), 0..512)) {
+        for (_, value) in extensions_from_bytes(&data) {
+            prop_assert!(value.len() <= data.len());
+        }
+
+        for extension_type in [
+            ExtensionType::Uninitialized,
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::TokenMetadata,
+            ExtensionType::PausableAccount,
+        ] {
+            get_extension_data_from_bytes(extension_type, &data);
+        }
+
+        get_extension_from_bytes::<MintCloseAuthority>(&data);
+    }
+
+    #[test]
+    fn tlv_agrees_with_spl_on_valid_layouts(entries in valid_entries()) {
+        let data = encode(&entries);
+
+        prop_assert_eq!(parse(&data), entries.clone());
+
+        let spl_types = StateWithExtensions::<Account>::unpack(&data)
+            .unwrap()
+            .get_extension_types()
+            .unwrap();
+        let spl_types: Vec<u16> = spl_types.into_iter().map(|x| x as u16).collect();
+        let types: Vec<u16> = entries.iter().map(|(tlv_type, _)| *tlv_type).collect();
+        prop_assert_eq!(spl_types, types);
+
+        let mint_close_authority = entries
+            .iter()
+            .find(|(tlv_type, _)| *tlv_type == ExtensionType::MintCloseAuthority as u16)
+            .map(|(_, value)| value.as_slice());
+        prop_assert_eq!(
+            get_extension_data_from_bytes(ExtensionType::MintCloseAuthority, &data),
+            mint_close_authority
+        );
+        // the typed getter requires the exact length
+        prop_assert_eq!(
+            get_extension_from_bytes::<MintCloseAuthority>(&data).is_some(),
+            mint_close_authority.is_some_and(|x| x.len() == 32)
+        );
+    }
+
+    #[test]
+    fn tlv_stops_at_truncated_entry(entries in valid_entries(), cut in any::<Index>()) {
+        let mut data = encode(&entries);
+        data.truncate(cut.index(data.len() + 1));
+
+        // only the entries fully within the data are returned
+        let parsed = parse(&data);
+        prop_assert!(entries.starts_with(&parsed));
+
+        // a multisig has no extensions
+        if data.len() == Multisig::LEN {
+            prop_assert_eq!(parsed, []);
+            return Ok(());
+        }
+
+        let mut end = BASE_ACCOUNT_LENGTH + 1;
+        for (_, value) in &entries[..parsed.len()] {
+            end += 4 + value.len();
+        }
+        if let Some((_, value)) = entries.get(parsed.len()) {
+            prop_assert!(data.len() < end + 4 + value.len());
+        }
+    }
+
+    #[test]
+    fn tlv_stops_at_oversized_length(
+        entries in valid_entries().prop_filter("non empty", |x| !x.is_empty()),
+        index in any::<Index>(),
+        extra in 0u16..1_000,
+    ) {
+        let index = index.index(entries.len());
+        let mut data = encode(&entries);
+
+        let mut offset = BASE_ACCOUNT_LENGTH + 1;
+        for (_, value) in &entries[..index] {
+            offset += 4 + value.len();
+        }
+        // the length runs past the end of the data
+        data[offset + 2..offset + 4].copy_from_slice(&(u16::MAX - extra).to_le_bytes());
+
+        prop_assert_eq!(parse(&data), entries[..index].to_vec());
+        prop_assert_eq!(
+            get_extension_data_from_bytes(
+                ExtensionType::MintCloseAuthority,
+                &data
+            )
+            .is_some(),
+            entries[..index]
+                .iter()
+                .any(|(tlv_type, _)| *tlv_type == ExtensionType::MintCloseAuthority as u16)
+        );
+    }
+
+    #[test]
+    fn tlv_rejects_non_extension_layouts(
+        entries in valid_entries().prop_filter("non empty", |x| !x.is_empty()),
+        account_type in any::<u8>(),
+    ) {
+        let mut data = encode(&entries);
+
+        // multisig length, the entries padded or cut to it
+        let mut multisig = data.clone();
+        multisig.resize(Multisig::LEN, 0);
+        prop_assert_eq!(parse(&multisig), []);
+        prop_assert!(StateWithExtensions::<Account>::unpack(&multisig).is_err());
+
+        // the entries of a mint or a token account are read, SPL checks which one it is
+        data[BASE_ACCOUNT_LENGTH] = account_type;
+        if matches!(account_type, 1 | 2) {
+            prop_assert_eq!(parse(&data), entries);
+        } else {
+            prop_assert_eq!(parse(&data), []);
+            prop_assert!(StateWithExtensions::<Account>::unpack(&data).is_err());
+        }
+    }
+
+    #[test]
+    fn tlv_skips_unknown_types(
+        entries in vec(entry(prop_oneof![KNOWN_TYPES, 28u16..]), 0..8),
+        value in vec(any::<u8>(), 0..64),
+    ) {
+        let entries: Vec<_> = entries
+            .into_iter()
+            .filter(|(tlv_type, _)| *tlv_type != ExtensionType::MintCloseAuthority as u16)
+            .chain([(ExtensionType::MintCloseAuthority as u16, value.clone())])
+            .collect();
+        let data = encode(&entries);
+
+        // unknown entries are returned as is and don't hide the following ones
+        prop_assert_eq!(parse(&data), entries);
+        prop_assert_eq!(
+            get_extension_data_from_bytes(ExtensionType::MintCloseAuthority, &data),
+            Some(value.as_slice())
+        );
+    }
+
+    #[test]
+    fn tlv_returns_first_duplicate_entry(
+        entries in valid_entries(),
+        first in vec(any::<u8>(), 0..64),
+        second in vec(any::<u8>(), 0..64),
+    ) {
+        let tlv_type = ExtensionType::MintCloseAuthority as u16;
+        let entries: Vec<_> = entries
+            .into_iter()
+            .filter(|(x, _)| *x != tlv_type)
+            .chain([(tlv_type, first.clone()), (tlv_type, second)])
+            .collect();
+        let data = encode(&entries);
+
+        prop_assert_eq!(parse(&data), entries);
+        prop_assert_eq!(
+            get_extension_data_from_bytes(ExtensionType::MintCloseAuthority, &data),
+            Some(first.as_slice())
+        );
+    }
+
+    #[test]
+    fn get_extensions_agrees_with_get_extension(
+        entries in vec(
+            (
+                prop_oneof![
+                    Just(ExtensionType::MintCloseAuthority as u16),
+                    Just(ExtensionType::Pausable as u16),
+                    Just(ExtensionType::TransferHook as u16),
+                    1u16..=30,
+                ],
+                // the lengths of the extensions and a few others
+                prop_oneof![Just(32usize), Just(33), Just(64), 0usize..70]
+                    .prop_flat_map(|len| vec(any::<u8>(), len)),
+            ),
+            0..8,
+        ),
+    ) {
+        let data = encode(&entries);
+
+        let (mint_close_authority, pausable_config, transfer_hook) =
+            get_extensions_from_bytes::<(MintCloseAuthority, PausableConfig, TransferHook)>(&data);
+        prop_assert_eq!(
+            mint_close_authority.map(|x| x as *const _),
+            get_extension_from_bytes::<MintCloseAuthority>(&data).map(|x| x as *const _)
+        );
+        prop_assert_eq!(
+            pausable_config.map(|x| x as *const _),
+            get_extension_from_bytes::<PausableConfig>(&data).map(|x| x as *const _)
+        );
+        prop_assert_eq!(
+            transfer_hook.map(|x| x as *const _),
+            get_extension_from_bytes::<TransferHook>(&data).map(|x| x as *const _)
+        );
+    }
+
+    #[test]
+    fn has_extension_agrees_with_spl(entries in valid_entries()) {
+        let data = encode(&entries);
+
+        let spl_types = StateWithExtensions::<Account>::unpack(&data)
+            .unwrap()
+            .get_extension_types()
+            .unwrap();
+        for tlv_type in KNOWN_TYPES {
+            let extension_type = ExtensionType::try_from(tlv_type).unwrap();
+            prop_assert_eq!(
+                has_extension_type(extension_type, &data),
+                spl_types.iter().any(|x| *x as u16 == tlv_type)
+            );
+        }
+
+        // the presence doesn't depend on the length of the value
+        prop_assert_eq!(
+            has_extension::<MintCloseAuthority>(&data),
+            entries
+                .iter()
+                .any(|(tlv_type, _)| *tlv_type == ExtensionType::MintCloseAuthority as u16)
+        );
+    }
+
+    #[test]
+    fn client_agrees_with_spl_on_valid_layouts(entries in valid_entries()) {
+        let data = encode(&entries);
+
+        prop_assert_eq!(account_type(&data), Some(AccountType::Account));
+        prop_assert_eq!(account_type(&data[..BASE_ACCOUNT_LENGTH]), None);
+
+        let spl_types = StateWithExtensions::<Account>::unpack(&data)
+            .unwrap()
+            .get_extension_types()
+            .unwrap();
+        let spl_types: Vec<u16> = spl_types.into_iter().map(|x| x as u16).collect();
+        let types: Vec<u16> = extension_types(&data).map(|x| x as u16).collect();
+        prop_assert_eq!(types, spl_types);
+    }
+
+    #[test]
+    fn client_skips_unknown_types(
+        entries in vec(entry(prop_oneof![KNOWN_TYPES, 28u16..]), 0..8),
+    ) {
+        let data = encode(&entries);
+
+        let types: Vec<u16> = extension_types(&data).map(|x| x as u16).collect();
+        let known_types: Vec<u16> = entries
+            .iter()
+            .map(|(tlv_type, _)| *tlv_type)
+            .filter(|tlv_type| KNOWN_TYPES.contains(tlv_type))
+            .collect();
+        prop_assert_eq!(types, known_types);
+    }
+
+    #[test]
+    fn account_len_agrees_with_spl(tlv_types in vec(0..=*KNOWN_TYPES.end(), 0..8)) {
+        let types: Vec<_> = tlv_types
+            .iter()
+            .map(|x| ExtensionType::try_from(*x).unwrap())
+            .collect();
+        let spl_types: Vec<_> = tlv_types
+            .iter()
+            .map(|x| SplExtensionType::try_from(*x).unwrap())
+            .collect();
+
+        prop_assert_eq!(
+            ExtensionType::try_calculate_account_len::<Mint>(&types).ok(),
+            SplExtensionType::try_calculate_account_len::<SplMint>(&spl_types).ok()
+        );
+        prop_assert_eq!(
+            ExtensionType::try_calculate_account_len::<TokenAccount>(&types).ok(),
+            SplExtensionType::try_calculate_account_len::<Account>(&spl_types).ok()
+        );
+    }
+}
+
+/// Interface TLV entry: 8 bytes discriminator, 4 bytes length and the value
+fn encode_interface_entry(discriminator: &[u8; 8], value: &[u8]) -> Vec<u8> {
+    [
+        &discriminator[..],
+        &(value.len() as u32).to_le_bytes(),
+        value,
+    ]
+    .concat()
+}
+
+proptest! {
+    #![proptest_config(proptest_config())]
+
+    #[test]
+    fn interface_tlv_finds_entry(
+        entries in vec((any::<[u8; 8]>(), vec(any::<u8>(), 0..64)), 0..8),
+        value in vec(any::<u8>(), 0..64),
+        padding in 0..32usize,
+    ) {
+        let discriminator = [0xaa; 8];
+        let entries: Vec<_> = entries
+            .into_iter()
+            .filter(|(x, _)| *x != discriminator)
+            .collect();
+        let data = [
+            entries
+                .iter()
+                .flat_map(|(x, value)| encode_interface_entry(x, value))
+                .collect(),
+            encode_interface_entry(&discriminator, &value),
+            vec![0; padding],
+        ]
+        .concat();
+
+        prop_assert_eq!(
+            get_interface_value_from_bytes(&discriminator, &data),
+            Some(value.as_slice())
+        );
+        // the truncated entry isn't returned
+        prop_assert_eq!(
+            get_interface_value_from_bytes(&discriminator, &data[..data.len() - padding - 1]),
+            None
+        );
+    }
+}
+
+#[test]
+fn tlv_stops_at_malformed_entries() {
+    let close_authority = [1; 32];
+
+    // the oversized entry runs into the following one
+    let data = TlvBuilder::mint()
+        .entry(u16::MAX, &[7; 3])
+        .raw_entry(ExtensionType::TransferHook as u16, 8, &[2; 4])
+        .extension(ExtensionType::MintCloseAuthority, &close_authority)
+        .build();
+    assert_eq!(
+        parse(&data),
+        [
+            (u16::MAX, vec![7; 3]),
+            (
+                ExtensionType::TransferHook as u16,
+                [
+                    [2; 4].as_slice(),
+                    &(ExtensionType::MintCloseAuthority as u16).to_le_bytes(),
+                    &32u16.to_le_bytes()
+                ]
+                .concat()
+            ),
+        ]
+    );
+    assert_eq!(
+        get_extension_data_from_bytes(ExtensionType::MintCloseAuthority, &data),
+        None
+    );
+
+    // the length runs past the end of the data
+    let data = TlvBuilder::mint()
+        .extension(ExtensionType::MintCloseAuthority, &close_authority)
+        .raw_entry(ExtensionType::TransferHook as u16, 64, &[2; 63])
+        .build();
+    assert_eq!(
+        parse(&data),
+        [(
+            ExtensionType::MintCloseAuthority as u16,
+            close_authority.to_vec()
+        )]
+    );
+
+    // truncated header
+    let data = TlvBuilder::mint()
+        .extension(ExtensionType::MintCloseAuthority, &close_authority)
+        .bytes(&[1, 0, 0])
+        .build();
+    assert_eq!(parse(&data).len(), 1);
+
+    // `Uninitialized` ends the TLV area
+    let data = TlvBuilder::mint()
+        .entry(ExtensionType::Uninitialized as u16, &[])
+        .extension(ExtensionType::MintCloseAuthority, &close_authority)
+        .build();
+    assert_eq!(parse(&data), []);
+    assert!(get_extension_from_bytes::<MintCloseAuthority>(&data).is_none());
+}
+
+#[test]
+fn token_group_from_interface_bytes() {
+    // the interface discriminators are the first 8 bytes of the namespaced state hash
+    for (discriminator, name) in [
+        (TOKEN_GROUP_DISCRIMINATOR, "group"),
+        (TOKEN_GROUP_MEMBER_DISCRIMINATOR, "member"),
+    ] {
+        let hash =
+            solana_program::hash::hash(format!("spl_token_group_interface:{name}").as_bytes());
+        assert_eq!(discriminator, hash.to_bytes()[..8]);
+    }
+
+    let mint = [1; 32];
+    let group_address = [2; 32];
+    let authority = [3; 32];
+
+    let group = spl_token_group_interface::state::TokenGroup::new(
+        &pin_pubkey_to_addr(&mint),
+        OptionalNonZeroPubkey(pin_pubkey_to_addr(&authority)),
+        10,
+    );
+    let data = encode_interface_entry(&TOKEN_GROUP_DISCRIMINATOR, bytemuck::bytes_of(&group));
+
+    let parsed = TokenGroup::from_interface_bytes(&data).unwrap();
+    assert_eq!(parsed.mint(), &mint);
+    assert_eq!(parsed.update_authority(), Some(&authority));
+    assert_eq!((parsed.size(), parsed.max_size()), (0, 10));
+
+    let member = spl_token_group_interface::state::TokenGroupMember::new(
+        &pin_pubkey_to_addr(&mint),
+        &pin_pubkey_to_addr(&group_address),
+        7,
+    );
+    let data = [
+        data,
+        encode_interface_entry(
+            &TOKEN_GROUP_MEMBER_DISCRIMINATOR,
+            bytemuck::bytes_of(&member),
+        ),
+    ]
+    .concat();
+
+    // a program may store both entries in the same account
+    let parsed = TokenGroupMember::from_interface_bytes(&data).unwrap();
+    assert_eq!(parsed.mint(), &mint);
+    assert_eq!(parsed.group(), &group_address);
+    assert_eq!(parsed.member_number(), 7);
+
+    // wrong length
+    let data = encode_interface_entry(&TOKEN_GROUP_DISCRIMINATOR, &[0; 32]);
+    assert!(matches!(
+        TokenGroup::from_interface_bytes(&data),
+        Err(ProgramError::InvalidAccountData)
+    ));
+}
+
+#[test]
+fn token_metadata_from_interface_bytes() {
+    let hash = solana_program::hash::hash(b"spl_token_metadata_interface:token_metadata");
+    assert_eq!(TOKEN_METADATA_DISCRIMINATOR, hash.to_bytes()[..8]);
+
+    let mint = [1; 32];
+    let authority = [3; 32];
+
+    // borsh: update authority, mint, name, symbol, uri and the additional pairs
+    let string = |x: &str| [&(x.len() as u32).to_le_bytes()[..], x.as_bytes()].concat();
+    let value = [
+        authority.to_vec(),
+        mint.to_vec(),
+        string("Name"),
+        string("SYM"),
+        string("https://example.com"),
+        1u32.to_le_bytes().to_vec(),
+        string("key"),
+        string("value"),
+    ]
+    .concat();
+
+    // a metadata program may store other entries before the metadata
+    let data = [
+        encode_interface_entry(&TOKEN_GROUP_DISCRIMINATOR, &[0; 80]),
+        encode_interface_entry(&TOKEN_METADATA_DISCRIMINATOR, &value),
+    ]
+    .concat();
+
+    let parsed = TokenMetadata::from_interface_bytes(&data).unwrap();
+    assert_eq!(parsed.mint, &mint);
+    assert_eq!(parsed.update_authority(), Some(&authority));
+    assert_eq!((parsed.name, parsed.symbol), ("Name", "SYM"));
+    assert_eq!(parsed.uri, "https://example.com");
+    assert_eq!(parsed.get("key"), Some("value"));
+
+    // the value without the entry header
+    assert!(matches!(
+        TokenMetadata::from_interface_bytes(&value),
+        Err(ProgramError::InvalidAccountData)
+    ));
+}
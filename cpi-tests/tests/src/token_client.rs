@@ -0,0 +1,112 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::mint_factory::ExtensionSpec,
+        suite::{
+            core::App,
+            types::{pin_pubkey_to_addr, to_c_option, AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022_interface::extension::{
+        metadata_pointer::MetadataPointer, pausable::PausableConfig,
+        transfer_fee::TransferFeeConfig, ExtensionType,
+    },
+};
+
+fn token_client_flow(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+    let mut client = app.token_client(target);
+
+    let mint = client.create_mint(
+        &[
+            ExtensionSpec::TransferFeeConfig {
+                transfer_fee_config_authority: Some(admin),
+                withdraw_withheld_authority: Some(admin),
+                transfer_fee_basis_points: 100,
+                maximum_fee: 10,
+            },
+            ExtensionSpec::MetadataPointer {
+                authority: Some(admin),
+                metadata_address: None,
+            },
+            ExtensionSpec::Pausable { authority: admin },
+        ],
+        6,
+        AppUser::Admin,
+        None,
+    )?;
+
+    // the account extensions required by the mint are added
+    let alice_account = client.create_token_account(AppUser::Alice, &mint, &[])?;
+    let bob_account =
+        client.create_token_account(AppUser::Bob, &mint, &[ExtensionType::ImmutableOwner])?;
+
+    client.mint_to(AppUser::Admin, &mint, &alice_account, 1_000)?;
+    client.transfer(AppUser::Alice, &alice_account, &bob_account, 400)?;
+    client.burn(AppUser::Alice, &alice_account, 100)?;
+
+    assert_eq!(client.balance(&alice_account)?, 500);
+    // 1% fee is withheld
+    assert_eq!(client.balance(&bob_account)?, 396);
+    assert_eq!(client.mint(&mint)?.supply, 900);
+
+    let transfer_fee_config = client.extension::<TransferFeeConfig>(&mint)?;
+    assert_eq!(
+        u16::from(
+            transfer_fee_config
+                .newer_transfer_fee
+                .transfer_fee_basis_points
+        ),
+        100
+    );
+    assert_eq!(
+        client.extension::<MetadataPointer>(&mint)?,
+        MetadataPointer {
+            authority: OptionalNonZeroPubkey(pin_pubkey_to_addr(&admin)),
+            metadata_address: OptionalNonZeroPubkey::default(),
+        }
+    );
+    assert_eq!(
+        client.extension::<PausableConfig>(&mint)?.paused,
+        false.into()
+    );
+
+    Ok(())
+}
+
+fn token_client_missing_extension(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mut client = app.token_client(target);
+
+    let mint = client.create_mint(&[], 6, AppUser::Admin, Some(AppUser::Admin))?;
+
+    assert_eq!(
+        client.mint(&mint)?.freeze_authority,
+        to_c_option(Some(pin_pubkey_to_addr(&AppUser::Admin.pubkey())))
+    );
+    assert!(client.extension::<MetadataPointer>(&mint).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn token_client_flow_spl() -> TestResult<()> {
+    token_client_flow(Target::Spl)
+}
+
+#[test]
+fn token_client_flow_proxy() -> TestResult<()> {
+    token_client_flow(Target::Proxy)
+}
+
+#[test]
+fn token_client_missing_extension_spl() -> TestResult<()> {
+    token_client_missing_extension(Target::Spl)
+}
+
+#[test]
+fn token_client_missing_extension_proxy() -> TestResult<()> {
+    token_client_missing_extension(Target::Proxy)
+}
@@ -0,0 +1,246 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+        },
+        suite::{
+            core::App,
+            types::{pin_to_sol_pubkey, AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_program_option::COption,
+    solana_signer::Signer,
+    spl_token_2022_interface::{instruction::AuthorityType, state::AccountState},
+};
+
+const DECIMALS: u8 = 6;
+
+/// Create and initialize a mint controlled by Admin, and token accounts owned by Alice and Bob
+fn prepare(app: &mut App) -> TestResult<([u8; 32], [u8; 32], [u8; 32])> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint_pubkey = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint_pubkey,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        Some(&AppUser::Admin.pubkey()),
+    )?;
+
+    let (_, alice_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+
+    let (_, bob_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        &mint_pubkey,
+        &[],
+    )?;
+
+    Ok((
+        mint_pubkey,
+        alice_keypair.pubkey().to_bytes(),
+        bob_keypair.pubkey().to_bytes(),
+    ))
+}
+
+fn mint_transfer_burn(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, bob_account) = prepare(&mut app)?;
+
+    app.token_2022_try_mint_to(target, AppUser::Admin, &mint, &alice_account, 1_000)?;
+    app.token_2022_try_mint_to_checked(
+        target,
+        AppUser::Admin,
+        &mint,
+        &alice_account,
+        500,
+        DECIMALS,
+    )?;
+
+    app.token_2022_try_transfer_checked(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        600,
+        DECIMALS,
+    )?;
+
+    app.token_2022_try_burn(target, AppUser::Alice, &alice_account, &mint, 100)?;
+    app.token_2022_try_burn_checked(target, AppUser::Bob, &bob_account, &mint, 50, DECIMALS)?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .amount,
+        800
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &bob_account)?
+            .amount,
+        550
+    );
+    assert_eq!(app.token_2022_query_mint(target, &mint)?.supply, 1_350);
+
+    Ok(())
+}
+
+fn approve_and_revoke(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, _) = prepare(&mut app)?;
+    let delegate = AppUser::Bob.pubkey();
+
+    app.token_2022_try_mint_to(target, AppUser::Admin, &mint, &alice_account, 1_000)?;
+
+    app.token_2022_try_approve(target, AppUser::Alice, &alice_account, &delegate, 300)?;
+
+    let account = app.token_2022_query_token_account(target, &alice_account)?;
+    assert_eq!(
+        account.delegate.map(|x| x.to_bytes()),
+        COption::Some(delegate)
+    );
+    assert_eq!(account.delegated_amount, 300);
+
+    app.token_2022_try_approve_checked(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &delegate,
+        400,
+        DECIMALS,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .delegated_amount,
+        400
+    );
+
+    app.token_2022_try_revoke(target, AppUser::Alice, &alice_account)?;
+
+    let account = app.token_2022_query_token_account(target, &alice_account)?;
+    assert_eq!(account.delegate, COption::None);
+    assert_eq!(account.delegated_amount, 0);
+
+    Ok(())
+}
+
+fn set_authority(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, _) = prepare(&mut app)?;
+    let close_authority = AppUser::Bob.pubkey();
+
+    app.token_2022_try_set_authority(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        AuthorityType::CloseAccount,
+        Some(&close_authority),
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .close_authority
+            .map(|x| x.to_bytes()),
+        COption::Some(close_authority)
+    );
+
+    app.token_2022_try_set_authority(
+        target,
+        AppUser::Admin,
+        &mint,
+        AuthorityType::FreezeAccount,
+        None,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_mint(target, &mint)?.freeze_authority,
+        COption::None
+    );
+
+    Ok(())
+}
+
+fn freeze_thaw_and_close(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, _) = prepare(&mut app)?;
+
+    app.token_2022_try_freeze_account(target, AppUser::Admin, &alice_account, &mint)?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .state,
+        AccountState::Frozen
+    );
+
+    app.token_2022_try_thaw_account(target, AppUser::Admin, &alice_account, &mint)?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .state,
+        AccountState::Initialized
+    );
+
+    app.token_2022_try_close_account(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &AppUser::Alice.pubkey(),
+    )?;
+
+    assert_eq!(app.get_coin_balance(&pin_to_sol_pubkey(&alice_account)), 0);
+
+    Ok(())
+}
+
+#[test]
+fn token_mint_transfer_burn() -> TestResult<()> {
+    mint_transfer_burn(Target::Spl)
+}
+
+#[test]
+fn proxy_token_mint_transfer_burn() -> TestResult<()> {
+    mint_transfer_burn(Target::Proxy)
+}
+
+#[test]
+fn token_approve_and_revoke() -> TestResult<()> {
+    approve_and_revoke(Target::Spl)
+}
+
+#[test]
+fn proxy_token_approve_and_revoke() -> TestResult<()> {
+    approve_and_revoke(Target::Proxy)
+}
+
+#[test]
+fn token_set_authority() -> TestResult<()> {
+    set_authority(Target::Spl)
+}
+
+#[test]
+fn proxy_token_set_authority() -> TestResult<()> {
+    set_authority(Target::Proxy)
+}
+
+#[test]
+fn token_freeze_thaw_and_close() -> TestResult<()> {
+    freeze_thaw_and_close(Target::Spl)
+}
+
+#[test]
+fn proxy_token_freeze_thaw_and_close() -> TestResult<()> {
+    freeze_thaw_and_close(Target::Proxy)
+}
@@ -0,0 +1,332 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+            token_metadata::Token2022TokenMetadataExtension,
+        },
+        suite::{
+            core::App,
+            types::{
+                pin_to_sol_pubkey, to_optional_non_zero_pubkey, AppUser, PinPubkey, Target,
+                TestError, TestResult,
+            },
+        },
+    },
+    pinocchio_token_2022::state::Multisig,
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+    spl_token_metadata_interface::state::Field,
+};
+
+const NAME: &str = "Proxy Token";
+const SYMBOL: &str = "PRX";
+const URI: &str = "https://example.com/proxy.json";
+
+/// Create a mint storing its own metadata with Admin as mint and update authority
+fn prepare(app: &mut App, target: Target) -> TestResult<[u8; 32]> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::MetadataPointer]),
+    )?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_metadata_pointer(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&mint),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    app.token_2022_try_initialize_token_metadata(target, AppUser::Admin, &mint, NAME, SYMBOL, URI)?;
+
+    Ok(mint)
+}
+
+/// Both parsers must read the same metadata from the mint
+fn assert_token_metadata(
+    app: &App,
+    mint: &[u8; 32],
+) -> TestResult<spl_token_metadata_interface::state::TokenMetadata> {
+    let spl = app.token_2022_query_token_metadata(Target::Spl, mint)?;
+    let pinocchio = app.token_2022_query_token_metadata(Target::Proxy, mint)?;
+
+    assert_eq!(pinocchio, spl);
+
+    Ok(spl)
+}
+
+fn initialize_token_metadata(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = prepare(&mut app, target)?;
+
+    let metadata = assert_token_metadata(&app, &mint)?;
+
+    assert_eq!(
+        metadata.update_authority,
+        to_optional_non_zero_pubkey(Some(&AppUser::Admin.pubkey()))
+    );
+    assert_eq!(metadata.mint.to_bytes(), mint);
+    assert_eq!(metadata.name, NAME);
+    assert_eq!(metadata.symbol, SYMBOL);
+    assert_eq!(metadata.uri, URI);
+    assert_eq!(metadata.additional_metadata, vec![]);
+
+    Ok(())
+}
+
+fn update_token_metadata_fields(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = prepare(&mut app, target)?;
+
+    app.token_2022_try_update_token_metadata_field(
+        target,
+        AppUser::Admin,
+        &mint,
+        Field::Name,
+        "Renamed Token",
+    )?;
+    app.token_2022_try_update_token_metadata_field(
+        target,
+        AppUser::Admin,
+        &mint,
+        Field::Uri,
+        "https://example.com/renamed.json",
+    )?;
+    app.token_2022_try_update_token_metadata_field(
+        target,
+        AppUser::Admin,
+        &mint,
+        Field::Key("color".to_string()),
+        "blue",
+    )?;
+    app.token_2022_try_update_token_metadata_field(
+        target,
+        AppUser::Admin,
+        &mint,
+        Field::Key("size".to_string()),
+        "42",
+    )?;
+    // an existing key is updated in place
+    app.token_2022_try_update_token_metadata_field(
+        target,
+        AppUser::Admin,
+        &mint,
+        Field::Key("color".to_string()),
+        "green",
+    )?;
+
+    let metadata = assert_token_metadata(&app, &mint)?;
+
+    assert_eq!(metadata.name, "Renamed Token");
+    assert_eq!(metadata.symbol, SYMBOL);
+    assert_eq!(metadata.uri, "https://example.com/renamed.json");
+    assert_eq!(
+        metadata.additional_metadata,
+        vec![
+            ("color".to_string(), "green".to_string()),
+            ("size".to_string(), "42".to_string()),
+        ]
+    );
+
+    Ok(())
+}
+
+fn remove_token_metadata_key(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = prepare(&mut app, target)?;
+
+    for (key, value) in [("color", "blue"), ("size", "42")] {
+        app.token_2022_try_update_token_metadata_field(
+            target,
+            AppUser::Admin,
+            &mint,
+            Field::Key(key.to_string()),
+            value,
+        )?;
+    }
+
+    app.token_2022_try_remove_token_metadata_key(target, AppUser::Admin, &mint, "color", false)?;
+
+    let metadata = assert_token_metadata(&app, &mint)?;
+
+    assert_eq!(
+        metadata.additional_metadata,
+        vec![("size".to_string(), "42".to_string())]
+    );
+
+    // a missing key can be removed only in idempotent mode
+    app.token_2022_try_remove_token_metadata_key(target, AppUser::Admin, &mint, "color", true)?;
+
+    let res = app
+        .token_2022_try_remove_token_metadata_key(target, AppUser::Admin, &mint, "color", false)
+        .unwrap_err();
+
+    // TokenMetadataError::KeyNotFound
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x35c2b5c2".to_string(),
+            index: None,
+        }
+    );
+
+    Ok(())
+}
+
+fn update_token_metadata_authority(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint = prepare(&mut app, target)?;
+
+    app.token_2022_try_update_token_metadata_authority(
+        target,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Alice.pubkey()),
+    )?;
+
+    let metadata = assert_token_metadata(&app, &mint)?;
+    assert_eq!(
+        metadata.update_authority,
+        to_optional_non_zero_pubkey(Some(&AppUser::Alice.pubkey()))
+    );
+
+    // the previous authority can't update the metadata anymore
+    let res = app
+        .token_2022_try_update_token_metadata_field(
+            target,
+            AppUser::Admin,
+            &mint,
+            Field::Symbol,
+            "ADM",
+        )
+        .unwrap_err();
+
+    // TokenMetadataError::IncorrectUpdateAuthority
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x35c2b5c0".to_string(),
+            index: None,
+        }
+    );
+
+    app.token_2022_try_update_token_metadata_field(
+        target,
+        AppUser::Alice,
+        &mint,
+        Field::Symbol,
+        "ALC",
+    )?;
+
+    // the metadata becomes immutable without the authority
+    app.token_2022_try_update_token_metadata_authority(target, AppUser::Alice, &mint, None)?;
+
+    let metadata = assert_token_metadata(&app, &mint)?;
+    assert_eq!(metadata.symbol, "ALC");
+    assert_eq!(metadata.update_authority, to_optional_non_zero_pubkey(None));
+
+    Ok(())
+}
+
+/// The proxy initializes a mint with its own metadata in one instruction, the payer funding
+/// the rent of the metadata Token-2022 reallocates the mint for
+#[test]
+fn initialize_metadata_with_pointer_proxy() -> TestResult<()> {
+    // the metadata brings the mint to the length of a multisig, which Token-2022 pads
+    const PADDED_NAME: &str = "Padded";
+    const PADDED_SYMBOL: &str = "PAD";
+    const PADDED_URI: &str = "https://example.com/pad.json";
+
+    let mut app = App::new(false);
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::MetadataPointer]),
+    )?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_metadata_with_pointer(
+        AppUser::Admin,
+        &mint,
+        6,
+        PADDED_NAME,
+        PADDED_SYMBOL,
+        PADDED_URI,
+    )?;
+
+    let account = app
+        .litesvm
+        .get_account(&pin_to_sol_pubkey(&mint))
+        .ok_or(TestError::from_raw_error("The mint isn't found"))?;
+    assert_eq!(account.data.len(), Multisig::LEN + 2);
+    assert_eq!(
+        account.lamports,
+        app.litesvm
+            .minimum_balance_for_rent_exemption(account.data.len())
+    );
+
+    let metadata = assert_token_metadata(&app, &mint)?;
+    assert_eq!(
+        metadata.update_authority,
+        to_optional_non_zero_pubkey(Some(&AppUser::Admin.pubkey()))
+    );
+    assert_eq!(metadata.mint.to_bytes(), mint);
+    assert_eq!(metadata.name, PADDED_NAME);
+    assert_eq!(metadata.symbol, PADDED_SYMBOL);
+    assert_eq!(metadata.uri, PADDED_URI);
+
+    Ok(())
+}
+
+#[test]
+fn initialize_token_metadata_spl() -> TestResult<()> {
+    initialize_token_metadata(Target::Spl)
+}
+
+#[test]
+fn initialize_token_metadata_proxy() -> TestResult<()> {
+    initialize_token_metadata(Target::Proxy)
+}
+
+#[test]
+fn update_token_metadata_fields_spl() -> TestResult<()> {
+    update_token_metadata_fields(Target::Spl)
+}
+
+#[test]
+fn update_token_metadata_fields_proxy() -> TestResult<()> {
+    update_token_metadata_fields(Target::Proxy)
+}
+
+#[test]
+fn remove_token_metadata_key_spl() -> TestResult<()> {
+    remove_token_metadata_key(Target::Spl)
+}
+
+#[test]
+fn remove_token_metadata_key_proxy() -> TestResult<()> {
+    remove_token_metadata_key(Target::Proxy)
+}
+
+#[test]
+fn update_token_metadata_authority_spl() -> TestResult<()> {
+    update_token_metadata_authority(Target::Spl)
+}
+
+#[test]
+fn update_token_metadata_authority_proxy() -> TestResult<()> {
+    update_token_metadata_authority(Target::Proxy)
+}
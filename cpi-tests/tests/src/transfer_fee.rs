@@ -0,0 +1,739 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            associated_token_account::Token2022AssociatedTokenAccountExtension,
+            initialize_mint::Token2022InitializeMintExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
+            transfer_fee::Token2022TransferFeeExtension,
+        },
+        suite::{
+            core::{extension::get_account_data, App, ComputeBudgetOptions},
+            rng::new_keypair,
+            types::{pin_to_sol_pubkey, AppUser, PinPubkey, Target, TestError, TestResult},
+            withheld_fees::WithheldFeeTracker,
+        },
+    },
+    pinocchio_token_2022::extension::{
+        tlv::extensions_from_bytes,
+        transfer_fee::state::{
+            expected_transfer_fee_from_bytes, net_amount_after_fee, TransferFeeAmount,
+            TransferFeeConfig,
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_address_lookup_table_interface::state::LOOKUP_TABLE_MAX_ADDRESSES,
+    solana_program::clock::Clock,
+    solana_signer::Signer,
+    spl_token_2022_interface::{
+        error::TokenError, extension::ExtensionType, instruction::AuthorityType,
+    },
+};
+
+const DECIMALS: u8 = 6;
+const TRANSFER_FEE_BASIS_POINTS: u16 = 100;
+const MAXIMUM_FEE: u64 = 5_000;
+/// Maximum size of a transaction
+const PACKET_DATA_SIZE: usize = 1_232;
+/// Base fee per signature
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Create a mint with 1% transfer fee controlled by Admin, and token accounts owned by
+/// Alice, Bob and Admin (fee receiver). Alice gets 1_000_000 tokens
+fn prepare(app: &mut App, target: Target) -> TestResult<([u8; 32], [u8; 32], [u8; 32], [u8; 32])> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::TransferFeeConfig]),
+    )?;
+    let mint = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_transfer_fee_config(
+        target,
+        AppUser::Admin,
+        &mint,
+        Some(&AppUser::Admin.pubkey()),
+        Some(&AppUser::Admin.pubkey()),
+        TRANSFER_FEE_BASIS_POINTS,
+        MAXIMUM_FEE,
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    let mut token_accounts = vec![];
+    for owner in [AppUser::Alice, AppUser::Bob, AppUser::Admin] {
+        let (_, keypair) = app.token_2022_try_create_and_init_token_account(
+            AppUser::Admin,
+            &owner.pubkey(),
+            &mint,
+            &[ExtensionType::TransferFeeAmount],
+        )?;
+        token_accounts.push(keypair.pubkey().to_bytes());
+    }
+    let [alice_account, bob_account, fee_receiver] = token_accounts[..] else {
+        unreachable!()
+    };
+
+    app.token_2022_try_mint_to(target, AppUser::Admin, &mint, &alice_account, 1_000_000)?;
+
+    Ok((mint, alice_account, bob_account, fee_receiver))
+}
+
+/// Transfer fee for `amount` according to the current mint config
+fn expected_fee(app: &App, target: Target, mint: &[u8; 32], amount: u64) -> TestResult<u64> {
+    let epoch = app.litesvm.get_sysvar::<Clock>().epoch;
+
+    Ok(app
+        .token_2022_query_transfer_fee_config(target, mint)?
+        .calculate_epoch_fee(epoch, amount)
+        .unwrap())
+}
+
+fn initialize_transfer_fee_config(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, ..) = prepare(&mut app, target)?;
+
+    let config = app.token_2022_query_transfer_fee_config(target, &mint)?;
+
+    assert_eq!(
+        Option::<solana_address::Address>::from(config.transfer_fee_config_authority)
+            .map(|x| x.to_bytes()),
+        Some(AppUser::Admin.pubkey())
+    );
+    assert_eq!(
+        Option::<solana_address::Address>::from(config.withdraw_withheld_authority)
+            .map(|x| x.to_bytes()),
+        Some(AppUser::Admin.pubkey())
+    );
+    assert_eq!(u64::from(config.withheld_amount), 0);
+    assert_eq!(
+        u16::from(config.newer_transfer_fee.transfer_fee_basis_points),
+        TRANSFER_FEE_BASIS_POINTS
+    );
+    assert_eq!(
+        u64::from(config.newer_transfer_fee.maximum_fee),
+        MAXIMUM_FEE
+    );
+
+    Ok(())
+}
+
+fn transfer_checked_with_fee(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, bob_account, _) = prepare(&mut app, target)?;
+
+    // 1% of the amount
+    let amount = 100_000;
+    let fee = expected_fee(&app, target, &mint, amount)?;
+    assert_eq!(fee, 1_000);
+
+    app.token_2022_try_transfer_checked_with_fee(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        amount,
+        DECIMALS,
+        fee,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(target, &alice_account)?
+            .amount,
+        900_000
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &bob_account)?
+            .amount,
+        amount - fee
+    );
+
+    // the pinocchio helper credits what the recipient receives
+    let epoch = app.litesvm.get_sysvar::<Clock>().epoch;
+    let data = get_account_data(&app, &mint)?;
+    let config = TransferFeeConfig::from_bytes(&data).map_err(TestError::from_raw_error)?;
+    assert_eq!(net_amount_after_fee(amount, config, epoch), Some(amount - fee));
+    assert_eq!(
+        app.token_2022_query_withheld_amount(target, &bob_account)?,
+        fee
+    );
+
+    // the fee is capped by the maximum fee
+    let amount = 800_000;
+    let fee = expected_fee(&app, target, &mint, amount)?;
+    assert_eq!(fee, MAXIMUM_FEE);
+
+    app.token_2022_try_transfer_checked_with_fee(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        amount,
+        DECIMALS,
+        fee,
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_withheld_amount(target, &bob_account)?,
+        1_000 + MAXIMUM_FEE
+    );
+
+    Ok(())
+}
+
+/// The associated token account holds `ImmutableOwner` before `TransferFeeAmount`, the
+/// withheld amount must be found behind it
+fn withheld_amount_of_associated_token_account(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, ..) = prepare(&mut app, target)?;
+    let (_, bob_ata) = app.token_2022_try_create_associated_token_account_idempotent(
+        AppUser::Admin,
+        AppUser::Bob,
+        &mint,
+    )?;
+
+    let amount = 100_000;
+    let fee = expected_fee(&app, target, &mint, amount)?;
+    app.token_2022_try_transfer_checked_with_fee(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_ata,
+        amount,
+        DECIMALS,
+        fee,
+    )?;
+
+    let data = get_account_data(&app, &bob_ata)?;
+    assert_eq!(
+        extensions_from_bytes(&data)
+            .map(|(tlv_type, _)| tlv_type)
+            .collect::<Vec<_>>(),
+        vec![
+            ExtensionType::ImmutableOwner as u16,
+            ExtensionType::TransferFeeAmount as u16
+        ]
+    );
+
+    let withheld = TransferFeeAmount::from_bytes(&data).map_err(TestError::from_raw_error)?;
+    assert_eq!(withheld.withheld_amount(), fee);
+    assert_eq!(app.token_2022_query_withheld_amount(target, &bob_ata)?, fee);
+
+    Ok(())
+}
+
+fn set_transfer_fee(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, ..) = prepare(&mut app, target)?;
+
+    app.token_2022_try_set_transfer_fee(target, AppUser::Admin, &mint, 200, 2_000)?;
+
+    let config = app.token_2022_query_transfer_fee_config(target, &mint)?;
+
+    assert_eq!(
+        u16::from(config.newer_transfer_fee.transfer_fee_basis_points),
+        200
+    );
+    assert_eq!(u64::from(config.newer_transfer_fee.maximum_fee), 2_000);
+    // the new fee takes effect 2 epochs later, the current one stays in place until then
+    assert_eq!(
+        u16::from(config.older_transfer_fee.transfer_fee_basis_points),
+        TRANSFER_FEE_BASIS_POINTS
+    );
+    assert_eq!(expected_fee(&app, target, &mint, 100_000)?, 1_000);
+
+    Ok(())
+}
+
+fn set_transfer_fee_config_authority(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, ..) = prepare(&mut app, target)?;
+
+    app.token_2022_try_set_authority(
+        target,
+        AppUser::Admin,
+        &mint,
+        AuthorityType::TransferFeeConfig,
+        Some(&AppUser::Bob.pubkey()),
+    )?;
+
+    let config = app.token_2022_query_transfer_fee_config(target, &mint)?;
+    assert_eq!(
+        Option::<solana_address::Address>::from(config.transfer_fee_config_authority)
+            .map(|x| x.to_bytes()),
+        Some(AppUser::Bob.pubkey())
+    );
+    // the withdraw withheld authority is unchanged
+    assert_eq!(
+        Option::<solana_address::Address>::from(config.withdraw_withheld_authority)
+            .map(|x| x.to_bytes()),
+        Some(AppUser::Admin.pubkey())
+    );
+
+    // only the new authority can set the fee
+    assert!(app
+        .token_2022_try_set_transfer_fee(target, AppUser::Admin, &mint, 200, 2_000)
+        .is_err());
+    app.token_2022_try_set_transfer_fee(target, AppUser::Bob, &mint, 200, 2_000)?;
+
+    Ok(())
+}
+
+fn set_transfer_fee_across_epochs(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, bob_account, _) = prepare(&mut app, target)?;
+    let epoch = app.litesvm.get_sysvar::<Clock>().epoch;
+
+    app.token_2022_try_set_transfer_fee(target, AppUser::Admin, &mint, 200, 2_000)?;
+
+    // the older fee is used until the newer one takes effect 2 epochs later
+    let amount = 50_000;
+    for (epoch, fee) in [(epoch, 500), (epoch + 1, 500), (epoch + 2, 1_000)] {
+        app.warp_to_epoch(epoch);
+
+        assert_eq!(expected_fee(&app, target, &mint, amount)?, fee);
+        // the pinocchio fee math must agree with the program
+        let data = get_account_data(&app, &mint)?;
+        assert_eq!(
+            TransferFeeConfig::from_bytes(&data)
+                .map_err(TestError::from_raw_error)?
+                .calculate_epoch_fee(epoch, amount),
+            Some(fee)
+        );
+        assert_eq!(expected_transfer_fee_from_bytes(&data, amount, epoch), Ok(fee));
+
+        app.token_2022_try_transfer_checked_with_fee(
+            target,
+            AppUser::Alice,
+            &alice_account,
+            &mint,
+            &bob_account,
+            amount,
+            DECIMALS,
+            fee,
+        )?;
+    }
+
+    assert_eq!(
+        app.token_2022_query_withheld_amount(target, &bob_account)?,
+        2_000
+    );
+
+    // the older fee isn't accepted anymore
+    assert_eq!(
+        app.token_2022_try_transfer_checked_with_fee(
+            target,
+            AppUser::Alice,
+            &alice_account,
+            &mint,
+            &bob_account,
+            amount,
+            DECIMALS,
+            500,
+        )
+        .unwrap_err(),
+        TestError::from_token_error(TokenError::FeeMismatch)
+    );
+
+    Ok(())
+}
+
+fn withdraw_harvest_withheld_tokens(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, bob_account, fee_receiver) = prepare(&mut app, target)?;
+
+    let amount = 100_000;
+    let fee = expected_fee(&app, target, &mint, amount)?;
+
+    for _ in 0..2 {
+        app.token_2022_try_transfer_checked_with_fee(
+            target,
+            AppUser::Alice,
+            &alice_account,
+            &mint,
+            &bob_account,
+            amount,
+            DECIMALS,
+            fee,
+        )?;
+    }
+    assert_eq!(
+        app.token_2022_query_withheld_amount(target, &bob_account)?,
+        2 * fee
+    );
+
+    // withdraw from accounts
+    app.token_2022_try_withdraw_withheld_tokens_from_accounts(
+        target,
+        AppUser::Admin,
+        &mint,
+        &fee_receiver,
+        &[bob_account],
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_withheld_amount(target, &bob_account)?,
+        0
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &fee_receiver)?
+            .amount,
+        2 * fee
+    );
+
+    app.token_2022_try_transfer_checked_with_fee(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        amount,
+        DECIMALS,
+        fee,
+    )?;
+
+    // harvest to mint
+    app.token_2022_try_harvest_withheld_tokens_to_mint(
+        target,
+        AppUser::Bob,
+        &mint,
+        &[bob_account],
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_withheld_amount(target, &bob_account)?,
+        0
+    );
+    assert_eq!(
+        u64::from(
+            app.token_2022_query_transfer_fee_config(target, &mint)?
+                .withheld_amount
+        ),
+        fee
+    );
+
+    // withdraw from mint
+    app.token_2022_try_withdraw_withheld_tokens_from_mint(
+        target,
+        AppUser::Admin,
+        &mint,
+        &fee_receiver,
+    )?;
+
+    assert_eq!(
+        u64::from(
+            app.token_2022_query_transfer_fee_config(target, &mint)?
+                .withheld_amount
+        ),
+        0
+    );
+    assert_eq!(
+        app.token_2022_query_token_account(target, &fee_receiver)?
+            .amount,
+        3 * fee
+    );
+
+    Ok(())
+}
+
+/// Withdraw from more source accounts than a legacy transaction can list, looking them up
+/// in a full address lookup table
+fn withdraw_withheld_tokens_from_many_accounts(target: Target) -> TestResult<()> {
+    const SOURCES: usize = 40;
+
+    let mut app = App::new(false);
+    let (mint, alice_account, _, fee_receiver) = prepare(&mut app, target)?;
+
+    let amount = 10_000;
+    let fee = expected_fee(&app, target, &mint, amount)?;
+
+    let mut sources = vec![];
+    for _ in 0..SOURCES {
+        let (_, keypair) = app.token_2022_try_create_and_init_token_account(
+            AppUser::Admin,
+            &AppUser::Bob.pubkey(),
+            &mint,
+            &[ExtensionType::TransferFeeAmount],
+        )?;
+        let source = keypair.pubkey().to_bytes();
+
+        app.token_2022_try_transfer_checked_with_fee(
+            target,
+            AppUser::Alice,
+            &alice_account,
+            &mint,
+            &source,
+            amount,
+            DECIMALS,
+            fee,
+        )?;
+        sources.push(source);
+    }
+
+    // the source addresses alone don't fit in a legacy transaction, they are the last
+    // entries of the table
+    assert!(SOURCES * 32 > PACKET_DATA_SIZE);
+    let mut addresses: Vec<_> = (SOURCES..LOOKUP_TABLE_MAX_ADDRESSES)
+        .map(|_| new_keypair().pubkey())
+        .collect();
+    addresses.extend(sources.iter().map(pin_to_sol_pubkey));
+    app.create_address_lookup_table(&addresses)?;
+
+    app.token_2022_try_withdraw_withheld_tokens_from_accounts(
+        target,
+        AppUser::Admin,
+        &mint,
+        &fee_receiver,
+        &sources,
+    )?;
+
+    for source in &sources {
+        assert_eq!(app.token_2022_query_withheld_amount(target, source)?, 0);
+    }
+    assert_eq!(
+        app.token_2022_query_token_account(target, &fee_receiver)?
+            .amount,
+        SOURCES as u64 * fee
+    );
+
+    Ok(())
+}
+
+/// Set the fee with the compute budget instructions prepended, running out of compute
+/// units first and paying a priority fee then
+fn set_transfer_fee_with_compute_budget(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, ..) = prepare(&mut app, target)?;
+
+    app.compute_budget = ComputeBudgetOptions {
+        compute_unit_limit: Some(1_000),
+        ..Default::default()
+    };
+    let err = app
+        .token_2022_try_set_transfer_fee(target, AppUser::Admin, &mint, 200, 2_000)
+        .unwrap_err();
+    assert!(
+        err.info
+            .starts_with("exceeded CUs meter at BPF instruction"),
+        "{:?}",
+        err
+    );
+
+    app.compute_budget = ComputeBudgetOptions {
+        compute_unit_limit: Some(200_000),
+        heap_frame_bytes: Some(256 * 1_024),
+        compute_unit_price: Some(1_000_000),
+    };
+    let balance_before = app.get_coin_balance(&pin_to_sol_pubkey(&AppUser::Admin.pubkey()));
+    app.token_2022_try_set_transfer_fee(target, AppUser::Admin, &mint, 200, 2_000)?;
+    let balance_after = app.get_coin_balance(&pin_to_sol_pubkey(&AppUser::Admin.pubkey()));
+
+    // 1 lamport per compute unit requested
+    assert_eq!(
+        balance_before - balance_after,
+        LAMPORTS_PER_SIGNATURE + 200_000
+    );
+
+    let config = app.token_2022_query_transfer_fee_config(target, &mint)?;
+    assert_eq!(u64::from(config.newer_transfer_fee.maximum_fee), 2_000);
+
+    Ok(())
+}
+
+/// Track the withheld amounts across transfers in both directions, a fee change, a
+/// harvest and both withdrawals
+fn withheld_fee_accounting(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let (mint, alice_account, bob_account, fee_receiver) = prepare(&mut app, target)?;
+    let mut tracker = WithheldFeeTracker::new(&mint);
+
+    // 1% and the maximum fee
+    for amount in [100_000, 800_000] {
+        tracker.try_transfer_checked_with_fee(
+            &mut app,
+            target,
+            AppUser::Alice,
+            &alice_account,
+            &bob_account,
+            amount,
+        )?;
+    }
+    tracker.try_transfer_checked_with_fee(
+        &mut app,
+        target,
+        AppUser::Bob,
+        &bob_account,
+        &alice_account,
+        10_000,
+    )?;
+    assert_eq!(tracker.accounts[&bob_account], 1_000 + MAXIMUM_FEE);
+    assert_eq!(tracker.accounts[&alice_account], 100);
+    tracker.assert_withheld(&app, target)?;
+
+    // 2% once the newer fee takes effect
+    let epoch = app.litesvm.get_sysvar::<Clock>().epoch;
+    app.token_2022_try_set_transfer_fee(target, AppUser::Admin, &mint, 200, 2_000)?;
+    app.warp_to_epoch(epoch + 2);
+
+    tracker.try_transfer_checked_with_fee(
+        &mut app,
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &bob_account,
+        50_000,
+    )?;
+    assert_eq!(tracker.accounts[&bob_account], 1_000 + MAXIMUM_FEE + 1_000);
+    tracker.assert_withheld(&app, target)?;
+
+    let total_withheld = tracker.total_withheld();
+
+    app.token_2022_try_harvest_withheld_tokens_to_mint(
+        target,
+        AppUser::Bob,
+        &mint,
+        &[bob_account],
+    )?;
+    tracker.record_harvest_to_mint(&[bob_account]);
+    tracker.assert_withheld(&app, target)?;
+
+    app.token_2022_try_withdraw_withheld_tokens_from_accounts(
+        target,
+        AppUser::Admin,
+        &mint,
+        &fee_receiver,
+        &[alice_account],
+    )?;
+    let mut withdrawn = tracker.record_withdraw_from_accounts(&[alice_account]);
+    tracker.assert_withheld(&app, target)?;
+
+    app.token_2022_try_withdraw_withheld_tokens_from_mint(
+        target,
+        AppUser::Admin,
+        &mint,
+        &fee_receiver,
+    )?;
+    withdrawn += tracker.record_withdraw_from_mint();
+    tracker.assert_withheld(&app, target)?;
+
+    assert_eq!(tracker.total_withheld(), 0);
+    assert_eq!(withdrawn, total_withheld);
+    assert_eq!(
+        app.token_2022_query_token_account(target, &fee_receiver)?
+            .amount,
+        withdrawn
+    );
+
+    Ok(())
+}
+
+#[test]
+fn initialize_transfer_fee_config_spl() -> TestResult<()> {
+    initialize_transfer_fee_config(Target::Spl)
+}
+
+#[test]
+fn initialize_transfer_fee_config_proxy() -> TestResult<()> {
+    initialize_transfer_fee_config(Target::Proxy)
+}
+
+#[test]
+fn transfer_checked_with_fee_spl() -> TestResult<()> {
+    transfer_checked_with_fee(Target::Spl)
+}
+
+#[test]
+fn transfer_checked_with_fee_proxy() -> TestResult<()> {
+    transfer_checked_with_fee(Target::Proxy)
+}
+
+#[test]
+fn withheld_amount_of_associated_token_account_spl() -> TestResult<()> {
+    withheld_amount_of_associated_token_account(Target::Spl)
+}
+
+#[test]
+fn withheld_amount_of_associated_token_account_proxy() -> TestResult<()> {
+    withheld_amount_of_associated_token_account(Target::Proxy)
+}
+
+#[test]
+fn set_transfer_fee_spl() -> TestResult<()> {
+    set_transfer_fee(Target::Spl)
+}
+
+#[test]
+fn set_transfer_fee_proxy() -> TestResult<()> {
+    set_transfer_fee(Target::Proxy)
+}
+
+#[test]
+fn set_transfer_fee_config_authority_spl() -> TestResult<()> {
+    set_transfer_fee_config_authority(Target::Spl)
+}
+
+#[test]
+fn set_transfer_fee_config_authority_proxy() -> TestResult<()> {
+    set_transfer_fee_config_authority(Target::Proxy)
+}
+
+#[test]
+fn set_transfer_fee_across_epochs_spl() -> TestResult<()> {
+    set_transfer_fee_across_epochs(Target::Spl)
+}
+
+#[test]
+fn set_transfer_fee_across_epochs_proxy() -> TestResult<()> {
+    set_transfer_fee_across_epochs(Target::Proxy)
+}
+
+#[test]
+fn withdraw_harvest_withheld_tokens_spl() -> TestResult<()> {
+    withdraw_harvest_withheld_tokens(Target::Spl)
+}
+
+#[test]
+fn withdraw_harvest_withheld_tokens_proxy() -> TestResult<()> {
+    withdraw_harvest_withheld_tokens(Target::Proxy)
+}
+
+#[test]
+fn withdraw_withheld_tokens_from_many_accounts_spl() -> TestResult<()> {
+    withdraw_withheld_tokens_from_many_accounts(Target::Spl)
+}
+
+#[test]
+fn withdraw_withheld_tokens_from_many_accounts_proxy() -> TestResult<()> {
+    withdraw_withheld_tokens_from_many_accounts(Target::Proxy)
+}
+
+#[test]
+fn set_transfer_fee_with_compute_budget_spl() -> TestResult<()> {
+    set_transfer_fee_with_compute_budget(Target::Spl)
+}
+
+#[test]
+fn set_transfer_fee_with_compute_budget_proxy() -> TestResult<()> {
+    set_transfer_fee_with_compute_budget(Target::Proxy)
+}
+
+#[test]
+fn withheld_fee_accounting_spl() -> TestResult<()> {
+    withheld_fee_accounting(Target::Spl)
+}
+
+#[test]
+fn withheld_fee_accounting_proxy() -> TestResult<()> {
+    withheld_fee_accounting(Target::Proxy)
+}
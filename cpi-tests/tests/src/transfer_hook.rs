@@ -3,13 +3,22 @@ use {
         extensions::token_2022::{
             initialize_mint::Token2022InitializeMintExtension,
             initialize_multisig::Token2022InitializeMultisigExtension,
+            initialize_token_account::Token2022InitializeAccountExtension,
+            mint_factory::{ExtensionSpec, Token2022MintFactoryExtension},
+            token_account::Token2022TokenAccountExtension,
+            token_instructions::Token2022TokenInstructionsExtension,
             transfer_hook::Token2022TransferHookExtension,
         },
         suite::{
             core::App,
-            types::{pin_pubkey_to_addr, AppUser, PinPubkey, Target, TestResult},
+            types::{
+                pin_pubkey_to_addr, pin_to_sol_pubkey, sol_to_pin_pubkey, AppUser, PinPubkey,
+                Target, TestError, TestResult,
+            },
         },
     },
+    dummy_transfer_hook::EXECUTE_LOG_PREFIX,
+    pinocchio_token_2022::transfer_hook_interface::{ExtraAccountMeta, Seed},
     pretty_assertions::assert_eq,
     solana_signer::Signer,
     spl_pod::optional_keys::OptionalNonZeroPubkey,
@@ -396,3 +405,349 @@ fn update_transfer_hook_multisig_proxy() -> TestResult<()> {
 
     Ok(())
 }
+
+/// Query the transfer hook through both the SPL and the pinocchio parsers
+fn assert_transfer_hook(app: &App, mint: &[u8; 32], expected: &TransferHook) -> TestResult<()> {
+    assert_eq!(
+        &app.token_2022_query_transfer_hook(Target::Spl, mint)?,
+        expected
+    );
+    assert_eq!(
+        &app.token_2022_query_transfer_hook(Target::Proxy, mint)?,
+        expected
+    );
+
+    Ok(())
+}
+
+/// Create a mint with transfer hook controlled by Alice and pointing to Bob's "program"
+fn prepare_transfer_hook(app: &mut App, target: Target) -> TestResult<[u8; 32]> {
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::TransferHook]),
+    )?;
+    let mint_pubkey = mint_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_initialize_transfer_hook(
+        target,
+        AppUser::Admin,
+        &mint_pubkey,
+        Some(&AppUser::Alice.pubkey()),
+        Some(&AppUser::Bob.pubkey()),
+    )?;
+
+    app.token_2022_try_initialize_mint(
+        target,
+        AppUser::Admin,
+        &mint_pubkey,
+        6,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    Ok(mint_pubkey)
+}
+
+fn disable_transfer_hook(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint_pubkey = &prepare_transfer_hook(&mut app, target)?;
+    let transfer_hook_authority = &AppUser::Alice.pubkey();
+
+    assert_transfer_hook(
+        &app,
+        mint_pubkey,
+        &TransferHook {
+            authority: OptionalNonZeroPubkey(pin_pubkey_to_addr(transfer_hook_authority)),
+            program_id: OptionalNonZeroPubkey(pin_pubkey_to_addr(&AppUser::Bob.pubkey())),
+        },
+    )?;
+
+    // updating the program to None disables the hook
+    app.token_2022_try_update_transfer_hook(
+        target,
+        AppUser::Alice,
+        mint_pubkey,
+        transfer_hook_authority,
+        None,
+    )?;
+
+    assert_transfer_hook(
+        &app,
+        mint_pubkey,
+        &TransferHook {
+            authority: OptionalNonZeroPubkey(pin_pubkey_to_addr(transfer_hook_authority)),
+            program_id: OptionalNonZeroPubkey::default(),
+        },
+    )?;
+
+    Ok(())
+}
+
+fn update_transfer_hook_with_wrong_authority(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let mint_pubkey = &prepare_transfer_hook(&mut app, target)?;
+
+    let res = app
+        .token_2022_try_update_transfer_hook(
+            target,
+            AppUser::Bob,
+            mint_pubkey,
+            &AppUser::Bob.pubkey(),
+            None,
+        )
+        .unwrap_err();
+    // TokenError::OwnerMismatch
+    assert_eq!(
+        res,
+        TestError {
+            info: "custom program error: 0x4".to_string(),
+            index: None,
+        },
+    );
+
+    // the state is unchanged
+    assert_transfer_hook(
+        &app,
+        mint_pubkey,
+        &TransferHook {
+            authority: OptionalNonZeroPubkey(pin_pubkey_to_addr(&AppUser::Alice.pubkey())),
+            program_id: OptionalNonZeroPubkey(pin_pubkey_to_addr(&AppUser::Bob.pubkey())),
+        },
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn disable_transfer_hook_spl() -> TestResult<()> {
+    disable_transfer_hook(Target::Spl)
+}
+
+#[test]
+fn disable_transfer_hook_proxy() -> TestResult<()> {
+    disable_transfer_hook(Target::Proxy)
+}
+
+#[test]
+fn update_transfer_hook_with_wrong_authority_spl() -> TestResult<()> {
+    update_transfer_hook_with_wrong_authority(Target::Spl)
+}
+
+#[test]
+fn update_transfer_hook_with_wrong_authority_proxy() -> TestResult<()> {
+    update_transfer_hook_with_wrong_authority(Target::Proxy)
+}
+
+fn transfer_checked_with_hook(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+
+    let mint = app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[ExtensionSpec::TransferHook {
+            authority: Some(admin),
+            program_id: None,
+        }],
+        6,
+        &admin,
+        None,
+    )?;
+
+    let (_, alice_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint,
+        &[ExtensionType::TransferHookAccount],
+    )?;
+    let alice_account = alice_keypair.pubkey().to_bytes();
+
+    let (_, bob_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        &mint,
+        &[ExtensionType::TransferHookAccount],
+    )?;
+    let bob_account = bob_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(Target::Spl, AppUser::Admin, &mint, &alice_account, 1_000)?;
+
+    // no hook program, so a plain transfer is sent
+    app.token_2022_try_transfer_checked_with_hook(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        400,
+        6,
+        &[],
+    )?;
+
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Spl, &bob_account)?
+            .amount,
+        400
+    );
+
+    // the hook program and the validation account are required now
+    app.token_2022_try_update_transfer_hook(
+        Target::Spl,
+        AppUser::Admin,
+        &mint,
+        &admin,
+        Some(&AppUser::Bob.pubkey()),
+    )?;
+
+    let res = app.token_2022_try_transfer_checked_with_hook(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        400,
+        6,
+        &[],
+    );
+    assert!(res.is_err());
+
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Spl, &alice_account)?
+            .amount,
+        600
+    );
+
+    Ok(())
+}
+
+#[test]
+fn transfer_checked_with_hook_spl() -> TestResult<()> {
+    transfer_checked_with_hook(Target::Spl)
+}
+
+#[test]
+fn transfer_checked_with_hook_proxy() -> TestResult<()> {
+    transfer_checked_with_hook(Target::Proxy)
+}
+
+fn transfer_checked_with_dummy_hook(target: Target) -> TestResult<()> {
+    let mut app = App::new(false);
+    let admin = AppUser::Admin.pubkey();
+    let hook_program = app.program_id.dummy_transfer_hook;
+
+    let mint = app.token_2022_try_create_mint_with_extensions(
+        Target::Spl,
+        &[ExtensionSpec::TransferHook {
+            authority: Some(admin),
+            program_id: Some(sol_to_pin_pubkey(&hook_program)),
+        }],
+        6,
+        &admin,
+        None,
+    )?;
+
+    let (_, alice_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Alice.pubkey(),
+        &mint,
+        &[ExtensionType::TransferHookAccount],
+    )?;
+    let alice_account = alice_keypair.pubkey().to_bytes();
+
+    let (_, bob_keypair) = app.token_2022_try_create_and_init_token_account(
+        AppUser::Admin,
+        &AppUser::Bob.pubkey(),
+        &mint,
+        &[ExtensionType::TransferHookAccount],
+    )?;
+    let bob_account = bob_keypair.pubkey().to_bytes();
+
+    app.token_2022_try_mint_to(Target::Spl, AppUser::Admin, &mint, &alice_account, 1_000)?;
+
+    // a fixed account and a PDA of the hook program derived from the source owner
+    let fixed_account = AppUser::Bob.pubkey();
+    let (owner_pda, _) = solana_pubkey::Pubkey::find_program_address(
+        &[b"owner", &AppUser::Alice.pubkey()],
+        &hook_program,
+    );
+
+    app.token_2022_try_initialize_extra_account_meta_list(
+        AppUser::Admin,
+        &mint,
+        &[
+            ExtraAccountMeta::new_with_pubkey(&fixed_account, false, false),
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"owner" },
+                    Seed::AccountKey { index: 3 },
+                ],
+                false,
+                false,
+            )
+            .map_err(TestError::from_raw_error)?,
+        ],
+    )?;
+
+    let extra_accounts = [
+        solana_instruction::AccountMeta::new_readonly(pin_to_sol_pubkey(&fixed_account), false),
+        solana_instruction::AccountMeta::new_readonly(owner_pda, false),
+    ];
+    let hook_accounts = [
+        solana_instruction::AccountMeta::new_readonly(hook_program, false),
+        solana_instruction::AccountMeta::new_readonly(
+            pin_to_sol_pubkey(&app.token_2022_query_extra_account_metas_address(&mint)),
+            false,
+        ),
+    ];
+
+    let res = app.token_2022_try_transfer_checked_with_hook(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        400,
+        6,
+        &[&extra_accounts[..], &hook_accounts].concat(),
+    )?;
+
+    assert!(res
+        .logs
+        .contains(&format!("Program log: {}{}", EXECUTE_LOG_PREFIX, 400)));
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Spl, &bob_account)?
+            .amount,
+        400
+    );
+
+    // the PDA can't be resolved without its account
+    let res = app.token_2022_try_transfer_checked_with_hook(
+        target,
+        AppUser::Alice,
+        &alice_account,
+        &mint,
+        &bob_account,
+        400,
+        6,
+        &[&extra_accounts[..1], &hook_accounts].concat(),
+    );
+    assert!(res.is_err());
+
+    assert_eq!(
+        app.token_2022_query_token_account(Target::Spl, &alice_account)?
+            .amount,
+        600
+    );
+
+    Ok(())
+}
+
+#[test]
+fn transfer_checked_with_dummy_hook_spl() -> TestResult<()> {
+    transfer_checked_with_dummy_hook(Target::Spl)
+}
+
+#[test]
+fn transfer_checked_with_dummy_hook_proxy() -> TestResult<()> {
+    transfer_checked_with_dummy_hook(Target::Proxy)
+}
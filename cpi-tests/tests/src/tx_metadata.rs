@@ -0,0 +1,111 @@
+use {
+    crate::helpers::{
+        extensions::token_2022::{
+            initialize_mint::Token2022InitializeMintExtension,
+            metadata_pointer::Token2022MetadataPointerExtension,
+        },
+        suite::{
+            core::App,
+            tx_metadata::{
+                assert_cpi_to, assert_no_cpi, get_cpis, get_inner_instruction_count,
+                get_instruction_compute_units, get_invocations, get_program_logs,
+            },
+            types::{AppUser, PinPubkey, Target, TestResult},
+        },
+    },
+    pretty_assertions::assert_eq,
+    solana_signer::Signer,
+    spl_token_2022_interface::extension::ExtensionType,
+};
+
+const DECIMALS: u8 = 6;
+
+#[test]
+fn initialize_mint_spl_has_no_cpi() -> TestResult<()> {
+    let mut app = App::new(false);
+    let token_2022_program = app.program_id.token_2022_program;
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(AppUser::Admin, None, None)?;
+    let mint = &mint_keypair.pubkey().to_bytes();
+
+    let tx = app.token_2022_try_initialize_mint(
+        Target::Spl,
+        AppUser::Admin,
+        mint,
+        DECIMALS,
+        &AppUser::Admin.pubkey(),
+        None,
+    )?;
+
+    assert_no_cpi(&tx);
+    assert_eq!(get_inner_instruction_count(&tx), 0);
+
+    let invocations = get_invocations(&tx);
+    assert_eq!(invocations.len(), 1);
+    assert_eq!(invocations[0].program_id, token_2022_program);
+    assert_eq!(invocations[0].stack_height, 1);
+    assert_eq!(
+        get_instruction_compute_units(&tx),
+        vec![tx.compute_units_consumed]
+    );
+    assert!(get_program_logs(&tx).contains(&"Instruction: InitializeMint".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn initialize_mint_proxy_forwards_to_token_2022() -> TestResult<()> {
+    let mut app = App::new(false);
+    let token_2022_program = app.program_id.token_2022_program;
+    let token_2022_proxy = app.program_id.token_2022_proxy;
+
+    let (_, mint_keypair) = app.token_2022_try_create_mint_account(
+        AppUser::Admin,
+        None,
+        Some(&[ExtensionType::MetadataPointer]),
+    )?;
+    let mint = &mint_keypair.pubkey().to_bytes();
+
+    let txs = [
+        app.token_2022_try_initialize_metadata_pointer(
+            Target::Proxy,
+            AppUser::Admin,
+            mint,
+            Some(&AppUser::Admin.pubkey()),
+            None,
+        )?,
+        app.token_2022_try_initialize_mint(
+            Target::Proxy,
+            AppUser::Admin,
+            mint,
+            DECIMALS,
+            &AppUser::Admin.pubkey(),
+            None,
+        )?,
+    ];
+
+    for tx in &txs {
+        assert_cpi_to(tx, &token_2022_program);
+        assert_eq!(get_inner_instruction_count(tx), 1);
+
+        let invocations = get_invocations(tx);
+        assert_eq!(
+            invocations
+                .iter()
+                .map(|x| (x.program_id, x.stack_height))
+                .collect::<Vec<_>>(),
+            vec![(token_2022_proxy, 1), (token_2022_program, 2)]
+        );
+
+        // the proxy is charged for the CPI as well
+        let [proxy_units, token_2022_units] = [&invocations[0], &invocations[1]]
+            .map(|x| x.compute_units.expect("compute units aren't logged"));
+        assert!(proxy_units > token_2022_units);
+        assert_eq!(get_instruction_compute_units(tx), vec![proxy_units]);
+        assert_eq!(get_cpis(tx), invocations[1..].to_vec());
+    }
+
+    assert!(get_program_logs(&txs[1]).contains(&"Instruction: InitializeMint".to_string()));
+
+    Ok(())
+}
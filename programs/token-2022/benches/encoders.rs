@@ -0,0 +1,157 @@
+//! Instruction data encoders of the wrappers.
+
+// host only, see the `criterion` dev-dependency
+#![cfg_attr(target_os = "solana", no_main)]
+#![cfg(not(target_os = "solana"))]
+
+use {
+    criterion::{criterion_group, criterion_main, Criterion},
+    pinocchio_token_2022::{
+        extension::{
+            interest_bearing_mint::interest_bearing_mint_initialize_instruction_data,
+            metadata_pointer::{self, offset_metadata_pointer_initialize},
+            mint_close_authority::{self, offset_mint_close_authority_initialize},
+            scaled_ui_amount::{
+                scaled_ui_amount_update_multiplier_instruction_data, ScaledUiAmountInstruction,
+            },
+            token_group::{initialize_group_instruction_data, offset_token_group_initialize_group},
+            token_metadata::{
+                initialize_token_metadata_instruction_data,
+                update_token_metadata_field_instruction_data, Field,
+                MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+            },
+            transfer_fee::{
+                initialize_transfer_fee_config_instruction_data, offset_transfer_fee_initialize,
+                offset_transfer_fee_transfer_checked_with_fee,
+                transfer_checked_with_fee_instruction_data,
+            },
+        },
+        instructions::withdraw_excess_lamports_instruction_data,
+        transfer_hook_interface::{
+            initialize_extra_account_meta_list_instruction_data, ExtraAccountMeta,
+            ExtraAccountMetaList,
+        },
+    },
+    std::hint::black_box,
+};
+
+const AUTHORITY: [u8; 32] = [1; 32];
+
+fn encoders(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encoders");
+
+    group.bench_function("initialize_transfer_fee_config", |b| {
+        let mut buffer = [0u8; offset_transfer_fee_initialize::END as usize];
+        b.iter(|| {
+            black_box(initialize_transfer_fee_config_instruction_data(
+                &mut buffer,
+                black_box(Some(&AUTHORITY)),
+                black_box(Some(&AUTHORITY)),
+                black_box(50),
+                black_box(5_000),
+            ));
+        })
+    });
+    group.bench_function("transfer_checked_with_fee", |b| {
+        let mut buffer = [0u8; offset_transfer_fee_transfer_checked_with_fee::END as usize];
+        b.iter(|| {
+            black_box(transfer_checked_with_fee_instruction_data(
+                &mut buffer,
+                black_box(1_000_000),
+                black_box(6),
+                black_box(5_000),
+            ));
+        })
+    });
+    group.bench_function("initialize_mint_close_authority", |b| {
+        let mut buffer = [0u8; offset_mint_close_authority_initialize::END as usize];
+        b.iter(|| {
+            black_box(mint_close_authority::initialize_instruction_data(
+                &mut buffer,
+                black_box(Some(&AUTHORITY)),
+            ));
+        })
+    });
+    group.bench_function("initialize_metadata_pointer", |b| {
+        let mut buffer = [0u8; offset_metadata_pointer_initialize::END as usize];
+        b.iter(|| {
+            black_box(metadata_pointer::initialize_instruction_data(
+                &mut buffer,
+                black_box(Some(&AUTHORITY)),
+                black_box(Some(&AUTHORITY)),
+            ));
+        })
+    });
+    group.bench_function("initialize_interest_bearing_mint", |b| {
+        b.iter(|| {
+            interest_bearing_mint_initialize_instruction_data(
+                black_box(Some(&AUTHORITY)),
+                black_box(500),
+            )
+        })
+    });
+    group.bench_function("update_scaled_ui_amount_multiplier", |b| {
+        b.iter(|| {
+            scaled_ui_amount_update_multiplier_instruction_data(
+                ScaledUiAmountInstruction::UpdateMultiplier,
+                black_box(1.5),
+                black_box(1_700_000_000),
+            )
+        })
+    });
+    group.bench_function("initialize_group", |b| {
+        let mut buffer = [0u8; offset_token_group_initialize_group::END as usize];
+        b.iter(|| {
+            black_box(initialize_group_instruction_data(
+                &mut buffer,
+                black_box(Some(&AUTHORITY)),
+                black_box(100),
+            ));
+        })
+    });
+    group.bench_function("initialize_token_metadata", |b| {
+        let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+        b.iter(|| {
+            black_box(initialize_token_metadata_instruction_data(
+                &mut buffer,
+                black_box("Token"),
+                black_box("TKN"),
+                black_box("https://example.com/token.json"),
+            ))
+            .unwrap();
+        })
+    });
+    group.bench_function("update_token_metadata_field", |b| {
+        let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+        b.iter(|| {
+            black_box(update_token_metadata_field_instruction_data(
+                &mut buffer,
+                Field::Key(black_box("color")),
+                black_box("blue"),
+            ))
+            .unwrap();
+        })
+    });
+    group.bench_function("initialize_extra_account_meta_list", |b| {
+        let metas = [ExtraAccountMeta::new_with_pubkey(&AUTHORITY, false, true); 3];
+        let mut buffer = [0u8; 8 + ExtraAccountMetaList::packed_len(3)];
+        b.iter(|| {
+            black_box(initialize_extra_account_meta_list_instruction_data(
+                &mut buffer,
+                black_box(&metas),
+            ))
+            .unwrap();
+        })
+    });
+    group.bench_function("withdraw_excess_lamports", |b| {
+        let mut buffer = [0u8; 1];
+        b.iter(|| {
+            black_box(withdraw_excess_lamports_instruction_data(&mut buffer));
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, encoders);
+criterion_main!(benches);
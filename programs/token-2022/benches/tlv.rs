@@ -0,0 +1,110 @@
+//! TLV walk over mints carrying 1, 5 and 15 extensions.
+
+// host only, see the `criterion` dev-dependency
+#![cfg_attr(target_os = "solana", no_main)]
+#![cfg(not(target_os = "solana"))]
+
+use {
+    criterion::{criterion_group, criterion_main, BenchmarkId, Criterion},
+    pinocchio_token_2022::extension::{
+        consts::ExtensionType,
+        pausable::PausableConfig,
+        tlv::{
+            extensions_from_bytes, get_extension_data_from_bytes, get_extension_from_bytes,
+            get_extensions_from_bytes, BASE_ACCOUNT_LENGTH,
+        },
+        transfer_fee::TransferFeeConfig,
+        transfer_hook::TransferHook,
+    },
+    std::hint::black_box,
+};
+
+/// Account type of a mint, stored right after the base account.
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Extensions of the benched mints in their stored order, with the length of
+/// their value.
+const EXTENSIONS: [(ExtensionType, u16); 15] = [
+    (ExtensionType::MetadataPointer, 64),
+    (ExtensionType::TransferFeeConfig, 108),
+    (ExtensionType::MintCloseAuthority, 32),
+    (ExtensionType::DefaultAccountState, 1),
+    (ExtensionType::NonTransferable, 0),
+    (ExtensionType::InterestBearingConfig, 52),
+    (ExtensionType::PermanentDelegate, 32),
+    (ExtensionType::TransferHook, 64),
+    (ExtensionType::ConfidentialTransferMint, 65),
+    (ExtensionType::ConfidentialTransferFeeConfig, 129),
+    (ExtensionType::GroupPointer, 64),
+    (ExtensionType::GroupMemberPointer, 64),
+    (ExtensionType::ScaledUiAmount, 56),
+    (ExtensionType::Pausable, 33),
+    (ExtensionType::TokenGroup, 80),
+];
+
+/// Number of extensions of the benched mints.
+const EXTENSION_COUNTS: [usize; 3] = [1, 5, 15];
+
+/// Mint data carrying the given extensions, with zeroed values.
+fn mint_with_extensions(extensions: &[(ExtensionType, u16)]) -> Vec<u8> {
+    let mut data = vec![0; BASE_ACCOUNT_LENGTH];
+    data.push(ACCOUNT_TYPE_MINT);
+
+    for &(extension_type, len) in extensions {
+        data.extend_from_slice(&(extension_type as u16).to_le_bytes());
+        data.extend_from_slice(&len.to_le_bytes());
+        data.resize(data.len() + len as usize, 0);
+    }
+
+    data
+}
+
+fn tlv(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tlv");
+
+    for count in EXTENSION_COUNTS {
+        let data = mint_with_extensions(&EXTENSIONS[..count]);
+        let (last, _) = EXTENSIONS[count - 1];
+
+        group.bench_with_input(BenchmarkId::new("walk", count), &data, |b, data| {
+            b.iter(|| extensions_from_bytes(black_box(data)).count())
+        });
+
+        // the last extension and a missing one are found after walking all
+        // the entries
+        group.bench_with_input(BenchmarkId::new("get_last", count), &data, |b, data| {
+            b.iter(|| get_extension_data_from_bytes(black_box(last), black_box(data)))
+        });
+        group.bench_with_input(BenchmarkId::new("get_missing", count), &data, |b, data| {
+            b.iter(|| get_extension_data_from_bytes(ExtensionType::TokenMetadata, black_box(data)))
+        });
+
+        // the extensions checked on transfer, one walk each or a single one
+        group.bench_with_input(BenchmarkId::new("get_three", count), &data, |b, data| {
+            b.iter(|| {
+                let data = black_box(data);
+                (
+                    get_extension_from_bytes::<TransferFeeConfig>(data),
+                    get_extension_from_bytes::<PausableConfig>(data),
+                    get_extension_from_bytes::<TransferHook>(data),
+                )
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("get_three_once", count),
+            &data,
+            |b, data| {
+                b.iter(|| {
+                    get_extensions_from_bytes::<(TransferFeeConfig, PausableConfig, TransferHook)>(
+                        black_box(data),
+                    )
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, tlv);
+criterion_main!(benches);
@@ -0,0 +1,369 @@
+//! Arbitrary field values fed to the instruction data encoders, checking the
+//! length of the encoded data.
+
+#![no_main]
+
+use {
+    arbitrary::Arbitrary,
+    libfuzzer_sys::fuzz_target,
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+    pinocchio_token_2022::{
+        extension::{
+            interest_bearing_mint::{
+                interest_bearing_mint_initialize_instruction_data,
+                interest_bearing_mint_update_rate_instruction_data,
+            },
+            metadata_pointer::{
+                self, offset_metadata_pointer_initialize, offset_metadata_pointer_update,
+            },
+            mint_close_authority::{self, offset_mint_close_authority_initialize},
+            permanent_delegate::{self, offset_permanent_delegate_initialize},
+            token_group::{
+                initialize_group_instruction_data, offset_token_group_initialize_group,
+                offset_token_group_update_authority, offset_token_group_update_max_size,
+                update_group_authority_instruction_data, update_group_max_size_instruction_data,
+            },
+            token_metadata::{
+                emit_token_metadata_instruction_data, initialize_token_metadata_instruction_data,
+                remove_token_metadata_key_instruction_data,
+                update_token_metadata_field_instruction_data, Field,
+                MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+            },
+            transfer_fee::{
+                initialize_transfer_fee_config_instruction_data, offset_transfer_fee_initialize,
+                offset_transfer_fee_set_transfer_fee,
+                offset_transfer_fee_transfer_checked_with_fee,
+                offset_transfer_fee_withdraw_withheld_tokens_from_accounts,
+                set_transfer_fee_instruction_data, transfer_checked_with_fee_instruction_data,
+                withdraw_withheld_tokens_from_accounts_instruction_data,
+            },
+        },
+        pod::pod_slice_from_bytes,
+        transfer_hook_interface::{
+            initialize_extra_account_meta_list_instruction_data, ExtraAccountMeta,
+            ExtraAccountMetaList,
+        },
+    },
+};
+
+/// Length of the buffer the extra account metas are encoded into.
+const MAX_EXTRA_ACCOUNT_METAS_DATA_LEN: usize = 1024;
+
+/// Length of a `COption<Pubkey>`: the option flag and the pubkey if any.
+fn c_option_len(value: &Option<Pubkey>) -> usize {
+    1 + value.map_or(0, |x| x.len())
+}
+
+/// Length of a borsh string: the length prefix and the bytes.
+fn str_len(value: &str) -> usize {
+    4 + value.len()
+}
+
+/// Variable-length data is encoded iff it fits in the buffer.
+fn check_encoded_len(data: Result<&[u8], ProgramError>, len: usize, buffer_len: usize) {
+    match data {
+        Ok(data) => assert_eq!(data.len(), len),
+        Err(error) => {
+            assert_eq!(error, ProgramError::InvalidArgument);
+            assert!(len > buffer_len);
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum TokenMetadataField<'a> {
+    Name,
+    Symbol,
+    Uri,
+    Key(&'a str),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Input<'a> {
+    InitializeTransferFeeConfig {
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    SetTransferFee {
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    TransferCheckedWithFee {
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    },
+    WithdrawWithheldTokensFromAccounts {
+        num_token_accounts: u8,
+    },
+    InitializeMintCloseAuthority {
+        close_authority: Option<Pubkey>,
+    },
+    InitializePermanentDelegate {
+        delegate: Pubkey,
+    },
+    InitializeMetadataPointer {
+        authority: Option<Pubkey>,
+        metadata_address: Option<Pubkey>,
+    },
+    UpdateMetadataPointer {
+        new_metadata_address: Option<Pubkey>,
+    },
+    InitializeInterestBearingMint {
+        rate_authority: Option<Pubkey>,
+        rate: i16,
+    },
+    UpdateInterestRate {
+        rate: i16,
+    },
+    InitializeGroup {
+        update_authority: Option<Pubkey>,
+        max_size: u64,
+    },
+    UpdateGroupMaxSize {
+        max_size: u64,
+    },
+    UpdateGroupAuthority {
+        new_authority: Option<Pubkey>,
+    },
+    InitializeTokenMetadata {
+        name: &'a str,
+        symbol: &'a str,
+        uri: &'a str,
+    },
+    UpdateTokenMetadataField {
+        field: TokenMetadataField<'a>,
+        value: &'a str,
+    },
+    RemoveTokenMetadataKey {
+        idempotent: bool,
+        key: &'a str,
+    },
+    EmitTokenMetadata {
+        start: Option<u64>,
+        end: Option<u64>,
+    },
+    InitializeExtraAccountMetaList {
+        metas: &'a [u8],
+    },
+}
+
+fuzz_target!(|input: Input| {
+    match input {
+        Input::InitializeTransferFeeConfig {
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+        } => {
+            use offset_transfer_fee_initialize as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = initialize_transfer_fee_config_instruction_data(
+                &mut buffer,
+                transfer_fee_config_authority.as_ref(),
+                withdraw_withheld_authority.as_ref(),
+                transfer_fee_basis_points,
+                maximum_fee,
+            );
+
+            assert_eq!(
+                data.len(),
+                OFFSET::START as usize
+                    + c_option_len(&transfer_fee_config_authority)
+                    + c_option_len(&withdraw_withheld_authority)
+                    + (OFFSET::TRANSFER_FEE_BASIS_POINTS + OFFSET::MAXIMUM_FEE) as usize
+            );
+        }
+        Input::SetTransferFee {
+            transfer_fee_basis_points,
+            maximum_fee,
+        } => {
+            use offset_transfer_fee_set_transfer_fee as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = set_transfer_fee_instruction_data(
+                &mut buffer,
+                transfer_fee_basis_points,
+                maximum_fee,
+            );
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::TransferCheckedWithFee {
+            amount,
+            decimals,
+            fee,
+        } => {
+            use offset_transfer_fee_transfer_checked_with_fee as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data =
+                transfer_checked_with_fee_instruction_data(&mut buffer, amount, decimals, fee);
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::WithdrawWithheldTokensFromAccounts { num_token_accounts } => {
+            use offset_transfer_fee_withdraw_withheld_tokens_from_accounts as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = withdraw_withheld_tokens_from_accounts_instruction_data(
+                &mut buffer,
+                num_token_accounts,
+            );
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::InitializeMintCloseAuthority { close_authority } => {
+            use offset_mint_close_authority_initialize as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = mint_close_authority::initialize_instruction_data(
+                &mut buffer,
+                close_authority.as_ref(),
+            );
+
+            assert_eq!(
+                data.len(),
+                OFFSET::START as usize + c_option_len(&close_authority)
+            );
+        }
+        Input::InitializePermanentDelegate { delegate } => {
+            use offset_permanent_delegate_initialize as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = permanent_delegate::initialize_instruction_data(&mut buffer, &delegate);
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::InitializeMetadataPointer {
+            authority,
+            metadata_address,
+        } => {
+            use offset_metadata_pointer_initialize as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = metadata_pointer::initialize_instruction_data(
+                &mut buffer,
+                authority.as_ref(),
+                metadata_address.as_ref(),
+            );
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::UpdateMetadataPointer {
+            new_metadata_address,
+        } => {
+            use offset_metadata_pointer_update as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = metadata_pointer::update_instruction_data(
+                &mut buffer,
+                new_metadata_address.as_ref(),
+            );
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::InitializeInterestBearingMint {
+            rate_authority,
+            rate,
+        } => {
+            interest_bearing_mint_initialize_instruction_data(rate_authority.as_ref(), rate);
+        }
+        Input::UpdateInterestRate { rate } => {
+            interest_bearing_mint_update_rate_instruction_data(rate);
+        }
+        Input::InitializeGroup {
+            update_authority,
+            max_size,
+        } => {
+            use offset_token_group_initialize_group as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data =
+                initialize_group_instruction_data(&mut buffer, update_authority.as_ref(), max_size);
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::UpdateGroupMaxSize { max_size } => {
+            use offset_token_group_update_max_size as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = update_group_max_size_instruction_data(&mut buffer, max_size);
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::UpdateGroupAuthority { new_authority } => {
+            use offset_token_group_update_authority as OFFSET;
+
+            let mut buffer = [0u8; OFFSET::END as usize];
+            let data = update_group_authority_instruction_data(&mut buffer, new_authority.as_ref());
+
+            assert_eq!(data.len(), OFFSET::END as usize);
+        }
+        Input::InitializeTokenMetadata { name, symbol, uri } => {
+            let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+            let data = initialize_token_metadata_instruction_data(&mut buffer, name, symbol, uri);
+
+            check_encoded_len(
+                data,
+                8 + str_len(name) + str_len(symbol) + str_len(uri),
+                MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+            );
+        }
+        Input::UpdateTokenMetadataField { field, value } => {
+            let (field, field_len) = match field {
+                TokenMetadataField::Name => (Field::Name, 1),
+                TokenMetadataField::Symbol => (Field::Symbol, 1),
+                TokenMetadataField::Uri => (Field::Uri, 1),
+                TokenMetadataField::Key(key) => (Field::Key(key), 1 + str_len(key)),
+            };
+
+            let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+            let data = update_token_metadata_field_instruction_data(&mut buffer, field, value);
+
+            check_encoded_len(
+                data,
+                8 + field_len + str_len(value),
+                MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+            );
+        }
+        Input::RemoveTokenMetadataKey { idempotent, key } => {
+            let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+            let data = remove_token_metadata_key_instruction_data(&mut buffer, idempotent, key);
+
+            check_encoded_len(
+                data,
+                8 + 1 + str_len(key),
+                MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+            );
+        }
+        Input::EmitTokenMetadata { start, end } => {
+            let option_len = |value: Option<u64>| 1 + value.map_or(0, |_| 8);
+
+            let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+            let data = emit_token_metadata_instruction_data(&mut buffer, start, end);
+
+            check_encoded_len(
+                data,
+                8 + option_len(start) + option_len(end),
+                MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+            );
+        }
+        Input::InitializeExtraAccountMetaList { metas } => {
+            let len = metas.len() - metas.len() % ExtraAccountMeta::LEN;
+            let metas = pod_slice_from_bytes::<ExtraAccountMeta>(&metas[..len]).unwrap();
+
+            let mut buffer = [0u8; MAX_EXTRA_ACCOUNT_METAS_DATA_LEN];
+            let data = initialize_extra_account_meta_list_instruction_data(&mut buffer, metas);
+
+            check_encoded_len(
+                data,
+                8 + ExtraAccountMetaList::packed_len(metas.len()),
+                MAX_EXTRA_ACCOUNT_METAS_DATA_LEN,
+            );
+        }
+    }
+});
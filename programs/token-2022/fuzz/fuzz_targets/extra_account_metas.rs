@@ -0,0 +1,27 @@
+//! Arbitrary data fed to the parser of the validation account of a transfer
+//! hook, and to the seeds of the metas it holds.
+
+#![no_main]
+
+use {
+    libfuzzer_sys::fuzz_target, pinocchio_token_2022::transfer_hook_interface::ExtraAccountMetaList,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(list) = ExtraAccountMetaList::from_bytes(data) else {
+        return;
+    };
+
+    assert_eq!(list.len(), list.metas().len());
+    assert!(ExtraAccountMetaList::size_of(list.len()) <= data.len());
+
+    // the seeds are packed in the 32 bytes address config
+    for meta in list.metas() {
+        let packed_len: usize = meta
+            .seeds()
+            .map_while(Result::ok)
+            .map(|seed| seed.packed_len())
+            .sum();
+        assert!(packed_len <= 32);
+    }
+});
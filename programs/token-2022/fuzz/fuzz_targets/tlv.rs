@@ -0,0 +1,89 @@
+//! Arbitrary account data fed to the TLV parser of mints and token accounts,
+//! and to the interface TLV parser of the accounts of other programs.
+
+#![no_main]
+
+use {
+    core::mem::size_of,
+    libfuzzer_sys::fuzz_target,
+    pinocchio_token_2022::extension::{
+        confidential_transfer::{ConfidentialTransferAccount, ConfidentialTransferMint},
+        confidential_transfer_fee::{ConfidentialTransferFeeAmount, ConfidentialTransferFeeConfig},
+        immutable_owner::ImmutableOwner,
+        mint_close_authority::MintCloseAuthority,
+        non_transferable::{NonTransferable, NonTransferableAccount},
+        pausable::PausableConfig,
+        tlv::{
+            extensions_from_bytes, get_extension_data_from_bytes, get_extension_from_bytes,
+            get_extensions_from_bytes, get_interface_value_from_bytes, has_extension, Extension,
+            EXTENSIONS_START, INTERFACE_TLV_HEADER_LEN, TLV_HEADER_LEN,
+        },
+        transfer_fee::TransferFeeConfig,
+        transfer_hook::{TransferHook, TransferHookAccount},
+    },
+};
+
+fuzz_target!(|data: &[u8]| {
+    // the entries follow each other from the start of the TLV area, each one
+    // within the data
+    let mut end = EXTENSIONS_START;
+    for (_, value) in extensions_from_bytes(data) {
+        let start = value.as_ptr() as usize - data.as_ptr() as usize;
+        assert_eq!(start, end + TLV_HEADER_LEN);
+
+        end = start + value.len();
+        assert!(end <= data.len());
+    }
+
+    check_extension::<TransferFeeConfig>(data);
+    check_extension::<MintCloseAuthority>(data);
+    check_extension::<ImmutableOwner>(data);
+    check_extension::<NonTransferable>(data);
+    check_extension::<NonTransferableAccount>(data);
+    check_extension::<TransferHook>(data);
+    check_extension::<TransferHookAccount>(data);
+    check_extension::<ConfidentialTransferMint>(data);
+    check_extension::<ConfidentialTransferAccount>(data);
+    check_extension::<ConfidentialTransferFeeConfig>(data);
+    check_extension::<ConfidentialTransferFeeAmount>(data);
+    check_extension::<PausableConfig>(data);
+
+    // a single walk finds the same extensions
+    let (transfer_fee_config, pausable_config, transfer_hook) =
+        get_extensions_from_bytes::<(TransferFeeConfig, PausableConfig, TransferHook)>(data);
+    assert_eq!(
+        transfer_fee_config.map(|x| x as *const _),
+        get_extension_from_bytes::<TransferFeeConfig>(data).map(|x| x as *const _)
+    );
+    assert_eq!(
+        pausable_config.map(|x| x as *const _),
+        get_extension_from_bytes::<PausableConfig>(data).map(|x| x as *const _)
+    );
+    assert_eq!(
+        transfer_hook.map(|x| x as *const _),
+        get_extension_from_bytes::<TransferHook>(data).map(|x| x as *const _)
+    );
+
+    // the first 8 bytes are used as the discriminator looked up
+    if let Some((discriminator, _)) = data.split_first_chunk::<8>() {
+        if let Some(value) = get_interface_value_from_bytes(discriminator, data) {
+            let start = value.as_ptr() as usize - data.as_ptr() as usize;
+            assert!(start >= INTERFACE_TLV_HEADER_LEN);
+            assert!(start + value.len() <= data.len());
+        }
+    }
+});
+
+/// The extension is found iff an entry of its type holds exactly its length,
+/// and present iff there is an entry of its type.
+fn check_extension<T: Extension>(data: &[u8]) {
+    let value = get_extension_data_from_bytes(T::TYPE, data);
+    let extension = get_extension_from_bytes::<T>(data);
+
+    assert_eq!(has_extension::<T>(data), value.is_some());
+
+    assert_eq!(
+        extension.is_some(),
+        value.is_some_and(|value| value.len() == size_of::<T>())
+    );
+}
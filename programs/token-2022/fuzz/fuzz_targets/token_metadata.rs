@@ -0,0 +1,46 @@
+//! Arbitrary data fed to the variable-length `TokenMetadata` parser, as a mint,
+//! as the account of another program and as the bare extension value.
+
+#![no_main]
+
+use {libfuzzer_sys::fuzz_target, pinocchio_token_2022::extension::token_metadata::TokenMetadata};
+
+fuzz_target!(|data: &[u8]| {
+    for metadata in [
+        TokenMetadata::from_bytes(data),
+        TokenMetadata::from_interface_bytes(data),
+        TokenMetadata::unpack(data),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        check_metadata(&metadata, data);
+    }
+});
+
+/// The pairs were validated when unpacking, iterating over them yields all of
+/// them, each string borrowed from the data.
+fn check_metadata(metadata: &TokenMetadata, data: &[u8]) {
+    let _ = metadata.update_authority();
+
+    for value in [metadata.name, metadata.symbol, metadata.uri] {
+        assert!(is_within(value, data));
+    }
+
+    let mut len = 0;
+    for (key, value) in metadata.additional_metadata() {
+        assert!(is_within(key, data));
+        assert!(is_within(value, data));
+        assert!(metadata.get(key).is_some());
+
+        len += 1;
+    }
+    assert_eq!(len, metadata.additional_metadata_len());
+}
+
+fn is_within(value: &str, data: &[u8]) -> bool {
+    let data = data.as_ptr_range();
+    let value = value.as_bytes().as_ptr_range();
+
+    data.start <= value.start && value.end <= data.end
+}
@@ -0,0 +1,49 @@
+//! Off-chain helpers to read the account data fetched from an RPC node,
+//! enabled by the `client` feature.
+//!
+//! Like the extension parsers, they only read the given bytes, so they build
+//! on any target, `wasm32-unknown-unknown` included, for browser wallets to
+//! parse Token-2022 accounts without the SPL crates:
+//!
+//! ```ignore
+//! let data = connection.get_account_data(&mint).await?;
+//!
+//! if account_type(&data) == Some(AccountType::Mint) {
+//!     for extension_type in extension_types(&data) {
+//!         // ..
+//!     }
+//!     let transfer_fee_config = get_extension_from_bytes::<TransferFeeConfig>(&data);
+//! }
+//! ```
+
+use crate::extension::{
+    consts::ExtensionType,
+    tlv::{extensions_from_bytes, BASE_ACCOUNT_LENGTH},
+};
+
+/// Type of an account carrying extensions, stored right after the base
+/// account.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountType {
+    Mint = 1,
+    Account = 2,
+}
+
+/// Return the type of the account, or `None` if the account carries no
+/// extensions or its type is unknown.
+#[inline]
+pub fn account_type(bytes: &[u8]) -> Option<AccountType> {
+    match bytes.get(BASE_ACCOUNT_LENGTH)? {
+        1 => Some(AccountType::Mint),
+        2 => Some(AccountType::Account),
+        _ => None,
+    }
+}
+
+/// Iterate over the types of the extensions of the account in their stored
+/// order, skipping the types unknown to this crate.
+#[inline]
+pub fn extension_types(bytes: &[u8]) -> impl Iterator<Item = ExtensionType> + '_ {
+    extensions_from_bytes(bytes).filter_map(|(tlv_type, _)| ExtensionType::try_from(tlv_type).ok())
+}
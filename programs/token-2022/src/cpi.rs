@@ -0,0 +1,178 @@
+//! The `pinocchio::cpi` functions used by the instruction wrappers.
+//!
+//! With the `log` feature, each of them logs the invoked instruction and its
+//! number of accounts before the CPI, e.g.
+//! `CPI TransferFeeExtension (instruction 1), 4 accounts`, to tell which
+//! wrapper fired when a transaction fails.
+//!
+//! With the `host-stub` feature on host targets, the instructions are recorded
+//! by [`host_stub`](crate::host_stub) instead of being invoked.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Instruction, Signer},
+    ProgramResult,
+};
+
+#[cfg(all(feature = "host-stub", not(target_os = "solana")))]
+use crate::host_stub as cpi;
+#[cfg(not(all(feature = "host-stub", not(target_os = "solana"))))]
+use pinocchio::cpi;
+
+#[inline(always)]
+pub(crate) fn invoke<const ACCOUNTS: usize>(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo; ACCOUNTS],
+) -> ProgramResult {
+    #[cfg(feature = "log")]
+    log::log_instruction(instruction);
+
+    cpi::invoke(instruction, account_infos)
+}
+
+#[inline(always)]
+pub(crate) fn invoke_with_bounds<const MAX_ACCOUNTS: usize>(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo],
+) -> ProgramResult {
+    #[cfg(feature = "log")]
+    log::log_instruction(instruction);
+
+    cpi::invoke_with_bounds::<MAX_ACCOUNTS>(instruction, account_infos)
+}
+
+#[inline(always)]
+pub(crate) fn invoke_signed<const ACCOUNTS: usize>(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo; ACCOUNTS],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    #[cfg(feature = "log")]
+    log::log_instruction(instruction);
+
+    cpi::invoke_signed(instruction, account_infos, signers_seeds)
+}
+
+#[inline(always)]
+pub(crate) fn invoke_signed_with_bounds<const MAX_ACCOUNTS: usize>(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    #[cfg(feature = "log")]
+    log::log_instruction(instruction);
+
+    cpi::invoke_signed_with_bounds::<MAX_ACCOUNTS>(instruction, account_infos, signers_seeds)
+}
+
+#[inline(always)]
+pub(crate) fn slice_invoke_signed(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    #[cfg(feature = "log")]
+    log::log_instruction(instruction);
+
+    cpi::slice_invoke_signed(instruction, account_infos, signers_seeds)
+}
+
+#[cfg(feature = "log")]
+mod log {
+    #[cfg(feature = "token-group")]
+    use crate::extension::token_group::InstructionDiscriminatorTokenGroup;
+    #[cfg(feature = "token-metadata")]
+    use crate::extension::token_metadata::InstructionDiscriminatorTokenMetadata;
+    use {
+        crate::{
+            instruction::TokenInstruction,
+            transfer_hook_interface::{
+                EXECUTE_DISCRIMINATOR, INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR,
+                UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR,
+            },
+        },
+        core::fmt::{self, Write},
+        pinocchio::{instruction::Instruction, msg},
+    };
+
+    /// Maximum length of a log line, longer lines are truncated.
+    const MAX_LOG_LEN: usize = 96;
+
+    /// A log line written on the stack.
+    struct LogLine {
+        buffer: [u8; MAX_LOG_LEN],
+        len: usize,
+    }
+
+    impl Write for LogLine {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let len = s.len().min(MAX_LOG_LEN - self.len);
+            self.buffer[self.len..self.len + len].copy_from_slice(&s.as_bytes()[..len]);
+            self.len += len;
+
+            Ok(())
+        }
+    }
+
+    #[cold]
+    pub(super) fn log_instruction(instruction: &Instruction) {
+        let mut line = LogLine {
+            buffer: [0; MAX_LOG_LEN],
+            len: 0,
+        };
+
+        let _ = write_instruction(&mut line, instruction);
+        let _ = write!(line, ", {} accounts", instruction.accounts.len());
+
+        // only ASCII is written, the line can't be truncated within a character
+        msg!(core::str::from_utf8(&line.buffer[..line.len]).unwrap_or_default());
+    }
+
+    fn write_instruction(line: &mut LogLine, instruction: &Instruction) -> fmt::Result {
+        let data = instruction.data;
+
+        if instruction.program_id == &crate::memo::ID {
+            return write!(line, "CPI Memo");
+        }
+        if instruction.program_id == &crate::SYSTEM_PROGRAM_ID {
+            return write!(line, "CPI System");
+        }
+
+        // the interfaces start with 8 bytes discriminators, matched before the
+        // 1 byte Token-2022 discriminator their first byte could be mistaken for
+        match data.first_chunk::<8>() {
+            Some(&EXECUTE_DISCRIMINATOR) => return write!(line, "CPI TransferHook Execute"),
+            Some(&INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR) => {
+                return write!(line, "CPI TransferHook InitializeExtraAccountMetaList")
+            }
+            Some(&UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR) => {
+                return write!(line, "CPI TransferHook UpdateExtraAccountMetaList")
+            }
+            _ => {}
+        }
+        #[cfg(feature = "token-group")]
+        if let Ok(x) = InstructionDiscriminatorTokenGroup::try_from(data) {
+            return write!(line, "CPI TokenGroup {:?}", x);
+        }
+        #[cfg(feature = "token-metadata")]
+        if let Ok(x) = InstructionDiscriminatorTokenMetadata::try_from(data) {
+            return write!(line, "CPI TokenMetadata {:?}", x);
+        }
+
+        match TokenInstruction::unpack(data) {
+            Ok(instruction) => match instruction.extension_instruction() {
+                Ok((extension_instruction, _)) => write!(
+                    line,
+                    "CPI {:?} (instruction {})",
+                    instruction.discriminator, extension_instruction
+                ),
+                Err(_) => write!(line, "CPI {:?}", instruction.discriminator),
+            },
+            Err(_) => write!(
+                line,
+                "CPI unknown instruction {:?}",
+                &data[..data.len().min(8)]
+            ),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Standard base64 with padding, the text encoding of the ElGamal types in
+//! the zk SDK and the SPL client tools.
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Length of the encoding of `len` bytes.
+pub const fn encoded_len(len: usize) -> usize {
+    len.div_ceil(3) * 4
+}
+
+/// Write the encoding of `bytes` into `out`, which is `encoded_len` long.
+pub fn encode(bytes: &[u8], out: &mut [u8]) {
+    for (chunk, out) in bytes.chunks(3).zip(out.chunks_mut(4)) {
+        let mut group = [0; 4];
+        group[1..=chunk.len()].copy_from_slice(chunk);
+        let group = u32::from_be_bytes(group);
+
+        // A chunk of n bytes takes n + 1 symbols, the rest is padding
+        for (i, symbol) in out.iter_mut().enumerate() {
+            *symbol = if i <= chunk.len() {
+                ALPHABET[(group >> (18 - 6 * i)) as usize & 0x3f]
+            } else {
+                b'='
+            };
+        }
+    }
+}
+
+#[inline(always)]
+fn decode_symbol(symbol: u8) -> Option<u32> {
+    let value = match symbol {
+        b'A'..=b'Z' => symbol - b'A',
+        b'a'..=b'z' => symbol - b'a' + 26,
+        b'0'..=b'9' => symbol - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    };
+
+    Some(value as u32)
+}
+
+/// Return the `N` bytes encoded in `encoded`, or `None` if it isn't their
+/// canonical encoding.
+pub fn decode<const N: usize>(encoded: &str) -> Option<[u8; N]> {
+    let encoded = encoded.as_bytes();
+
+    if encoded.len() != encoded_len(N) {
+        return None;
+    }
+
+    let mut bytes = [0; N];
+
+    for (chunk, out) in encoded.chunks(4).zip(bytes.chunks_mut(3)) {
+        let mut group = 0;
+
+        for (i, &symbol) in chunk.iter().enumerate() {
+            group <<= 6;
+
+            if i <= out.len() {
+                group |= decode_symbol(symbol)?;
+            } else if symbol != b'=' {
+                return None;
+            }
+        }
+
+        // The bits of the last symbol past the encoded bytes are zero
+        if group & ((1 << (24 - 8 * out.len())) - 1) != 0 {
+            return None;
+        }
+
+        out.copy_from_slice(&group.to_be_bytes()[1..=out.len()]);
+    }
+
+    Some(bytes)
+}
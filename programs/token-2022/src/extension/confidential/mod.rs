@@ -0,0 +1,5 @@
+#[cfg(feature = "client")]
+mod base64;
+pub mod pod;
+
+pub use pod::*;
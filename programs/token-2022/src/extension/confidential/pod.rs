@@ -0,0 +1,190 @@
+//! ElGamal and authenticated encryption types stored in the confidential
+//! extensions, as raw bytes.
+
+#[cfg(feature = "client")]
+extern crate alloc;
+
+use {
+    crate::pod::{pod_from_bytes, Nullable, Pod, PodOption},
+    pinocchio::program_error::ProgramError,
+};
+#[cfg(feature = "client")]
+use {
+    alloc::string::{String, ToString},
+    core::{fmt, str::FromStr},
+};
+
+/// Define a transparent wrapper of `$len` bytes with the conversions shared by
+/// all the confidential types.
+macro_rules! pod_bytes {
+    ($(#[$meta:meta])* $name:ident, $len:expr) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct $name(pub [u8; $len]);
+
+        // SAFETY: a byte array has an alignment of 1 and accepts any bit pattern
+        unsafe impl Pod for $name {}
+
+        impl $name {
+            /// The length of the data.
+            pub const LEN: usize = $len;
+
+            #[inline(always)]
+            pub const fn new(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+
+            #[inline(always)]
+            pub const fn zeroed() -> Self {
+                Self([0; $len])
+            }
+
+            #[inline(always)]
+            pub fn is_zeroed(&self) -> bool {
+                self.0 == [0; $len]
+            }
+
+            #[inline(always)]
+            pub const fn as_bytes(&self) -> &[u8; $len] {
+                &self.0
+            }
+
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; $len] {
+                self.0
+            }
+
+            /// Return the value stored in exactly `LEN` bytes.
+            #[inline]
+            pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+                pod_from_bytes(bytes)
+            }
+        }
+
+        impl Default for $name {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::zeroed()
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            #[inline(always)]
+            fn from(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; $len] {
+            #[inline(always)]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            #[inline(always)]
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl PartialEq<[u8; $len]> for $name {
+            #[inline(always)]
+            fn eq(&self, other: &[u8; $len]) -> bool {
+                &self.0 == other
+            }
+        }
+    };
+}
+
+pod_bytes!(
+    /// ElGamal public key (32 bytes)
+    PodElGamalPubkey,
+    32
+);
+
+pod_bytes!(
+    /// ElGamal ciphertext (64 bytes)
+    ///
+    /// The zeroed ciphertext is the encryption of `0` with no randomness, i.e.
+    /// an empty balance.
+    PodElGamalCiphertext,
+    64
+);
+
+pod_bytes!(
+    /// Authenticated encryption ciphertext (36 bytes)
+    PodAeCiphertext,
+    36
+);
+
+impl Nullable for PodElGamalPubkey {
+    const NONE: Self = Self::zeroed();
+}
+
+#[cfg(feature = "client")]
+impl PodElGamalPubkey {
+    /// The length of the base64 encoding.
+    pub const BASE64_LEN: usize = super::base64::encoded_len(Self::LEN);
+
+    /// Parse the base64 encoding printed by the zk SDK and the SPL client
+    /// tools, failing with `InvalidArgument` if it isn't a canonical encoding
+    /// of 32 bytes.
+    pub fn from_base64(encoded: &str) -> Result<Self, ProgramError> {
+        super::base64::decode(encoded)
+            .map(Self)
+            .ok_or(ProgramError::InvalidArgument)
+    }
+
+    /// Return the base64 encoding, also written by `Display`.
+    pub fn to_base64(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(feature = "client")]
+impl fmt::Display for PodElGamalPubkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; Self::BASE64_LEN];
+        super::base64::encode(&self.0, &mut buf);
+
+        // The encoding is ASCII
+        f.write_str(core::str::from_utf8(&buf).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(feature = "client")]
+impl FromStr for PodElGamalPubkey {
+    type Err = ProgramError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_base64(s)
+    }
+}
+
+/// Compare with a base64 encoded pubkey, e.g. in assertions on the
+/// confidential config fields.
+#[cfg(feature = "client")]
+impl PartialEq<str> for PodElGamalPubkey {
+    fn eq(&self, other: &str) -> bool {
+        Self::from_base64(other).is_ok_and(|x| x == *self)
+    }
+}
+
+#[cfg(feature = "client")]
+impl PartialEq<&str> for PodElGamalPubkey {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// An optional ElGamal public key, zeroed if none.
+pub type OptionalNonZeroElGamalPubkey = PodOption<PodElGamalPubkey>;
+
+/// ElGamal ciphertext containing an account balance
+pub type EncryptedBalance = PodElGamalCiphertext;
+
+/// Authenticated encryption containing an account balance
+pub type DecryptableBalance = PodAeCiphertext;
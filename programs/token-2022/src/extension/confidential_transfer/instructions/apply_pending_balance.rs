@@ -0,0 +1,66 @@
+use crate::{
+    extension::confidential_transfer::state::{
+        offset_confidential_transfer_apply_pending_balance as OFFSET, DecryptableBalance,
+        InstructionDiscriminatorConfidentialTransfer,
+    },
+    macros::define_extension_instruction,
+};
+
+define_extension_instruction! {
+    /// Apply the pending balance of a confidential token account to its
+    /// available balance.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[signer]` The single account owner.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[]` The multisig account owner.
+    ///   2. `..2+M` `[signer]` M signer accounts.
+    pub struct ApplyPendingBalance<'a> {
+        accounts: {
+            /// Token Account
+            token_account: writable,
+        },
+        authority: {
+            /// The account owner.
+            authority
+        },
+        data: {
+            /// `pending_balance_credit_counter` of the account when the new
+            /// decryptable balance was computed
+            expected_pending_balance_credit_counter: u64,
+            /// The new decryptable balance if the pending balance is applied
+            new_decryptable_available_balance: &'a DecryptableBalance,
+        },
+        instruction_data: apply_pending_balance_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn apply_pending_balance_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    expected_pending_balance_credit_counter: u64,
+    new_decryptable_available_balance: &DecryptableBalance,
+) -> &'a [u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset].copy_from_slice(
+        &InstructionDiscriminatorConfidentialTransfer::ApplyPendingBalance.as_bytes(),
+    );
+
+    // Set expected_pending_balance_credit_counter
+    buffer[offset..offset + OFFSET::EXPECTED_PENDING_BALANCE_CREDIT_COUNTER as usize]
+        .copy_from_slice(&expected_pending_balance_credit_counter.to_le_bytes());
+    offset += OFFSET::EXPECTED_PENDING_BALANCE_CREDIT_COUNTER as usize;
+
+    // Set new_decryptable_available_balance
+    buffer[offset..offset + OFFSET::NEW_DECRYPTABLE_AVAILABLE_BALANCE as usize]
+        .copy_from_slice(new_decryptable_available_balance.as_ref());
+
+    buffer
+}
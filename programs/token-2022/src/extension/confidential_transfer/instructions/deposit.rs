@@ -0,0 +1,66 @@
+use crate::{
+    extension::confidential_transfer::state::{
+        offset_confidential_transfer_deposit as OFFSET,
+        InstructionDiscriminatorConfidentialTransfer,
+    },
+    macros::define_extension_instruction,
+};
+
+define_extension_instruction! {
+    /// Deposit SPL Tokens into the pending balance of a confidential token
+    /// account.
+    ///
+    /// The pending balance is moved to the available balance by
+    /// `ApplyPendingBalance`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The single account owner or delegate.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The multisig account owner or delegate.
+    ///   3. `..3+M` `[signer]` M signer accounts.
+    pub struct Deposit<'a> {
+        accounts: {
+            /// Token Account
+            token_account: writable,
+            /// Mint Account
+            mint: readonly,
+        },
+        authority: {
+            /// The account owner or delegate.
+            authority
+        },
+        data: {
+            /// Amount of tokens to deposit
+            amount: u64,
+            /// Expected number of base 10 digits to the right of the decimal
+            /// place
+            decimals: u8,
+        },
+        instruction_data: deposit_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn deposit_instruction_data(buffer: &mut [u8], amount: u64, decimals: u8) -> &[u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorConfidentialTransfer::Deposit.as_bytes());
+
+    // Set amount
+    buffer[offset..offset + OFFSET::AMOUNT as usize].copy_from_slice(&amount.to_le_bytes());
+    offset += OFFSET::AMOUNT as usize;
+
+    // Set decimals
+    buffer[offset] = decimals;
+
+    buffer
+}
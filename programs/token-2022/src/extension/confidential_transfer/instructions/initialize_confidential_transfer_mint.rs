@@ -0,0 +1,69 @@
+use {
+    crate::{
+        extension::confidential_transfer::state::{
+            offset_confidential_transfer_initialize_mint as OFFSET,
+            InstructionDiscriminatorConfidentialTransfer, PodElGamalPubkey,
+        },
+        macros::define_extension_instruction,
+    },
+    pinocchio::pubkey::Pubkey,
+};
+
+define_extension_instruction! {
+    /// Initializes confidential transfers for a mint.
+    ///
+    /// Must be included within the same transaction as `InitializeMint`,
+    /// otherwise another party can initialize the configuration.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The SPL Token mint.
+    pub struct InitializeConfidentialTransferMint<'a> {
+        accounts: {
+            /// Mint Account
+            mint: writable,
+        },
+        data: {
+            /// Optional authority to modify the configuration and approve
+            /// accounts
+            authority: Option<&'a Pubkey>,
+            /// Whether new accounts are approved on configuration
+            auto_approve_new_accounts: bool,
+            /// Optional ElGamal public key of the auditor
+            auditor_elgamal_pubkey: Option<&'a PodElGamalPubkey>,
+        },
+        instruction_data: initialize_confidential_transfer_mint_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn initialize_confidential_transfer_mint_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    authority: Option<&Pubkey>,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<&PodElGamalPubkey>,
+) -> &'a [u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorConfidentialTransfer::InitializeMint.as_bytes());
+
+    // Set authority
+    if let Some(x) = authority {
+        buffer[offset..offset + OFFSET::AUTHORITY_PUBKEY as usize].copy_from_slice(x);
+    }
+    offset += OFFSET::AUTHORITY_PUBKEY as usize;
+
+    // Set auto_approve_new_accounts
+    buffer[offset] = auto_approve_new_accounts as u8;
+    offset += OFFSET::AUTO_APPROVE_NEW_ACCOUNTS as usize;
+
+    // Set auditor_elgamal_pubkey
+    if let Some(x) = auditor_elgamal_pubkey {
+        buffer[offset..offset + OFFSET::AUDITOR_ELGAMAL_PUBKEY as usize]
+            .copy_from_slice(x.as_ref());
+    }
+
+    buffer
+}
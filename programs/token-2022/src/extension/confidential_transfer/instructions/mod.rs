@@ -0,0 +1,11 @@
+mod apply_pending_balance;
+mod configure_account;
+mod deposit;
+mod initialize_confidential_transfer_mint;
+mod withdraw;
+
+pub use apply_pending_balance::*;
+pub use configure_account::*;
+pub use deposit::*;
+pub use initialize_confidential_transfer_mint::*;
+pub use withdraw::*;
@@ -0,0 +1,207 @@
+use {
+    crate::{
+        cpi::invoke_signed_with_bounds,
+        extension::confidential_transfer::state::{
+            offset_confidential_transfer_withdraw as OFFSET, DecryptableBalance,
+            InstructionDiscriminatorConfidentialTransfer,
+        },
+        instructions::MAX_MULTISIG_SIGNERS,
+    },
+    core::{mem::MaybeUninit, slice},
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Withdraw SPL Tokens from the available balance of a confidential token
+/// account.
+///
+/// Must be accompanied by the `VerifyCiphertextCommitmentEquality` and
+/// `VerifyBatchedRangeProofU64` instructions of the `zk_elgamal_proof`
+/// program in the same transaction, or the addresses of context state
+/// accounts for the proofs must be provided.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The SPL Token account.
+///   1. `[]` The token mint.
+///   2. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///      included in the same transaction.
+///   3. `[]` (Optional) Equality proof record account or context state
+///      account.
+///   4. `[]` (Optional) Range proof record account or context state account.
+///   5. `[signer]` The single source account owner.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The SPL Token account.
+///   1. `[]` The token mint.
+///   2. `[]` (Optional) Instructions sysvar.
+///   3. `[]` (Optional) Equality proof record account or context state
+///      account.
+///   4. `[]` (Optional) Range proof record account or context state account.
+///   5. `[]` The multisig source account owner.
+///   6. `..6+M` `[signer]` M signer accounts.
+pub struct Withdraw<'a> {
+    /// Token Account
+    pub token_account: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Optional instructions sysvar
+    pub instructions_sysvar: Option<&'a AccountInfo>,
+    /// Optional equality proof record account or context state account
+    pub equality_proof_account: Option<&'a AccountInfo>,
+    /// Optional range proof record account or context state account
+    pub range_proof_account: Option<&'a AccountInfo>,
+    /// The account owner
+    pub authority: &'a AccountInfo,
+    /// The Signer accounts if `authority` is a multisig
+    pub signers: &'a [AccountInfo],
+    /// Amount of tokens to withdraw
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place
+    pub decimals: u8,
+    /// The new decryptable balance if the withdrawal succeeds
+    pub new_decryptable_available_balance: &'a DecryptableBalance,
+    /// Relative location of the equality proof instruction, `0` to use a
+    /// context state account
+    pub equality_proof_instruction_offset: i8,
+    /// Relative location of the range proof instruction, `0` to use a
+    /// context state account
+    pub range_proof_instruction_offset: i8,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl Withdraw<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            token_account,
+            mint,
+            instructions_sysvar,
+            equality_proof_account,
+            range_proof_account,
+            authority,
+            signers: account_signers,
+            token_program,
+            ..
+        } = self;
+
+        if account_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 6 + MAX_MULTISIG_SIGNERS];
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 6 + MAX_MULTISIG_SIGNERS];
+
+        acc_metas[0].write(AccountMeta::writable(token_account.key()));
+        acc_infos[0].write(token_account);
+
+        acc_metas[1].write(AccountMeta::readonly(mint.key()));
+        acc_infos[1].write(mint);
+
+        let mut num_accounts = 2;
+
+        for account in [
+            instructions_sysvar,
+            equality_proof_account,
+            range_proof_account,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            acc_metas[num_accounts].write(AccountMeta::readonly(account.key()));
+            acc_infos[num_accounts].write(account);
+            num_accounts += 1;
+        }
+
+        if account_signers.is_empty() {
+            acc_metas[num_accounts].write(AccountMeta::readonly_signer(authority.key()));
+        } else {
+            acc_metas[num_accounts].write(AccountMeta::readonly(authority.key()));
+        }
+        acc_infos[num_accounts].write(authority);
+        num_accounts += 1;
+
+        // Fill signer accounts
+        for signer in account_signers {
+            acc_metas[num_accounts].write(AccountMeta::readonly_signer(signer.key()));
+            acc_infos[num_accounts].write(signer);
+            num_accounts += 1;
+        }
+
+        let mut buffer = [0u8; OFFSET::END as usize];
+        let data = withdraw_instruction_data(
+            &mut buffer,
+            self.amount,
+            self.decimals,
+            self.new_decryptable_available_balance,
+            self.equality_proof_instruction_offset,
+            self.range_proof_instruction_offset,
+        );
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data,
+        };
+
+        invoke_signed_with_bounds::<{ 6 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
+
+pub fn withdraw_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    amount: u64,
+    decimals: u8,
+    new_decryptable_available_balance: &DecryptableBalance,
+    equality_proof_instruction_offset: i8,
+    range_proof_instruction_offset: i8,
+) -> &'a [u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorConfidentialTransfer::Withdraw.as_bytes());
+
+    // Set amount
+    buffer[offset..offset + OFFSET::AMOUNT as usize].copy_from_slice(&amount.to_le_bytes());
+    offset += OFFSET::AMOUNT as usize;
+
+    // Set decimals
+    buffer[offset] = decimals;
+    offset += OFFSET::DECIMALS as usize;
+
+    // Set new_decryptable_available_balance
+    buffer[offset..offset + OFFSET::NEW_DECRYPTABLE_AVAILABLE_BALANCE as usize]
+        .copy_from_slice(new_decryptable_available_balance.as_ref());
+    offset += OFFSET::NEW_DECRYPTABLE_AVAILABLE_BALANCE as usize;
+
+    // Set equality_proof_instruction_offset
+    buffer[offset] = equality_proof_instruction_offset as u8;
+    offset += OFFSET::EQUALITY_PROOF_INSTRUCTION_OFFSET as usize;
+
+    // Set range_proof_instruction_offset
+    buffer[offset] = range_proof_instruction_offset as u8;
+
+    buffer
+}
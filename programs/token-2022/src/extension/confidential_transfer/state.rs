@@ -0,0 +1,313 @@
+use {
+    crate::{
+        extension::{
+            consts::ExtensionType,
+            tlv::{get_extension_from_bytes, Extension},
+        },
+        ID,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+pub use crate::extension::confidential::{
+    DecryptableBalance, EncryptedBalance, OptionalNonZeroElGamalPubkey, PodAeCiphertext,
+    PodElGamalCiphertext, PodElGamalPubkey,
+};
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstructionDiscriminatorConfidentialTransfer {
+    InitializeMint = 0,
+    UpdateMint = 1,
+    ConfigureAccount = 2,
+    ApproveAccount = 3,
+    EmptyAccount = 4,
+    Deposit = 5,
+    Withdraw = 6,
+    Transfer = 7,
+    ApplyPendingBalance = 8,
+    EnableConfidentialCredits = 9,
+    DisableConfidentialCredits = 10,
+    EnableNonConfidentialCredits = 11,
+    DisableNonConfidentialCredits = 12,
+    TransferWithFee = 13,
+    ConfigureAccountWithRegistry = 14,
+}
+
+crate::extension::consts::extension_instruction!(
+    InstructionDiscriminatorConfidentialTransfer,
+    ConfidentialTransfer,
+    ConfigureAccountWithRegistry
+);
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2..34]                    : authority pubkey (optional, 32 bytes)
+/// - [34]                       : auto_approve_new_accounts (1 byte, bool)
+/// - [35..67]                   : auditor_elgamal_pubkey (optional, 32 bytes)
+pub mod offset_confidential_transfer_initialize_mint {
+    pub const START: u8 = 2;
+    pub const AUTHORITY_PUBKEY: u8 = 32;
+    pub const AUTO_APPROVE_NEW_ACCOUNTS: u8 = 1;
+    pub const AUDITOR_ELGAMAL_PUBKEY: u8 = 32;
+    pub const END: u8 =
+        START + AUTHORITY_PUBKEY + AUTO_APPROVE_NEW_ACCOUNTS + AUDITOR_ELGAMAL_PUBKEY;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2..38]                    : decryptable_zero_balance (36 bytes)
+/// - [38..46]                   : maximum_pending_balance_credit_counter (8 bytes, u64)
+/// - [46]                       : proof_instruction_offset (1 byte, i8)
+pub mod offset_confidential_transfer_configure_account {
+    pub const START: u8 = 2;
+    pub const DECRYPTABLE_ZERO_BALANCE: u8 = 36;
+    pub const MAXIMUM_PENDING_BALANCE_CREDIT_COUNTER: u8 = 8;
+    pub const PROOF_INSTRUCTION_OFFSET: u8 = 1;
+    pub const END: u8 = START
+        + DECRYPTABLE_ZERO_BALANCE
+        + MAXIMUM_PENDING_BALANCE_CREDIT_COUNTER
+        + PROOF_INSTRUCTION_OFFSET;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2..10]                    : amount (8 bytes, u64)
+/// - [10]                       : decimals (1 byte, u8)
+pub mod offset_confidential_transfer_deposit {
+    pub const START: u8 = 2;
+    pub const AMOUNT: u8 = 8;
+    pub const DECIMALS: u8 = 1;
+    pub const END: u8 = START + AMOUNT + DECIMALS;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2..10]                    : amount (8 bytes, u64)
+/// - [10]                       : decimals (1 byte, u8)
+/// - [11..47]                   : new_decryptable_available_balance (36 bytes)
+/// - [47]                       : equality_proof_instruction_offset (1 byte, i8)
+/// - [48]                       : range_proof_instruction_offset (1 byte, i8)
+pub mod offset_confidential_transfer_withdraw {
+    pub const START: u8 = 2;
+    pub const AMOUNT: u8 = 8;
+    pub const DECIMALS: u8 = 1;
+    pub const NEW_DECRYPTABLE_AVAILABLE_BALANCE: u8 = 36;
+    pub const EQUALITY_PROOF_INSTRUCTION_OFFSET: u8 = 1;
+    pub const RANGE_PROOF_INSTRUCTION_OFFSET: u8 = 1;
+    pub const END: u8 = START
+        + AMOUNT
+        + DECIMALS
+        + NEW_DECRYPTABLE_AVAILABLE_BALANCE
+        + EQUALITY_PROOF_INSTRUCTION_OFFSET
+        + RANGE_PROOF_INSTRUCTION_OFFSET;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2..10]                    : expected_pending_balance_credit_counter (8 bytes, u64)
+/// - [10..46]                   : new_decryptable_available_balance (36 bytes)
+pub mod offset_confidential_transfer_apply_pending_balance {
+    pub const START: u8 = 2;
+    pub const EXPECTED_PENDING_BALANCE_CREDIT_COUNTER: u8 = 8;
+    pub const NEW_DECRYPTABLE_AVAILABLE_BALANCE: u8 = 36;
+    pub const END: u8 =
+        START + EXPECTED_PENDING_BALANCE_CREDIT_COUNTER + NEW_DECRYPTABLE_AVAILABLE_BALANCE;
+}
+
+/// Confidential transfer extension data for mints
+#[repr(C)]
+pub struct ConfidentialTransferMint {
+    /// Optional authority to modify the confidential transfer mint
+    /// configuration and approve accounts
+    authority: Pubkey,
+    /// If `true`, new accounts are approved on configuration
+    auto_approve_new_accounts: u8,
+    /// Optional ElGamal key of the auditor of the confidential transfers
+    auditor_elgamal_pubkey: OptionalNonZeroElGamalPubkey,
+}
+
+unsafe impl Extension for ConfidentialTransferMint {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialTransferMint;
+}
+
+impl ConfidentialTransferMint {
+    /// The length of the `ConfidentialTransferMint` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<ConfidentialTransferMint>();
+
+    /// Return a `ConfidentialTransferMint` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<ConfidentialTransferMint>, ProgramError> {
+        // Check owner
+        if account_info.owner() != &ID {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        // Safely borrow and map the data
+        let data_ref = account_info
+            .try_borrow_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+        Ref::try_map(data_ref, Self::from_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `ConfidentialTransferMint` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    pub fn has_authority(&self) -> bool {
+        self.authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn authority(&self) -> Option<&Pubkey> {
+        if self.has_authority() {
+            Some(&self.authority)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn auto_approve_new_accounts(&self) -> bool {
+        self.auto_approve_new_accounts != 0
+    }
+
+    #[inline(always)]
+    pub fn auditor_elgamal_pubkey(&self) -> Option<&PodElGamalPubkey> {
+        self.auditor_elgamal_pubkey.get()
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(ConfidentialTransferMint);
+
+/// Confidential transfer extension data for token accounts
+#[repr(C)]
+pub struct ConfidentialTransferAccount {
+    /// If `false`, the account can't be used for confidential transfers
+    /// until the mint authority approves it
+    approved: u8,
+    /// ElGamal key the balances of the account are encrypted with
+    elgamal_pubkey: PodElGamalPubkey,
+    /// Low 16 bits of the pending balance
+    pending_balance_lo: EncryptedBalance,
+    /// High 48 bits of the pending balance
+    pending_balance_hi: EncryptedBalance,
+    /// Available balance
+    available_balance: EncryptedBalance,
+    /// Available balance, decryptable by the owner
+    decryptable_available_balance: DecryptableBalance,
+    /// If `false`, incoming confidential transfers are rejected
+    allow_confidential_credits: u8,
+    /// If `false`, incoming non-confidential transfers are rejected
+    allow_non_confidential_credits: u8,
+    /// Number of incoming credits to the pending balance
+    pending_balance_credit_counter: [u8; 8],
+    /// Number of incoming credits the pending balance accepts before
+    /// `ApplyPendingBalance` is required
+    maximum_pending_balance_credit_counter: [u8; 8],
+    /// `expected_pending_balance_credit_counter` of the last
+    /// `ApplyPendingBalance`
+    expected_pending_balance_credit_counter: [u8; 8],
+    /// `pending_balance_credit_counter` when the last `ApplyPendingBalance`
+    /// was processed
+    actual_pending_balance_credit_counter: [u8; 8],
+}
+
+unsafe impl Extension for ConfidentialTransferAccount {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialTransferAccount;
+}
+
+impl ConfidentialTransferAccount {
+    /// The length of the `ConfidentialTransferAccount` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<ConfidentialTransferAccount>();
+
+    /// Return a `ConfidentialTransferAccount` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    pub fn approved(&self) -> bool {
+        self.approved != 0
+    }
+
+    #[inline(always)]
+    pub fn elgamal_pubkey(&self) -> &PodElGamalPubkey {
+        &self.elgamal_pubkey
+    }
+
+    #[inline(always)]
+    pub fn pending_balance_lo(&self) -> &EncryptedBalance {
+        &self.pending_balance_lo
+    }
+
+    #[inline(always)]
+    pub fn pending_balance_hi(&self) -> &EncryptedBalance {
+        &self.pending_balance_hi
+    }
+
+    #[inline(always)]
+    pub fn available_balance(&self) -> &EncryptedBalance {
+        &self.available_balance
+    }
+
+    #[inline(always)]
+    pub fn decryptable_available_balance(&self) -> &DecryptableBalance {
+        &self.decryptable_available_balance
+    }
+
+    #[inline(always)]
+    pub fn allow_confidential_credits(&self) -> bool {
+        self.allow_confidential_credits != 0
+    }
+
+    #[inline(always)]
+    pub fn allow_non_confidential_credits(&self) -> bool {
+        self.allow_non_confidential_credits != 0
+    }
+
+    #[inline(always)]
+    pub fn pending_balance_credit_counter(&self) -> u64 {
+        u64::from_le_bytes(self.pending_balance_credit_counter)
+    }
+
+    #[inline(always)]
+    pub fn maximum_pending_balance_credit_counter(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_pending_balance_credit_counter)
+    }
+
+    #[inline(always)]
+    pub fn expected_pending_balance_credit_counter(&self) -> u64 {
+        u64::from_le_bytes(self.expected_pending_balance_credit_counter)
+    }
+
+    #[inline(always)]
+    pub fn actual_pending_balance_credit_counter(&self) -> u64 {
+        u64::from_le_bytes(self.actual_pending_balance_credit_counter)
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(ConfidentialTransferAccount);
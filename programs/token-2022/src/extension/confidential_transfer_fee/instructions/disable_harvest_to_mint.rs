@@ -0,0 +1,45 @@
+use crate::{
+    extension::confidential_transfer_fee::state::{
+        offset_confidential_transfer_fee_no_data as OFFSET,
+        InstructionDiscriminatorConfidentialTransferFee,
+    },
+    macros::define_extension_instruction,
+};
+
+define_extension_instruction! {
+    /// Configure a confidential transfer fee mint to reject any
+    /// harvested confidential fees.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The token mint.
+    ///   1. `[signer]` The confidential transfer fee authority.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The token mint.
+    ///   1. `[]` The confidential transfer fee multisig authority.
+    ///   2. `..2+M` `[signer]` M signer accounts.
+    pub struct DisableHarvestToMint<'a> {
+        accounts: {
+            /// Mint Account
+            mint: writable,
+        },
+        authority: {
+            /// The confidential transfer fee authority.
+            authority
+        },
+        data: {},
+        instruction_data: disable_harvest_to_mint_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn disable_harvest_to_mint_instruction_data(buffer: &mut [u8]) -> &[u8] {
+    // Set discriminators
+    buffer[..OFFSET::START as usize].copy_from_slice(
+        &InstructionDiscriminatorConfidentialTransferFee::DisableHarvestToMint.as_bytes(),
+    );
+
+    buffer
+}
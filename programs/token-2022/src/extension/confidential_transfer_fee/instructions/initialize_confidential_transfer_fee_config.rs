@@ -0,0 +1,61 @@
+use {
+    crate::{
+        extension::confidential_transfer_fee::state::{
+            offset_confidential_transfer_fee_initialize as OFFSET,
+            InstructionDiscriminatorConfidentialTransferFee, PodElGamalPubkey,
+        },
+        macros::define_extension_instruction,
+    },
+    pinocchio::pubkey::Pubkey,
+};
+
+define_extension_instruction! {
+    /// Initializes confidential transfer fees for a mint.
+    ///
+    /// Must be included within the same transaction as `InitializeMint`,
+    /// otherwise another party can initialize the configuration.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The SPL Token mint.
+    pub struct InitializeConfidentialTransferFeeConfig<'a> {
+        accounts: {
+            /// Mint Account
+            mint: writable,
+        },
+        data: {
+            /// Optional confidential transfer fee authority
+            authority: Option<&'a Pubkey>,
+            /// ElGamal public key used to encrypt withheld fees
+            withdraw_withheld_authority_elgamal_pubkey: &'a PodElGamalPubkey,
+        },
+        instruction_data: initialize_confidential_transfer_fee_config_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn initialize_confidential_transfer_fee_config_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    authority: Option<&Pubkey>,
+    withdraw_withheld_authority_elgamal_pubkey: &PodElGamalPubkey,
+) -> &'a [u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset].copy_from_slice(
+        &InstructionDiscriminatorConfidentialTransferFee::InitializeConfidentialTransferFeeConfig
+            .as_bytes(),
+    );
+
+    // Set authority
+    if let Some(x) = authority {
+        buffer[offset..offset + OFFSET::AUTHORITY_PUBKEY as usize].copy_from_slice(x);
+    }
+    offset += OFFSET::AUTHORITY_PUBKEY as usize;
+
+    // Set withdraw_withheld_authority_elgamal_pubkey
+    buffer[offset..offset + OFFSET::ELGAMAL_PUBKEY as usize]
+        .copy_from_slice(withdraw_withheld_authority_elgamal_pubkey.as_ref());
+
+    buffer
+}
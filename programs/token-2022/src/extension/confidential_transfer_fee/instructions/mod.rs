@@ -0,0 +1,13 @@
+mod disable_harvest_to_mint;
+mod enable_harvest_to_mint;
+mod harvest_withheld_tokens_to_mint;
+mod initialize_confidential_transfer_fee_config;
+mod withdraw_withheld_tokens_from_accounts;
+mod withdraw_withheld_tokens_from_mint;
+
+pub use disable_harvest_to_mint::*;
+pub use enable_harvest_to_mint::*;
+pub use harvest_withheld_tokens_to_mint::*;
+pub use initialize_confidential_transfer_fee_config::*;
+pub use withdraw_withheld_tokens_from_accounts::*;
+pub use withdraw_withheld_tokens_from_mint::*;
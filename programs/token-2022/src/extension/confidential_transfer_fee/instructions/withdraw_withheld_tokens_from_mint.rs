@@ -0,0 +1,182 @@
+use {
+    crate::{
+        cpi::invoke_signed_with_bounds,
+        extension::confidential_transfer_fee::state::{
+            offset_confidential_transfer_fee_withdraw_withheld_tokens_from_mint as OFFSET,
+            DecryptableBalance, InstructionDiscriminatorConfidentialTransferFee,
+        },
+        instructions::MAX_MULTISIG_SIGNERS,
+    },
+    core::{mem::MaybeUninit, slice},
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Transfer all withheld confidential tokens in the mint to an account.
+/// Signed by the mint's withdraw withheld tokens authority.
+///
+/// Must be accompanied by the `VerifyCiphertextCiphertextEquality`
+/// instruction of the `zk_elgamal_proof` program in the same transaction, or
+/// the address of a context state account for the proof must be provided.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The token mint.
+///   1. `[writable]` The fee receiver account.
+///   2. `[]` Instructions sysvar or context state account.
+///   3. `[]` (Optional) Record account if the proof is read from a record
+///      account.
+///   4. `[signer]` The mint's `withdraw_withheld_authority`.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The token mint.
+///   1. `[writable]` The fee receiver account.
+///   2. `[]` Instructions sysvar or context state account.
+///   3. `[]` (Optional) Record account.
+///   4. `[]` The mint's multisig `withdraw_withheld_authority`.
+///   5. `..5+M` `[signer]` M signer accounts.
+pub struct WithdrawWithheldTokensFromMint<'a> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// The fee receiver account
+    pub destination: &'a AccountInfo,
+    /// Instructions sysvar or context state account
+    pub instructions_sysvar_or_context_state: &'a AccountInfo,
+    /// Optional record account
+    pub record: Option<&'a AccountInfo>,
+    /// The mint's withdraw withheld authority
+    pub authority: &'a AccountInfo,
+    /// The Signer accounts if `authority` is a multisig
+    pub signers: &'a [AccountInfo],
+    /// Relative location of the proof instruction, `0` to use a context
+    /// state account
+    pub proof_instruction_offset: i8,
+    /// The new decryptable balance in the destination token account
+    pub new_decryptable_available_balance: &'a DecryptableBalance,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl WithdrawWithheldTokensFromMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            destination,
+            instructions_sysvar_or_context_state,
+            record,
+            authority,
+            signers: account_signers,
+            token_program,
+            ..
+        } = self;
+
+        if account_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_fixed_accounts = 4 + record.is_some() as usize;
+        let num_accounts = num_fixed_accounts + account_signers.len();
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 5 + MAX_MULTISIG_SIGNERS];
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 5 + MAX_MULTISIG_SIGNERS];
+
+        acc_metas[0].write(AccountMeta::writable(mint.key()));
+        acc_infos[0].write(mint);
+
+        acc_metas[1].write(AccountMeta::writable(destination.key()));
+        acc_infos[1].write(destination);
+
+        acc_metas[2].write(AccountMeta::readonly(
+            instructions_sysvar_or_context_state.key(),
+        ));
+        acc_infos[2].write(instructions_sysvar_or_context_state);
+
+        if let Some(record) = record {
+            acc_metas[3].write(AccountMeta::readonly(record.key()));
+            acc_infos[3].write(record);
+        }
+
+        let authority_index = num_fixed_accounts - 1;
+
+        if account_signers.is_empty() {
+            acc_metas[authority_index].write(AccountMeta::readonly_signer(authority.key()));
+        } else {
+            acc_metas[authority_index].write(AccountMeta::readonly(authority.key()));
+        }
+        acc_infos[authority_index].write(authority);
+
+        // Fill signer accounts
+        for (account_meta, signer) in acc_metas[num_fixed_accounts..]
+            .iter_mut()
+            .zip(account_signers.iter())
+        {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
+
+        for (account_info, signer) in acc_infos[num_fixed_accounts..]
+            .iter_mut()
+            .zip(account_signers.iter())
+        {
+            account_info.write(signer);
+        }
+
+        let mut buffer = [0u8; OFFSET::END as usize];
+        let data = withdraw_withheld_tokens_from_mint_instruction_data(
+            &mut buffer,
+            self.proof_instruction_offset,
+            self.new_decryptable_available_balance,
+        );
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data,
+        };
+
+        invoke_signed_with_bounds::<{ 5 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
+
+pub fn withdraw_withheld_tokens_from_mint_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    proof_instruction_offset: i8,
+    new_decryptable_available_balance: &DecryptableBalance,
+) -> &'a [u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset].copy_from_slice(
+        &InstructionDiscriminatorConfidentialTransferFee::WithdrawWithheldTokensFromMint.as_bytes(),
+    );
+
+    // Set proof_instruction_offset
+    buffer[offset] = proof_instruction_offset as u8;
+    offset += OFFSET::PROOF_INSTRUCTION_OFFSET as usize;
+
+    // Set new_decryptable_available_balance
+    buffer[offset..offset + OFFSET::NEW_DECRYPTABLE_AVAILABLE_BALANCE as usize]
+        .copy_from_slice(new_decryptable_available_balance.as_ref());
+
+    buffer
+}
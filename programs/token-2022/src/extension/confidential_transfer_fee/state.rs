@@ -0,0 +1,204 @@
+use {
+    crate::{
+        extension::{
+            consts::ExtensionType,
+            tlv::{get_extension_from_bytes, Extension},
+        },
+        ID,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+pub use crate::extension::confidential::{
+    DecryptableBalance, PodAeCiphertext, PodElGamalCiphertext, PodElGamalPubkey,
+};
+
+/// ElGamal ciphertext containing a withheld fee in an account
+pub type EncryptedWithheldAmount = PodElGamalCiphertext;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstructionDiscriminatorConfidentialTransferFee {
+    InitializeConfidentialTransferFeeConfig = 0,
+    WithdrawWithheldTokensFromMint = 1,
+    WithdrawWithheldTokensFromAccounts = 2,
+    HarvestWithheldTokensToMint = 3,
+    EnableHarvestToMint = 4,
+    DisableHarvestToMint = 5,
+}
+
+crate::extension::consts::extension_instruction!(
+    InstructionDiscriminatorConfidentialTransferFee,
+    ConfidentialTransferFee,
+    DisableHarvestToMint
+);
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2..34]                    : authority pubkey (optional, 32 bytes)
+/// - [34..66]                   : withdraw_withheld_authority_elgamal_pubkey (32 bytes)
+pub mod offset_confidential_transfer_fee_initialize {
+    pub const START: u8 = 2;
+    pub const AUTHORITY_PUBKEY: u8 = 32;
+    pub const ELGAMAL_PUBKEY: u8 = 32;
+    pub const END: u8 = START + AUTHORITY_PUBKEY + ELGAMAL_PUBKEY;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2]                        : proof_instruction_offset (1 byte, i8)
+/// - [3..39]                    : new_decryptable_available_balance (36 bytes)
+pub mod offset_confidential_transfer_fee_withdraw_withheld_tokens_from_mint {
+    pub const START: u8 = 2;
+    pub const PROOF_INSTRUCTION_OFFSET: u8 = 1;
+    pub const NEW_DECRYPTABLE_AVAILABLE_BALANCE: u8 = 36;
+    pub const END: u8 = START + PROOF_INSTRUCTION_OFFSET + NEW_DECRYPTABLE_AVAILABLE_BALANCE;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2]                        : num_token_accounts (1 byte, u8)
+/// - [3]                        : proof_instruction_offset (1 byte, i8)
+/// - [4..40]                    : new_decryptable_available_balance (36 bytes)
+pub mod offset_confidential_transfer_fee_withdraw_withheld_tokens_from_accounts {
+    pub const START: u8 = 2;
+    pub const NUM_TOKEN_ACCOUNTS: u8 = 1;
+    pub const PROOF_INSTRUCTION_OFFSET: u8 = 1;
+    pub const NEW_DECRYPTABLE_AVAILABLE_BALANCE: u8 = 36;
+    pub const END: u8 = START
+        + NUM_TOKEN_ACCOUNTS
+        + PROOF_INSTRUCTION_OFFSET
+        + NEW_DECRYPTABLE_AVAILABLE_BALANCE;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+///
+/// Shared by `HarvestWithheldTokensToMint`, `EnableHarvestToMint` and
+/// `DisableHarvestToMint`, which carry no data.
+pub mod offset_confidential_transfer_fee_no_data {
+    pub const START: u8 = 2;
+    pub const END: u8 = START;
+}
+
+/// Confidential transfer fee extension data for mints
+#[repr(C)]
+pub struct ConfidentialTransferFeeConfig {
+    /// Optional authority to set the withdraw withheld authority ElGamal key
+    authority: Pubkey,
+    /// Withheld fees from accounts must be encrypted with this ElGamal key
+    withdraw_withheld_authority_elgamal_pubkey: PodElGamalPubkey,
+    /// If `false`, the harvest of withheld tokens to mint is rejected
+    harvest_to_mint_enabled: u8,
+    /// Withheld confidential transfer fee tokens that have been moved to the
+    /// mint for withdrawal
+    withheld_amount: EncryptedWithheldAmount,
+}
+
+unsafe impl Extension for ConfidentialTransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialTransferFeeConfig;
+}
+
+impl ConfidentialTransferFeeConfig {
+    /// The length of the `ConfidentialTransferFeeConfig` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<ConfidentialTransferFeeConfig>();
+
+    /// Return a `ConfidentialTransferFeeConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<ConfidentialTransferFeeConfig>, ProgramError> {
+        // Check owner
+        if account_info.owner() != &ID {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        // Safely borrow and map the data
+        let data_ref = account_info
+            .try_borrow_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+        Ref::try_map(data_ref, Self::from_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `ConfidentialTransferFeeConfig` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    pub fn has_authority(&self) -> bool {
+        self.authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn authority(&self) -> Option<&Pubkey> {
+        if self.has_authority() {
+            Some(&self.authority)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn withdraw_withheld_authority_elgamal_pubkey(&self) -> &PodElGamalPubkey {
+        &self.withdraw_withheld_authority_elgamal_pubkey
+    }
+
+    #[inline(always)]
+    pub fn harvest_to_mint_enabled(&self) -> bool {
+        self.harvest_to_mint_enabled != 0
+    }
+
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> &EncryptedWithheldAmount {
+        &self.withheld_amount
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(ConfidentialTransferFeeConfig);
+
+/// Confidential transfer fee extension data for token accounts
+#[repr(C)]
+pub struct ConfidentialTransferFeeAmount {
+    /// Amount withheld during confidential transfers, to be harvest to the mint
+    withheld_amount: EncryptedWithheldAmount,
+}
+
+unsafe impl Extension for ConfidentialTransferFeeAmount {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialTransferFeeAmount;
+}
+
+impl ConfidentialTransferFeeAmount {
+    /// The length of the `ConfidentialTransferFeeAmount` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<ConfidentialTransferFeeAmount>();
+
+    /// Return a `ConfidentialTransferFeeAmount` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> &EncryptedWithheldAmount {
+        &self.withheld_amount
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(ConfidentialTransferFeeAmount);
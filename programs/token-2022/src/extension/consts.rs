@@ -1,4 +1,17 @@
+use {
+    crate::{
+        extension::tlv::{EXTENSIONS_START, TLV_HEADER_LEN},
+        state::{BaseState, Multisig},
+    },
+    core::mem::size_of,
+    pinocchio::program_error::ProgramError,
+};
+
+/// Top-level discriminators of the instructions of the extensions, followed
+/// by the extension instruction discriminator for extensions with several
+/// instructions.
 #[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ExtensionDiscriminator {
     CpiGuard = 34,
     MemoTransfer = 30,
@@ -11,4 +24,230 @@ pub enum ExtensionDiscriminator {
     TransferHook = 36,
     InterestBearingMint = 33,
     MetadataPointer = 39,
+    TransferFee = 26,
+    ConfidentialTransfer = 27,
+    ConfidentialTransferFee = 37,
+    NonTransferable = 32,
+    MintCloseAuthority = 25,
+    ImmutableOwner = 22,
+}
+
+impl TryFrom<u8> for ExtensionDiscriminator {
+    type Error = ProgramError;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            22 => Self::ImmutableOwner,
+            25 => Self::MintCloseAuthority,
+            26 => Self::TransferFee,
+            27 => Self::ConfidentialTransfer,
+            28 => Self::DefaultAccountState,
+            30 => Self::MemoTransfer,
+            32 => Self::NonTransferable,
+            33 => Self::InterestBearingMint,
+            34 => Self::CpiGuard,
+            35 => Self::PermanentDelegate,
+            36 => Self::TransferHook,
+            37 => Self::ConfidentialTransferFee,
+            39 => Self::MetadataPointer,
+            40 => Self::GroupPointer,
+            41 => Self::GroupMemberPointer,
+            43 => Self::ScaledUiAmount,
+            44 => Self::Pausable,
+            _ => Err(ProgramError::InvalidInstructionData)?,
+        })
+    }
+}
+
+/// Implement `as_bytes` and `TryFrom<u8>` for the `#[repr(u8)]` instruction
+/// discriminators of an extension, numbered from 0 to `$last`.
+// unused if no extension with several instructions is enabled
+#[allow(unused_macros)]
+macro_rules! extension_instruction {
+    ($instruction:ident, $extension:ident, $last:ident) => {
+        impl $instruction {
+            /// Return the extension and instruction discriminators starting the
+            /// instruction data.
+            #[inline(always)]
+            pub const fn as_bytes(self) -> [u8; 2] {
+                [
+                    $crate::extension::consts::ExtensionDiscriminator::$extension as u8,
+                    self as u8,
+                ]
+            }
+        }
+
+        impl TryFrom<u8> for $instruction {
+            type Error = pinocchio::program_error::ProgramError;
+
+            #[inline]
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                if value > Self::$last as u8 {
+                    Err(pinocchio::program_error::ProgramError::InvalidInstructionData)?;
+                }
+
+                // SAFETY: `value` is within the range of the enum discriminants.
+                Ok(unsafe { core::mem::transmute::<u8, Self>(value) })
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use extension_instruction;
+
+/// Extension types as they are stored in the TLV `type` field of mint and
+/// token accounts.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtensionType {
+    Uninitialized = 0,
+    TransferFeeConfig = 1,
+    TransferFeeAmount = 2,
+    MintCloseAuthority = 3,
+    ConfidentialTransferMint = 4,
+    ConfidentialTransferAccount = 5,
+    DefaultAccountState = 6,
+    ImmutableOwner = 7,
+    MemoTransfer = 8,
+    NonTransferable = 9,
+    InterestBearingConfig = 10,
+    CpiGuard = 11,
+    PermanentDelegate = 12,
+    NonTransferableAccount = 13,
+    TransferHook = 14,
+    TransferHookAccount = 15,
+    ConfidentialTransferFeeConfig = 16,
+    ConfidentialTransferFeeAmount = 17,
+    MetadataPointer = 18,
+    TokenMetadata = 19,
+    GroupPointer = 20,
+    TokenGroup = 21,
+    GroupMemberPointer = 22,
+    TokenGroupMember = 23,
+    ConfidentialMintBurn = 24,
+    ScaledUiAmount = 25,
+    Pausable = 26,
+    PausableAccount = 27,
+}
+
+impl ExtensionType {
+    /// Return the extensions Token-2022 requires on the token accounts of a
+    /// mint carrying this extension.
+    #[inline]
+    pub const fn required_account_extensions(self) -> &'static [Self] {
+        match self {
+            Self::TransferFeeConfig => &[Self::TransferFeeAmount],
+            Self::NonTransferable => &[Self::NonTransferableAccount, Self::ImmutableOwner],
+            Self::TransferHook => &[Self::TransferHookAccount],
+            Self::Pausable => &[Self::PausableAccount],
+            _ => &[],
+        }
+    }
+
+    /// Return the length of the value of this extension.
+    ///
+    /// Fails with `InvalidArgument` for `TokenMetadata`, whose length
+    /// depends on its content.
+    #[inline]
+    pub const fn try_get_type_len(self) -> Result<usize, ProgramError> {
+        Ok(match self {
+            Self::Uninitialized
+            | Self::ImmutableOwner
+            | Self::NonTransferable
+            | Self::NonTransferableAccount => 0,
+            Self::DefaultAccountState
+            | Self::MemoTransfer
+            | Self::CpiGuard
+            | Self::TransferHookAccount
+            | Self::PausableAccount => 1,
+            Self::TransferFeeAmount => 8,
+            Self::MintCloseAuthority | Self::PermanentDelegate => 32,
+            Self::Pausable => 33,
+            Self::InterestBearingConfig => 52,
+            Self::ScaledUiAmount => 56,
+            Self::TransferHook
+            | Self::ConfidentialTransferFeeAmount
+            | Self::MetadataPointer
+            | Self::GroupPointer
+            | Self::GroupMemberPointer => 64,
+            Self::ConfidentialTransferMint => 65,
+            Self::TokenGroupMember => 72,
+            Self::TokenGroup => 80,
+            Self::TransferFeeConfig => 108,
+            Self::ConfidentialTransferFeeConfig => 129,
+            Self::ConfidentialMintBurn => 196,
+            Self::ConfidentialTransferAccount => 295,
+            Self::TokenMetadata => return Err(ProgramError::InvalidArgument),
+        })
+    }
+
+    /// Return the length of an account carrying the given extensions, each
+    /// counted once, or of the base state if there are none.
+    ///
+    /// Fails with `InvalidArgument` if one of the extensions has a variable
+    /// length.
+    pub fn try_calculate_account_len<S: BaseState>(
+        extension_types: &[Self],
+    ) -> Result<usize, ProgramError> {
+        if extension_types.is_empty() {
+            return Ok(S::BASE_LEN);
+        }
+
+        let mut len = EXTENSIONS_START;
+        for (i, extension_type) in extension_types.iter().enumerate() {
+            if !extension_types[..i].contains(extension_type) {
+                len += TLV_HEADER_LEN + extension_type.try_get_type_len()?;
+            }
+        }
+
+        // the length of a multisig tells it apart from the other accounts, so
+        // it is padded with an uninitialized extension type
+        if len == Multisig::LEN {
+            len += size_of::<u16>();
+        }
+
+        Ok(len)
+    }
+}
+
+impl TryFrom<u16> for ExtensionType {
+    type Error = ProgramError;
+
+    /// Fails for TLV types unknown to this crate.
+    #[inline]
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Uninitialized,
+            1 => Self::TransferFeeConfig,
+            2 => Self::TransferFeeAmount,
+            3 => Self::MintCloseAuthority,
+            4 => Self::ConfidentialTransferMint,
+            5 => Self::ConfidentialTransferAccount,
+            6 => Self::DefaultAccountState,
+            7 => Self::ImmutableOwner,
+            8 => Self::MemoTransfer,
+            9 => Self::NonTransferable,
+            10 => Self::InterestBearingConfig,
+            11 => Self::CpiGuard,
+            12 => Self::PermanentDelegate,
+            13 => Self::NonTransferableAccount,
+            14 => Self::TransferHook,
+            15 => Self::TransferHookAccount,
+            16 => Self::ConfidentialTransferFeeConfig,
+            17 => Self::ConfidentialTransferFeeAmount,
+            18 => Self::MetadataPointer,
+            19 => Self::TokenMetadata,
+            20 => Self::GroupPointer,
+            21 => Self::TokenGroup,
+            22 => Self::GroupMemberPointer,
+            23 => Self::TokenGroupMember,
+            24 => Self::ConfidentialMintBurn,
+            25 => Self::ScaledUiAmount,
+            26 => Self::Pausable,
+            27 => Self::PausableAccount,
+            _ => Err(ProgramError::InvalidAccountData)?,
+        })
+    }
 }
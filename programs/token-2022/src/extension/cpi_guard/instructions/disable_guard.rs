@@ -1,6 +1,7 @@
 use core::{mem::MaybeUninit, slice};
 
 use crate::{
+    cpi::invoke_with_bounds,
     extension::cpi_guard::state::{
         cpi_guard_instruction_data, CpiGuardInstruction,
     },
@@ -9,7 +10,6 @@ use crate::{
 
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::invoke_with_bounds,
     instruction::{AccountMeta, Instruction, Signer},
     pubkey::Pubkey,
     ProgramResult,
@@ -65,7 +65,7 @@ impl DisableCpiGuard<'_, '_> {
             program_id: token_program,
         };
 
-        pinocchio::cpi::invoke_signed(&instruction, &[token_account, owner], signers)
+        crate::cpi::invoke_signed(&instruction, &[token_account, owner], signers)
     }
 
     #[inline(always)]
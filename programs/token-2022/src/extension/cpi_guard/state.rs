@@ -1,15 +1,19 @@
-use crate::extension::consts::ExtensionDiscriminator;
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{
+    extension::{consts::ExtensionType, tlv::Extension},
+    write_bytes, UNINIT_BYTE,
+};
 use core::mem::MaybeUninit;
 use pinocchio::program_error::ProgramError;
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CpiGuardInstruction {
     Enable,
     Disable,
 }
 
+crate::extension::consts::extension_instruction!(CpiGuardInstruction, CpiGuard, Disable);
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CpiGuard {
@@ -17,6 +21,10 @@ pub struct CpiGuard {
     pub lock_cpi: u8,
 }
 
+unsafe impl Extension for CpiGuard {
+    const TYPE: ExtensionType = ExtensionType::CpiGuard;
+}
+
 impl CpiGuard {
     /// The length of the account with `CpiGuard` extension data
     const LEN: u8 = 171;
@@ -55,15 +63,15 @@ impl CpiGuard {
     }
 }
 
+crate::owner::impl_from_account_info_with_owner!(CpiGuard);
+
 pub fn cpi_guard_instruction_data(instruction_type: CpiGuardInstruction) -> [MaybeUninit<u8>; 2] {
     // instruction data
     // -  [0]: instruction discriminator (1 byte, u8)
     // -  [1]: instruction_type (1 byte, u8)
     let mut data = [UNINIT_BYTE; 2];
-    // Set extension discriminator at offset [0]
-    write_bytes(&mut data, &[ExtensionDiscriminator::CpiGuard as u8]);
-    // Set sub-instruction at offset [1]
-    write_bytes(&mut data[1..2], &[instruction_type as u8]);
+    // Set extension and sub-instruction discriminators at offset [0..2]
+    write_bytes(&mut data, &instruction_type.as_bytes());
 
     data
 }
@@ -6,12 +6,13 @@ use crate::extension::default_account_state::state::{
 
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::invoke_signed,
     instruction::{AccountMeta, Instruction, Signer},
     pubkey::Pubkey,
     ProgramResult,
 };
 
+use crate::cpi::invoke_signed;
+
 pub struct InitializeDefaultAccountState<'a, 'b> {
     /// Mint Account to initialize.
     pub mint_account: &'a AccountInfo,
@@ -0,0 +1,87 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Signer, program_error::ProgramError, pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    extension::{
+        default_account_state::state::DefaultAccountStateConfig, tlv::get_extension_from_bytes,
+    },
+    instructions::{AccountBuilder, AccountExtensionInitializer, ThawAccount},
+    state::{AccountState, Mint},
+};
+
+/// Initialize a new token account with extensions, then thaw it if its mint
+/// has the `DefaultAccountState` extension set to `Frozen` and
+/// `freeze_authority` is the freeze authority of the mint.
+///
+/// The account stays frozen when `freeze_authority` isn't the freeze
+/// authority of the mint, e.g. until a KYC check passes. The account must have
+/// been created with the space of the base account and of the extensions.
+///
+/// ### Accounts:
+///   0. `[WRITE]`  The account to initialize.
+///   1. `[]` The mint this account will be associated with.
+///   2. `[SIGNER]` The mint freeze authority.
+pub struct InitializeAccountThawed<'a, 'b> {
+    /// New Account.
+    pub account: &'a AccountInfo,
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+    /// Owner of the new Account.
+    pub owner: &'a Pubkey,
+    /// Freeze authority held by the caller, signing `ThawAccount`.
+    pub freeze_authority: &'a AccountInfo,
+    /// Initializers of the extensions, invoked before `InitializeAccount3`.
+    pub extensions: &'b [&'b dyn AccountExtensionInitializer],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl InitializeAccountThawed<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invoke the instructions, `ThawAccount` being signed with `signers`.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let thaw = self.is_thawed_by_caller()?;
+
+        AccountBuilder {
+            account: self.account,
+            mint: self.mint,
+            owner: self.owner,
+            extensions: self.extensions,
+            enabled_extensions: &[],
+            token_program: self.token_program,
+        }
+        .invoke()?;
+
+        if !thaw {
+            return Ok(());
+        }
+
+        ThawAccount {
+            account: self.account,
+            mint: self.mint,
+            freeze_authority: self.freeze_authority,
+            token_program: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+
+    /// Return whether the new account is frozen by default and
+    /// `freeze_authority` can thaw it.
+    #[inline(always)]
+    fn is_thawed_by_caller(&self) -> Result<bool, ProgramError> {
+        let data = self.mint.try_borrow_data()?;
+
+        let frozen = get_extension_from_bytes::<DefaultAccountStateConfig>(&data)
+            .is_some_and(|config| config.state() == AccountState::Frozen as u8);
+
+        Ok(frozen
+            && Mint::from_bytes(&data)?.freeze_authority() == Some(self.freeze_authority.key()))
+    }
+}
@@ -1,2 +1,3 @@
 pub mod initialize;
+pub mod initialize_account;
 pub mod update;
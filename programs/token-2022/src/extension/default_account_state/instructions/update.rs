@@ -1,6 +1,7 @@
 use core::{mem::MaybeUninit, slice};
 
 use crate::{
+    cpi::{invoke_signed, invoke_with_bounds},
     extension::default_account_state::state::{
         default_account_state_instruction_data, DefaultAccountStateInstruction,
     },
@@ -9,7 +10,6 @@ use crate::{
 
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::{invoke_with_bounds, invoke_signed},
     instruction::{AccountMeta, Instruction, Signer},
     pubkey::Pubkey,
     ProgramResult,
@@ -1,6 +1,8 @@
 use core::mem::MaybeUninit;
-use crate::{write_bytes, UNINIT_BYTE, ID};
-use crate::extension::consts::ExtensionDiscriminator;
+use crate::{
+    extension::{consts::ExtensionType, tlv::Extension},
+    write_bytes, UNINIT_BYTE, ID,
+};
 use pinocchio::{
     account_info::{AccountInfo, Ref},
     program_error::ProgramError,
@@ -8,8 +10,8 @@ use pinocchio::{
 };
 
 /// Default Account State extension instructions
-#[derive(Clone, Copy, Debug, PartialEq)]
 #[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DefaultAccountStateInstruction {
     /// Initialize a new mint with the default state for new Accounts
     Initialize = 0,
@@ -17,6 +19,12 @@ pub enum DefaultAccountStateInstruction {
     Update = 1,
 }
 
+crate::extension::consts::extension_instruction!(
+    DefaultAccountStateInstruction,
+    DefaultAccountState,
+    Update
+);
+
 /// Configuration for the DefaultAccountState extension
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -25,6 +33,10 @@ pub struct DefaultAccountStateConfig {
     pub state: u8,
 }
 
+unsafe impl Extension for DefaultAccountStateConfig {
+    const TYPE: ExtensionType = ExtensionType::DefaultAccountState;
+}
+
 impl DefaultAccountStateConfig {
     pub const AUTHORITY_START: usize = 170;
 
@@ -111,6 +123,8 @@ impl DefaultAccountStateConfig {
     }
 }
 
+crate::owner::impl_from_account_info_with_owner!(DefaultAccountStateConfig);
+
 pub fn default_account_state_instruction_data(
     instruction_type: DefaultAccountStateInstruction,
     state: u8,
@@ -122,10 +136,8 @@ pub fn default_account_state_instruction_data(
     
     const UNINIT_BYTE: MaybeUninit<u8> = MaybeUninit::uninit();
     let mut data = [UNINIT_BYTE; 3];
-    // Set extension discriminator at offset [0]
-    write_bytes(&mut data, &[ExtensionDiscriminator::DefaultAccountState as u8]);
-    // Set sub-instruction at offset [1]
-    write_bytes(&mut data[1..2], &[instruction_type as u8]);
+    // Set extension and sub-instruction discriminators at offset [0..2]
+    write_bytes(&mut data, &instruction_type.as_bytes());
     // Set state at offset [2]
     write_bytes(&mut data[2..3], &[state]);
 
@@ -8,11 +8,18 @@ use {
 };
 
 #[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InstructionDiscriminatorGroupMemberPointer {
     Initialize = 0,
     Update = 1,
 }
 
+crate::extension::consts::extension_instruction!(
+    InstructionDiscriminatorGroupMemberPointer,
+    GroupMemberPointer,
+    Update
+);
+
 /// Instruction data layout:
 /// - [0]                        : Extension discriminator (1 byte)
 /// - [1]                        : Initialize discriminator (1 byte)
@@ -186,3 +193,5 @@ impl GroupMemberPointer {
         &self.member_address
     }
 }
+
+crate::owner::impl_from_account_info_with_owner!(GroupMemberPointer);
@@ -8,11 +8,18 @@ use {
 };
 
 #[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InstructionDiscriminatorGroupPointer {
     Initialize = 0,
     Update = 1,
 }
 
+crate::extension::consts::extension_instruction!(
+    InstructionDiscriminatorGroupPointer,
+    GroupPointer,
+    Update
+);
+
 /// Instruction data layout:
 /// - [0]                        : Extension discriminator (1 byte)
 /// - [1]                        : Instruction discriminator (1 byte)
@@ -186,3 +193,5 @@ impl GroupPointer {
         &self.group_address
     }
 }
+
+crate::owner::impl_from_account_info_with_owner!(GroupPointer);
@@ -0,0 +1,35 @@
+use crate::{
+    extension::{
+        consts::ExtensionDiscriminator,
+        immutable_owner::state::offset_immutable_owner_initialize as OFFSET,
+    },
+    macros::define_extension_instruction,
+};
+
+define_extension_instruction! {
+    /// Initialize the immutable owner extension for the given token account.
+    ///
+    /// Fails if the account has already been initialized, so must be called
+    /// before `InitializeAccount`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The token account to initialize.
+    pub struct InitializeImmutableOwner<'a> {
+        accounts: {
+            /// The token account to initialize with an immutable owner
+            token_account: writable,
+        },
+        data: {},
+        instruction_data: initialize_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn initialize_instruction_data(buffer: &mut [u8]) -> &[u8] {
+    // Set discriminator
+    buffer[..OFFSET::START as usize]
+        .copy_from_slice(&[ExtensionDiscriminator::ImmutableOwner as u8]);
+
+    buffer
+}
@@ -0,0 +1,45 @@
+use {
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{has_extension, Extension},
+    },
+    pinocchio::program_error::ProgramError,
+};
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+pub mod offset_immutable_owner_initialize {
+    pub const START: u8 = 1;
+    pub const END: u8 = START;
+}
+
+/// Indicates that the owner of this token account can't be changed. The
+/// extension carries no data, only its presence matters.
+#[repr(C)]
+pub struct ImmutableOwner;
+
+unsafe impl Extension for ImmutableOwner {
+    const TYPE: ExtensionType = ExtensionType::ImmutableOwner;
+}
+
+impl ImmutableOwner {
+    /// Return whether the given token account data carries the
+    /// `ImmutableOwner` extension.
+    #[inline]
+    pub fn is_present(bytes: &[u8]) -> bool {
+        has_extension::<Self>(bytes)
+    }
+
+    /// Return `Ok` if the given token account data carries the
+    /// `ImmutableOwner` extension.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&'static Self, ProgramError> {
+        if Self::is_present(bytes) {
+            Ok(&ImmutableOwner)
+        } else {
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(ImmutableOwner);
@@ -1,9 +1,9 @@
 use {
+    crate::cpi::invoke_signed,
     crate::extension::interest_bearing_mint::state::interest_bearing_mint_initialize_instruction_data,
     core::slice,
     pinocchio::{
         account_info::AccountInfo,
-        cpi::invoke_signed,
         instruction::{AccountMeta, Instruction, Signer},
         pubkey::Pubkey,
         ProgramResult,
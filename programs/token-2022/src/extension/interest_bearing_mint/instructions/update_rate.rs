@@ -1,12 +1,12 @@
 use {
     crate::{
+        cpi::{invoke_signed, invoke_with_bounds},
         extension::interest_bearing_mint::state::interest_bearing_mint_update_rate_instruction_data,
         instructions::MAX_MULTISIG_SIGNERS,
     },
     core::{mem::MaybeUninit, slice},
     pinocchio::{
         account_info::AccountInfo,
-        cpi::{invoke_signed, invoke_with_bounds},
         instruction::{AccountMeta, Instruction, Signer},
         program_error::ProgramError,
         pubkey::Pubkey,
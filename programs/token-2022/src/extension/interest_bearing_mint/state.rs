@@ -8,13 +8,24 @@ use {
     },
 };
 
+/// Annual interest rate is expressed in basis points
+const ONE_IN_BASIS_POINTS: f64 = 10_000.;
+/// Seconds per year, accounting for leap years the same way as Token-2022
+const SECONDS_PER_YEAR: f64 = 60. * 60. * 24. * 365.24;
+
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InterestBearingMintInstruction {
     Initialize = 0,
     UpdateRate = 1,
 }
 
+crate::extension::consts::extension_instruction!(
+    InterestBearingMintInstruction,
+    InterestBearingMint,
+    UpdateRate
+);
+
 #[repr(C, packed)]
 pub struct InterestBearingConfig {
     /// Authority that can set the interest rate
@@ -171,6 +182,139 @@ impl InterestBearingConfig {
     pub fn current_rate(&self) -> i16 {
         self.current_rate
     }
+
+    /// Seconds the pre-update average rate applied for, from the initialization to
+    /// the last rate update
+    #[inline]
+    pub fn pre_update_timespan(&self) -> Option<i64> {
+        self.last_update_timestamp
+            .checked_sub(self.initialization_timestamp)
+    }
+
+    /// Seconds the current rate applied for, from the last rate update to
+    /// `unix_timestamp`
+    #[inline]
+    pub fn post_update_timespan(&self, unix_timestamp: i64) -> Option<i64> {
+        unix_timestamp.checked_sub(self.last_update_timestamp)
+    }
+
+    /// Seconds elapsed from the initialization to `unix_timestamp`, over which
+    /// [`average_rate`](Self::average_rate) is weighted
+    #[inline]
+    pub fn total_elapsed(&self, unix_timestamp: i64) -> Option<i64> {
+        self.pre_update_timespan()?
+            .checked_add(self.post_update_timespan(unix_timestamp)?)
+    }
+
+    /// Time-weighted average of the pre-update average rate and the current rate
+    /// at `unix_timestamp`, in basis points, the rate accruing the same interest
+    /// since the initialization.
+    ///
+    /// Token-2022 stores it as the pre-update average rate when the rate is
+    /// updated, so `average_rate(Clock::get()?.unix_timestamp)` is the rate a
+    /// program reporting the APY gets after an `UpdateRate`. Return the current
+    /// rate if no time elapsed, or `None` on overflow.
+    pub fn average_rate(&self, unix_timestamp: i64) -> Option<i16> {
+        let pre_update_timespan = i128::from(self.pre_update_timespan()?);
+        let post_update_timespan = i128::from(self.post_update_timespan(unix_timestamp)?);
+        let total_elapsed = pre_update_timespan.checked_add(post_update_timespan)?;
+
+        if total_elapsed == 0 {
+            return Some(self.current_rate);
+        }
+
+        i128::from(self.pre_update_average_rate)
+            .checked_mul(pre_update_timespan)?
+            .checked_add(i128::from(self.current_rate).checked_mul(post_update_timespan)?)?
+            .checked_div(total_elapsed)?
+            .try_into()
+            .ok()
+    }
+
+    /// Interest accrued before the last rate update, as a continuously compounded factor
+    fn pre_update_exp(&self) -> Option<f64> {
+        Some(accrual_exp(
+            self.pre_update_average_rate,
+            self.pre_update_timespan()?,
+        ))
+    }
+
+    /// Interest accrued since the last rate update, as a continuously compounded factor
+    fn post_update_exp(&self, unix_timestamp: i64) -> Option<f64> {
+        Some(accrual_exp(
+            self.current_rate,
+            self.post_update_timespan(unix_timestamp)?,
+        ))
+    }
+
+    /// Total factor converting a raw amount into a UI amount at `unix_timestamp`,
+    /// including the interest and the mint decimals
+    pub fn total_scale(&self, decimals: u8, unix_timestamp: i64) -> Option<f64> {
+        let mut scale = self.pre_update_exp()? * self.post_update_exp(unix_timestamp)?;
+
+        for _ in 0..decimals {
+            scale /= 10.;
+        }
+
+        Some(scale)
+    }
+
+    /// Convert a raw amount into a UI amount with the interest accrued up to `unix_timestamp`,
+    /// matching `AmountToUiAmount` of Token-2022 before it's rounded to `decimals`.
+    ///
+    /// Programs should pass `Clock::get()?.unix_timestamp` to get the current UI amount.
+    #[inline]
+    pub fn amount_to_ui_amount(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Option<f64> {
+        Some(amount as f64 * self.total_scale(decimals, unix_timestamp)?)
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(InterestBearingConfig);
+
+/// `e^(rate * timespan / year)` for `rate` in basis points and `timespan` in seconds
+fn accrual_exp(rate: i16, timespan: i64) -> f64 {
+    let numerator = (i128::from(rate) * i128::from(timespan)) as f64;
+
+    exp(numerator / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS)
+}
+
+/// `e^x` for `no_std` targets, accurate to a few ULPs.
+///
+/// The argument is reduced to `x = k * ln(2) + r` with `|r| <= ln(2) / 2`, so `e^r` converges
+/// quickly as a Taylor series and `2^k` is built from the exponent bits directly.
+fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    // out of the normal f64 range
+    if x >= 709. {
+        return f64::INFINITY;
+    }
+    if x <= -708. {
+        return 0.;
+    }
+
+    let k = if x < 0. {
+        (x / core::f64::consts::LN_2 - 0.5) as i64
+    } else {
+        (x / core::f64::consts::LN_2 + 0.5) as i64
+    };
+    // ln(2) split into high and low parts to keep the reduction exact
+    let r = (x - k as f64 * 6.931_471_803_691_238e-1) - k as f64 * 1.908_214_929_270_587_7e-10;
+
+    let mut term = 1.;
+    let mut sum = 1.;
+    for i in 1..=20 {
+        term *= r / i as f64;
+        sum += term;
+    }
+
+    sum * f64::from_bits(((k + 1023) as u64) << 52)
 }
 
 pub fn interest_bearing_mint_initialize_instruction_data(
@@ -185,16 +329,8 @@ pub fn interest_bearing_mint_initialize_instruction_data(
 
     let mut data = [UNINIT_BYTE; 36];
 
-    // Set extension discriminator at offset [0]
-    write_bytes(
-        &mut data,
-        &[crate::extension::consts::ExtensionDiscriminator::InterestBearingMint as u8],
-    );
-    // Set sub-instruction at offset [1]
-    write_bytes(
-        &mut data[1..2],
-        &[InterestBearingMintInstruction::Initialize as u8],
-    );
+    // Set extension and sub-instruction discriminators at offset [0..2]
+    write_bytes(&mut data, &InterestBearingMintInstruction::Initialize.as_bytes());
     // Set rate_authority at offset [2..34]
     if let Some(auth) = rate_authority {
         write_bytes(&mut data[2..34], auth);
@@ -215,16 +351,8 @@ pub fn interest_bearing_mint_update_rate_instruction_data(rate: i16) -> [MaybeUn
 
     let mut data = [UNINIT_BYTE; 4];
 
-    // Set extension discriminator at offset [0]
-    write_bytes(
-        &mut data,
-        &[crate::extension::consts::ExtensionDiscriminator::InterestBearingMint as u8],
-    );
-    // Set sub-instruction at offset [1]
-    write_bytes(
-        &mut data[1..2],
-        &[InterestBearingMintInstruction::UpdateRate as u8],
-    );
+    // Set extension and sub-instruction discriminators at offset [0..2]
+    write_bytes(&mut data, &InterestBearingMintInstruction::UpdateRate.as_bytes());
     // Set rate at offset [2..4]
     write_bytes(&mut data[2..4], &rate.to_le_bytes());
 
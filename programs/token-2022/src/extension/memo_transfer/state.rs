@@ -8,11 +8,18 @@ use {
 };
 
 #[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InstructionDiscriminatorMemoTransfer {
     Enable = 0,
     Disable = 1,
 }
 
+crate::extension::consts::extension_instruction!(
+    InstructionDiscriminatorMemoTransfer,
+    MemoTransfer,
+    Disable
+);
+
 /// Instruction data layout:
 /// - [0]                        : Extension discriminator (1 byte)
 /// - [1]                        : Instruction discriminator (1 byte)
@@ -140,3 +147,5 @@ impl MemoTransfer {
         !self.require_incoming_transfer_memos
     }
 }
+
+crate::owner::impl_from_account_info_with_owner!(MemoTransfer);
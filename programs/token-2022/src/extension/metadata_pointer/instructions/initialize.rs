@@ -1,55 +1,32 @@
 use {
-    crate::extension::{
-        consts::ExtensionDiscriminator,
-        metadata_pointer::state::{
+    crate::{
+        extension::metadata_pointer::state::{
             offset_metadata_pointer_initialize as OFFSET, InstructionDiscriminatorMetadataPointer,
         },
+        macros::define_extension_instruction,
     },
-    pinocchio::{
-        account_info::AccountInfo,
-        cpi::invoke_signed,
-        instruction::{AccountMeta, Instruction, Signer},
-        pubkey::Pubkey,
-        ProgramResult,
-    },
+    pinocchio::pubkey::Pubkey,
 };
 
-/// Initialize a new mint with a metadata pointer
-///
-/// Accounts expected by this instruction:
-///
-///  0. `[writable]` The mint to initialize.
-pub struct Initialize<'a> {
-    /// The mint to initialize with the metadata pointer extension.
-    pub mint: &'a AccountInfo,
-    /// Optional authority that can later update the metadata address.
-    pub authority: Option<&'a Pubkey>,
-    /// Optional initial metadata address.
-    pub metadata_address: Option<&'a Pubkey>,
-    /// Token program (Token-2022).
-    pub token_program: &'a Pubkey,
-}
-
-impl Initialize<'_> {
-    #[inline(always)]
-    pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
-    }
-
-    #[inline(always)]
-    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
-        let account_metas = [AccountMeta::writable(self.mint.key())];
-
-        let mut buffer = [0u8; OFFSET::END as usize];
-        let data = initialize_instruction_data(&mut buffer, self.authority, self.metadata_address);
-
-        let instruction = Instruction {
-            program_id: self.token_program,
-            data,
-            accounts: &account_metas,
-        };
-
-        invoke_signed(&instruction, &[self.mint], signers)
+define_extension_instruction! {
+    /// Initialize a new mint with a metadata pointer
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[writable]` The mint to initialize.
+    pub struct Initialize<'a> {
+        accounts: {
+            /// The mint to initialize with the metadata pointer extension.
+            mint: writable,
+        },
+        data: {
+            /// Optional authority that can later update the metadata address.
+            authority: Option<&'a Pubkey>,
+            /// Optional initial metadata address.
+            metadata_address: Option<&'a Pubkey>,
+        },
+        instruction_data: initialize_instruction_data,
+        data_len: OFFSET::END as usize,
     }
 }
 
@@ -61,10 +38,8 @@ pub fn initialize_instruction_data<'a>(
     let mut offset = OFFSET::START as usize;
 
     // Encode discriminators (Metadata + Initialize)
-    buffer[..offset].copy_from_slice(&[
-        ExtensionDiscriminator::MetadataPointer as u8,
-        InstructionDiscriminatorMetadataPointer::Initialize as u8,
-    ]);
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorMetadataPointer::Initialize.as_bytes());
 
     // Write authority pubkey bytes
     if let Some(authority) = authority {
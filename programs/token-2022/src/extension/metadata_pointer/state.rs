@@ -8,11 +8,18 @@ use {
 };
 
 #[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InstructionDiscriminatorMetadataPointer {
     Initialize = 0,
     Update = 1,
 }
 
+crate::extension::consts::extension_instruction!(
+    InstructionDiscriminatorMetadataPointer,
+    MetadataPointer,
+    Update
+);
+
 /// Instruction data layout:
 /// - [0]                        : Extension discriminator (1 byte)
 /// - [1]                        : Instruction discriminator (1 byte)
@@ -188,3 +195,5 @@ impl MetadataPointer {
         &self.metadata_address
     }
 }
+
+crate::owner::impl_from_account_info_with_owner!(MetadataPointer);
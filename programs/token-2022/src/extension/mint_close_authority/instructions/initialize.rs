@@ -0,0 +1,56 @@
+use {
+    crate::{
+        extension::{
+            consts::ExtensionDiscriminator,
+            mint_close_authority::state::offset_mint_close_authority_initialize as OFFSET,
+        },
+        macros::define_extension_instruction,
+    },
+    pinocchio::pubkey::Pubkey,
+};
+
+define_extension_instruction! {
+    /// Initialize the close account authority on a new mint.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    pub struct InitializeMintCloseAuthority<'a> {
+        accounts: {
+            /// The mint to initialize the close authority
+            mint: writable,
+        },
+        data: {
+            /// Optional authority that can close the mint
+            close_authority: Option<&'a Pubkey>,
+        },
+        instruction_data: initialize_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn initialize_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    close_authority: Option<&Pubkey>,
+) -> &'a [u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminator
+    buffer[..offset].copy_from_slice(&[ExtensionDiscriminator::MintCloseAuthority as u8]);
+
+    // Set close_authority as `COption<Pubkey>`
+    if let Some(x) = close_authority {
+        buffer[offset] = 1;
+        offset += OFFSET::OPTION_FLAG as usize;
+        buffer[offset..offset + OFFSET::CLOSE_AUTHORITY_PUBKEY as usize].copy_from_slice(x);
+        offset += OFFSET::CLOSE_AUTHORITY_PUBKEY as usize;
+    } else {
+        buffer[offset] = 0;
+        offset += OFFSET::OPTION_FLAG as usize;
+    }
+
+    &buffer[..offset]
+}
@@ -0,0 +1,89 @@
+use {
+    crate::{
+        extension::{
+            consts::ExtensionType,
+            tlv::{get_extension_from_bytes, Extension},
+        },
+        ID,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : close_authority flag (1 byte)
+/// - [2..34]                    : close_authority pubkey (optional, 32 bytes)
+///
+/// The close authority is encoded as `COption<Pubkey>`, so the data is
+/// variable length and `END` is the maximum length with the authority present.
+pub mod offset_mint_close_authority_initialize {
+    pub const START: u8 = 1;
+    pub const OPTION_FLAG: u8 = 1;
+    pub const CLOSE_AUTHORITY_PUBKEY: u8 = 32;
+    pub const END: u8 = START + OPTION_FLAG + CLOSE_AUTHORITY_PUBKEY;
+}
+
+/// Close authority extension data for mints.
+#[repr(C)]
+pub struct MintCloseAuthority {
+    /// Optional authority to close the mint
+    close_authority: Pubkey,
+}
+
+unsafe impl Extension for MintCloseAuthority {
+    const TYPE: ExtensionType = ExtensionType::MintCloseAuthority;
+}
+
+impl MintCloseAuthority {
+    /// The length of the `MintCloseAuthority` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<MintCloseAuthority>();
+
+    /// Return a `MintCloseAuthority` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<MintCloseAuthority>, ProgramError> {
+        // Check owner
+        if account_info.owner() != &ID {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        // Safely borrow and map the data
+        let data_ref = account_info
+            .try_borrow_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+        Ref::try_map(data_ref, Self::from_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `MintCloseAuthority` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    pub fn has_close_authority(&self) -> bool {
+        self.close_authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn close_authority(&self) -> Option<&Pubkey> {
+        if self.has_close_authority() {
+            Some(&self.close_authority)
+        } else {
+            None
+        }
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(MintCloseAuthority);
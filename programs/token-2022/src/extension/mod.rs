@@ -1,13 +1,42 @@
+#[cfg(feature = "confidential")]
+pub mod confidential;
+#[cfg(feature = "confidential-transfer")]
+pub mod confidential_transfer;
+#[cfg(feature = "confidential-transfer-fee")]
+pub mod confidential_transfer_fee;
 pub mod consts;
+#[cfg(feature = "cpi-guard")]
 pub mod cpi_guard;
+#[cfg(feature = "group-member-pointer")]
 pub mod group_member_pointer;
+#[cfg(feature = "group-pointer")]
 pub mod group_pointer;
+#[cfg(feature = "immutable-owner")]
+pub mod immutable_owner;
+#[cfg(feature = "memo-transfer")]
 pub mod memo_transfer;
+#[cfg(feature = "metadata-pointer")]
 pub mod metadata_pointer;
+#[cfg(feature = "mint-close-authority")]
+pub mod mint_close_authority;
+#[cfg(feature = "non-transferable")]
+pub mod non_transferable;
+#[cfg(feature = "permanent-delegate")]
 pub mod permanent_delegate;
+#[cfg(feature = "token-group")]
 pub mod token_group;
+#[cfg(feature = "token-metadata")]
+pub mod token_metadata;
+#[cfg(feature = "default-account-state")]
 pub mod default_account_state;
+#[cfg(feature = "pausable")]
 pub mod pausable;
+#[cfg(feature = "scaled-ui-amount")]
 pub mod scaled_ui_amount;
+#[cfg(feature = "transfer-hook")]
 pub mod transfer_hook;
+#[cfg(feature = "interest-bearing-mint")]
 pub mod interest_bearing_mint;
+#[cfg(feature = "transfer-fee")]
+pub mod transfer_fee;
+pub mod tlv;
@@ -0,0 +1,35 @@
+use crate::{
+    extension::{
+        consts::ExtensionDiscriminator,
+        non_transferable::state::offset_non_transferable_initialize as OFFSET,
+    },
+    macros::define_extension_instruction,
+};
+
+define_extension_instruction! {
+    /// Initialize the non transferable extension for the given mint account.
+    ///
+    /// Fails if the account has already been initialized, so must be called
+    /// before `InitializeMint`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint account to initialize.
+    pub struct InitializeNonTransferableMint<'a> {
+        accounts: {
+            /// The mint to initialize as non-transferable
+            mint: writable,
+        },
+        data: {},
+        instruction_data: initialize_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn initialize_instruction_data(buffer: &mut [u8]) -> &[u8] {
+    // Set discriminator
+    buffer[..OFFSET::START as usize]
+        .copy_from_slice(&[ExtensionDiscriminator::NonTransferable as u8]);
+
+    buffer
+}
@@ -0,0 +1,76 @@
+use {
+    crate::extension::{
+        consts::ExtensionType,
+        tlv::{has_extension, Extension},
+    },
+    pinocchio::program_error::ProgramError,
+};
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+pub mod offset_non_transferable_initialize {
+    pub const START: u8 = 1;
+    pub const END: u8 = START;
+}
+
+/// Indicates that the tokens from this mint can't be transferred. The
+/// extension carries no data, only its presence matters.
+#[repr(C)]
+pub struct NonTransferable;
+
+unsafe impl Extension for NonTransferable {
+    const TYPE: ExtensionType = ExtensionType::NonTransferable;
+}
+
+impl NonTransferable {
+    /// Return whether the given mint data carries the `NonTransferable`
+    /// extension.
+    #[inline]
+    pub fn is_present(bytes: &[u8]) -> bool {
+        has_extension::<Self>(bytes)
+    }
+
+    /// Return `Ok` if the given mint data carries the `NonTransferable`
+    /// extension.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&'static Self, ProgramError> {
+        if Self::is_present(bytes) {
+            Ok(&NonTransferable)
+        } else {
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(NonTransferable);
+
+/// Indicates that the tokens from this account belong to a non-transferable
+/// mint. Added to token accounts automatically.
+#[repr(C)]
+pub struct NonTransferableAccount;
+
+unsafe impl Extension for NonTransferableAccount {
+    const TYPE: ExtensionType = ExtensionType::NonTransferableAccount;
+}
+
+impl NonTransferableAccount {
+    /// Return whether the given token account data carries the
+    /// `NonTransferableAccount` extension.
+    #[inline]
+    pub fn is_present(bytes: &[u8]) -> bool {
+        has_extension::<Self>(bytes)
+    }
+
+    /// Return `Ok` if the given token account data carries the
+    /// `NonTransferableAccount` extension.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&'static Self, ProgramError> {
+        if Self::is_present(bytes) {
+            Ok(&NonTransferableAccount)
+        } else {
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(NonTransferableAccount);
@@ -51,6 +51,6 @@ impl InitializePausable<'_> {
             program_id: token_program,
         };
 
-        pinocchio::cpi::invoke_signed(&instruction, &[mint_account], signers)
+        crate::cpi::invoke_signed(&instruction, &[mint_account], signers)
     }
 }
@@ -1,6 +1,9 @@
 use core::mem::MaybeUninit;
 use crate::{write_bytes, UNINIT_BYTE, ID};
-use crate::extension::consts::ExtensionDiscriminator;
+use crate::extension::{
+    consts::ExtensionType,
+    tlv::Extension,
+};
 use pinocchio::{
     account_info::{AccountInfo, Ref},
     program_error::ProgramError,
@@ -8,13 +11,15 @@ use pinocchio::{
 };
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PausableInstruction {
     Initialize,
     Pause,
     Resume,
 }
 
+crate::extension::consts::extension_instruction!(PausableInstruction, Pausable, Resume);
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct PausableConfig {
@@ -24,6 +29,10 @@ pub struct PausableConfig {
     paused: u8,
 }
 
+unsafe impl Extension for PausableConfig {
+    const TYPE: ExtensionType = ExtensionType::Pausable;
+}
+
 impl PausableConfig {
     pub const AUTHORITY_START: usize = 170;
 
@@ -77,6 +86,8 @@ impl PausableConfig {
     }
 }
 
+crate::owner::impl_from_account_info_with_owner!(PausableConfig);
+
 
 pub fn pausable_instruction_data(
     instruction_type: PausableInstruction,
@@ -86,10 +97,8 @@ pub fn pausable_instruction_data(
     // -  [1]: instruction_type (1 byte, u8)
     
     let mut data = [UNINIT_BYTE; 2];
-    // Set extension discriminator at offset [0]
-    write_bytes(&mut data, &[ExtensionDiscriminator::Pausable as u8]);
-    // Set sub-instruction at offset [1]
-    write_bytes(&mut data[1..2], &[instruction_type as u8]);
+    // Set extension and sub-instruction discriminators at offset [0..2]
+    write_bytes(&mut data, &instruction_type.as_bytes());
 
     data
 }
@@ -104,10 +113,8 @@ pub fn pausable_initialize_instruction_data(
     // -  [2..34]: authority (32 bytes, Pubkey)
     
     let mut data = [UNINIT_BYTE; 34];
-    // Set extension discriminator at offset [0]
-    write_bytes(&mut data, &[ExtensionDiscriminator::Pausable as u8]);
-    // Set sub-instruction at offset [1]
-    write_bytes(&mut data[1..2], &[instruction_type as u8]);
+    // Set extension and sub-instruction discriminators at offset [0..2]
+    write_bytes(&mut data, &instruction_type.as_bytes());
     // Set authority at offset [2..34]
     write_bytes(&mut data[2..34], &authority);
 
@@ -0,0 +1,114 @@
+use {
+    crate::{
+        error::TokenError,
+        extension::{permanent_delegate::PermanentDelegate, tlv::get_extension_from_bytes},
+        instructions,
+    },
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Fail with `TokenError::OwnerMismatch` unless `delegate` is the permanent
+/// delegate of the mint.
+#[inline(always)]
+fn check_permanent_delegate(mint: &AccountInfo, delegate: &AccountInfo) -> ProgramResult {
+    let data = mint.try_borrow_data()?;
+
+    match get_extension_from_bytes::<PermanentDelegate>(&data).and_then(|x| x.delegate()) {
+        Some(permanent_delegate) if permanent_delegate == delegate.key() => Ok(()),
+        _ => Err(TokenError::OwnerMismatch.into()),
+    }
+}
+
+/// Transfer tokens from any token account of the mint as its permanent
+/// delegate.
+///
+/// Fails with `TokenError::OwnerMismatch` before the CPI if `delegate` isn't
+/// the permanent delegate of the mint.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The permanent delegate of the mint.
+pub struct TransferChecked<'a, 'b> {
+    /// Sender account.
+    pub from: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Recipient account.
+    pub to: &'a AccountInfo,
+    /// Permanent delegate account.
+    pub delegate: &'a AccountInfo,
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token
+    pub decimals: u8,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl TransferChecked<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        check_permanent_delegate(self.mint, self.delegate)?;
+
+        instructions::TransferChecked {
+            from: self.from,
+            mint: self.mint,
+            to: self.to,
+            authority: self.delegate,
+            amount: self.amount,
+            decimals: self.decimals,
+            token_program: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+}
+
+/// Burn tokens from any token account of the mint as its permanent delegate.
+///
+/// Fails with `TokenError::OwnerMismatch` before the CPI if `delegate` isn't
+/// the permanent delegate of the mint.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to burn from.
+///   1. `[WRITE]` The token mint.
+///   2. `[SIGNER]` The permanent delegate of the mint.
+pub struct Burn<'a, 'b> {
+    /// Source of the Burn Account
+    pub account: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Permanent delegate account.
+    pub delegate: &'a AccountInfo,
+    /// Amount
+    pub amount: u64,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl Burn<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        check_permanent_delegate(self.mint, self.delegate)?;
+
+        instructions::Burn {
+            account: self.account,
+            mint: self.mint,
+            authority: self.delegate,
+            amount: self.amount,
+            token_program: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+}
@@ -1,11 +1,11 @@
 use {
+    crate::cpi::invoke_signed,
     crate::extension::{
         consts::ExtensionDiscriminator,
         permanent_delegate::state::offset_permanent_delegate_initialize as OFFSET,
     },
     pinocchio::{
         account_info::AccountInfo,
-        cpi::invoke_signed,
         instruction::{AccountMeta, Instruction, Signer},
         pubkey::Pubkey,
         ProgramResult,
@@ -1,3 +1,5 @@
+mod delegated;
 mod initialize;
 
+pub use delegated::*;
 pub use initialize::*;
@@ -1,5 +1,8 @@
 use {
-    crate::ID,
+    crate::{
+        extension::{consts::ExtensionType, tlv::Extension},
+        ID,
+    },
     pinocchio::{
         account_info::{AccountInfo, Ref},
         program_error::ProgramError,
@@ -23,6 +26,10 @@ pub struct PermanentDelegate {
     delegate: Pubkey,
 }
 
+unsafe impl Extension for PermanentDelegate {
+    const TYPE: ExtensionType = ExtensionType::PermanentDelegate;
+}
+
 impl PermanentDelegate {
     /// The length of the mint with `PermanentDelegate` extension data
     const LEN: u8 = 202;
@@ -142,3 +149,5 @@ impl PermanentDelegate {
         &self.delegate
     }
 }
+
+crate::owner::impl_from_account_info_with_owner!(PermanentDelegate);
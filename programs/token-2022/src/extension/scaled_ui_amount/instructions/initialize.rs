@@ -55,6 +55,6 @@ impl InitializeScaledUiAmount<'_, '_> {
             program_id: token_program,
         };
 
-        pinocchio::cpi::invoke_signed(&instruction, &[mint_account], signers)
+        crate::cpi::invoke_signed(&instruction, &[mint_account], signers)
     }
 }
@@ -1,6 +1,7 @@
 use core::{mem::MaybeUninit, slice};
 
 use crate::{
+    cpi::{invoke_signed, invoke_with_bounds},
     extension::scaled_ui_amount::state::{
         scaled_ui_amount_update_multiplier_instruction_data, ScaledUiAmountInstruction,
     },
@@ -9,7 +10,6 @@ use crate::{
 
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::{invoke_with_bounds, invoke_signed},
     instruction::{AccountMeta, Instruction, Signer},
     pubkey::Pubkey,
     ProgramResult,
@@ -1,15 +1,20 @@
 use core::mem::MaybeUninit;
 use crate::{write_bytes, UNINIT_BYTE};
-use crate::extension::consts::ExtensionDiscriminator;
 use pinocchio::pubkey::Pubkey;
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ScaledUiAmountInstruction {
     Initialize,
     UpdateMultiplier,
 }
 
+crate::extension::consts::extension_instruction!(
+    ScaledUiAmountInstruction,
+    ScaledUiAmount,
+    UpdateMultiplier
+);
+
 /// Configuration for the ScaledUiAmount extension
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -78,8 +83,22 @@ impl ScaledUiAmountConfig {
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
+
+    /// Get the timestamp at which the new multiplier comes into effect
+    #[inline]
+    pub fn new_multiplier_effective_timestamp(&self) -> i64 {
+        self.new_multiplier_effective_timestamp
+    }
+
+    /// Get the multiplier in effect from `new_multiplier_effective_timestamp`
+    #[inline]
+    pub fn new_multiplier(&self) -> f64 {
+        self.new_multiplier
+    }
 }
 
+crate::owner::impl_from_account_info_with_owner!(ScaledUiAmountConfig);
+
 pub fn scaled_ui_amount_initialize_instruction_data(
     instruction_type: ScaledUiAmountInstruction,
     authority: Pubkey,
@@ -92,10 +111,8 @@ pub fn scaled_ui_amount_initialize_instruction_data(
     // -  [34..42]: multiplier (8 bytes, f64)
     
     let mut data = [UNINIT_BYTE; 42];
-    // Set extension discriminator at offset [0]
-    write_bytes(&mut data, &[ExtensionDiscriminator::ScaledUiAmount as u8]);
-    // Set sub-instruction at offset [1]
-    write_bytes(&mut data[1..2], &[instruction_type as u8]);
+    // Set extension and sub-instruction discriminators at offset [0..2]
+    write_bytes(&mut data, &instruction_type.as_bytes());
     // Set authority at offset [2..34] (32 bytes)
     write_bytes(&mut data[2..34], &authority);
     // Set multiplier at offset [34..42] (8 bytes)
@@ -116,10 +133,8 @@ pub fn scaled_ui_amount_update_multiplier_instruction_data(
     // -  [10..18]: effective_timestamp (8 bytes, i64)
     
     let mut data = [UNINIT_BYTE; 18];
-    // Set extension discriminator at offset [0]
-    write_bytes(&mut data, &[ExtensionDiscriminator::ScaledUiAmount as u8]);
-    // Set sub-instruction at offset [1]
-    write_bytes(&mut data[1..2], &[instruction_type as u8]);
+    // Set extension and sub-instruction discriminators at offset [0..2]
+    write_bytes(&mut data, &instruction_type.as_bytes());
     // Set multiplier at offset [2..10]
     write_bytes(&mut data[2..10], &multiplier.to_le_bytes());
     // Set effective_timestamp at offset [10..18]
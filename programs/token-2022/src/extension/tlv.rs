@@ -0,0 +1,249 @@
+use crate::{extension::consts::ExtensionType, state::Multisig};
+
+/// Length of the base account data (a `Mint` is padded up to this length when
+/// it carries extensions).
+pub const BASE_ACCOUNT_LENGTH: usize = 165;
+
+/// The index of the first TLV entry, right after the account type byte.
+pub const EXTENSIONS_START: usize = BASE_ACCOUNT_LENGTH + 1;
+
+/// Length of the TLV header (2 bytes type, 2 bytes length).
+pub const TLV_HEADER_LEN: usize = 4;
+
+/// Account types of the accounts carrying extensions, stored at
+/// [`BASE_ACCOUNT_LENGTH`].
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// Fixed-size extension data stored in the TLV area of a mint or token account.
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]` with an alignment of 1, so that any
+/// `size_of::<Self>()` bytes of account data are a valid representation.
+pub unsafe trait Extension: Sized {
+    /// The TLV type of the extension
+    const TYPE: ExtensionType;
+}
+
+/// Iterator over the TLV entries of mint or token account data, see
+/// [`extensions_from_bytes`].
+#[derive(Clone, Debug)]
+pub struct TlvIter<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for TlvIter<'a> {
+    /// Raw TLV type, which may be unknown to this crate, and the value bytes
+    type Item = (u16, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.bytes;
+        let offset = self.offset;
+
+        if offset + TLV_HEADER_LEN > bytes.len() {
+            return None;
+        }
+
+        let tlv_type = u16::from_le_bytes([bytes[offset], bytes[offset + 1]]);
+        let tlv_len = u16::from_le_bytes([bytes[offset + 2], bytes[offset + 3]]) as usize;
+
+        if tlv_type == ExtensionType::Uninitialized as u16 {
+            return None;
+        }
+
+        let value_start = offset + TLV_HEADER_LEN;
+        let value_end = value_start + tlv_len;
+
+        if value_end > bytes.len() {
+            return None;
+        }
+
+        self.offset = value_end;
+
+        Some((tlv_type, &bytes[value_start..value_end]))
+    }
+}
+
+// a stop condition doesn't move the offset, so it holds on every following call
+impl core::iter::FusedIterator for TlvIter<'_> {}
+
+/// Iterate over the TLV entries of the given account data in their stored
+/// order.
+///
+/// The iteration stops at the first `Uninitialized` entry, at the first entry
+/// whose value runs past the end of the data, or when the data ends.
+///
+/// Like SPL, the data has no entries unless it is longer than the base account,
+/// isn't a multisig and its account type is a mint or a token account.
+#[inline]
+pub fn extensions_from_bytes(bytes: &[u8]) -> TlvIter<'_> {
+    let has_extensions = bytes.len() > BASE_ACCOUNT_LENGTH
+        && bytes.len() != Multisig::LEN
+        && matches!(
+            bytes[BASE_ACCOUNT_LENGTH],
+            ACCOUNT_TYPE_MINT | ACCOUNT_TYPE_ACCOUNT
+        );
+
+    TlvIter {
+        bytes,
+        // starting at the end yields nothing
+        offset: if has_extensions {
+            EXTENSIONS_START
+        } else {
+            bytes.len()
+        },
+    }
+}
+
+/// Return the value bytes of the first TLV entry of `extension_type` in the
+/// given account data, or `None` if the account has no such extension.
+///
+/// Works for both mints and token accounts, regardless of the position of the
+/// extension in the TLV area.
+#[inline]
+pub fn get_extension_data_from_bytes(extension_type: ExtensionType, bytes: &[u8]) -> Option<&[u8]> {
+    extensions_from_bytes(bytes)
+        .find(|&(tlv_type, _)| tlv_type == extension_type as u16)
+        .map(|(_, value)| value)
+}
+
+/// Return a reference to the `T` extension stored in the given account data,
+/// or `None` if the account has no such extension or its length does not
+/// match `T`.
+#[inline]
+pub fn get_extension_from_bytes<T: Extension>(bytes: &[u8]) -> Option<&T> {
+    extension_from_value(get_extension_data_from_bytes(T::TYPE, bytes)?)
+}
+
+/// Return a reference to the `T` extension held by the given value bytes, or
+/// `None` if their length does not match `T`.
+#[inline(always)]
+fn extension_from_value<T: Extension>(data: &[u8]) -> Option<&T> {
+    if data.len() != core::mem::size_of::<T>() {
+        return None;
+    }
+
+    // SAFETY: `data` is exactly `size_of::<T>()` bytes long and `T` has an
+    // alignment of 1 per the `Extension` contract.
+    Some(unsafe { &*(data.as_ptr() as *const T) })
+}
+
+/// Tuple of extensions read in a single walk over the TLV area, see
+/// [`get_extensions_from_bytes`].
+pub trait ExtensionSet<'a> {
+    /// A reference to each extension, `None` where
+    /// [`get_extension_from_bytes`] would return `None`
+    type Output;
+
+    fn from_bytes(bytes: &'a [u8]) -> Self::Output;
+}
+
+macro_rules! impl_extension_set {
+    ($len:literal; $($T:ident $index:tt),+) => {
+        impl<'a, $($T: Extension + 'a),+> ExtensionSet<'a> for ($($T,)+) {
+            type Output = ($(Option<&'a $T>,)+);
+
+            #[inline]
+            fn from_bytes(bytes: &'a [u8]) -> Self::Output {
+                let types = [$($T::TYPE as u16),+];
+                let mut values: [Option<&'a [u8]>; $len] = [None; $len];
+                let mut missing = $len;
+
+                // like `get_extension_data_from_bytes`, the first entry of
+                // each type is kept
+                for (tlv_type, value) in extensions_from_bytes(bytes) {
+                    for (slot, extension_type) in values.iter_mut().zip(types) {
+                        if slot.is_none() && tlv_type == extension_type {
+                            *slot = Some(value);
+                            missing -= 1;
+                        }
+                    }
+
+                    if missing == 0 {
+                        break;
+                    }
+                }
+
+                ($(values[$index].and_then(extension_from_value::<$T>),)+)
+            }
+        }
+    };
+}
+
+impl_extension_set!(1; A 0);
+impl_extension_set!(2; A 0, B 1);
+impl_extension_set!(3; A 0, B 1, C 2);
+impl_extension_set!(4; A 0, B 1, C 2, D 3);
+impl_extension_set!(5; A 0, B 1, C 2, D 3, E 4);
+impl_extension_set!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_extension_set!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_extension_set!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Return a reference to each extension of the tuple `S` stored in the given
+/// account data, walking the TLV area once instead of once per extension.
+///
+/// ```ignore
+/// let (transfer_fee_config, pausable_config, transfer_hook) =
+///     get_extensions_from_bytes::<(TransferFeeConfig, PausableConfig, TransferHook)>(&data);
+/// ```
+#[inline]
+pub fn get_extensions_from_bytes<'a, S: ExtensionSet<'a>>(bytes: &'a [u8]) -> S::Output {
+    S::from_bytes(bytes)
+}
+
+/// Return whether the given account data carries an entry of
+/// `extension_type`, without reading its value.
+#[inline]
+pub fn has_extension_type(extension_type: ExtensionType, bytes: &[u8]) -> bool {
+    get_extension_data_from_bytes(extension_type, bytes).is_some()
+}
+
+/// Return whether the given account data carries the `T` extension, e.g. to
+/// check that a mint has a transfer hook when its content doesn't matter.
+///
+/// Unlike [`get_extension_from_bytes`], the length of the value is not
+/// checked against `T`.
+#[inline]
+pub fn has_extension<T: Extension>(bytes: &[u8]) -> bool {
+    has_extension_type(T::TYPE, bytes)
+}
+
+/// Length of the header of an interface TLV entry (8 bytes discriminator, 4
+/// bytes length).
+pub const INTERFACE_TLV_HEADER_LEN: usize = 12;
+
+/// Return the value bytes of the first interface TLV entry with the given
+/// discriminator, or `None` if the data has no such entry.
+///
+/// Accounts of programs implementing an SPL interface (token group, token
+/// metadata, transfer hook validation) store their state as
+/// `spl-type-length-value` entries starting at the beginning of the data: an
+/// 8 bytes discriminator, a 4 bytes length (u32) and the value.
+#[inline]
+pub fn get_interface_value_from_bytes<'a>(
+    discriminator: &[u8; 8],
+    bytes: &'a [u8],
+) -> Option<&'a [u8]> {
+    let mut offset = 0;
+
+    while offset + INTERFACE_TLV_HEADER_LEN <= bytes.len() {
+        let value_start = offset + INTERFACE_TLV_HEADER_LEN;
+        let length = u32::from_le_bytes([
+            bytes[offset + 8],
+            bytes[offset + 9],
+            bytes[offset + 10],
+            bytes[offset + 11],
+        ]) as usize;
+        let value = bytes.get(value_start..value_start.checked_add(length)?)?;
+
+        if bytes[offset..offset + 8] == *discriminator {
+            return Some(value);
+        }
+
+        offset = value_start + length;
+    }
+
+    None
+}
@@ -1,10 +1,10 @@
 use {
+    crate::cpi::invoke_signed,
     crate::extension::token_group::state::{
         offset_token_group_initialize_group as OFFSET, InstructionDiscriminatorTokenGroup,
     },
     pinocchio::{
         account_info::AccountInfo,
-        cpi::invoke_signed,
         instruction::{AccountMeta, Instruction, Signer},
         pubkey::Pubkey,
         ProgramResult,
@@ -31,7 +31,7 @@ pub struct InitializeGroup<'a> {
     pub update_authority: Option<&'a Pubkey>,
     /// The maximum number of group members
     pub max_size: u64,
-    /// Token Group Program
+    /// Token Group Program: Token-2022 or any program implementing the interface
     pub program_id: &'a Pubkey,
 }
 
@@ -75,9 +75,8 @@ pub fn initialize_group_instruction_data<'a>(
     let mut offset = OFFSET::START as usize;
 
     // Set discriminators
-    buffer[..offset].copy_from_slice(
-        &(InstructionDiscriminatorTokenGroup::InitializeGroup as u64).to_le_bytes(),
-    );
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorTokenGroup::InitializeGroup.as_bytes());
 
     // Set update_authority
     if let Some(x) = update_authority {
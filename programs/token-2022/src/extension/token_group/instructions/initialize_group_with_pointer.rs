@@ -0,0 +1,92 @@
+use {
+    crate::{
+        extension::{consts::ExtensionType, group_pointer, token_group::InitializeGroup},
+        instructions::{MintBuilder, MintExtensionInitializer},
+    },
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
+        pubkey::Pubkey, ProgramResult,
+    },
+};
+
+/// Initialize a new mint holding its own `Group`: the group pointer pointing
+/// at the mint, the other extensions, `InitializeMint2`, then
+/// `InitializeGroup` on the mint.
+///
+/// The group pointer and the group share `update_authority`. The mint account
+/// must have been created with the space of the base mint, of the
+/// `GroupPointer` and `TokenGroup` extensions and of the other extensions.
+/// Fails with `InvalidArgument` before any CPI if an initializer targets
+/// another account than `mint` or initializes the group pointer.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Mint
+///   1. `[signer]` Mint authority
+pub struct InitializeGroupWithPointer<'a, 'b> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Decimals
+    pub decimals: u8,
+    /// Mint authority, signing `InitializeGroup`
+    pub mint_authority: &'a AccountInfo,
+    /// Freeze authority
+    pub freeze_authority: Option<&'a Pubkey>,
+    /// Authority that can set the group address and update the group
+    pub update_authority: Option<&'a Pubkey>,
+    /// The maximum number of group members
+    pub max_size: u64,
+    /// Initializers of the other mint extensions
+    pub extensions: &'b [&'b dyn MintExtensionInitializer],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl InitializeGroupWithPointer<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invoke the instructions, `InitializeGroup` being signed with `signers`.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let builder = MintBuilder {
+            mint: self.mint,
+            decimals: self.decimals,
+            mint_authority: self.mint_authority.key(),
+            freeze_authority: self.freeze_authority,
+            extensions: self.extensions,
+            token_program: self.token_program,
+        };
+
+        builder.check_extensions()?;
+        if self
+            .extensions
+            .iter()
+            .any(|extension| extension.extension_type() == ExtensionType::GroupPointer)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        group_pointer::Initialize {
+            mint: self.mint,
+            authority: self.update_authority,
+            group_address: Some(self.mint.key()),
+            token_program: self.token_program,
+        }
+        .invoke()?;
+
+        builder.invoke()?;
+
+        InitializeGroup {
+            group: self.mint,
+            mint: self.mint,
+            mint_authority: self.mint_authority,
+            update_authority: self.update_authority,
+            max_size: self.max_size,
+            program_id: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+}
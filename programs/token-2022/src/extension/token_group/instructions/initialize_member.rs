@@ -1,10 +1,10 @@
 use {
+    crate::cpi::invoke_signed,
     crate::extension::token_group::state::{
         offset_token_group_initialize_member as OFFSET, InstructionDiscriminatorTokenGroup,
     },
     pinocchio::{
         account_info::AccountInfo,
-        cpi::invoke_signed,
         instruction::{AccountMeta, Instruction, Signer},
         pubkey::Pubkey,
         ProgramResult,
@@ -34,7 +34,7 @@ pub struct InitializeMember<'a> {
     pub group: &'a AccountInfo,
     /// Group update authority
     pub group_update_authority: &'a AccountInfo,
-    /// Token Group Program
+    /// Token Group Program: Token-2022 or any program implementing the interface
     pub program_id: &'a Pubkey,
 }
 
@@ -81,9 +81,8 @@ pub fn initialize_member_instruction_data<'a>(buffer: &'a mut [u8]) -> &'a [u8]
     let offset = OFFSET::START as usize;
 
     // Set discriminators
-    buffer[..offset].copy_from_slice(
-        &(InstructionDiscriminatorTokenGroup::InitializeMember as u64).to_le_bytes(),
-    );
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorTokenGroup::InitializeMember.as_bytes());
 
     buffer
 }
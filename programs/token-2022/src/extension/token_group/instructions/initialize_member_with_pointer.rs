@@ -0,0 +1,121 @@
+use {
+    crate::{
+        extension::{
+            consts::ExtensionType,
+            group_member_pointer,
+            token_group::{InitializeMember, TokenGroup, TokenGroupError},
+        },
+        instructions::{MintBuilder, MintExtensionInitializer},
+    },
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
+        pubkey::Pubkey, ProgramResult,
+    },
+};
+
+/// Fail with `TokenGroupError::SizeExceedsMaxSize` if the group holds its
+/// maximum number of members, or with `InvalidAccountData` if the account
+/// doesn't hold a group.
+///
+/// The group is read with the parser of its owner, Token-2022 or a program
+/// implementing the token group interface.
+#[inline(always)]
+fn check_group_size(group: &AccountInfo) -> ProgramResult {
+    let group = TokenGroup::from_account_info_with_program_id(group, group.owner())?;
+
+    if group.size() >= group.max_size() {
+        Err(TokenGroupError::SizeExceedsMaxSize)?;
+    }
+
+    Ok(())
+}
+
+/// Initialize a new mint holding its own `Member` of a `Group`: the group
+/// member pointer pointing at the mint, the other extensions,
+/// `InitializeMint2`, then `InitializeMember` on the mint.
+///
+/// The mint account must have been created with the space of the base mint,
+/// of the `GroupMemberPointer` and `TokenGroupMember` extensions and of the
+/// other extensions. Fails before any CPI with
+/// `TokenGroupError::SizeExceedsMaxSize` if the group is full, or
+/// `InvalidArgument` if an initializer targets another account than
+/// `member_mint` or initializes the group member pointer.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Member mint
+///   1. `[signer]` Member mint authority
+///   2. `[writable]` Group
+///   3. `[signer]` Group update authority
+pub struct InitializeMemberWithPointer<'a, 'b> {
+    /// Member mint
+    pub member_mint: &'a AccountInfo,
+    /// Decimals
+    pub decimals: u8,
+    /// Member mint authority, signing `InitializeMember`
+    pub member_mint_authority: &'a AccountInfo,
+    /// Freeze authority
+    pub freeze_authority: Option<&'a Pubkey>,
+    /// Authority that can set the member address
+    pub pointer_authority: Option<&'a Pubkey>,
+    /// Group Account
+    pub group: &'a AccountInfo,
+    /// Group update authority
+    pub group_update_authority: &'a AccountInfo,
+    /// Initializers of the other mint extensions
+    pub extensions: &'b [&'b dyn MintExtensionInitializer],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl InitializeMemberWithPointer<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invoke the instructions, `InitializeMember` being signed with
+    /// `signers`.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let builder = MintBuilder {
+            mint: self.member_mint,
+            decimals: self.decimals,
+            mint_authority: self.member_mint_authority.key(),
+            freeze_authority: self.freeze_authority,
+            extensions: self.extensions,
+            token_program: self.token_program,
+        };
+
+        builder.check_extensions()?;
+        if self
+            .extensions
+            .iter()
+            .any(|extension| extension.extension_type() == ExtensionType::GroupMemberPointer)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        check_group_size(self.group)?;
+
+        group_member_pointer::Initialize {
+            mint: self.member_mint,
+            authority: self.pointer_authority,
+            member_address: Some(self.member_mint.key()),
+            token_program: self.token_program,
+        }
+        .invoke()?;
+
+        builder.invoke()?;
+
+        InitializeMember {
+            member: self.member_mint,
+            member_mint: self.member_mint,
+            member_mint_authority: self.member_mint_authority,
+            group: self.group,
+            group_update_authority: self.group_update_authority,
+            program_id: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+}
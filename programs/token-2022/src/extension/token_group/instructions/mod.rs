@@ -1,9 +1,17 @@
 mod initialize_group;
+#[cfg(feature = "group-pointer")]
+mod initialize_group_with_pointer;
 mod initialize_member;
+#[cfg(feature = "group-member-pointer")]
+mod initialize_member_with_pointer;
 mod update_group_authority;
 mod update_max_size;
 
 pub use initialize_group::*;
+#[cfg(feature = "group-pointer")]
+pub use initialize_group_with_pointer::*;
 pub use initialize_member::*;
+#[cfg(feature = "group-member-pointer")]
+pub use initialize_member_with_pointer::*;
 pub use update_group_authority::*;
 pub use update_max_size::*;
@@ -1,10 +1,10 @@
 use {
+    crate::cpi::invoke_signed,
     crate::extension::token_group::state::{
         offset_token_group_update_authority as OFFSET, InstructionDiscriminatorTokenGroup,
     },
     pinocchio::{
         account_info::AccountInfo,
-        cpi::invoke_signed,
         instruction::{AccountMeta, Instruction, Signer},
         pubkey::Pubkey,
         ProgramResult,
@@ -24,7 +24,7 @@ pub struct UpdateGroupAuthority<'a> {
     pub current_authority: &'a AccountInfo,
     /// New authority for the group, or None to unset
     pub new_authority: Option<&'a Pubkey>,
-    /// Token Group Program
+    /// Token Group Program: Token-2022 or any program implementing the interface
     pub program_id: &'a Pubkey,
 }
 
@@ -61,9 +61,8 @@ pub fn update_group_authority_instruction_data<'a>(
     let offset = OFFSET::START as usize;
 
     // Set discriminators
-    buffer[..offset].copy_from_slice(
-        &(InstructionDiscriminatorTokenGroup::UpdateGroupAuthority as u64).to_le_bytes(),
-    );
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorTokenGroup::UpdateGroupAuthority.as_bytes());
 
     // Set new_authority (optional)
     if let Some(authority) = new_authority {
@@ -1,10 +1,10 @@
 use {
+    crate::cpi::invoke_signed,
     crate::extension::token_group::state::{
         offset_token_group_update_max_size as OFFSET, InstructionDiscriminatorTokenGroup,
     },
     pinocchio::{
         account_info::AccountInfo,
-        cpi::invoke_signed,
         instruction::{AccountMeta, Instruction, Signer},
         pubkey::Pubkey,
         ProgramResult,
@@ -24,7 +24,7 @@ pub struct UpdateGroupMaxSize<'a> {
     pub update_authority: &'a AccountInfo,
     /// New max size for the group
     pub max_size: u64,
-    /// Token Group Program
+    /// Token Group Program: Token-2022 or any program implementing the interface
     pub program_id: &'a Pubkey,
 }
 
@@ -58,9 +58,8 @@ pub fn update_group_max_size_instruction_data<'a>(buffer: &'a mut [u8], max_size
     let offset = OFFSET::START as usize;
 
     // Set discriminators
-    buffer[..offset].copy_from_slice(
-        &(InstructionDiscriminatorTokenGroup::UpdateGroupMaxSize as u64).to_le_bytes(),
-    );
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorTokenGroup::UpdateGroupMaxSize.as_bytes());
 
     // Set max_size
     buffer[offset..offset + OFFSET::MAX_SIZE as usize].copy_from_slice(&max_size.to_le_bytes());
@@ -1,5 +1,11 @@
 use {
-    crate::ID,
+    crate::{
+        extension::{
+            consts::ExtensionType,
+            tlv::{get_extension_from_bytes, get_interface_value_from_bytes, Extension},
+        },
+        ID,
+    },
     core::mem,
     pinocchio::{
         account_info::{AccountInfo, Ref},
@@ -9,6 +15,7 @@ use {
 };
 
 #[repr(u64)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InstructionDiscriminatorTokenGroup {
     InitializeGroup = 288286683834380665, // [121, 113, 108, 39, 54, 51, 0, 4]
     UpdateGroupMaxSize = 7931435946663945580, // [108, 37, 171, 143, 248, 30, 18, 110]
@@ -16,6 +23,34 @@ pub enum InstructionDiscriminatorTokenGroup {
     InitializeMember = 9688630243381616792, // [152, 32, 222, 176, 223, 237, 116, 134]
 }
 
+impl InstructionDiscriminatorTokenGroup {
+    /// Return the 8 bytes discriminator starting the instruction data.
+    #[inline(always)]
+    pub const fn as_bytes(self) -> [u8; 8] {
+        (self as u64).to_le_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for InstructionDiscriminatorTokenGroup {
+    type Error = ProgramError;
+
+    /// Decode the discriminator from the first 8 bytes of token group instruction data.
+    #[inline]
+    fn try_from(instruction_data: &[u8]) -> Result<Self, Self::Error> {
+        let Some(discriminator) = instruction_data.first_chunk::<8>() else {
+            Err(ProgramError::InvalidInstructionData)?
+        };
+
+        Ok(match u64::from_le_bytes(*discriminator) {
+            x if x == Self::InitializeGroup as u64 => Self::InitializeGroup,
+            x if x == Self::UpdateGroupMaxSize as u64 => Self::UpdateGroupMaxSize,
+            x if x == Self::UpdateGroupAuthority as u64 => Self::UpdateGroupAuthority,
+            x if x == Self::InitializeMember as u64 => Self::InitializeMember,
+            _ => Err(ProgramError::InvalidInstructionData)?,
+        })
+    }
+}
+
 /// Instruction data layout:
 /// - [0..8]                     : Instruction discriminator (8 bytes)
 /// - [8..40]                    : update_authority pubkey (32 bytes)
@@ -52,6 +87,16 @@ pub mod offset_token_group_initialize_member {
     pub const END: u8 = START;
 }
 
+/// `TokenGroup` discriminator in the TLV data of a group account owned by a
+/// program other than Token-2022, the first 8 bytes of
+/// `sha256("spl_token_group_interface:group")`.
+pub const TOKEN_GROUP_DISCRIMINATOR: [u8; 8] = [214, 15, 63, 132, 49, 119, 209, 40];
+
+/// `TokenGroupMember` discriminator in the TLV data of a member account owned by
+/// a program other than Token-2022, the first 8 bytes of
+/// `sha256("spl_token_group_interface:member")`.
+pub const TOKEN_GROUP_MEMBER_DISCRIMINATOR: [u8; 8] = [254, 50, 168, 134, 88, 126, 100, 186];
+
 /// Data struct for a `TokenGroup`
 #[repr(C)]
 pub struct TokenGroup {
@@ -61,32 +106,25 @@ pub struct TokenGroup {
     /// belongs to a particular mint
     mint: Pubkey,
     /// The current number of group members
-    size: u64,
+    size: [u8; 8],
     /// The maximum number of group members
-    max_size: u64,
+    max_size: [u8; 8],
 }
 
-impl TokenGroup {
-    /// The length of the account with `TokenGroup` data
-    const LEN: usize = Self::DATA_START + Self::BASE_LEN;
-
-    /// The index where TokenGroup data starts
-    const DATA_START: usize = 238;
+unsafe impl Extension for TokenGroup {
+    const TYPE: ExtensionType = ExtensionType::TokenGroup;
+}
 
+impl TokenGroup {
     /// The length of the `TokenGroup` data.
     pub const BASE_LEN: usize = mem::size_of::<TokenGroup>();
 
     /// Return a `TokenGroup` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(account_info: &AccountInfo) -> Result<Ref<TokenGroup>, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
         // Check owner
         if account_info.owner() != &ID {
             Err(ProgramError::InvalidAccountOwner)?;
@@ -97,15 +135,13 @@ impl TokenGroup {
             .try_borrow_data()
             .map_err(|_| ProgramError::AccountBorrowFailed)?;
 
-        Ok(Ref::map(data_ref, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+        Ref::try_map(data_ref, Self::from_bytes).map_err(|(_, error)| error)
     }
 
     /// Return a `TokenGroup` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -115,43 +151,71 @@ impl TokenGroup {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
         // Check owner
         if account_info.owner() != &ID {
             Err(ProgramError::InvalidAccountOwner)?;
         }
 
         // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-
-        Ok(Self::from_bytes_unchecked(data))
+        Self::from_bytes(account_info.borrow_data_unchecked())
     }
 
-    /// Return a `TokenGroup` from the given bytes.
+    /// Return a `TokenGroup` from the given account data.
     ///
     /// # Safety
     ///
-    /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
-    /// 2. `bytes` contains a valid representation of `TokenGroup`
-    /// 3. The data is properly aligned
+    /// The caller must ensure that `bytes` holds a `TokenGroup` extension.
     #[inline(always)]
     pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::DATA_START..].as_ptr() as *const TokenGroup)
+        get_extension_from_bytes::<Self>(bytes).unwrap_unchecked()
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `TokenGroup` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// Return a `TokenGroup` from a group account owned by `program_id`, which is
+    /// either Token-2022 or a program implementing the token group interface.
+    ///
+    /// This method performs owner validation on `AccountInfo`, safe borrowing
+    /// the account data.
+    #[inline]
+    pub fn from_account_info_with_program_id<'a>(
+        account_info: &'a AccountInfo,
+        program_id: &Pubkey,
+    ) -> Result<Ref<'a, TokenGroup>, ProgramError> {
+        if program_id == &ID {
+            return Self::from_account_info(account_info);
+        }
+
+        // Check owner
+        if account_info.owner() != program_id {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        let data_ref = account_info
+            .try_borrow_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+        Ref::try_map(data_ref, Self::from_interface_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `TokenGroup` from the TLV data of a group account owned by a
+    /// program other than Token-2022, see [`get_interface_value_from_bytes`].
+    #[inline]
+    pub fn from_interface_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        let data = get_interface_value_from_bytes(&TOKEN_GROUP_DISCRIMINATOR, bytes)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        if data.len() != Self::BASE_LEN {
             Err(ProgramError::InvalidAccountData)?;
         }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+        Ok(unsafe { &*(data.as_ptr() as *const TokenGroup) })
     }
 
     /// Creates a new `TokenGroup` state
@@ -159,8 +223,8 @@ impl TokenGroup {
         Self {
             mint: *mint,
             update_authority: update_authority.map(|&x| x).unwrap_or_default(),
-            size: 0,
-            max_size,
+            size: [0; 8],
+            max_size: max_size.to_le_bytes(),
         }
     }
 
@@ -194,21 +258,21 @@ impl TokenGroup {
 
     #[inline(always)]
     pub fn size(&self) -> u64 {
-        self.size
+        u64::from_le_bytes(self.size)
     }
 
     #[inline(always)]
     pub fn max_size(&self) -> u64 {
-        self.max_size
+        u64::from_le_bytes(self.max_size)
     }
 
     /// Updates the max size for a group
     pub fn update_max_size(&mut self, new_max_size: u64) -> Result<(), ProgramError> {
         // The new max size cannot be less than the current size
-        if new_max_size < self.size {
+        if new_max_size < self.size() {
             Err(TokenGroupError::SizeExceedsNewMaxSize)?;
         }
-        self.max_size = new_max_size;
+        self.max_size = new_max_size.to_le_bytes();
         Ok(())
     }
 
@@ -216,17 +280,19 @@ impl TokenGroup {
     pub fn increment_size(&mut self) -> Result<u64, ProgramError> {
         // The new size cannot be greater than the max size
         let new_size = self
-            .size
+            .size()
             .checked_add(1)
             .ok_or(ProgramError::ArithmeticOverflow)?;
-        if new_size > self.max_size {
+        if new_size > self.max_size() {
             Err(TokenGroupError::SizeExceedsMaxSize)?;
         }
-        self.size = new_size;
+        self.size = new_size.to_le_bytes();
         Ok(new_size)
     }
 }
 
+crate::owner::impl_from_account_info_with_owner!(TokenGroup);
+
 /// Data struct for a `TokenGroupMember`
 #[repr(C)]
 pub struct TokenGroupMember {
@@ -236,32 +302,25 @@ pub struct TokenGroupMember {
     /// The pubkey of the `TokenGroup`
     group: Pubkey,
     /// The member number
-    member_number: u64,
+    member_number: [u8; 8],
 }
 
-impl TokenGroupMember {
-    /// The length of the account with `TokenGroupMember` data
-    const LEN: usize = Self::DATA_START + Self::BASE_LEN;
-
-    /// The index where TokenGroupMember data starts
-    const DATA_START: usize = 238;
+unsafe impl Extension for TokenGroupMember {
+    const TYPE: ExtensionType = ExtensionType::TokenGroupMember;
+}
 
+impl TokenGroupMember {
     /// The length of the `TokenGroupMember` data.
     pub const BASE_LEN: usize = mem::size_of::<TokenGroupMember>();
 
     /// Return a `TokenGroupMember` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, safe borrowing
-    /// the account data.
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, safe borrowing the account data.
     #[inline]
     pub fn from_account_info(
         account_info: &AccountInfo,
     ) -> Result<Ref<TokenGroupMember>, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
         // Check owner
         if account_info.owner() != &ID {
             Err(ProgramError::InvalidAccountOwner)?;
@@ -272,15 +331,13 @@ impl TokenGroupMember {
             .try_borrow_data()
             .map_err(|_| ProgramError::AccountBorrowFailed)?;
 
-        Ok(Ref::map(data_ref, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+        Ref::try_map(data_ref, Self::from_bytes).map_err(|(_, error)| error)
     }
 
     /// Return a `TokenGroupMember` from the given account info.
     ///
-    /// This method performs owner and length validation on `AccountInfo`, but does not
-    /// perform the borrow check.
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -290,43 +347,71 @@ impl TokenGroupMember {
     pub unsafe fn from_account_info_unchecked(
         account_info: &AccountInfo,
     ) -> Result<&Self, ProgramError> {
-        // Check data length first
-        if account_info.data_len() < Self::LEN {
-            Err(ProgramError::InvalidAccountData)?;
-        }
-
         // Check owner
         if account_info.owner() != &ID {
             Err(ProgramError::InvalidAccountOwner)?;
         }
 
         // Get unchecked borrow and convert
-        let data = account_info.borrow_data_unchecked();
-
-        Ok(Self::from_bytes_unchecked(data))
+        Self::from_bytes(account_info.borrow_data_unchecked())
     }
 
-    /// Return a `TokenGroupMember` from the given bytes.
+    /// Return a `TokenGroupMember` from the given account data.
     ///
     /// # Safety
     ///
-    /// The caller must ensure that:
-    /// 1. `bytes` contains at least `LEN` bytes
-    /// 2. `bytes` contains a valid representation of `TokenGroupMember`
-    /// 3. The data is properly aligned
+    /// The caller must ensure that `bytes` holds a `TokenGroupMember` extension.
     #[inline(always)]
     pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
-        &*(bytes[Self::DATA_START..].as_ptr() as *const TokenGroupMember)
+        get_extension_from_bytes::<Self>(bytes).unwrap_unchecked()
     }
 
-    /// Safe version of from_bytes that performs validation
+    /// Return a `TokenGroupMember` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        if bytes.len() < Self::LEN {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// Return a `TokenGroupMember` from a member account owned by `program_id`, which is
+    /// either Token-2022 or a program implementing the token group interface.
+    ///
+    /// This method performs owner validation on `AccountInfo`, safe borrowing
+    /// the account data.
+    #[inline]
+    pub fn from_account_info_with_program_id<'a>(
+        account_info: &'a AccountInfo,
+        program_id: &Pubkey,
+    ) -> Result<Ref<'a, TokenGroupMember>, ProgramError> {
+        if program_id == &ID {
+            return Self::from_account_info(account_info);
+        }
+
+        // Check owner
+        if account_info.owner() != program_id {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        let data_ref = account_info
+            .try_borrow_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+        Ref::try_map(data_ref, Self::from_interface_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `TokenGroupMember` from the TLV data of a member account owned by a
+    /// program other than Token-2022, see [`get_interface_value_from_bytes`].
+    #[inline]
+    pub fn from_interface_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        let data = get_interface_value_from_bytes(&TOKEN_GROUP_MEMBER_DISCRIMINATOR, bytes)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        if data.len() != Self::BASE_LEN {
             Err(ProgramError::InvalidAccountData)?;
         }
 
-        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+        Ok(unsafe { &*(data.as_ptr() as *const TokenGroupMember) })
     }
 
     /// Creates a new `TokenGroupMember` state
@@ -334,7 +419,7 @@ impl TokenGroupMember {
         Self {
             mint: *mint,
             group: *group,
-            member_number,
+            member_number: member_number.to_le_bytes(),
         }
     }
 
@@ -350,10 +435,12 @@ impl TokenGroupMember {
 
     #[inline(always)]
     pub fn member_number(&self) -> u64 {
-        self.member_number
+        u64::from_le_bytes(self.member_number)
     }
 }
 
+crate::owner::impl_from_account_info_with_owner!(TokenGroupMember);
+
 /// Errors that may be returned by the interface.
 #[repr(u32)]
 #[derive(Clone, Debug, Eq, PartialEq)]
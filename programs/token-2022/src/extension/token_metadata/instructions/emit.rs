@@ -0,0 +1,69 @@
+use {
+    crate::cpi::invoke,
+    crate::extension::token_metadata::state::{InstructionDiscriminatorTokenMetadata, Writer},
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// The maximum length of the `Emit` instruction data
+pub const EMIT_TOKEN_METADATA_INSTRUCTION_DATA_LEN: usize = 8 + 2 * 9;
+
+/// Emit the borsh-serialized `TokenMetadata`, or the `start..end` slice of it,
+/// as return data
+///
+/// Read the emitted bytes with `pinocchio::cpi::get_return_data` after the
+/// invocation.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[]` Metadata
+pub struct EmitTokenMetadata<'a> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Start of the range of data to emit
+    pub start: Option<u64>,
+    /// End of the range of data to emit
+    pub end: Option<u64>,
+    /// Token Metadata Program: Token-2022 or any program implementing the interface
+    pub program_id: &'a Pubkey,
+}
+
+impl EmitTokenMetadata<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        let account_metas = [AccountMeta::readonly(self.metadata.key())];
+
+        let mut buffer = [0u8; EMIT_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+        let data = emit_token_metadata_instruction_data(&mut buffer, self.start, self.end)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke(&instruction, &[self.metadata])
+    }
+}
+
+/// Instruction data layout:
+/// - [0..8]  : Instruction discriminator (8 bytes)
+/// - start   : optional u64 (1 byte tag, followed by 8 bytes if set)
+/// - end     : optional u64 (1 byte tag, followed by 8 bytes if set)
+pub fn emit_token_metadata_instruction_data(
+    buffer: &mut [u8],
+    start: Option<u64>,
+    end: Option<u64>,
+) -> Result<&[u8], ProgramError> {
+    let mut writer = Writer::new(buffer, InstructionDiscriminatorTokenMetadata::Emit)?;
+
+    writer.option_u64(start)?;
+    writer.option_u64(end)?;
+
+    Ok(writer.finish())
+}
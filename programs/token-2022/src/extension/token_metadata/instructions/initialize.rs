@@ -0,0 +1,104 @@
+use {
+    crate::cpi::invoke_signed,
+    crate::extension::token_metadata::state::{
+        InstructionDiscriminatorTokenMetadata, Writer, MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Initialize the basic fields of a `TokenMetadata`
+///
+/// With Token-2022 the metadata account is the mint itself, other programs
+/// implementing the interface store it in a separate account, which the
+/// mint's `MetadataPointer` points to.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata
+///   1. `[]` Update authority
+///   2. `[]` Mint
+///   3. `[signer]` Mint authority
+pub struct InitializeTokenMetadata<'a> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Update authority for the metadata
+    pub update_authority: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Mint authority
+    pub mint_authority: &'a AccountInfo,
+    /// The longer name of the token
+    pub name: &'a str,
+    /// The shortened symbol for the token
+    pub symbol: &'a str,
+    /// The URI pointing to richer metadata
+    pub uri: &'a str,
+    /// Token Metadata Program: Token-2022 or any program implementing the interface
+    pub program_id: &'a Pubkey,
+}
+
+impl InitializeTokenMetadata<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly(self.update_authority.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly_signer(self.mint_authority.key()),
+        ];
+
+        let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+        let data = initialize_token_metadata_instruction_data(
+            &mut buffer,
+            self.name,
+            self.symbol,
+            self.uri,
+        )?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.metadata,
+                self.update_authority,
+                self.mint,
+                self.mint_authority,
+            ],
+            signers,
+        )
+    }
+}
+
+/// Instruction data layout:
+/// - [0..8]  : Instruction discriminator (8 bytes)
+/// - name, symbol, uri : u32 LE length followed by UTF-8 bytes each
+pub fn initialize_token_metadata_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> Result<&'a [u8], ProgramError> {
+    let mut writer = Writer::new(buffer, InstructionDiscriminatorTokenMetadata::Initialize)?;
+
+    writer.str(name)?;
+    writer.str(symbol)?;
+    writer.str(uri)?;
+
+    Ok(writer.finish())
+}
@@ -0,0 +1,138 @@
+use {
+    crate::{
+        extension::{
+            consts::ExtensionType,
+            metadata_pointer,
+            token_metadata::{InitializeTokenMetadata, TokenMetadata},
+        },
+        instructions::{MintBuilder, MintExtensionInitializer},
+        state::Multisig,
+        system,
+    },
+    core::mem::size_of,
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
+        pubkey::Pubkey, sysvars::rent::Rent, ProgramResult,
+    },
+};
+
+/// Initialize a new mint holding its own `TokenMetadata`: the metadata
+/// pointer pointing at the mint, the other extensions, `InitializeMint2`,
+/// then `Initialize` of the token metadata interface on the mint.
+///
+/// The metadata pointer and the metadata share `update_authority`. The mint
+/// account must have been created with the space of the base mint, of the
+/// `MetadataPointer` extension and of the other extensions: Token-2022
+/// reallocates the mint for the variable-length metadata, to the length
+/// returned by [`mint_len`](Self::mint_len). With `rent_payer`, the lamports
+/// missing for the reallocated mint to be rent exempt are transferred to it
+/// first, otherwise the mint must already hold them. Fails
+/// with `InvalidArgument` before any CPI if an initializer targets another
+/// account than `mint` or initializes the metadata pointer.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Mint
+///   1. `[]` Update authority
+///   2. `[signer]` Mint authority
+///   3. `[writable, signer]` Optional rent payer
+pub struct InitializeMetadataWithPointer<'a, 'b> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Decimals
+    pub decimals: u8,
+    /// Mint authority, signing `Initialize`
+    pub mint_authority: &'a AccountInfo,
+    /// Freeze authority
+    pub freeze_authority: Option<&'a Pubkey>,
+    /// Authority that can set the metadata address and update the metadata
+    pub update_authority: &'a AccountInfo,
+    /// The longer name of the token
+    pub name: &'a str,
+    /// The shortened symbol for the token
+    pub symbol: &'a str,
+    /// The URI pointing to richer metadata
+    pub uri: &'a str,
+    /// Account funding the rent of the metadata, with the rent sysvar
+    pub rent_payer: Option<(&'a AccountInfo, &'a Rent)>,
+    /// Initializers of the other mint extensions
+    pub extensions: &'b [&'b dyn MintExtensionInitializer],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl InitializeMetadataWithPointer<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invoke the instructions, the rent transfer and `Initialize` being
+    /// signed with `signers`.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let builder = MintBuilder {
+            mint: self.mint,
+            decimals: self.decimals,
+            mint_authority: self.mint_authority.key(),
+            freeze_authority: self.freeze_authority,
+            extensions: self.extensions,
+            token_program: self.token_program,
+        };
+
+        builder.check_extensions()?;
+        if self
+            .extensions
+            .iter()
+            .any(|extension| extension.extension_type() == ExtensionType::MetadataPointer)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        metadata_pointer::Initialize {
+            mint: self.mint,
+            authority: Some(self.update_authority.key()),
+            metadata_address: Some(self.mint.key()),
+            token_program: self.token_program,
+        }
+        .invoke()?;
+
+        builder.invoke()?;
+
+        if let Some((payer, rent)) = self.rent_payer {
+            let lamports = rent
+                .minimum_balance(self.mint_len())
+                .saturating_sub(self.mint.lamports());
+
+            if lamports > 0 {
+                system::transfer(payer, self.mint, lamports, signers)?;
+            }
+        }
+
+        InitializeTokenMetadata {
+            metadata: self.mint,
+            update_authority: self.update_authority,
+            mint: self.mint,
+            mint_authority: self.mint_authority,
+            name: self.name,
+            symbol: self.symbol,
+            uri: self.uri,
+            program_id: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+
+    /// Return the length of the mint account once Token-2022 has reallocated
+    /// it for the metadata.
+    pub fn mint_len(&self) -> usize {
+        let len =
+            self.mint.data_len() + TokenMetadata::tlv_size_of(self.name, self.symbol, self.uri);
+
+        // Token-2022 pads the accounts which would have the length of a multisig
+        if len == Multisig::LEN {
+            len + size_of::<u16>()
+        } else {
+            len
+        }
+    }
+}
@@ -0,0 +1,15 @@
+mod emit;
+mod initialize;
+#[cfg(feature = "metadata-pointer")]
+mod initialize_with_pointer;
+mod remove_key;
+mod update_authority;
+mod update_field;
+
+pub use emit::*;
+pub use initialize::*;
+#[cfg(feature = "metadata-pointer")]
+pub use initialize_with_pointer::*;
+pub use remove_key::*;
+pub use update_authority::*;
+pub use update_field::*;
@@ -0,0 +1,80 @@
+use {
+    crate::cpi::invoke_signed,
+    crate::extension::token_metadata::state::{
+        InstructionDiscriminatorTokenMetadata, Writer, MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Remove a key-value pair from the additional metadata of a `TokenMetadata`
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata
+///   1. `[signer]` Update authority
+pub struct RemoveTokenMetadataKey<'a> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Update authority
+    pub update_authority: &'a AccountInfo,
+    /// If false, the instruction fails when the key is missing
+    pub idempotent: bool,
+    /// Key to remove
+    pub key: &'a str,
+    /// Token Metadata Program: Token-2022 or any program implementing the interface
+    pub program_id: &'a Pubkey,
+}
+
+impl RemoveTokenMetadataKey<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly_signer(self.update_authority.key()),
+        ];
+
+        let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+        let data =
+            remove_token_metadata_key_instruction_data(&mut buffer, self.idempotent, self.key)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
+
+/// Instruction data layout:
+/// - [0..8]  : Instruction discriminator (8 bytes)
+/// - [8]     : idempotent (1 byte, bool)
+/// - key     : u32 LE length followed by UTF-8 bytes
+pub fn remove_token_metadata_key_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    idempotent: bool,
+    key: &str,
+) -> Result<&'a [u8], ProgramError> {
+    let mut writer = Writer::new(buffer, InstructionDiscriminatorTokenMetadata::RemoveKey)?;
+
+    writer.u8(idempotent.into())?;
+    writer.str(key)?;
+
+    Ok(writer.finish())
+}
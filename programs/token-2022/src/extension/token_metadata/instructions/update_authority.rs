@@ -0,0 +1,79 @@
+use {
+    crate::cpi::invoke_signed,
+    crate::extension::token_metadata::state::{InstructionDiscriminatorTokenMetadata, Writer},
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// The length of the `UpdateAuthority` instruction data
+pub const UPDATE_TOKEN_METADATA_AUTHORITY_INSTRUCTION_DATA_LEN: usize = 8 + 32;
+
+/// Update the authority of a `TokenMetadata`
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata
+///   1. `[signer]` Current update authority
+pub struct UpdateTokenMetadataAuthority<'a> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Current update authority
+    pub current_authority: &'a AccountInfo,
+    /// New authority for the metadata, or None to make it immutable
+    pub new_authority: Option<&'a Pubkey>,
+    /// Token Metadata Program: Token-2022 or any program implementing the interface
+    pub program_id: &'a Pubkey,
+}
+
+impl UpdateTokenMetadataAuthority<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly_signer(self.current_authority.key()),
+        ];
+
+        let mut buffer = [0u8; UPDATE_TOKEN_METADATA_AUTHORITY_INSTRUCTION_DATA_LEN];
+        let data =
+            update_token_metadata_authority_instruction_data(&mut buffer, self.new_authority)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.current_authority],
+            signers,
+        )
+    }
+}
+
+/// Instruction data layout:
+/// - [0..8]  : Instruction discriminator (8 bytes)
+/// - [8..40] : new_authority pubkey, zeroed if none (32 bytes)
+pub fn update_token_metadata_authority_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    new_authority: Option<&Pubkey>,
+) -> Result<&'a [u8], ProgramError> {
+    let mut writer = Writer::new(
+        buffer,
+        InstructionDiscriminatorTokenMetadata::UpdateAuthority,
+    )?;
+
+    writer.bytes(new_authority.unwrap_or(&Pubkey::default()))?;
+
+    Ok(writer.finish())
+}
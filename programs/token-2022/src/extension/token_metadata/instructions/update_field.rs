@@ -0,0 +1,101 @@
+use {
+    crate::cpi::invoke_signed,
+    crate::extension::token_metadata::state::{
+        InstructionDiscriminatorTokenMetadata, Writer, MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Field of a `TokenMetadata` updated by `UpdateField`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Field<'a> {
+    /// The name field
+    Name,
+    /// The symbol field
+    Symbol,
+    /// The uri field
+    Uri,
+    /// A user field, added to the additional metadata if missing
+    Key(&'a str),
+}
+
+/// Update a field in a `TokenMetadata`
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` Metadata
+///   1. `[signer]` Update authority
+pub struct UpdateTokenMetadataField<'a> {
+    /// Metadata Account
+    pub metadata: &'a AccountInfo,
+    /// Update authority
+    pub update_authority: &'a AccountInfo,
+    /// Field to update
+    pub field: Field<'a>,
+    /// Value to write for the field
+    pub value: &'a str,
+    /// Token Metadata Program: Token-2022 or any program implementing the interface
+    pub program_id: &'a Pubkey,
+}
+
+impl UpdateTokenMetadataField<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly_signer(self.update_authority.key()),
+        ];
+
+        let mut buffer = [0u8; MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN];
+        let data =
+            update_token_metadata_field_instruction_data(&mut buffer, self.field, self.value)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
+
+/// Instruction data layout:
+/// - [0..8]  : Instruction discriminator (8 bytes)
+/// - [8]     : field (1 byte, u8), followed by the key string for `Field::Key`
+/// - value   : u32 LE length followed by UTF-8 bytes
+pub fn update_token_metadata_field_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    field: Field,
+    value: &str,
+) -> Result<&'a [u8], ProgramError> {
+    let mut writer = Writer::new(buffer, InstructionDiscriminatorTokenMetadata::UpdateField)?;
+
+    match field {
+        Field::Name => writer.u8(0)?,
+        Field::Symbol => writer.u8(1)?,
+        Field::Uri => writer.u8(2)?,
+        Field::Key(key) => {
+            writer.u8(3)?;
+            writer.str(key)?;
+        }
+    }
+    writer.str(value)?;
+
+    Ok(writer.finish())
+}
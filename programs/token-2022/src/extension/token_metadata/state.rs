@@ -0,0 +1,362 @@
+use {
+    crate::{
+        extension::{
+            consts::ExtensionType,
+            tlv::{get_extension_data_from_bytes, get_interface_value_from_bytes, TLV_HEADER_LEN},
+        },
+        pod::{pod_from_bytes, read_pod_str, OptionalNonZeroPubkey, LENGTH_PREFIX_LEN},
+        ID,
+    },
+    core::mem::size_of,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+};
+
+#[repr(u64)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstructionDiscriminatorTokenMetadata {
+    Initialize = 10181997023371125202, // [210, 225, 30, 162, 88, 184, 77, 141]
+    UpdateField = 14473666181958068701, // [221, 233, 49, 45, 181, 202, 220, 200]
+    RemoveKey = 13052994509336482538,  // [234, 18, 32, 56, 89, 141, 37, 181]
+    UpdateAuthority = 8887401230438425815, // [215, 228, 166, 228, 84, 100, 86, 123]
+    Emit = 5095836232549967610,        // [250, 166, 180, 250, 13, 12, 184, 70]
+}
+
+impl InstructionDiscriminatorTokenMetadata {
+    /// Return the 8 bytes discriminator starting the instruction data.
+    #[inline(always)]
+    pub const fn as_bytes(self) -> [u8; 8] {
+        (self as u64).to_le_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for InstructionDiscriminatorTokenMetadata {
+    type Error = ProgramError;
+
+    /// Decode the discriminator from the first 8 bytes of token metadata instruction data.
+    #[inline]
+    fn try_from(instruction_data: &[u8]) -> Result<Self, Self::Error> {
+        let Some(discriminator) = instruction_data.first_chunk::<8>() else {
+            Err(ProgramError::InvalidInstructionData)?
+        };
+
+        Ok(match u64::from_le_bytes(*discriminator) {
+            x if x == Self::Initialize as u64 => Self::Initialize,
+            x if x == Self::UpdateField as u64 => Self::UpdateField,
+            x if x == Self::RemoveKey as u64 => Self::RemoveKey,
+            x if x == Self::UpdateAuthority as u64 => Self::UpdateAuthority,
+            x if x == Self::Emit as u64 => Self::Emit,
+            _ => Err(ProgramError::InvalidInstructionData)?,
+        })
+    }
+}
+
+/// The length of the buffer the instruction wrappers encode their data into,
+/// the maximum size of a transaction.
+pub const MAX_TOKEN_METADATA_INSTRUCTION_DATA_LEN: usize = 1232;
+
+/// `TokenMetadata` discriminator in the TLV data of a metadata account owned by
+/// a program other than Token-2022, the first 8 bytes of
+/// `sha256("spl_token_metadata_interface:token_metadata")`.
+pub const TOKEN_METADATA_DISCRIMINATOR: [u8; 8] = [112, 132, 90, 90, 11, 88, 157, 87];
+
+/// Variable-length `TokenMetadata` borrowed from the account data.
+///
+/// Data layout (borsh):
+/// - [0..32]  : update_authority pubkey, zeroed if none (32 bytes)
+/// - [32..64] : mint pubkey (32 bytes)
+/// - name, symbol, uri : u32 LE length followed by UTF-8 bytes each
+/// - additional_metadata : u32 LE count followed by `count` key/value string pairs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenMetadata<'a> {
+    /// The authority that can sign to update the metadata
+    update_authority: &'a OptionalNonZeroPubkey,
+    /// The associated mint, used to counter spoofing
+    pub mint: &'a Pubkey,
+    /// The longer name of the token
+    pub name: &'a str,
+    /// The shortened symbol for the token
+    pub symbol: &'a str,
+    /// The URI pointing to richer metadata
+    pub uri: &'a str,
+    /// Number of additional key/value pairs
+    additional_metadata_len: u32,
+    /// Serialized additional key/value pairs
+    additional_metadata: &'a [u8],
+}
+
+impl<'a> TokenMetadata<'a> {
+    /// Return the `TokenMetadata` stored in the given account data, wherever its
+    /// TLV entry is located.
+    #[inline]
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        let data = get_extension_data_from_bytes(ExtensionType::TokenMetadata, bytes)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Self::unpack(data)
+    }
+
+    /// Return the `TokenMetadata` stored in the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo`. The returned
+    /// value borrows the account data without a borrow guard.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the account data is not mutably borrowed
+    /// while the returned value is alive.
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &'a AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if account_info.owner() != &ID {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        Self::from_bytes(account_info.borrow_data_unchecked())
+    }
+
+    /// Return the `TokenMetadata` stored in a metadata account owned by
+    /// `program_id`, which is either Token-2022 (the metadata lives in the mint)
+    /// or a program implementing the token metadata interface.
+    ///
+    /// This method performs owner validation on `AccountInfo`. The returned
+    /// value borrows the account data without a borrow guard.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the account data is not mutably borrowed
+    /// while the returned value is alive.
+    #[inline]
+    pub unsafe fn from_account_info_with_program_id_unchecked(
+        account_info: &'a AccountInfo,
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        if program_id == &ID {
+            return Self::from_account_info_unchecked(account_info);
+        }
+
+        if account_info.owner() != program_id {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        Self::from_interface_bytes(account_info.borrow_data_unchecked())
+    }
+
+    /// Return the `TokenMetadata` from the TLV data of a metadata account owned
+    /// by a program other than Token-2022, see [`get_interface_value_from_bytes`].
+    #[inline]
+    pub fn from_interface_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        let data = get_interface_value_from_bytes(&TOKEN_METADATA_DISCRIMINATOR, bytes)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Self::unpack(data)
+    }
+
+    /// Parse the `TokenMetadata` extension value, i.e. the data following the
+    /// TLV header.
+    pub fn unpack(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let mut reader = Reader { data };
+
+        let update_authority = pod_from_bytes(reader.take(32)?)?;
+        let mint = reader.pubkey()?;
+        let name = reader.str()?;
+        let symbol = reader.str()?;
+        let uri = reader.str()?;
+        let additional_metadata_len = reader.u32()?;
+        let additional_metadata = reader.data;
+
+        // validate all the pairs upfront, so iterating over them can't fail
+        let mut pairs = Reader {
+            data: additional_metadata,
+        };
+        for _ in 0..additional_metadata_len {
+            pairs.str()?;
+            pairs.str()?;
+        }
+        if !pairs.data.is_empty() {
+            Err(ProgramError::InvalidAccountData)?;
+        }
+
+        Ok(Self {
+            update_authority,
+            mint,
+            name,
+            symbol,
+            uri,
+            additional_metadata_len,
+            additional_metadata,
+        })
+    }
+
+    #[inline]
+    pub fn update_authority(&self) -> Option<&'a Pubkey> {
+        self.update_authority.get()
+    }
+
+    /// Number of additional key/value pairs
+    #[inline]
+    pub fn additional_metadata_len(&self) -> usize {
+        self.additional_metadata_len as usize
+    }
+
+    /// Iterate over the additional key/value pairs in their stored order
+    #[inline]
+    pub fn additional_metadata(&self) -> AdditionalMetadata<'a> {
+        AdditionalMetadata {
+            reader: Reader {
+                data: self.additional_metadata,
+            },
+            remaining: self.additional_metadata_len,
+        }
+    }
+
+    /// Return the value of the given additional metadata key
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.additional_metadata()
+            .find(|&(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Return the length of the TLV entry of a `TokenMetadata` with the given
+    /// fields and no additional metadata, as written by `Initialize`.
+    #[inline]
+    pub const fn tlv_size_of(name: &str, symbol: &str, uri: &str) -> usize {
+        TLV_HEADER_LEN
+            + 2 * size_of::<Pubkey>()
+            + LENGTH_PREFIX_LEN
+            + name.len()
+            + LENGTH_PREFIX_LEN
+            + symbol.len()
+            + LENGTH_PREFIX_LEN
+            + uri.len()
+            + LENGTH_PREFIX_LEN
+    }
+}
+
+/// Iterator over the additional metadata key/value pairs of a `TokenMetadata`
+pub struct AdditionalMetadata<'a> {
+    reader: Reader<'a>,
+    remaining: u32,
+}
+
+impl<'a> Iterator for AdditionalMetadata<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // the pairs are validated when unpacking
+        Some((self.reader.str().ok()?, self.reader.str().ok()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+/// Borsh reader over a byte slice
+#[derive(Clone, Copy)]
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    #[inline]
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ProgramError> {
+        if self.data.len() < len {
+            Err(ProgramError::InvalidAccountData)?;
+        }
+
+        let (value, rest) = self.data.split_at(len);
+        self.data = rest;
+
+        Ok(value)
+    }
+
+    #[inline]
+    fn pubkey(&mut self) -> Result<&'a Pubkey, ProgramError> {
+        pod_from_bytes(self.take(32)?)
+    }
+
+    #[inline]
+    fn u32(&mut self) -> Result<u32, ProgramError> {
+        let bytes = self.take(LENGTH_PREFIX_LEN)?;
+
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    #[inline]
+    fn str(&mut self) -> Result<&'a str, ProgramError> {
+        let (value, rest) = read_pod_str(self.data)?;
+        self.data = rest;
+
+        Ok(value)
+    }
+}
+
+/// Borsh writer over a byte slice, used to encode the instruction data
+pub(super) struct Writer<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> Writer<'a> {
+    /// Start the instruction data with the given discriminator
+    #[inline]
+    pub(super) fn new(
+        buffer: &'a mut [u8],
+        discriminator: InstructionDiscriminatorTokenMetadata,
+    ) -> Result<Self, ProgramError> {
+        let mut writer = Self { buffer, len: 0 };
+        writer.bytes(&discriminator.as_bytes())?;
+
+        Ok(writer)
+    }
+
+    #[inline]
+    pub(super) fn bytes(&mut self, bytes: &[u8]) -> Result<(), ProgramError> {
+        let end = self.len + bytes.len();
+
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(ProgramError::InvalidArgument)?
+            .copy_from_slice(bytes);
+        self.len = end;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub(super) fn u8(&mut self, value: u8) -> Result<(), ProgramError> {
+        self.bytes(&[value])
+    }
+
+    #[inline]
+    pub(super) fn option_u64(&mut self, value: Option<u64>) -> Result<(), ProgramError> {
+        match value {
+            Some(x) => {
+                self.u8(1)?;
+                self.bytes(&x.to_le_bytes())
+            }
+            None => self.u8(0),
+        }
+    }
+
+    #[inline]
+    pub(super) fn str(&mut self, value: &str) -> Result<(), ProgramError> {
+        let len = u32::try_from(value.len()).map_err(|_| ProgramError::InvalidArgument)?;
+
+        self.bytes(&len.to_le_bytes())?;
+        self.bytes(value.as_bytes())
+    }
+
+    /// Return the written data
+    #[inline]
+    pub(super) fn finish(self) -> &'a [u8] {
+        &self.buffer[..self.len]
+    }
+}
@@ -0,0 +1,137 @@
+use {
+    crate::cpi::invoke_signed_with_bounds,
+    crate::extension::transfer_fee::state::{
+        offset_transfer_fee_harvest_withheld_tokens_to_mint as OFFSET,
+        InstructionDiscriminatorTransferFee,
+    },
+    core::{mem::MaybeUninit, slice},
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::MAX_CPI_ACCOUNTS,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Permissionless instruction to transfer all withheld tokens to the mint.
+///
+/// Succeeds for frozen accounts.
+///
+/// Accounts provided should include the `TransferFeeAmount` extension. If
+/// not, the account is skipped.
+///
+/// Accounts expected by this instruction:
+///
+///   0. `[writable]` The mint.
+///   1. `..1+N` `[writable]` The source accounts to harvest from.
+pub struct HarvestWithheldTokensToMint<'a> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// The source accounts to harvest from
+    pub sources: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl HarvestWithheldTokensToMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            sources,
+            token_program,
+        } = self;
+
+        let num_accounts = 1 + sources.len();
+
+        if num_accounts > MAX_CPI_ACCOUNTS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; MAX_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_metas` is sized to MAX_CPI_ACCOUNTS
+            // - Index 0 is always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(mint.key()));
+        }
+
+        for (account_meta, source) in acc_metas[1..].iter_mut().zip(sources.iter()) {
+            account_meta.write(AccountMeta::writable(source.key()));
+        }
+
+        let mut buffer = [0u8; OFFSET::END as usize];
+        let data = harvest_withheld_tokens_to_mint_instruction_data(&mut buffer);
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data,
+        };
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; MAX_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_infos` is sized to MAX_CPI_ACCOUNTS
+            // - Index 0 is always present
+            acc_infos.get_unchecked_mut(0).write(mint);
+        }
+
+        // Fill source accounts
+        for (account_info, source) in acc_infos[1..].iter_mut().zip(sources.iter()) {
+            account_info.write(source);
+        }
+
+        invoke_signed_with_bounds::<MAX_CPI_ACCOUNTS>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+
+    /// Same as [`Self::invoke`], splitting `sources` into as many CPIs as needed
+    /// to stay under `MAX_CPI_ACCOUNTS`.
+    ///
+    /// No CPI is made for empty `sources`.
+    #[inline(always)]
+    pub fn invoke_chunked(&self) -> ProgramResult {
+        self.invoke_signed_chunked(&[])
+    }
+
+    /// Same as [`Self::invoke_signed`], splitting `sources` into as many CPIs as
+    /// needed to stay under `MAX_CPI_ACCOUNTS`.
+    ///
+    /// No CPI is made for empty `sources`.
+    pub fn invoke_signed_chunked(&self, signers: &[Signer]) -> ProgramResult {
+        // the mint is passed to every CPI
+        for sources in self.sources.chunks(MAX_CPI_ACCOUNTS - 1) {
+            Self { sources, ..*self }.invoke_signed(signers)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn harvest_withheld_tokens_to_mint_instruction_data(buffer: &mut [u8]) -> &[u8] {
+    // Set discriminators
+    buffer[..OFFSET::START as usize].copy_from_slice(
+        &InstructionDiscriminatorTransferFee::HarvestWithheldTokensToMint.as_bytes(),
+    );
+
+    buffer
+}
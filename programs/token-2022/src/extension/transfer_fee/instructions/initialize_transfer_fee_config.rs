@@ -0,0 +1,79 @@
+use {
+    crate::{
+        extension::transfer_fee::state::{
+            offset_transfer_fee_initialize as OFFSET, InstructionDiscriminatorTransferFee,
+        },
+        macros::define_extension_instruction,
+    },
+    pinocchio::pubkey::Pubkey,
+};
+
+define_extension_instruction! {
+    /// Initialize the transfer fee on a new mint.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    pub struct InitializeTransferFeeConfig<'a> {
+        accounts: {
+            /// Mint Account
+            mint: writable,
+        },
+        data: {
+            /// Pubkey that may update the fees
+            transfer_fee_config_authority: Option<&'a Pubkey>,
+            /// Withdraw instructions must be signed by this key
+            withdraw_withheld_authority: Option<&'a Pubkey>,
+            /// Amount of transfer collected as fees, expressed as basis points of the
+            /// transfer amount
+            transfer_fee_basis_points: u16,
+            /// Maximum fee assessed on transfers
+            maximum_fee: u64,
+        },
+        instruction_data: initialize_transfer_fee_config_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn initialize_transfer_fee_config_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    transfer_fee_config_authority: Option<&Pubkey>,
+    withdraw_withheld_authority: Option<&Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> &'a [u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset].copy_from_slice(
+        &InstructionDiscriminatorTransferFee::InitializeTransferFeeConfig.as_bytes(),
+    );
+
+    // Set authorities as `COption<Pubkey>`
+    for authority in [transfer_fee_config_authority, withdraw_withheld_authority] {
+        if let Some(x) = authority {
+            buffer[offset] = 1;
+            offset += OFFSET::OPTION_FLAG as usize;
+            buffer[offset..offset + OFFSET::AUTHORITY_PUBKEY as usize].copy_from_slice(x);
+            offset += OFFSET::AUTHORITY_PUBKEY as usize;
+        } else {
+            buffer[offset] = 0;
+            offset += OFFSET::OPTION_FLAG as usize;
+        }
+    }
+
+    // Set transfer_fee_basis_points
+    buffer[offset..offset + OFFSET::TRANSFER_FEE_BASIS_POINTS as usize]
+        .copy_from_slice(&transfer_fee_basis_points.to_le_bytes());
+    offset += OFFSET::TRANSFER_FEE_BASIS_POINTS as usize;
+
+    // Set maximum_fee
+    buffer[offset..offset + OFFSET::MAXIMUM_FEE as usize]
+        .copy_from_slice(&maximum_fee.to_le_bytes());
+    offset += OFFSET::MAXIMUM_FEE as usize;
+
+    &buffer[..offset]
+}
@@ -0,0 +1,64 @@
+use crate::{
+    extension::transfer_fee::state::{
+        offset_transfer_fee_set_transfer_fee as OFFSET, InstructionDiscriminatorTransferFee,
+    },
+    macros::define_extension_instruction,
+};
+
+define_extension_instruction! {
+    /// Set transfer fee. Only supported for mints that include the
+    /// `TransferFeeConfig` extension.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[signer]` The mint's fee account owner.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[]` The mint's multisignature fee account owner.
+    ///   2. `..2+M` `[signer]` M signer accounts.
+    pub struct SetTransferFee<'a> {
+        accounts: {
+            /// Mint Account
+            mint: writable,
+        },
+        authority: {
+            /// The transfer fee config authority.
+            authority
+        },
+        data: {
+            /// Amount of transfer collected as fees, expressed as basis points of the
+            /// transfer amount
+            transfer_fee_basis_points: u16,
+            /// Maximum fee assessed on transfers
+            maximum_fee: u64,
+        },
+        instruction_data: set_transfer_fee_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn set_transfer_fee_instruction_data(
+    buffer: &mut [u8],
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> &[u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorTransferFee::SetTransferFee.as_bytes());
+
+    // Set transfer_fee_basis_points
+    buffer[offset..offset + OFFSET::TRANSFER_FEE_BASIS_POINTS as usize]
+        .copy_from_slice(&transfer_fee_basis_points.to_le_bytes());
+    offset += OFFSET::TRANSFER_FEE_BASIS_POINTS as usize;
+
+    // Set maximum_fee
+    buffer[offset..offset + OFFSET::MAXIMUM_FEE as usize]
+        .copy_from_slice(&maximum_fee.to_le_bytes());
+
+    buffer
+}
@@ -0,0 +1,195 @@
+use {
+    crate::{
+        cpi::invoke_signed_with_bounds,
+        extension::transfer_fee::state::{
+            offset_transfer_fee_transfer_checked_with_fee as OFFSET,
+            InstructionDiscriminatorTransferFee,
+        },
+        instructions::MAX_MULTISIG_SIGNERS,
+    },
+    core::{mem::MaybeUninit, slice},
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Transfer, providing expected mint information and fees.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[writable]` The source account. Must include the
+///      `TransferFeeAmount` extension.
+///   1. `[]` The token mint. Must include the `TransferFeeConfig` extension.
+///   2. `[writable]` The destination account. Must include the
+///      `TransferFeeAmount` extension.
+///   3. `[signer]` The source account's owner/delegate.
+///
+///   * Multisignature owner/delegate
+///   0. `[writable]` The source account.
+///   1. `[]` The token mint.
+///   2. `[writable]` The destination account.
+///   3. `[]` The source account's multisignature owner/delegate.
+///   4. `..4+M` `[signer]` M signer accounts.
+pub struct TransferCheckedWithFee<'a> {
+    /// Sender account
+    pub source: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Recipient account
+    pub destination: &'a AccountInfo,
+    /// The source account's owner/delegate
+    pub authority: &'a AccountInfo,
+    /// The Signer accounts if `authority` is a multisig
+    pub signers: &'a [AccountInfo],
+    /// The amount of tokens to transfer
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place
+    pub decimals: u8,
+    /// Expected fee assessed on this transfer, calculated off-chain based on
+    /// the `transfer_fee_basis_points` and `maximum_fee` of the mint
+    pub fee: u64,
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl TransferCheckedWithFee<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            source,
+            mint,
+            destination,
+            authority,
+            signers: account_signers,
+            token_program,
+            ..
+        } = self;
+
+        #[cfg(feature = "preflight")]
+        crate::guards::assert_cpi_guard_allows(
+            source,
+            crate::guards::CpiGuardedInstruction::Transfer {
+                authority: authority.key(),
+            },
+        )?;
+
+        if account_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 4 + account_signers.len();
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_metas` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 is always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::writable(source.key()));
+            // - Index 1 is always present
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::readonly(mint.key()));
+            // - Index 2 is always present
+            acc_metas
+                .get_unchecked_mut(2)
+                .write(AccountMeta::writable(destination.key()));
+            // - Index 3 is always present
+            if account_signers.is_empty() {
+                acc_metas
+                    .get_unchecked_mut(3)
+                    .write(AccountMeta::readonly_signer(authority.key()));
+            } else {
+                acc_metas
+                    .get_unchecked_mut(3)
+                    .write(AccountMeta::readonly(authority.key()));
+            }
+        }
+
+        for (account_meta, signer) in acc_metas[4..].iter_mut().zip(account_signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
+
+        let mut buffer = [0u8; OFFSET::END as usize];
+        let data = transfer_checked_with_fee_instruction_data(
+            &mut buffer,
+            self.amount,
+            self.decimals,
+            self.fee,
+        );
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data,
+        };
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_infos` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 is always present
+            acc_infos.get_unchecked_mut(0).write(source);
+            // - Index 1 is always present
+            acc_infos.get_unchecked_mut(1).write(mint);
+            // - Index 2 is always present
+            acc_infos.get_unchecked_mut(2).write(destination);
+            // - Index 3 is always present
+            acc_infos.get_unchecked_mut(3).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_info, signer) in acc_infos[4..].iter_mut().zip(account_signers.iter()) {
+            account_info.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
+
+pub fn transfer_checked_with_fee_instruction_data(
+    buffer: &mut [u8],
+    amount: u64,
+    decimals: u8,
+    fee: u64,
+) -> &[u8] {
+    let mut offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset]
+        .copy_from_slice(&InstructionDiscriminatorTransferFee::TransferCheckedWithFee.as_bytes());
+
+    // Set amount
+    buffer[offset..offset + OFFSET::AMOUNT as usize].copy_from_slice(&amount.to_le_bytes());
+    offset += OFFSET::AMOUNT as usize;
+
+    // Set decimals
+    buffer[offset] = decimals;
+    offset += OFFSET::DECIMALS as usize;
+
+    // Set fee
+    buffer[offset..offset + OFFSET::FEE as usize].copy_from_slice(&fee.to_le_bytes());
+
+    buffer
+}
@@ -0,0 +1,212 @@
+use {
+    crate::{
+        cpi::invoke_signed_with_bounds,
+        extension::transfer_fee::state::{
+            offset_transfer_fee_withdraw_withheld_tokens_from_accounts as OFFSET,
+            InstructionDiscriminatorTransferFee,
+        },
+        instructions::MAX_MULTISIG_SIGNERS,
+    },
+    core::{mem::MaybeUninit, slice},
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::MAX_CPI_ACCOUNTS,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Transfer all withheld tokens to an account. Signed by the mint's
+/// withdraw withheld tokens authority.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single owner/delegate
+///   0. `[]` The token mint. Must include the `TransferFeeConfig` extension.
+///   1. `[writable]` The fee receiver account. Must include the
+///      `TransferFeeAmount` extension and be associated with the provided
+///      mint.
+///   2. `[signer]` The mint's `withdraw_withheld_authority`.
+///   3. `..3+N` `[writable]` The source accounts to withdraw from.
+///
+///   * Multisignature owner/delegate
+///   0. `[]` The token mint.
+///   1. `[writable]` The destination account.
+///   2. `[]` The mint's multisig `withdraw_withheld_authority`.
+///   3. `..3+M` `[signer]` M signer accounts.
+///   4. `3+M+1..3+M+N` `[writable]` The source accounts to withdraw from.
+pub struct WithdrawWithheldTokensFromAccounts<'a> {
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// The fee receiver account
+    pub destination: &'a AccountInfo,
+    /// The mint's withdraw withheld authority
+    pub authority: &'a AccountInfo,
+    /// The Signer accounts if `authority` is a multisig
+    pub signers: &'a [AccountInfo],
+    /// The source accounts to withdraw from
+    pub sources: &'a [AccountInfo],
+    /// Token Program
+    pub token_program: &'a Pubkey,
+}
+
+impl WithdrawWithheldTokensFromAccounts<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            destination,
+            authority,
+            signers: account_signers,
+            sources,
+            token_program,
+        } = self;
+
+        if account_signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        let num_accounts = 3 + account_signers.len() + sources.len();
+
+        if num_accounts > MAX_CPI_ACCOUNTS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // Account metadata
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        let mut acc_metas = [UNINIT_META; MAX_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_metas` is sized to MAX_CPI_ACCOUNTS
+            // - Index 0 is always present
+            acc_metas
+                .get_unchecked_mut(0)
+                .write(AccountMeta::readonly(mint.key()));
+            // - Index 1 is always present
+            acc_metas
+                .get_unchecked_mut(1)
+                .write(AccountMeta::writable(destination.key()));
+            // - Index 2 is always present
+            if account_signers.is_empty() {
+                acc_metas
+                    .get_unchecked_mut(2)
+                    .write(AccountMeta::readonly_signer(authority.key()));
+            } else {
+                acc_metas
+                    .get_unchecked_mut(2)
+                    .write(AccountMeta::readonly(authority.key()));
+            }
+        }
+
+        for (account_meta, signer) in acc_metas[3..].iter_mut().zip(account_signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
+
+        for (account_meta, source) in acc_metas[3 + account_signers.len()..]
+            .iter_mut()
+            .zip(sources.iter())
+        {
+            account_meta.write(AccountMeta::writable(source.key()));
+        }
+
+        let mut buffer = [0u8; OFFSET::END as usize];
+        let data = withdraw_withheld_tokens_from_accounts_instruction_data(
+            &mut buffer,
+            sources.len() as u8,
+        );
+
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: unsafe { slice::from_raw_parts(acc_metas.as_ptr() as _, num_accounts) },
+            data,
+        };
+
+        // Account info array
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::uninit();
+        let mut acc_infos = [UNINIT_INFO; MAX_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_infos` is sized to MAX_CPI_ACCOUNTS
+            // - Index 0 is always present
+            acc_infos.get_unchecked_mut(0).write(mint);
+            // - Index 1 is always present
+            acc_infos.get_unchecked_mut(1).write(destination);
+            // - Index 2 is always present
+            acc_infos.get_unchecked_mut(2).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_info, signer) in acc_infos[3..].iter_mut().zip(account_signers.iter()) {
+            account_info.write(signer);
+        }
+
+        // Fill source accounts
+        for (account_info, source) in acc_infos[3 + account_signers.len()..]
+            .iter_mut()
+            .zip(sources.iter())
+        {
+            account_info.write(source);
+        }
+
+        invoke_signed_with_bounds::<MAX_CPI_ACCOUNTS>(
+            &instruction,
+            unsafe { slice::from_raw_parts(acc_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+
+    /// Same as [`Self::invoke`], splitting `sources` into as many CPIs as needed
+    /// to stay under `MAX_CPI_ACCOUNTS`.
+    ///
+    /// No CPI is made for empty `sources`.
+    #[inline(always)]
+    pub fn invoke_chunked(&self) -> ProgramResult {
+        self.invoke_signed_chunked(&[])
+    }
+
+    /// Same as [`Self::invoke_signed`], splitting `sources` into as many CPIs as
+    /// needed to stay under `MAX_CPI_ACCOUNTS`.
+    ///
+    /// No CPI is made for empty `sources`.
+    pub fn invoke_signed_chunked(&self, signers: &[Signer]) -> ProgramResult {
+        if self.signers.len() > MAX_MULTISIG_SIGNERS {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // the mint, the destination, the authority and the multisig signers
+        // are passed to every CPI
+        let max_sources = MAX_CPI_ACCOUNTS - 3 - self.signers.len();
+
+        for sources in self.sources.chunks(max_sources) {
+            Self { sources, ..*self }.invoke_signed(signers)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_instruction_data(
+    buffer: &mut [u8],
+    num_token_accounts: u8,
+) -> &[u8] {
+    let offset = OFFSET::START as usize;
+
+    // Set discriminators
+    buffer[..offset].copy_from_slice(
+        &InstructionDiscriminatorTransferFee::WithdrawWithheldTokensFromAccounts.as_bytes(),
+    );
+
+    // Set num_token_accounts
+    buffer[offset] = num_token_accounts;
+
+    buffer
+}
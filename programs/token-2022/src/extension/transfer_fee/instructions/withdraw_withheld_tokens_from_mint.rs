@@ -0,0 +1,51 @@
+use crate::{
+    extension::transfer_fee::state::{
+        offset_transfer_fee_withdraw_withheld_tokens_from_mint as OFFSET,
+        InstructionDiscriminatorTransferFee,
+    },
+    macros::define_extension_instruction,
+};
+
+define_extension_instruction! {
+    /// Transfer all withheld tokens in the mint to an account. Signed by the
+    /// mint's withdraw withheld tokens authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The token mint. Must include the `TransferFeeConfig`
+    ///      extension.
+    ///   1. `[writable]` The fee receiver account. Must include the
+    ///      `TransferFeeAmount` extension associated with the provided mint.
+    ///   2. `[signer]` The mint's `withdraw_withheld_authority`.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The token mint.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The mint's multisig `withdraw_withheld_authority`.
+    ///   3. `..3+M` `[signer]` M signer accounts.
+    pub struct WithdrawWithheldTokensFromMint<'a> {
+        accounts: {
+            /// Mint Account
+            mint: writable,
+            /// The fee receiver account
+            destination: writable,
+        },
+        authority: {
+            /// The mint's withdraw withheld authority
+            authority
+        },
+        data: {},
+        instruction_data: withdraw_withheld_tokens_from_mint_instruction_data,
+        data_len: OFFSET::END as usize,
+    }
+}
+
+pub fn withdraw_withheld_tokens_from_mint_instruction_data(buffer: &mut [u8]) -> &[u8] {
+    // Set discriminators
+    buffer[..OFFSET::START as usize].copy_from_slice(
+        &InstructionDiscriminatorTransferFee::WithdrawWithheldTokensFromMint.as_bytes(),
+    );
+
+    buffer
+}
@@ -0,0 +1,417 @@
+use {
+    crate::{
+        error::TokenError,
+        extension::{
+            consts::ExtensionType,
+            tlv::{get_extension_from_bytes, Extension},
+        },
+        ID,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Maximum possible fee in basis points is 100%, aka 10_000 basis points
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstructionDiscriminatorTransferFee {
+    InitializeTransferFeeConfig = 0,
+    TransferCheckedWithFee = 1,
+    WithdrawWithheldTokensFromMint = 2,
+    WithdrawWithheldTokensFromAccounts = 3,
+    HarvestWithheldTokensToMint = 4,
+    SetTransferFee = 5,
+}
+
+crate::extension::consts::extension_instruction!(
+    InstructionDiscriminatorTransferFee,
+    TransferFee,
+    SetTransferFee
+);
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte)
+/// - [1]                        : Instruction discriminator (1 byte)
+/// - [2]                        : transfer_fee_config_authority flag (1 byte)
+/// - [3..35]                    : transfer_fee_config_authority pubkey (optional, 32 bytes)
+/// - [..]                       : withdraw_withheld_authority flag (1 byte)
+/// - [..]                       : withdraw_withheld_authority pubkey (optional, 32 bytes)
+/// - [..]                       : transfer_fee_basis_points (2 bytes, u16)
+/// - [..]                       : maximum_fee (8 bytes, u64)
+///
+/// Authorities are encoded as `COption<Pubkey>`, so the data is variable length
+/// and `END` is the maximum length with both authorities present.
+pub mod offset_transfer_fee_initialize {
+    pub const START: u8 = 2;
+    pub const OPTION_FLAG: u8 = 1;
+    pub const AUTHORITY_PUBKEY: u8 = 32;
+    pub const TRANSFER_FEE_BASIS_POINTS: u8 = 2;
+    pub const MAXIMUM_FEE: u8 = 8;
+    pub const END: u8 = START
+        + 2 * (OPTION_FLAG + AUTHORITY_PUBKEY)
+        + TRANSFER_FEE_BASIS_POINTS
+        + MAXIMUM_FEE;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte, u8)
+/// - [1]                        : Instruction discriminator (1 byte, u8)
+/// - [2..10]                    : amount (8 bytes, u64)
+/// - [10]                       : decimals (1 byte, u8)
+/// - [11..19]                   : fee (8 bytes, u64)
+pub mod offset_transfer_fee_transfer_checked_with_fee {
+    pub const START: u8 = 2;
+    pub const AMOUNT: u8 = 8;
+    pub const DECIMALS: u8 = 1;
+    pub const FEE: u8 = 8;
+    pub const END: u8 = START + AMOUNT + DECIMALS + FEE;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte, u8)
+/// - [1]                        : Instruction discriminator (1 byte, u8)
+pub mod offset_transfer_fee_withdraw_withheld_tokens_from_mint {
+    pub const START: u8 = 2;
+    pub const END: u8 = START;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte, u8)
+/// - [1]                        : Instruction discriminator (1 byte, u8)
+/// - [2]                        : num_token_accounts (1 byte, u8)
+pub mod offset_transfer_fee_withdraw_withheld_tokens_from_accounts {
+    pub const START: u8 = 2;
+    pub const NUM_TOKEN_ACCOUNTS: u8 = 1;
+    pub const END: u8 = START + NUM_TOKEN_ACCOUNTS;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte, u8)
+/// - [1]                        : Instruction discriminator (1 byte, u8)
+pub mod offset_transfer_fee_harvest_withheld_tokens_to_mint {
+    pub const START: u8 = 2;
+    pub const END: u8 = START;
+}
+
+/// Instruction data layout:
+/// - [0]                        : Extension discriminator (1 byte, u8)
+/// - [1]                        : Instruction discriminator (1 byte, u8)
+/// - [2..4]                     : transfer_fee_basis_points (2 bytes, u16)
+/// - [4..12]                    : maximum_fee (8 bytes, u64)
+pub mod offset_transfer_fee_set_transfer_fee {
+    pub const START: u8 = 2;
+    pub const TRANSFER_FEE_BASIS_POINTS: u8 = 2;
+    pub const MAXIMUM_FEE: u8 = 8;
+    pub const END: u8 = START + TRANSFER_FEE_BASIS_POINTS + MAXIMUM_FEE;
+}
+
+/// Transfer fee information
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferFee {
+    /// First epoch where the transfer fee takes effect
+    pub epoch: [u8; 8],
+    /// Maximum fee assessed on transfers, expressed as an amount of tokens
+    pub maximum_fee: [u8; 8],
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount, ie. increments of 0.01%
+    pub transfer_fee_basis_points: [u8; 2],
+}
+
+impl TransferFee {
+    #[inline(always)]
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    #[inline(always)]
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    #[inline(always)]
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    /// Calculate the transfer fee for `pre_fee_amount`, rounded up and capped by the
+    /// maximum fee. Returns `None` if the basis points exceed 100%
+    pub fn calculate_fee(&self, pre_fee_amount: u64) -> Option<u64> {
+        let transfer_fee_basis_points = self.transfer_fee_basis_points();
+        if transfer_fee_basis_points > MAX_FEE_BASIS_POINTS {
+            return None;
+        }
+        if transfer_fee_basis_points == 0 || pre_fee_amount == 0 {
+            return Some(0);
+        }
+
+        let numerator = (pre_fee_amount as u128).checked_mul(transfer_fee_basis_points as u128)?;
+        let raw_fee = numerator.div_ceil(MAX_FEE_BASIS_POINTS as u128);
+
+        Some(core::cmp::min(
+            u64::try_from(raw_fee).ok()?,
+            self.maximum_fee(),
+        ))
+    }
+}
+
+#[repr(C)]
+pub struct TransferFeeConfig {
+    /// Optional authority to set the fee
+    pub transfer_fee_config_authority: Pubkey,
+    /// Withdraw from mint instructions must be signed by this key
+    pub withdraw_withheld_authority: Pubkey,
+    /// Withheld transfer fee tokens that have been moved to the mint for
+    /// withdrawal
+    pub withheld_amount: [u8; 8],
+    /// Older transfer fee, used if `current epoch < new_transfer_fee.epoch`
+    pub older_transfer_fee: TransferFee,
+    /// Newer transfer fee, used if `current epoch >= new_transfer_fee.epoch`
+    pub newer_transfer_fee: TransferFee,
+}
+
+unsafe impl Extension for TransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+}
+
+impl TransferFeeConfig {
+    /// The length of the `TransferFeeConfig` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<TransferFeeConfig>();
+
+    /// Return a `TransferFeeConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<TransferFeeConfig>, ProgramError> {
+        // Check owner
+        if account_info.owner() != &ID {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        // Safely borrow and map the data
+        let data_ref = account_info
+            .try_borrow_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+        Ref::try_map(data_ref, Self::from_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `TransferFeeConfig` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, but does not perform the borrow check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountInfo,
+    ) -> Result<&Self, ProgramError> {
+        // Check owner
+        if account_info.owner() != &ID {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        // Get unchecked borrow and convert
+        Self::from_bytes(account_info.borrow_data_unchecked())
+    }
+
+    /// Return a `TransferFeeConfig` from the given account data.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` holds a `TransferFeeConfig` extension.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        get_extension_from_bytes::<Self>(bytes).unwrap_unchecked()
+    }
+
+    /// Return a `TransferFeeConfig` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    pub fn has_transfer_fee_config_authority(&self) -> bool {
+        self.transfer_fee_config_authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn transfer_fee_config_authority(&self) -> Option<&Pubkey> {
+        if self.has_transfer_fee_config_authority() {
+            Some(&self.transfer_fee_config_authority)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn has_withdraw_withheld_authority(&self) -> bool {
+        self.withdraw_withheld_authority != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn withdraw_withheld_authority(&self) -> Option<&Pubkey> {
+        if self.has_withdraw_withheld_authority() {
+            Some(&self.withdraw_withheld_authority)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    #[inline(always)]
+    pub fn older_transfer_fee(&self) -> &TransferFee {
+        &self.older_transfer_fee
+    }
+
+    #[inline(always)]
+    pub fn newer_transfer_fee(&self) -> &TransferFee {
+        &self.newer_transfer_fee
+    }
+
+    /// Get the fee for the given epoch
+    #[inline]
+    pub fn get_epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+
+    /// Calculate the fee for `pre_fee_amount` transferred in the given epoch
+    #[inline]
+    pub fn calculate_epoch_fee(&self, epoch: u64, pre_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch).calculate_fee(pre_fee_amount)
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(TransferFeeConfig);
+
+/// Return the fee Token-2022 expects for transferring `amount` tokens of the
+/// mint in the given epoch, i.e. the `fee` to pass to `TransferCheckedWithFee`.
+///
+/// Mints without the `TransferFeeConfig` extension and mints of another
+/// program, e.g. SPL Token, have no fee.
+#[inline]
+pub fn expected_transfer_fee(
+    mint: &AccountInfo,
+    amount: u64,
+    epoch: u64,
+) -> Result<u64, ProgramError> {
+    if mint.owner() != &ID {
+        return Ok(0);
+    }
+
+    expected_transfer_fee_from_bytes(&mint.try_borrow_data()?, amount, epoch)
+}
+
+/// Same as [`expected_transfer_fee`] for the given mint data.
+///
+/// Fails with `TokenError::Overflow` if the fee can't be calculated, like
+/// Token-2022 does.
+#[inline]
+pub fn expected_transfer_fee_from_bytes(
+    mint: &[u8],
+    amount: u64,
+    epoch: u64,
+) -> Result<u64, ProgramError> {
+    match get_extension_from_bytes::<TransferFeeConfig>(mint) {
+        Some(config) => config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or_else(|| TokenError::Overflow.into()),
+        None => Ok(0),
+    }
+}
+
+/// Return the amount received by the recipient of a transfer of `amount` tokens
+/// in the given epoch, i.e. `amount` minus the fee withheld in the recipient
+/// account.
+///
+/// The fee is calculated with u128 intermediates, returns `None` if it can't be
+/// calculated.
+#[inline]
+pub fn net_amount_after_fee(amount: u64, config: &TransferFeeConfig, epoch: u64) -> Option<u64> {
+    let fee = config.calculate_epoch_fee(epoch, amount)?;
+
+    amount.checked_sub(fee)
+}
+
+#[repr(C)]
+pub struct TransferFeeAmount {
+    /// Amount withheld during transfers, to be harvested to the mint
+    pub withheld_amount: [u8; 8],
+}
+
+unsafe impl Extension for TransferFeeAmount {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeAmount;
+}
+
+impl TransferFeeAmount {
+    /// The length of the `TransferFeeAmount` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<TransferFeeAmount>();
+
+    /// Return a `TransferFeeAmount` from the given account info.
+    ///
+    /// This method performs owner validation on `AccountInfo` and looks the
+    /// extension up in the TLV data, safe borrowing the account data.
+    #[inline]
+    pub fn from_account_info(
+        account_info: &AccountInfo,
+    ) -> Result<Ref<TransferFeeAmount>, ProgramError> {
+        // Check owner
+        if account_info.owner() != &ID {
+            Err(ProgramError::InvalidAccountOwner)?;
+        }
+
+        // Safely borrow and map the data
+        let data_ref = account_info
+            .try_borrow_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+        Ref::try_map(data_ref, Self::from_bytes).map_err(|(_, error)| error)
+    }
+
+    /// Return a `TransferFeeAmount` from the given account data.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` holds a `TransferFeeAmount` extension.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        get_extension_from_bytes::<Self>(bytes).unwrap_unchecked()
+    }
+
+    /// Return a `TransferFeeAmount` from the given account data.
+    ///
+    /// The extension can be at any position of the TLV data.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(TransferFeeAmount);
@@ -1,4 +1,5 @@
 use crate::{
+    cpi::invoke_signed,
     extension::transfer_hook::state::{
         transfer_hook_initialize_instruction_data, TransferHookInstruction,
     },
@@ -6,7 +7,6 @@ use crate::{
 
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::invoke_signed,
     instruction::{AccountMeta, Instruction, Signer},
     pubkey::Pubkey,
     ProgramResult,
@@ -1,6 +1,9 @@
 use core::mem::MaybeUninit;
 use crate::{write_bytes, UNINIT_BYTE, ID};
-use crate::extension::consts::ExtensionDiscriminator;
+use crate::extension::{
+    consts::ExtensionType,
+    tlv::{get_extension_from_bytes, Extension},
+};
 use pinocchio::{
     account_info::{AccountInfo, Ref},
     program_error::ProgramError,
@@ -8,12 +11,14 @@ use pinocchio::{
 };
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TransferHookInstruction {
     Initialize,
     Update,
 }
 
+crate::extension::consts::extension_instruction!(TransferHookInstruction, TransferHook, Update);
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct TransferHook {
@@ -23,6 +28,10 @@ pub struct TransferHook {
     program_id: Pubkey,
 }
 
+unsafe impl Extension for TransferHook {
+    const TYPE: ExtensionType = ExtensionType::TransferHook;
+}
+
 impl TransferHook {
     pub const AUTHORITY_START: usize = 170;
 
@@ -118,6 +127,43 @@ impl TransferHook {
     }
 }
 
+crate::owner::impl_from_account_info_with_owner!(TransferHook);
+
+/// Transfer hook extension data for token accounts, added automatically to the
+/// accounts of a mint with the `TransferHook` extension.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferHookAccount {
+    /// Set by Token-2022 while the hook program is invoked during a transfer
+    transferring: u8,
+}
+
+unsafe impl Extension for TransferHookAccount {
+    const TYPE: ExtensionType = ExtensionType::TransferHookAccount;
+}
+
+impl TransferHookAccount {
+    /// The length of the `TransferHookAccount` extension data.
+    pub const BASE_LEN: usize = core::mem::size_of::<TransferHookAccount>();
+
+    /// Return a `TransferHookAccount` from the given token account data.
+    ///
+    /// The extension can be at any position of the TLV data.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        get_extension_from_bytes::<Self>(bytes).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// Whether the account is the source or destination of the transfer whose
+    /// hook is being executed
+    #[inline(always)]
+    pub fn transferring(&self) -> bool {
+        self.transferring != 0
+    }
+}
+
+crate::owner::impl_from_account_info_with_owner!(TransferHookAccount);
+
 pub fn transfer_hook_initialize_instruction_data<'a>(
     buffer: &'a mut [u8],
     instruction_type: TransferHookInstruction,
@@ -131,10 +177,7 @@ pub fn transfer_hook_initialize_instruction_data<'a>(
     // -  [34..66]: program_id (32 bytes, Pubkey)
     
     // Set discriminators
-    buffer[..2].copy_from_slice(&[
-        ExtensionDiscriminator::TransferHook as u8,
-        instruction_type as u8,
-    ]);
+    buffer[..2].copy_from_slice(&instruction_type.as_bytes());
     
     // Set authority at offset [2..34]
     if let Some(x) = authority {
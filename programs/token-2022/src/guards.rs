@@ -0,0 +1,152 @@
+//! Checks failing early with the error Token-2022 would return, so a program
+//! doesn't spend compute units on a CPI bound to fail.
+
+use {
+    crate::{error::TokenError, extension::tlv::get_extension_from_bytes, ID},
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+};
+
+#[cfg(feature = "pausable")]
+use crate::extension::pausable::PausableConfig;
+#[cfg(all(feature = "non-transferable", feature = "transfer-hook"))]
+use crate::extension::{non_transferable::NonTransferable, transfer_hook::TransferHookAccount};
+#[cfg(feature = "cpi-guard")]
+use {
+    crate::{extension::cpi_guard::CpiGuard, state::TokenAccount},
+    pinocchio::pubkey::Pubkey,
+};
+
+/// Fail with `TokenError::MintPaused` if the mint has the `Pausable` extension
+/// and is paused.
+///
+/// Mints without the extension and mints owned by another program, e.g. SPL
+/// Token, pass the check.
+#[cfg(feature = "pausable")]
+#[inline]
+pub fn assert_not_paused(mint: &AccountInfo) -> ProgramResult {
+    if mint.owner() != &ID {
+        return Ok(());
+    }
+
+    assert_not_paused_from_bytes(&mint.try_borrow_data()?)
+}
+
+/// Same as [`assert_not_paused`] for the given mint account data.
+#[cfg(feature = "pausable")]
+#[inline]
+pub fn assert_not_paused_from_bytes(bytes: &[u8]) -> ProgramResult {
+    match get_extension_from_bytes::<PausableConfig>(bytes) {
+        Some(config) if config.is_paused() => Err(TokenError::MintPaused.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Fail with `TokenError::NonTransferable` if the mint has the
+/// `NonTransferable` extension, e.g. to reject soulbound tokens on deposit.
+///
+/// If `token_account` is given, also fail with `TokenError::InvalidState` while
+/// the account is in the middle of a transfer, i.e. the `transferring` flag of
+/// its `TransferHookAccount` extension is set.
+///
+/// Accounts owned by another program, e.g. SPL Token, pass the check.
+#[cfg(all(feature = "non-transferable", feature = "transfer-hook"))]
+#[inline]
+pub fn assert_transferable(
+    mint: &AccountInfo,
+    token_account: Option<&AccountInfo>,
+) -> ProgramResult {
+    if mint.owner() != &ID {
+        return Ok(());
+    }
+
+    match token_account.filter(|x| x.owner() == &ID) {
+        Some(token_account) => assert_transferable_from_bytes(
+            &mint.try_borrow_data()?,
+            Some(&token_account.try_borrow_data()?),
+        ),
+        None => assert_transferable_from_bytes(&mint.try_borrow_data()?, None),
+    }
+}
+
+/// Same as [`assert_transferable`] for the given mint and token account data.
+#[cfg(all(feature = "non-transferable", feature = "transfer-hook"))]
+#[inline]
+pub fn assert_transferable_from_bytes(mint: &[u8], token_account: Option<&[u8]>) -> ProgramResult {
+    if NonTransferable::is_present(mint) {
+        Err(TokenError::NonTransferable)?;
+    }
+
+    if token_account
+        .and_then(get_extension_from_bytes::<TransferHookAccount>)
+        .is_some_and(|x| x.transferring())
+    {
+        Err(TokenError::InvalidState)?;
+    }
+
+    Ok(())
+}
+
+/// Token account instruction that Token-2022 rejects when it is invoked by a
+/// program while the `CpiGuard` extension of the account is enabled.
+#[cfg(feature = "cpi-guard")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpiGuardedInstruction<'a> {
+    /// `Transfer`, `TransferChecked` or `TransferCheckedWithFee` signed by
+    /// `authority`, blocked if it is the owner of the account.
+    Transfer { authority: &'a Pubkey },
+    /// `Burn` or `BurnChecked` signed by `authority`, blocked if it is the
+    /// owner of the account.
+    Burn { authority: &'a Pubkey },
+    /// `Approve` or `ApproveChecked`, always blocked.
+    Approve,
+    /// `CloseAccount` to `destination`, blocked if it isn't the owner of the
+    /// account.
+    CloseAccount { destination: &'a Pubkey },
+}
+
+/// Fail with the `TokenError::CpiGuard*Blocked` error of `instruction` if the
+/// token account has the `CpiGuard` extension enabled and Token-2022 would
+/// reject the instruction invoked by a program.
+///
+/// Accounts without the extension and accounts owned by another program, e.g.
+/// SPL Token, pass the check.
+#[cfg(feature = "cpi-guard")]
+#[inline]
+pub fn assert_cpi_guard_allows(
+    account: &AccountInfo,
+    instruction: CpiGuardedInstruction,
+) -> ProgramResult {
+    if account.owner() != &ID {
+        return Ok(());
+    }
+
+    assert_cpi_guard_allows_from_bytes(&account.try_borrow_data()?, instruction)
+}
+
+/// Same as [`assert_cpi_guard_allows`] for the given token account data.
+#[cfg(feature = "cpi-guard")]
+#[inline]
+pub fn assert_cpi_guard_allows_from_bytes(
+    bytes: &[u8],
+    instruction: CpiGuardedInstruction,
+) -> ProgramResult {
+    if !get_extension_from_bytes::<CpiGuard>(bytes).is_some_and(|x| x.lock_cpi()) {
+        return Ok(());
+    }
+
+    let owner = TokenAccount::from_bytes(bytes)?.owner();
+
+    match instruction {
+        CpiGuardedInstruction::Transfer { authority } if authority == owner => {
+            Err(TokenError::CpiGuardTransferBlocked.into())
+        }
+        CpiGuardedInstruction::Burn { authority } if authority == owner => {
+            Err(TokenError::CpiGuardBurnBlocked.into())
+        }
+        CpiGuardedInstruction::Approve => Err(TokenError::CpiGuardApproveBlocked.into()),
+        CpiGuardedInstruction::CloseAccount { destination } if destination != owner => {
+            Err(TokenError::CpiGuardCloseAccountBlocked.into())
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,212 @@
+//! Stub CPI backend to unit test the wrappers off-chain, enabled by the
+//! `host-stub` feature on host targets.
+//!
+//! Instead of invoking the runtime, the wrappers record the instruction they
+//! would invoke, after the checks `pinocchio::cpi` performs on the accounts,
+//! and return `Ok`. Tests read the recorded instructions back with
+//! [`take_invoked`]:
+//!
+//! ```ignore
+//! let mut mint = HostAccount::new([1; 32], ID, &[]);
+//!
+//! InitializeNonTransferableMint {
+//!     mint: &mint.info(),
+//!     token_program: &ID,
+//! }
+//! .invoke()?;
+//!
+//! let [instruction] = &take_invoked()[..] else { panic!() };
+//! assert_eq!(instruction.data, [32]);
+//! ```
+
+extern crate std;
+
+use {
+    core::mem::size_of,
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::MAX_CPI_ACCOUNTS,
+        instruction::{Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+    std::{cell::RefCell, thread_local, vec::Vec},
+};
+
+thread_local! {
+    static INVOKED: RefCell<Vec<InvokedInstruction>> = const { RefCell::new(Vec::new()) };
+}
+
+/// An account of an instruction recorded by the stub.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvokedAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_writable: bool,
+    pub is_signer: bool,
+}
+
+/// An instruction recorded by the stub instead of being invoked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvokedInstruction {
+    /// Program the instruction is addressed to
+    pub program_id: Pubkey,
+    /// Accounts of the instruction
+    pub accounts: Vec<InvokedAccountMeta>,
+    /// Keys of the account infos passed to the CPI
+    pub account_infos: Vec<Pubkey>,
+    /// Instruction data
+    pub data: Vec<u8>,
+    /// Number of PDA signers the CPI is signed with
+    pub signers_len: usize,
+}
+
+/// Return the instructions recorded on the current thread since the last
+/// call, oldest first.
+pub fn take_invoked() -> Vec<InvokedInstruction> {
+    INVOKED.with(|invoked| invoked.take())
+}
+
+/// Length of the header of an account in the input buffer of a program: the
+/// borrow state, signer, writable and executable flags, the resize delta
+/// (4 bytes, i32), the key, the owner, the lamports and the data length
+/// (8 bytes, u64 each).
+const ACCOUNT_HEADER_LEN: usize = 8 + 32 + 32 + 8 + 8;
+
+/// Marks an account that is not a duplicate and not borrowed.
+const NOT_BORROWED: u8 = u8::MAX;
+
+/// An account laid out as the runtime passes it to a program, to build the
+/// `AccountInfo`s given to the wrappers.
+pub struct HostAccount {
+    // `u64` words to get the alignment of the input buffer
+    buffer: Vec<u64>,
+}
+
+impl HostAccount {
+    /// A writable account holding `data`, which doesn't sign.
+    pub fn new(key: Pubkey, owner: Pubkey, data: &[u8]) -> Self {
+        let len = ACCOUNT_HEADER_LEN + data.len();
+        let mut buffer = std::vec![0u64; len.div_ceil(size_of::<u64>())];
+
+        // SAFETY: `buffer` holds at least `len` bytes
+        let bytes = unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, len) };
+        bytes[0] = NOT_BORROWED;
+        bytes[2] = 1;
+        bytes[8..40].copy_from_slice(&key);
+        bytes[40..72].copy_from_slice(&owner);
+        bytes[80..88].copy_from_slice(&(data.len() as u64).to_le_bytes());
+        bytes[ACCOUNT_HEADER_LEN..].copy_from_slice(data);
+
+        Self { buffer }
+    }
+
+    /// Set whether the account signs the transaction.
+    pub fn set_signer(&mut self, is_signer: bool) {
+        self.header_mut()[1] = is_signer.into();
+    }
+
+    /// Set whether the account is writable.
+    pub fn set_writable(&mut self, is_writable: bool) {
+        self.header_mut()[2] = is_writable.into();
+    }
+
+    /// The `AccountInfo` of the account, valid as long as the account isn't
+    /// moved or dropped.
+    pub fn info(&mut self) -> AccountInfo {
+        // SAFETY: `AccountInfo` is a pointer to the account header
+        unsafe { core::mem::transmute::<*mut u64, AccountInfo>(self.buffer.as_mut_ptr()) }
+    }
+
+    fn header_mut(&mut self) -> &mut [u8] {
+        // SAFETY: `buffer` holds at least the header
+        unsafe {
+            core::slice::from_raw_parts_mut(self.buffer.as_mut_ptr() as *mut u8, ACCOUNT_HEADER_LEN)
+        }
+    }
+}
+
+/// Record the instruction after the checks of `pinocchio::cpi`: at most
+/// `max_accounts` accounts, and an account info with the same key for each of
+/// them.
+fn record(
+    max_accounts: usize,
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    if max_accounts < instruction.accounts.len() {
+        Err(ProgramError::InvalidArgument)?;
+    }
+
+    if account_infos.len() < instruction.accounts.len() {
+        Err(ProgramError::NotEnoughAccountKeys)?;
+    }
+
+    if account_infos
+        .iter()
+        .zip(instruction.accounts)
+        .any(|(info, meta)| info.key() != meta.pubkey)
+    {
+        Err(ProgramError::InvalidArgument)?;
+    }
+
+    let instruction = InvokedInstruction {
+        program_id: *instruction.program_id,
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|meta| InvokedAccountMeta {
+                pubkey: *meta.pubkey,
+                is_writable: meta.is_writable,
+                is_signer: meta.is_signer,
+            })
+            .collect(),
+        account_infos: account_infos.iter().map(|info| *info.key()).collect(),
+        data: instruction.data.to_vec(),
+        signers_len: signers_seeds.len(),
+    };
+    INVOKED.with(|invoked| invoked.borrow_mut().push(instruction));
+
+    Ok(())
+}
+
+// the `pinocchio::cpi` functions used by the wrappers
+
+pub(crate) fn invoke<const ACCOUNTS: usize>(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo; ACCOUNTS],
+) -> ProgramResult {
+    record(MAX_CPI_ACCOUNTS, instruction, account_infos, &[])
+}
+
+pub(crate) fn invoke_with_bounds<const MAX_ACCOUNTS: usize>(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo],
+) -> ProgramResult {
+    record(MAX_ACCOUNTS, instruction, account_infos, &[])
+}
+
+pub(crate) fn invoke_signed<const ACCOUNTS: usize>(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo; ACCOUNTS],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    record(MAX_CPI_ACCOUNTS, instruction, account_infos, signers_seeds)
+}
+
+pub(crate) fn invoke_signed_with_bounds<const MAX_ACCOUNTS: usize>(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    record(MAX_ACCOUNTS, instruction, account_infos, signers_seeds)
+}
+
+pub(crate) fn slice_invoke_signed(
+    instruction: &Instruction,
+    account_infos: &[&AccountInfo],
+    signers_seeds: &[Signer],
+) -> ProgramResult {
+    record(MAX_CPI_ACCOUNTS, instruction, account_infos, signers_seeds)
+}
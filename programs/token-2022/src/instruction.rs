@@ -0,0 +1,139 @@
+use pinocchio::program_error::ProgramError;
+
+/// Top-level discriminators of the Token-2022 instructions.
+///
+/// Extension instructions (`*Extension`) carry a second, extension specific
+/// discriminator as the first byte of their payload.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenInstructionDiscriminator {
+    InitializeMint = 0,
+    InitializeAccount = 1,
+    InitializeMultisig = 2,
+    Transfer = 3,
+    Approve = 4,
+    Revoke = 5,
+    SetAuthority = 6,
+    MintTo = 7,
+    Burn = 8,
+    CloseAccount = 9,
+    FreezeAccount = 10,
+    ThawAccount = 11,
+    TransferChecked = 12,
+    ApproveChecked = 13,
+    MintToChecked = 14,
+    BurnChecked = 15,
+    InitializeAccount2 = 16,
+    SyncNative = 17,
+    InitializeAccount3 = 18,
+    InitializeMultisig2 = 19,
+    InitializeMint2 = 20,
+    GetAccountDataSize = 21,
+    InitializeImmutableOwner = 22,
+    AmountToUiAmount = 23,
+    UiAmountToAmount = 24,
+    InitializeMintCloseAuthority = 25,
+    TransferFeeExtension = 26,
+    ConfidentialTransferExtension = 27,
+    DefaultAccountStateExtension = 28,
+    Reallocate = 29,
+    MemoTransferExtension = 30,
+    CreateNativeMint = 31,
+    InitializeNonTransferableMint = 32,
+    InterestBearingMintExtension = 33,
+    CpiGuardExtension = 34,
+    InitializePermanentDelegate = 35,
+    TransferHookExtension = 36,
+    ConfidentialTransferFeeExtension = 37,
+    WithdrawExcessLamports = 38,
+    MetadataPointerExtension = 39,
+    GroupPointerExtension = 40,
+    GroupMemberPointerExtension = 41,
+    ConfidentialMintBurnExtension = 42,
+    ScaledUiAmountExtension = 43,
+    PausableExtension = 44,
+}
+
+impl TokenInstructionDiscriminator {
+    /// Returns `true` if the instruction is routed to an extension, i.e. its
+    /// payload starts with an extension instruction discriminator.
+    #[inline]
+    pub fn is_extension(self) -> bool {
+        matches!(
+            self,
+            Self::TransferFeeExtension
+                | Self::ConfidentialTransferExtension
+                | Self::DefaultAccountStateExtension
+                | Self::MemoTransferExtension
+                | Self::InterestBearingMintExtension
+                | Self::CpiGuardExtension
+                | Self::TransferHookExtension
+                | Self::ConfidentialTransferFeeExtension
+                | Self::MetadataPointerExtension
+                | Self::GroupPointerExtension
+                | Self::GroupMemberPointerExtension
+                | Self::ConfidentialMintBurnExtension
+                | Self::ScaledUiAmountExtension
+                | Self::PausableExtension
+        )
+    }
+}
+
+impl TryFrom<u8> for TokenInstructionDiscriminator {
+    type Error = ProgramError;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            // SAFETY: `value` is within the range of the enum discriminants.
+            0..=44 => Ok(unsafe { core::mem::transmute::<u8, Self>(value) }),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// A Token-2022 instruction split into its top-level discriminator and the
+/// raw payload following it.
+///
+/// Decoding the payload is left to the caller, so on-chain routers can
+/// inspect Token-2022 instructions without depending on the SPL crates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenInstruction<'a> {
+    /// The top-level instruction discriminator
+    pub discriminator: TokenInstructionDiscriminator,
+    /// Instruction data after the top-level discriminator
+    pub data: &'a [u8],
+}
+
+impl<'a> TokenInstruction<'a> {
+    /// Split the given instruction data into the instruction discriminator
+    /// and its payload.
+    #[inline]
+    pub fn unpack(instruction_data: &'a [u8]) -> Result<Self, ProgramError> {
+        let (&discriminator, data) = instruction_data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        Ok(Self {
+            discriminator: TokenInstructionDiscriminator::try_from(discriminator)?,
+            data,
+        })
+    }
+
+    /// Split the payload of an extension instruction into the extension
+    /// instruction discriminator and its data.
+    ///
+    /// Fails with `InvalidInstructionData` if this is not an extension
+    /// instruction or the payload is empty.
+    #[inline]
+    pub fn extension_instruction(&self) -> Result<(u8, &'a [u8]), ProgramError> {
+        if !self.discriminator.is_extension() {
+            Err(ProgramError::InvalidInstructionData)?;
+        }
+
+        self.data
+            .split_first()
+            .map(|(&discriminator, data)| (discriminator, data))
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+}
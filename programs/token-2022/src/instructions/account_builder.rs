@@ -0,0 +1,139 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Signer, program_error::ProgramError, pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::instructions::InitializeAccount3;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Instruction initializing a token account extension, which Token-2022 only
+/// accepts before `InitializeAccount`.
+pub trait AccountExtensionInitializer: private::Sealed {
+    /// Token account the extension is initialized on.
+    fn token_account(&self) -> &AccountInfo;
+
+    /// Invoke the instruction.
+    fn invoke(&self) -> ProgramResult;
+}
+
+/// Instruction enabling a token account extension, which Token-2022 only
+/// accepts after `InitializeAccount`, signed by the owner of the account.
+pub trait AccountExtensionEnabler: private::Sealed {
+    /// Token account the extension is enabled on.
+    fn token_account(&self) -> &AccountInfo;
+
+    /// Invoke the instruction with the given signer seeds.
+    fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult;
+}
+
+macro_rules! impl_account_extension {
+    ($trait:ident { $($feature:literal => $instruction:path),+ $(,)? }) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl private::Sealed for $instruction {}
+
+            #[cfg(feature = $feature)]
+            impl $trait for $instruction {
+                #[inline(always)]
+                fn token_account(&self) -> &AccountInfo {
+                    self.token_account
+                }
+
+                impl_account_extension!(@invoke $trait, $instruction);
+            }
+        )+
+    };
+    (@invoke AccountExtensionInitializer, $instruction:path) => {
+        #[inline(always)]
+        fn invoke(&self) -> ProgramResult {
+            <$instruction>::invoke(self)
+        }
+    };
+    (@invoke AccountExtensionEnabler, $instruction:path) => {
+        #[inline(always)]
+        fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+            <$instruction>::invoke_signed(self, signers)
+        }
+    };
+}
+
+impl_account_extension!(AccountExtensionInitializer {
+    "immutable-owner" => crate::extension::immutable_owner::InitializeImmutableOwner<'_>,
+});
+
+impl_account_extension!(AccountExtensionEnabler {
+    "cpi-guard" => crate::extension::cpi_guard::EnableCpiGuard<'_, '_>,
+    "memo-transfer" => crate::extension::memo_transfer::Enable<'_>,
+});
+
+/// Initialize a new token account with extensions: invoke the extension
+/// initializers, `InitializeAccount3`, then the extension enablers with the
+/// given signer seeds.
+///
+/// The token account must have been created with the space of the base
+/// account and of every extension. Fails with `InvalidArgument` before any
+/// CPI if an extension targets another account than `account`.
+///
+/// ### Accounts:
+///   0. `[WRITE]`  The account to initialize.
+///   1. `[]` The mint this account will be associated with.
+pub struct AccountBuilder<'a, 'b> {
+    /// New Account.
+    pub account: &'a AccountInfo,
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+    /// Owner of the new Account.
+    pub owner: &'a Pubkey,
+    /// Initializers of the extensions, invoked before `InitializeAccount3`.
+    pub extensions: &'b [&'b dyn AccountExtensionInitializer],
+    /// Enablers of the extensions, invoked after `InitializeAccount3` and
+    /// signed by the owner.
+    pub enabled_extensions: &'b [&'b dyn AccountExtensionEnabler],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl AccountBuilder<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account = self.account.key();
+
+        if self
+            .extensions
+            .iter()
+            .any(|extension| extension.token_account().key() != account)
+            || self
+                .enabled_extensions
+                .iter()
+                .any(|extension| extension.token_account().key() != account)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        for extension in self.extensions {
+            extension.invoke()?;
+        }
+
+        InitializeAccount3 {
+            account: self.account,
+            mint: self.mint,
+            owner: self.owner,
+            token_program: self.token_program,
+        }
+        .invoke()?;
+
+        for extension in self.enabled_extensions {
+            extension.invoke_signed(signers)?;
+        }
+
+        Ok(())
+    }
+}
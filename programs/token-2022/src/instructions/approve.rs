@@ -3,12 +3,11 @@ use core::slice::from_raw_parts;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
     pubkey::Pubkey,
     ProgramResult,
 };
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{cpi::invoke_signed, write_bytes, UNINIT_BYTE};
 
 /// Approves a delegate.
 ///
@@ -37,6 +36,12 @@ impl Approve<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        #[cfg(feature = "preflight")]
+        crate::guards::assert_cpi_guard_allows(
+            self.source,
+            crate::guards::CpiGuardedInstruction::Approve,
+        )?;
+
         // Account metadata
         let account_metas: [AccountMeta; 3] = [
             AccountMeta::writable(self.source.key()),
@@ -3,12 +3,11 @@ use core::slice::from_raw_parts;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
     pubkey::Pubkey,
     ProgramResult,
 };
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{cpi::invoke_signed, write_bytes, UNINIT_BYTE};
 
 /// Burns tokens by removing them from an account.
 ///
@@ -37,6 +36,14 @@ impl Burn<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        #[cfg(feature = "preflight")]
+        crate::guards::assert_cpi_guard_allows(
+            self.account,
+            crate::guards::CpiGuardedInstruction::Burn {
+                authority: self.authority.key(),
+            },
+        )?;
+
         // Account metadata
         let account_metas: [AccountMeta; 3] = [
             AccountMeta::writable(self.account.key()),
@@ -1,10 +1,9 @@
 use core::slice::from_raw_parts;
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{cpi::invoke_signed, write_bytes, UNINIT_BYTE};
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
     pubkey::Pubkey,
     ProgramResult,
 };
@@ -38,6 +37,14 @@ impl BurnChecked<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        #[cfg(feature = "preflight")]
+        crate::guards::assert_cpi_guard_allows(
+            self.account,
+            crate::guards::CpiGuardedInstruction::Burn {
+                authority: self.authority.key(),
+            },
+        )?;
+
         // Account metadata
         let account_metas: [AccountMeta; 3] = [
             AccountMeta::writable(self.account.key()),
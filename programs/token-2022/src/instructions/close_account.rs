@@ -1,11 +1,12 @@
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
     pubkey::Pubkey,
     ProgramResult,
 };
 
+use crate::cpi::invoke_signed;
+
 /// Close an account by transferring all its SOL to the destination account.
 ///
 /// ### Accounts:
@@ -31,6 +32,14 @@ impl CloseAccount<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        #[cfg(feature = "preflight")]
+        crate::guards::assert_cpi_guard_allows(
+            self.account,
+            crate::guards::CpiGuardedInstruction::CloseAccount {
+                destination: self.destination.key(),
+            },
+        )?;
+
         // account metadata
         let account_metas: [AccountMeta; 3] = [
             AccountMeta::writable(self.account.key()),
@@ -1,11 +1,12 @@
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
     pubkey::Pubkey,
     ProgramResult,
 };
 
+use crate::cpi::invoke_signed;
+
 /// Freeze an Initialized account using the Mint's freeze authority
 ///
 /// ### Accounts:
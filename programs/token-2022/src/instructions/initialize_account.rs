@@ -1,13 +1,18 @@
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::invoke,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     ProgramResult,
 };
 
+use crate::cpi::invoke;
+
 /// Initialize a new Token Account.
 ///
+/// The original instruction, reading the rent from the Rent sysvar account and
+/// taking the owner as an account. Prefer `InitializeAccount3` unless the
+/// legacy account layout is required.
+///
 /// ### Accounts:
 ///   0. `[WRITE]`  The account to initialize.
 ///   1. `[]` The mint this account will be associated with.
@@ -2,20 +2,19 @@ use core::slice::from_raw_parts;
 
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::invoke,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     ProgramResult,
 };
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{cpi::invoke, write_bytes, UNINIT_BYTE};
 
 /// Initialize a new Token Account.
 ///
 /// ### Accounts:
 ///   0. `[WRITE]`  The account to initialize.
 ///   1. `[]` The mint this account will be associated with.
-///   3. `[]` Rent sysvar
+///   2. `[]` Rent sysvar
 pub struct InitializeAccount2<'a, 'b> {
     /// New Account.
     pub account: &'a AccountInfo,
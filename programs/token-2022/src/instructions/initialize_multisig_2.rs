@@ -2,13 +2,14 @@ use core::{mem::MaybeUninit, slice};
 
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::invoke_with_bounds,
     instruction::{AccountMeta, Instruction},
     program_error::ProgramError,
     pubkey::Pubkey,
     ProgramResult,
 };
 
+use crate::cpi::invoke_with_bounds;
+
 use crate::instructions::MAX_MULTISIG_SIGNERS;
 
 /// Initialize a new Multisig.
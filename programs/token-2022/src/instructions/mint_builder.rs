@@ -0,0 +1,217 @@
+use core::slice::from_raw_parts;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::rent::Rent,
+    ProgramResult,
+};
+
+use crate::{
+    cpi::invoke_signed, extension::consts::ExtensionType, instructions::InitializeMint2,
+    state::Mint, write_bytes, SYSTEM_PROGRAM_ID, UNINIT_BYTE,
+};
+
+/// Number of mint extensions initialized before `InitializeMint`.
+const MAX_MINT_EXTENSIONS: usize = 14;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Instruction initializing a mint extension, which Token-2022 only accepts
+/// before `InitializeMint`.
+///
+/// Only implemented by the initializers of the extensions stored in the mint
+/// before its initialization, so the ones initializing a mint which must have
+/// been initialized first (`TokenMetadata`, `TokenGroup`, ...) can't be given
+/// to [`MintBuilder`].
+pub trait MintExtensionInitializer: private::Sealed {
+    /// Type of the initialized extension.
+    fn extension_type(&self) -> ExtensionType;
+
+    /// Mint account the extension is initialized on.
+    fn mint(&self) -> &AccountInfo;
+
+    /// Invoke the instruction.
+    fn invoke(&self) -> ProgramResult;
+}
+
+macro_rules! impl_mint_extension_initializer {
+    ($($feature:literal => $initializer:path { $mint:ident, $extension:ident }),+ $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl private::Sealed for $initializer {}
+
+            #[cfg(feature = $feature)]
+            impl MintExtensionInitializer for $initializer {
+                #[inline(always)]
+                fn extension_type(&self) -> ExtensionType {
+                    ExtensionType::$extension
+                }
+
+                #[inline(always)]
+                fn mint(&self) -> &AccountInfo {
+                    self.$mint
+                }
+
+                #[inline(always)]
+                fn invoke(&self) -> ProgramResult {
+                    <$initializer>::invoke(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_mint_extension_initializer! {
+    "confidential-transfer" => crate::extension::confidential_transfer::InitializeConfidentialTransferMint<'_> { mint, ConfidentialTransferMint },
+    "confidential-transfer-fee" => crate::extension::confidential_transfer_fee::InitializeConfidentialTransferFeeConfig<'_> { mint, ConfidentialTransferFeeConfig },
+    "default-account-state" => crate::extension::default_account_state::instructions::initialize::InitializeDefaultAccountState<'_, '_> { mint_account, DefaultAccountState },
+    "group-member-pointer" => crate::extension::group_member_pointer::Initialize<'_> { mint, GroupMemberPointer },
+    "group-pointer" => crate::extension::group_pointer::Initialize<'_> { mint, GroupPointer },
+    "interest-bearing-mint" => crate::extension::interest_bearing_mint::Initialize<'_> { mint, InterestBearingConfig },
+    "metadata-pointer" => crate::extension::metadata_pointer::Initialize<'_> { mint, MetadataPointer },
+    "mint-close-authority" => crate::extension::mint_close_authority::InitializeMintCloseAuthority<'_> { mint, MintCloseAuthority },
+    "non-transferable" => crate::extension::non_transferable::InitializeNonTransferableMint<'_> { mint, NonTransferable },
+    "pausable" => crate::extension::pausable::InitializePausable<'_> { mint_account, Pausable },
+    "permanent-delegate" => crate::extension::permanent_delegate::InitializePermanentDelegate<'_> { mint, PermanentDelegate },
+    "scaled-ui-amount" => crate::extension::scaled_ui_amount::InitializeScaledUiAmount<'_, '_> { mint_account, ScaledUiAmount },
+    "transfer-fee" => crate::extension::transfer_fee::InitializeTransferFeeConfig<'_> { mint, TransferFeeConfig },
+    "transfer-hook" => crate::extension::transfer_hook::InitializeTransferHook<'_> { mint_account, TransferHook },
+}
+
+/// Initialize a new mint with extensions: invoke the extension initializers
+/// in the given order, then `InitializeMint2`.
+///
+/// The mint account must have been created with the space of the base mint
+/// and of every extension, see [`create_mint_with_extensions`]. Fails with `InvalidArgument` before any CPI if an
+/// initializer targets another account than `mint`.
+///
+/// ### Accounts:
+///   0. `[WRITABLE]` Mint account
+pub struct MintBuilder<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+    /// Decimals.
+    pub decimals: u8,
+    /// Mint Authority.
+    pub mint_authority: &'a Pubkey,
+    /// Freeze Authority.
+    pub freeze_authority: Option<&'a Pubkey>,
+    /// Initializers of the mint extensions.
+    pub extensions: &'b [&'b dyn MintExtensionInitializer],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl MintBuilder<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.check_extensions()?;
+
+        for extension in self.extensions {
+            extension.invoke()?;
+        }
+
+        InitializeMint2 {
+            mint: self.mint,
+            decimals: self.decimals,
+            mint_authority: self.mint_authority,
+            freeze_authority: self.freeze_authority,
+            token_program: self.token_program,
+        }
+        .invoke()
+    }
+
+    /// Return the length of the mint account carrying the extensions.
+    pub fn try_calculate_mint_len(&self) -> Result<usize, ProgramError> {
+        let mut extension_types = [ExtensionType::Uninitialized; MAX_MINT_EXTENSIONS];
+        let mut len = 0;
+
+        for extension in self.extensions {
+            let extension_type = extension.extension_type();
+
+            if !extension_types[..len].contains(&extension_type) {
+                extension_types[len] = extension_type;
+                len += 1;
+            }
+        }
+
+        ExtensionType::try_calculate_account_len::<Mint>(&extension_types[..len])
+    }
+
+    /// Fail with `InvalidArgument` if an initializer targets another account
+    /// than `mint`.
+    #[inline(always)]
+    pub(crate) fn check_extensions(&self) -> ProgramResult {
+        if self
+            .extensions
+            .iter()
+            .any(|extension| extension.mint().key() != self.mint.key())
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Create the mint account of `builder` with the space of its extensions,
+/// funded by `payer` for rent exemption, then initialize it with `builder`.
+///
+/// `signers` sign the System Program `CreateAccount` instruction, for a PDA
+/// payer or mint. Return the length of the mint account.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Payer account
+///   1. `[WRITE, SIGNER]` Mint account
+pub fn create_mint_with_extensions(
+    payer: &AccountInfo,
+    rent: &Rent,
+    builder: &MintBuilder,
+    signers: &[Signer],
+) -> Result<usize, ProgramError> {
+    builder.check_extensions()?;
+
+    let len = builder.try_calculate_mint_len()?;
+
+    // Account metadata
+    let account_metas: [AccountMeta; 2] = [
+        AccountMeta::writable_signer(payer.key()),
+        AccountMeta::writable_signer(builder.mint.key()),
+    ];
+
+    // Instruction data layout:
+    // -  [0..4]: instruction discriminator (4 bytes, u32)
+    // -  [4..12]: lamports (8 bytes, u64)
+    // -  [12..20]: space (8 bytes, u64)
+    // -  [20..52]: owner (32 bytes, Pubkey)
+    let mut instruction_data = [UNINIT_BYTE; 52];
+
+    // Set discriminator as u32 at offset [0..4]
+    write_bytes(&mut instruction_data, &0u32.to_le_bytes());
+    // Set lamports as u64 at offset [4..12]
+    write_bytes(
+        &mut instruction_data[4..12],
+        &rent.minimum_balance(len).to_le_bytes(),
+    );
+    // Set space as u64 at offset [12..20]
+    write_bytes(&mut instruction_data[12..20], &(len as u64).to_le_bytes());
+    // Set owner as Pubkey at offset [20..52]
+    write_bytes(&mut instruction_data[20..52], builder.token_program);
+
+    let instruction = Instruction {
+        program_id: &SYSTEM_PROGRAM_ID,
+        accounts: &account_metas,
+        data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 52) },
+    };
+
+    invoke_signed(&instruction, &[payer, builder.mint], signers)?;
+
+    builder.invoke()?;
+
+    Ok(len)
+}
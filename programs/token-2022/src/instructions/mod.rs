@@ -1,3 +1,4 @@
+mod account_builder;
 mod approve;
 mod approve_checked;
 mod burn;
@@ -11,15 +12,19 @@ mod initialize_mint;
 mod initialize_mint_2;
 mod initialize_multisig;
 mod initialize_multisig_2;
+mod mint_builder;
 mod mint_to;
 mod mint_to_checked;
+mod reallocate;
 mod revoke;
 mod set_authority;
 mod sync_native;
 mod thaw_account;
 mod transfer;
 mod transfer_checked;
+mod withdraw_excess_lamports;
 
+pub use account_builder::*;
 pub use approve::*;
 pub use approve_checked::*;
 pub use burn::*;
@@ -33,11 +38,14 @@ pub use initialize_mint::*;
 pub use initialize_mint_2::*;
 pub use initialize_multisig::*;
 pub use initialize_multisig_2::*;
+pub use mint_builder::*;
 pub use mint_to::*;
 pub use mint_to_checked::*;
+pub use reallocate::*;
 pub use revoke::*;
 pub use set_authority::*;
 pub use sync_native::*;
 pub use thaw_account::*;
 pub use transfer::*;
 pub use transfer_checked::*;
+pub use withdraw_excess_lamports::*;
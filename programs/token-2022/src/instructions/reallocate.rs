@@ -0,0 +1,197 @@
+use core::slice::from_raw_parts;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    cpi::invoke_signed,
+    error::TokenError,
+    extension::{
+        consts::ExtensionType,
+        tlv::{extensions_from_bytes, has_extension_type},
+    },
+    instruction::TokenInstructionDiscriminator,
+    state::TokenAccount,
+    write_bytes, UNINIT_BYTE,
+};
+
+/// Maximum number of extension types that can be added by a single
+/// `Reallocate` instruction.
+pub const MAX_REALLOCATE_EXTENSION_TYPES: usize = ExtensionType::PausableAccount as usize + 1;
+
+/// Maximum number of account extensions required by the extensions of a mint,
+/// see [`ExtensionType::required_account_extensions`].
+pub const MAX_REQUIRED_ACCOUNT_EXTENSIONS: usize = 5;
+
+/// Check to see if a token account is large enough for a list of
+/// extension types, and if not, use reallocation to increase the data size.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to reallocate.
+///   1. `[WRITE, SIGNER]` The payer account to fund reallocation.
+///   2. `[]` System program for reallocation funding.
+///   3. `[SIGNER]` The account's owner.
+pub struct Reallocate<'a, 'b> {
+    /// Account to reallocate.
+    pub account: &'a AccountInfo,
+    /// Payer Account.
+    pub payer: &'a AccountInfo,
+    /// System Program.
+    pub system_program: &'a AccountInfo,
+    /// Owner Account.
+    pub owner: &'a AccountInfo,
+    /// Extension types to make room for.
+    pub extension_types: &'b [ExtensionType],
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl Reallocate<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.extension_types.len() > MAX_REALLOCATE_EXTENSION_TYPES {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        // account metadata
+        let account_metas: [AccountMeta; 4] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::writable_signer(self.payer.key()),
+            AccountMeta::readonly(self.system_program.key()),
+            AccountMeta::readonly_signer(self.owner.key()),
+        ];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..]: extension types (2 bytes each, u16 LE)
+        let mut instruction_data = [UNINIT_BYTE; 1 + 2 * MAX_REALLOCATE_EXTENSION_TYPES];
+        let length = 1 + 2 * self.extension_types.len();
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(
+            &mut instruction_data,
+            &[TokenInstructionDiscriminator::Reallocate as u8],
+        );
+        // Set extension types as u16 at offset [1..]
+        for (destination, extension_type) in instruction_data[1..length]
+            .chunks_exact_mut(2)
+            .zip(self.extension_types.iter())
+        {
+            write_bytes(destination, &(*extension_type as u16).to_le_bytes());
+        }
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.account, self.payer, self.system_program, self.owner],
+            signers,
+        )
+    }
+}
+
+/// Return the account extensions required by the extensions of the given mint
+/// data and missing from the given token account data, in the order of the
+/// mint extensions.
+#[inline]
+pub fn missing_account_extensions<'a>(
+    mint: &[u8],
+    account: &[u8],
+    buffer: &'a mut [ExtensionType; MAX_REQUIRED_ACCOUNT_EXTENSIONS],
+) -> &'a [ExtensionType] {
+    let mut len = 0;
+
+    let required = extensions_from_bytes(mint)
+        .filter_map(|(tlv_type, _)| ExtensionType::try_from(tlv_type).ok())
+        .flat_map(ExtensionType::required_account_extensions);
+
+    for &extension_type in required {
+        // a duplicated mint extension requires its account extensions once, so
+        // the buffer holds all of them
+        if !buffer[..len].contains(&extension_type) && !has_extension_type(extension_type, account)
+        {
+            buffer[len] = extension_type;
+            len += 1;
+        }
+    }
+
+    &buffer[..len]
+}
+
+/// Reallocate a token account to add the account extensions required by the
+/// extensions of its mint and missing from the account, e.g. before using an
+/// account created before the mint was upgraded.
+///
+/// Nothing is invoked if no extension is missing.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to reallocate.
+///   1. `[]` The mint of the account, only read.
+///   2. `[WRITE, SIGNER]` The payer account to fund reallocation.
+///   3. `[]` System program for reallocation funding.
+///   4. `[SIGNER]` The account's owner.
+pub struct ReallocateForMint<'a, 'b> {
+    /// Account to reallocate.
+    pub account: &'a AccountInfo,
+    /// Mint of the account.
+    pub mint: &'a AccountInfo,
+    /// Payer Account.
+    pub payer: &'a AccountInfo,
+    /// System Program.
+    pub system_program: &'a AccountInfo,
+    /// Owner Account.
+    pub owner: &'a AccountInfo,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl ReallocateForMint<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut buffer = [ExtensionType::Uninitialized; MAX_REQUIRED_ACCOUNT_EXTENSIONS];
+
+        // the borrows end before the CPI
+        let extension_types = {
+            let account = self.account.try_borrow_data()?;
+
+            if TokenAccount::from_bytes(&account)?.mint() != self.mint.key() {
+                Err(TokenError::MintMismatch)?;
+            }
+
+            missing_account_extensions(&self.mint.try_borrow_data()?, &account, &mut buffer)
+        };
+
+        if extension_types.is_empty() {
+            return Ok(());
+        }
+
+        Reallocate {
+            account: self.account,
+            payer: self.payer,
+            system_program: self.system_program,
+            owner: self.owner,
+            extension_types,
+            token_program: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+}
@@ -3,20 +3,50 @@ use core::slice::from_raw_parts;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
     pubkey::Pubkey,
     ProgramResult,
 };
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{cpi::invoke_signed, write_bytes, UNINIT_BYTE};
 
 #[repr(u8)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AuthorityType {
+    /// Authority to mint new tokens
     MintTokens = 0,
+    /// Authority to freeze any account associated with the Mint
     FreezeAccount = 1,
+    /// Owner of a given token account
     AccountOwner = 2,
+    /// Authority to close a token account
     CloseAccount = 3,
+    /// Authority to set the transfer fee
+    TransferFeeConfig = 4,
+    /// Authority to withdraw withheld tokens from a mint
+    WithheldWithdraw = 5,
+    /// Authority to close a mint account
+    CloseMint = 6,
+    /// Authority to set the interest rate
+    InterestRate = 7,
+    /// Authority to transfer or burn any tokens for a mint
+    PermanentDelegate = 8,
+    /// Authority to update confidential transfer mint and approve accounts for
+    /// confidential transfers
+    ConfidentialTransferMint = 9,
+    /// Authority to set the transfer hook program id
+    TransferHookProgramId = 10,
+    /// Authority to set the withdraw withheld authority encryption key
+    ConfidentialTransferFeeConfig = 11,
+    /// Authority to set the metadata address
+    MetadataPointer = 12,
+    /// Authority to set the group address
+    GroupPointer = 13,
+    /// Authority to set the group member address
+    GroupMemberPointer = 14,
+    /// Authority to set the UI amount scale
+    ScaledUiAmount = 15,
+    /// Authority to pause or resume minting / transferring / burning
+    Pause = 16,
 }
 
 /// Sets a new authority of a mint or account.
@@ -37,6 +67,102 @@ pub struct SetAuthority<'a, 'b> {
     pub token_program: &'b Pubkey,
 }
 
+/// Define `SetAuthority` constructors for an authority type of a mint.
+macro_rules! mint_authority_constructors {
+    ($($(#[$doc:meta])* $name:ident => $authority_type:ident,)*) => {
+        impl<'a, 'b> SetAuthority<'a, 'b> {
+            $(
+                $(#[$doc])*
+                #[inline(always)]
+                pub fn $name(
+                    mint: &'a AccountInfo,
+                    authority: &'a AccountInfo,
+                    new_authority: Option<&'a Pubkey>,
+                    token_program: &'b Pubkey,
+                ) -> Self {
+                    Self {
+                        account: mint,
+                        authority,
+                        authority_type: AuthorityType::$authority_type,
+                        new_authority,
+                        token_program,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+mint_authority_constructors! {
+    /// Set the mint authority of `mint`.
+    set_mint_authority => MintTokens,
+    /// Set the freeze authority of `mint`.
+    set_freeze_authority => FreezeAccount,
+    /// Set the `TransferFeeConfig` authority of `mint`.
+    set_transfer_fee_config_authority => TransferFeeConfig,
+    /// Set the `TransferFeeConfig` withdraw withheld authority of `mint`.
+    set_withdraw_withheld_authority => WithheldWithdraw,
+    /// Set the `MintCloseAuthority` of `mint`.
+    set_close_mint_authority => CloseMint,
+    /// Set the `InterestBearingConfig` rate authority of `mint`.
+    set_interest_rate_authority => InterestRate,
+    /// Set the `PermanentDelegate` of `mint`.
+    set_permanent_delegate => PermanentDelegate,
+    /// Set the `ConfidentialTransferMint` authority of `mint`.
+    set_confidential_transfer_mint_authority => ConfidentialTransferMint,
+    /// Set the `TransferHook` authority of `mint`.
+    set_transfer_hook_program_id_authority => TransferHookProgramId,
+    /// Set the `ConfidentialTransferFeeConfig` authority of `mint`.
+    set_confidential_transfer_fee_config_authority => ConfidentialTransferFeeConfig,
+    /// Set the `MetadataPointer` authority of `mint`.
+    set_metadata_pointer_authority => MetadataPointer,
+    /// Set the `GroupPointer` authority of `mint`.
+    set_group_pointer_authority => GroupPointer,
+    /// Set the `GroupMemberPointer` authority of `mint`.
+    set_group_member_pointer_authority => GroupMemberPointer,
+    /// Set the `ScaledUiAmountConfig` authority of `mint`.
+    set_scaled_ui_amount_authority => ScaledUiAmount,
+    /// Set the `PausableConfig` authority of `mint`.
+    set_pause_authority => Pause,
+}
+
+impl<'a, 'b> SetAuthority<'a, 'b> {
+    /// Set the owner of the token `account`.
+    #[inline(always)]
+    pub fn set_account_owner(
+        account: &'a AccountInfo,
+        owner: &'a AccountInfo,
+        new_owner: &'a Pubkey,
+        token_program: &'b Pubkey,
+    ) -> Self {
+        Self {
+            account,
+            authority: owner,
+            authority_type: AuthorityType::AccountOwner,
+            new_authority: Some(new_owner),
+            token_program,
+        }
+    }
+
+    /// Set the close authority of the token `account`, `None` to fall back to
+    /// the owner.
+    #[inline(always)]
+    pub fn set_close_account_authority(
+        account: &'a AccountInfo,
+        authority: &'a AccountInfo,
+        new_authority: Option<&'a Pubkey>,
+        token_program: &'b Pubkey,
+    ) -> Self {
+        Self {
+            account,
+            authority,
+            authority_type: AuthorityType::CloseAccount,
+            new_authority,
+            token_program,
+        }
+    }
+}
+
 impl SetAuthority<'_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
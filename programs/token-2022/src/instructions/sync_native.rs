@@ -1,11 +1,12 @@
 use pinocchio::{
     account_info::AccountInfo,
-    cpi::invoke,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     ProgramResult,
 };
 
+use crate::cpi::invoke;
+
 /// Given a native token account updates its amount field based
 /// on the account's underlying `lamports`.
 ///
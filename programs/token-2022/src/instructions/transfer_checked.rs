@@ -3,12 +3,11 @@ use core::slice::from_raw_parts;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
     pubkey::Pubkey,
     ProgramResult,
 };
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{cpi::invoke_signed, write_bytes, UNINIT_BYTE};
 
 /// Transfer Tokens from one Token Account to another.
 ///
@@ -42,6 +41,14 @@ impl TransferChecked<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        #[cfg(feature = "preflight")]
+        crate::guards::assert_cpi_guard_allows(
+            self.from,
+            crate::guards::CpiGuardedInstruction::Transfer {
+                authority: self.authority.key(),
+            },
+        )?;
+
         // account metadata
         let account_metas: [AccountMeta; 4] = [
             AccountMeta::writable(self.from.key()),
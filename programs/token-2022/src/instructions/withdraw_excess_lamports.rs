@@ -0,0 +1,42 @@
+use crate::{instruction::TokenInstructionDiscriminator, macros::define_extension_instruction};
+
+define_extension_instruction! {
+    /// Withdraw the lamports exceeding the rent exemption of a mint, token or
+    /// multisig account.
+    ///
+    /// ### Accounts:
+    ///
+    /// **Single authority**
+    ///   0. `[WRITE]` The source account.
+    ///   1. `[WRITE]` The destination account.
+    ///   2. `[SIGNER]` The source account's owner, mint authority or multisig.
+    ///
+    /// **Multisignature authority**
+    ///   0. `[WRITE]` The source account.
+    ///   1. `[WRITE]` The destination account.
+    ///   2. `[]` The source account's multisig owner or mint authority.
+    ///   3. `..3+M` `[SIGNER]` M signer accounts.
+    pub struct WithdrawExcessLamports<'a> {
+        accounts: {
+            /// Source Account.
+            source: writable,
+            /// Destination Account.
+            destination: writable,
+        },
+        authority: {
+            /// Owner or mint authority of the source account.
+            authority
+        },
+        data: {},
+        instruction_data: withdraw_excess_lamports_instruction_data,
+        data_len: 1,
+    }
+}
+
+pub fn withdraw_excess_lamports_instruction_data(buffer: &mut [u8]) -> &[u8] {
+    // Instruction data layout:
+    // -  [0]: instruction discriminator (1 byte, u8)
+    buffer[0] = TokenInstructionDiscriminator::WithdrawExcessLamports as u8;
+
+    buffer
+}
@@ -0,0 +1,25 @@
+//! Instructions shared by the SPL Token and SPL Token-2022 programs.
+//!
+//! The instructions below have the same layout in both programs, so a program
+//! supporting both of them can use a single API and pass the `token_program`
+//! of the mint it received.
+
+use pinocchio::pubkey::Pubkey;
+
+pub use crate::instructions::{
+    Approve, ApproveChecked, Burn, BurnChecked, CloseAccount, FreezeAccount, InitializeAccount,
+    InitializeAccount2, InitializeAccount3, InitializeMint, InitializeMint2, InitializeMultisig,
+    InitializeMultisig2, MintTo, MintToChecked, Revoke, SetAuthority, SyncNative, ThawAccount,
+    Transfer, TransferChecked, MAX_MULTISIG_SIGNERS,
+};
+
+/// The SPL Token program ID.
+pub const TOKEN_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Returns `true` if the given pubkey is either the SPL Token or the SPL
+/// Token-2022 program ID.
+#[inline]
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    program_id == &TOKEN_PROGRAM_ID || program_id == &crate::ID
+}
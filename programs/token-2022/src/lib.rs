@@ -1,7 +1,33 @@
 #![no_std]
+#[cfg(feature = "client")]
+pub mod client;
+mod cpi;
+pub mod error;
 pub mod extension;
+#[cfg(any(
+    feature = "cpi-guard",
+    feature = "pausable",
+    all(feature = "non-transferable", feature = "transfer-hook")
+))]
+pub mod guards;
+#[cfg(all(feature = "host-stub", not(target_os = "solana")))]
+pub mod host_stub;
+pub mod instruction;
 pub mod instructions;
+#[cfg(feature = "legacy")]
+pub mod legacy;
+mod macros;
+pub mod memo;
+pub mod native_mint;
+pub mod owner;
+pub mod pod;
+pub mod prelude;
 pub mod state;
+mod system;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod transfer_hook_interface;
+pub mod ui_amount;
 
 pinocchio_pubkey::declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 
@@ -9,6 +35,9 @@ use core::mem::MaybeUninit;
 
 const UNINIT_BYTE: MaybeUninit<u8> = MaybeUninit::<u8>::uninit();
 
+/// System Program.
+const SYSTEM_PROGRAM_ID: pinocchio::pubkey::Pubkey = [0; 32];
+
 #[inline(always)]
 fn write_bytes(destination: &mut [MaybeUninit<u8>], source: &[u8]) {
     for (d, s) in destination.iter_mut().zip(source.iter()) {
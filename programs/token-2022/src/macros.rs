@@ -0,0 +1,155 @@
+//! Declarative macros shared by the instruction wrappers.
+
+/// Define the CPI wrapper of an instruction from the list of its accounts,
+/// its data fields and its instruction data encoder.
+///
+/// Generates the struct, holding the accounts, the data fields and the token
+/// program id, and its `invoke` and `invoke_signed` methods. The accounts are
+/// passed in the order they are listed, followed by the optional `authority`
+/// and, if it is a multisig, its `signers` (at most
+/// [`MAX_MULTISIG_SIGNERS`](crate::instructions::MAX_MULTISIG_SIGNERS)).
+///
+/// Each account is declared `writable`, `readonly`, `writable_signer` or
+/// `readonly_signer`, after the [`AccountMeta`](pinocchio::instruction::AccountMeta)
+/// constructors. The instruction data is written by
+/// `$encoder(&mut [u8; $data_len], fields..) -> &[u8]`, called with the data
+/// fields in the order they are listed.
+///
+/// ```ignore
+/// define_extension_instruction! {
+///     /// Withdraw the lamports exceeding the rent exemption of an account.
+///     pub struct WithdrawExcessLamports<'a> {
+///         accounts: {
+///             /// Source account.
+///             source: writable,
+///             /// Destination account.
+///             destination: writable,
+///         },
+///         authority: {
+///             /// Owner or authority of the source account.
+///             authority
+///         },
+///         data: {},
+///         instruction_data: withdraw_excess_lamports_instruction_data,
+///         data_len: 1,
+///     }
+/// }
+/// ```
+macro_rules! define_extension_instruction {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident<$lt:lifetime> {
+            accounts: {
+                $( $(#[$account_meta:meta])* $account:ident: $role:ident ),* $(,)?
+            },
+            $( authority: { $(#[$authority_meta:meta])* $authority:ident }, )?
+            data: {
+                $( $(#[$field_meta:meta])* $field:ident: $field_ty:ty ),* $(,)?
+            },
+            instruction_data: $encoder:path,
+            data_len: $data_len:expr $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        pub struct $name<$lt> {
+            $(
+                $(#[$account_meta])*
+                pub $account: &$lt ::pinocchio::account_info::AccountInfo,
+            )*
+            $(
+                $(#[$authority_meta])*
+                pub $authority: &$lt ::pinocchio::account_info::AccountInfo,
+                /// Signer accounts if the authority is a multisig.
+                pub signers: &$lt [::pinocchio::account_info::AccountInfo],
+            )?
+            $(
+                $(#[$field_meta])*
+                pub $field: $field_ty,
+            )*
+            /// Token program (Token-2022).
+            pub token_program: &$lt ::pinocchio::pubkey::Pubkey,
+        }
+
+        impl $name<'_> {
+            #[inline(always)]
+            pub fn invoke(&self) -> ::pinocchio::ProgramResult {
+                self.invoke_signed(&[])
+            }
+
+            #[inline(always)]
+            pub fn invoke_signed(
+                &self,
+                signers: &[::pinocchio::instruction::Signer],
+            ) -> ::pinocchio::ProgramResult {
+                use {
+                    ::core::{mem::MaybeUninit, slice},
+                    ::pinocchio::{
+                        account_info::AccountInfo,
+                        instruction::{AccountMeta, Instruction},
+                    },
+                };
+
+                const MAX_ACCOUNTS: usize = 0
+                    $( + { let _ = stringify!($account); 1 } )*
+                    $( + { let _ = stringify!($authority); 1 }
+                        + $crate::instructions::MAX_MULTISIG_SIGNERS )?;
+                const UNINIT_META: MaybeUninit<AccountMeta> =
+                    MaybeUninit::<AccountMeta>::uninit();
+                const UNINIT_INFO: MaybeUninit<&AccountInfo> =
+                    MaybeUninit::<&AccountInfo>::uninit();
+
+                let mut account_metas = [UNINIT_META; MAX_ACCOUNTS];
+                let mut account_infos = [UNINIT_INFO; MAX_ACCOUNTS];
+                let mut num_accounts = 0;
+
+                $(
+                    account_metas[num_accounts].write(AccountMeta::$role(self.$account.key()));
+                    account_infos[num_accounts].write(self.$account);
+                    num_accounts += 1;
+                )*
+
+                $(
+                    if self.signers.len() > $crate::instructions::MAX_MULTISIG_SIGNERS {
+                        Err(::pinocchio::program_error::ProgramError::InvalidArgument)?;
+                    }
+
+                    // the authority only signs when it is not a multisig
+                    account_metas[num_accounts].write(AccountMeta::new(
+                        self.$authority.key(),
+                        false,
+                        self.signers.is_empty(),
+                    ));
+                    account_infos[num_accounts].write(self.$authority);
+                    num_accounts += 1;
+
+                    for signer in self.signers {
+                        account_metas[num_accounts]
+                            .write(AccountMeta::readonly_signer(signer.key()));
+                        account_infos[num_accounts].write(signer);
+                        num_accounts += 1;
+                    }
+                )?
+
+                let mut buffer = [0u8; $data_len];
+                let data = $encoder(&mut buffer $(, self.$field)*);
+
+                // SAFETY: the first `num_accounts` metas and infos are initialized
+                let instruction = Instruction {
+                    program_id: self.token_program,
+                    accounts: unsafe {
+                        slice::from_raw_parts(account_metas.as_ptr() as _, num_accounts)
+                    },
+                    data,
+                };
+
+                $crate::cpi::invoke_signed_with_bounds::<MAX_ACCOUNTS>(
+                    &instruction,
+                    unsafe { slice::from_raw_parts(account_infos.as_ptr() as _, num_accounts) },
+                    signers,
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use define_extension_instruction;
@@ -0,0 +1,61 @@
+//! SPL Memo program CPI.
+//!
+//! Incoming transfers into a token account with the `MemoTransfer` extension
+//! enabled require a memo, invoked right before the transfer by the same program.
+
+use core::{mem::MaybeUninit, slice};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::MAX_CPI_ACCOUNTS,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    ProgramResult,
+};
+
+use crate::cpi::slice_invoke_signed;
+
+pinocchio_pubkey::declare_id!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Log a memo, the Memo program rejects bytes that aren't valid UTF-8.
+///
+/// ### Accounts:
+///   0. `..+N` `[SIGNER]` N optional signing accounts
+pub struct Memo<'a, 'b, 'c> {
+    /// Signing accounts, the memo is logged with their addresses
+    pub signers: &'b [&'a AccountInfo],
+    /// Memo
+    pub memo: &'c [u8],
+}
+
+impl Memo<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers_seeds: &[Signer]) -> ProgramResult {
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+
+        let num_accounts = self.signers.len();
+        if num_accounts > MAX_CPI_ACCOUNTS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut account_metas = [UNINIT_META; MAX_CPI_ACCOUNTS];
+
+        for (account_meta, signer) in account_metas.iter_mut().zip(self.signers.iter()) {
+            account_meta.write(AccountMeta::readonly_signer(signer.key()));
+        }
+
+        let instruction = Instruction {
+            program_id: &ID,
+            // SAFETY: the first `num_accounts` metas are initialized
+            accounts: unsafe { slice::from_raw_parts(account_metas.as_ptr() as _, num_accounts) },
+            data: self.memo,
+        };
+
+        slice_invoke_signed(&instruction, self.signers, signers_seeds)
+    }
+}
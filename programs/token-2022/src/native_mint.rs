@@ -0,0 +1,101 @@
+//! Wrapped SOL of Token-2022.
+//!
+//! The lamports of a token account of the native mint are its balance, kept
+//! in sync with `SyncNative` and recovered by closing the account.
+
+use pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult};
+
+use crate::{
+    error::TokenError,
+    instructions::{CloseAccount, SyncNative},
+    state::TokenAccount,
+    system,
+};
+
+pinocchio_pubkey::declare_id!("9pan9bMn5HatX4EJdBwg9VgCa7Uz5HL8N1m5D3NdXejP");
+
+/// Fail with `NonNativeNotSupported` if `account` isn't a token account of
+/// the native mint.
+#[inline(always)]
+fn check_native(account: &AccountInfo) -> ProgramResult {
+    if TokenAccount::from_bytes(&account.try_borrow_data()?)?.mint() != &ID {
+        Err(TokenError::NonNativeNotSupported)?;
+    }
+
+    Ok(())
+}
+
+/// Wrap SOL: transfer lamports to a native token account with the System
+/// Program, then sync its balance with `SyncNative`.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE]` The native token account
+pub struct WrapSol<'a, 'b> {
+    /// Funding Account.
+    pub from: &'a AccountInfo,
+    /// Native Token Account.
+    pub native_token: &'a AccountInfo,
+    /// Amount of lamports to wrap.
+    pub amount: u64,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl WrapSol<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        check_native(self.native_token)?;
+
+        system::transfer(self.from, self.native_token, self.amount, signers)?;
+
+        SyncNative {
+            native_token: self.native_token,
+            token_program: self.token_program,
+        }
+        .invoke()
+    }
+}
+
+/// Unwrap SOL: close a native token account, transferring all its lamports
+/// to the destination account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The native token account
+///   1. `[WRITE]` The destination account
+///   2. `[SIGNER]` The account's owner
+pub struct UnwrapSol<'a, 'b> {
+    /// Native Token Account.
+    pub native_token: &'a AccountInfo,
+    /// Destination Account
+    pub destination: &'a AccountInfo,
+    /// Owner Account
+    pub authority: &'a AccountInfo,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl UnwrapSol<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        check_native(self.native_token)?;
+
+        CloseAccount {
+            account: self.native_token,
+            destination: self.destination,
+            authority: self.authority,
+            token_program: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+}
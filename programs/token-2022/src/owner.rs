@@ -0,0 +1,78 @@
+//! Owner validation of the `from_account_info_with_owner` constructors, for
+//! programs using a fork or a test deployment of Token-2022 at another address.
+//!
+//! The expected owner is a single program id or a set of them:
+//!
+//! ```ignore
+//! const TOKEN_PROGRAM_IDS: [Pubkey; 2] = [ID, FORK_ID];
+//!
+//! let state = Mint::from_account_info_with_owner(mint, &FORK_ID)?;
+//! let config = TransferFeeConfig::from_account_info_with_owner(mint, &TOKEN_PROGRAM_IDS)?;
+//! ```
+
+use pinocchio::pubkey::Pubkey;
+
+/// Program ids accepted as the owner of an account.
+pub trait TokenProgramIds {
+    /// Return whether `owner` is one of the program ids.
+    fn contains(&self, owner: &Pubkey) -> bool;
+}
+
+impl TokenProgramIds for Pubkey {
+    #[inline(always)]
+    fn contains(&self, owner: &Pubkey) -> bool {
+        self == owner
+    }
+}
+
+impl TokenProgramIds for [Pubkey] {
+    #[inline(always)]
+    fn contains(&self, owner: &Pubkey) -> bool {
+        self.iter().any(|x| x == owner)
+    }
+}
+
+impl<const N: usize> TokenProgramIds for [Pubkey; N] {
+    #[inline(always)]
+    fn contains(&self, owner: &Pubkey) -> bool {
+        self.as_slice().contains(owner)
+    }
+}
+
+/// Implement `from_account_info_with_owner` for states with a
+/// `from_bytes(&[u8]) -> Result<&Self, ProgramError>` constructor.
+macro_rules! impl_from_account_info_with_owner {
+    ($($state:ident),+ $(,)?) => {
+        $(
+            impl $state {
+                #[doc = concat!("Return a `", stringify!($state), "` from the given account info.")]
+                ///
+                /// Same as `from_account_info`, except that the account may be owned
+                /// by any of `owners` instead of Token-2022.
+                #[inline]
+                pub fn from_account_info_with_owner<'a, O>(
+                    account_info: &'a ::pinocchio::account_info::AccountInfo,
+                    owners: &O,
+                ) -> Result<
+                    ::pinocchio::account_info::Ref<'a, Self>,
+                    ::pinocchio::program_error::ProgramError,
+                >
+                where
+                    O: $crate::owner::TokenProgramIds + ?Sized,
+                {
+                    if !owners.contains(account_info.owner()) {
+                        Err(::pinocchio::program_error::ProgramError::InvalidAccountOwner)?;
+                    }
+
+                    ::pinocchio::account_info::Ref::try_map(
+                        account_info.try_borrow_data()?,
+                        |data| Self::from_bytes(data),
+                    )
+                    .map_err(|(_, error)| error)
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use impl_from_account_info_with_owner;
@@ -0,0 +1,179 @@
+//! Plain-old-data types and readers equivalent to the `spl-pod` and
+//! `spl-type-length-value` ones, to parse extension data without the SPL
+//! crates.
+
+use {
+    core::{mem, slice, str},
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// Length of the prefix of a length-prefixed value: the number of elements
+/// (4 bytes, u32).
+pub const LENGTH_PREFIX_LEN: usize = 4;
+
+/// Data that can be read from any `size_of::<Self>()` bytes.
+///
+/// # Safety
+///
+/// Implementors must have an alignment of 1 and accept any bit pattern.
+pub unsafe trait Pod: Copy {}
+
+unsafe impl Pod for u8 {}
+
+unsafe impl<const N: usize> Pod for [u8; N] {}
+
+/// Data where the zeroed value means no value, see [`PodOption`].
+pub trait Nullable: Pod + PartialEq {
+    /// The value meaning no value
+    const NONE: Self;
+
+    #[inline(always)]
+    fn is_none(&self) -> bool {
+        self == &Self::NONE
+    }
+
+    #[inline(always)]
+    fn is_some(&self) -> bool {
+        !self.is_none()
+    }
+}
+
+impl<const N: usize> Nullable for [u8; N] {
+    const NONE: Self = [0; N];
+}
+
+/// An optional value stored in the same bytes as the value, using
+/// [`Nullable::NONE`] for `None`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PodOption<T: Nullable>(T);
+
+unsafe impl<T: Nullable> Pod for PodOption<T> {}
+
+/// An optional pubkey, zeroed if none.
+pub type OptionalNonZeroPubkey = PodOption<Pubkey>;
+
+impl<T: Nullable> PodOption<T> {
+    #[inline(always)]
+    pub const fn none() -> Self {
+        Self(T::NONE)
+    }
+
+    /// Return `None` for the value meaning no value, which can't be stored as
+    /// `Some`.
+    #[inline(always)]
+    pub fn some(value: T) -> Option<Self> {
+        value.is_some().then_some(Self(value))
+    }
+
+    #[inline(always)]
+    pub fn get(&self) -> Option<&T> {
+        self.0.is_some().then_some(&self.0)
+    }
+
+    #[inline(always)]
+    pub fn is_some(&self) -> bool {
+        self.0.is_some()
+    }
+
+    #[inline(always)]
+    pub fn is_none(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<T: Nullable> Default for PodOption<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T: Nullable> From<PodOption<T>> for Option<T> {
+    #[inline(always)]
+    fn from(value: PodOption<T>) -> Self {
+        value.get().copied()
+    }
+}
+
+impl<T: Nullable> TryFrom<Option<T>> for PodOption<T> {
+    type Error = ProgramError;
+
+    /// Fails for `Some` of the value meaning no value.
+    #[inline(always)]
+    fn try_from(value: Option<T>) -> Result<Self, Self::Error> {
+        match value {
+            Some(x) => Self::some(x).ok_or(ProgramError::InvalidArgument),
+            None => Ok(Self::none()),
+        }
+    }
+}
+
+/// Return the `T` stored in exactly `size_of::<T>()` bytes.
+#[inline]
+pub fn pod_from_bytes<T: Pod>(bytes: &[u8]) -> Result<&T, ProgramError> {
+    if bytes.len() != mem::size_of::<T>() {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    // SAFETY: `T` has an alignment of 1 and accepts any bit pattern
+    Ok(unsafe { &*(bytes.as_ptr() as *const T) })
+}
+
+/// Return the `T`s stored in `bytes`, whose length must be a multiple of
+/// `size_of::<T>()`.
+#[inline]
+pub fn pod_slice_from_bytes<T: Pod>(bytes: &[u8]) -> Result<&[T], ProgramError> {
+    let size = mem::size_of::<T>();
+
+    if bytes.len().checked_rem(size) != Some(0) {
+        Err(ProgramError::InvalidAccountData)?;
+    }
+
+    // SAFETY: `T` has an alignment of 1 and accepts any bit pattern, and
+    // `bytes` holds `bytes.len() / size` of them
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T, bytes.len() / size) })
+}
+
+/// Read a length-prefixed list of `T`s: the number of elements (4 bytes, u32)
+/// followed by the elements.
+///
+/// Returns the elements and the bytes following them.
+#[inline]
+pub fn read_pod_slice<T: Pod>(bytes: &[u8]) -> Result<(&[T], &[u8]), ProgramError> {
+    let (length, rest) = read_length_prefix(bytes)?;
+
+    let len = length
+        .checked_mul(mem::size_of::<T>())
+        .filter(|&len| len <= rest.len())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let (values, rest) = rest.split_at(len);
+
+    // SAFETY: `T` has an alignment of 1 and accepts any bit pattern, and
+    // `values` holds `length` of them
+    let values = unsafe { slice::from_raw_parts(values.as_ptr() as *const T, length) };
+
+    Ok((values, rest))
+}
+
+/// Read a length-prefixed (borsh) string: the number of bytes (4 bytes, u32)
+/// followed by the UTF-8 bytes.
+///
+/// Returns the string and the bytes following it.
+#[inline]
+pub fn read_pod_str(bytes: &[u8]) -> Result<(&str, &[u8]), ProgramError> {
+    let (value, rest) = read_pod_slice::<u8>(bytes)?;
+
+    let value = str::from_utf8(value).map_err(|_| ProgramError::InvalidAccountData)?;
+
+    Ok((value, rest))
+}
+
+#[inline(always)]
+fn read_length_prefix(bytes: &[u8]) -> Result<(usize, &[u8]), ProgramError> {
+    let Some((length, rest)) = bytes.split_first_chunk::<LENGTH_PREFIX_LEN>() else {
+        Err(ProgramError::InvalidAccountData)?
+    };
+
+    Ok((u32::from_le_bytes(*length) as usize, rest))
+}
@@ -0,0 +1,77 @@
+//! The most-used items of the crate, to import them with a single
+//! `use pinocchio_token_2022::prelude::*;`.
+//!
+//! The extension modules are re-exported as well, their instructions are
+//! reachable as e.g. `transfer_fee::TransferCheckedWithFee`. Only the
+//! extensions enabled by the crate features are included.
+
+pub use crate::{
+    error::TokenError,
+    extension::{
+        consts::ExtensionType,
+        tlv::{
+            get_extension_from_bytes, get_extensions_from_bytes, has_extension, has_extension_type,
+            Extension,
+        },
+    },
+    instructions::*,
+    pod::{Nullable, OptionalNonZeroPubkey, Pod, PodOption},
+    state::{AccountState, Mint, Multisig, TokenAccount},
+    ID,
+};
+
+#[cfg(feature = "confidential")]
+pub use crate::extension::confidential::{PodAeCiphertext, PodElGamalCiphertext, PodElGamalPubkey};
+#[cfg(feature = "confidential-transfer")]
+pub use crate::extension::confidential_transfer::{
+    self, ConfidentialTransferAccount, ConfidentialTransferMint,
+};
+#[cfg(feature = "confidential-transfer-fee")]
+pub use crate::extension::confidential_transfer_fee::{
+    self, ConfidentialTransferFeeAmount, ConfidentialTransferFeeConfig,
+};
+#[cfg(feature = "default-account-state")]
+pub use crate::extension::default_account_state::{self, state::DefaultAccountStateConfig};
+#[cfg(feature = "group-member-pointer")]
+pub use crate::extension::group_member_pointer::{self, GroupMemberPointer};
+#[cfg(feature = "group-pointer")]
+pub use crate::extension::group_pointer::{self, GroupPointer};
+#[cfg(feature = "immutable-owner")]
+pub use crate::extension::immutable_owner::{self, ImmutableOwner};
+#[cfg(feature = "interest-bearing-mint")]
+pub use crate::extension::interest_bearing_mint::{self, InterestBearingConfig};
+#[cfg(feature = "memo-transfer")]
+pub use crate::extension::memo_transfer::{self, MemoTransfer};
+#[cfg(feature = "metadata-pointer")]
+pub use crate::extension::metadata_pointer::{self, MetadataPointer};
+#[cfg(feature = "mint-close-authority")]
+pub use crate::extension::mint_close_authority::{self, MintCloseAuthority};
+#[cfg(feature = "non-transferable")]
+pub use crate::extension::non_transferable::{self, NonTransferable, NonTransferableAccount};
+#[cfg(feature = "permanent-delegate")]
+pub use crate::extension::permanent_delegate::{self, PermanentDelegate};
+#[cfg(feature = "scaled-ui-amount")]
+pub use crate::extension::scaled_ui_amount::{self, ScaledUiAmountConfig};
+#[cfg(feature = "token-group")]
+pub use crate::extension::token_group::{self, TokenGroup, TokenGroupMember};
+#[cfg(feature = "token-metadata")]
+pub use crate::extension::token_metadata::{self, TokenMetadata};
+#[cfg(feature = "transfer-fee")]
+pub use crate::extension::transfer_fee::{self, TransferFeeAmount, TransferFeeConfig};
+#[cfg(all(feature = "non-transferable", feature = "transfer-hook"))]
+pub use crate::guards::{assert_transferable, assert_transferable_from_bytes};
+#[cfg(feature = "cpi-guard")]
+pub use crate::{
+    extension::cpi_guard::{self, CpiGuard},
+    guards::{assert_cpi_guard_allows, assert_cpi_guard_allows_from_bytes, CpiGuardedInstruction},
+};
+#[cfg(feature = "pausable")]
+pub use crate::{
+    extension::pausable::{self, PausableConfig},
+    guards::{assert_not_paused, assert_not_paused_from_bytes},
+};
+#[cfg(feature = "transfer-hook")]
+pub use crate::{
+    extension::transfer_hook::{self, TransferHook, TransferHookAccount},
+    transfer_hook_interface::TransferCheckedWithHook,
+};
@@ -92,6 +92,18 @@ impl Mint {
         &*(bytes[..Self::BASE_LEN].as_ptr() as *const Mint)
     }
 
+    /// Return a `Mint` from the given bytes.
+    ///
+    /// This method performs length validation, so it can read the data of a mint
+    /// fetched off-chain. The bytes may carry extensions after the base mint.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::BASE_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
     #[inline(always)]
     pub fn has_mint_authority(&self) -> bool {
         self.mint_authority_flag[0] == 1
@@ -148,3 +160,5 @@ impl Mint {
         &self.freeze_authority
     }
 }
+
+crate::owner::impl_from_account_info_with_owner!(Mint);
@@ -7,3 +7,17 @@ pub use account_state::*;
 pub use mint::*;
 pub use multisig::*;
 pub use token::*;
+
+/// Base state of the accounts which may carry extensions after it.
+pub trait BaseState {
+    /// Length of the base state.
+    const BASE_LEN: usize;
+}
+
+impl BaseState for Mint {
+    const BASE_LEN: usize = core::mem::size_of::<Mint>();
+}
+
+impl BaseState for TokenAccount {
+    const BASE_LEN: usize = core::mem::size_of::<TokenAccount>();
+}
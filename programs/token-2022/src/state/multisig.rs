@@ -76,6 +76,18 @@ impl Multisig {
         &*(bytes.as_ptr() as *const Multisig)
     }
 
+    /// Return a `Multisig` from the given bytes.
+    ///
+    /// This method performs length validation, so it can read the data of a
+    /// multisig fetched off-chain.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
     /// Number of signers required to validate the `Multisig` signature.
     #[inline(always)]
     pub const fn required_signers(&self) -> u8 {
@@ -106,3 +118,5 @@ impl Multisig {
         self.is_initialized != 0
     }
 }
+
+crate::owner::impl_from_account_info_with_owner!(Multisig);
@@ -107,6 +107,19 @@ impl TokenAccount {
         &*(bytes[..Self::BASE_LEN].as_ptr() as *const TokenAccount)
     }
 
+    /// Return a `TokenAccount` from the given bytes.
+    ///
+    /// This method performs length validation, so it can read the data of a token
+    /// account fetched off-chain. The bytes may carry extensions after the base
+    /// account.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::BASE_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
     pub fn mint(&self) -> &Pubkey {
         &self.mint
     }
@@ -201,3 +214,5 @@ impl TokenAccount {
         self.state == AccountState::Frozen as u8
     }
 }
+
+crate::owner::impl_from_account_info_with_owner!(TokenAccount);
@@ -0,0 +1,46 @@
+//! System Program instructions invoked by the helpers funding accounts.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    ProgramResult,
+};
+
+use crate::{cpi::invoke_signed, write_bytes, SYSTEM_PROGRAM_ID, UNINIT_BYTE};
+
+/// Transfer lamports with the System Program.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE]` Recipient account
+#[inline(always)]
+pub(crate) fn transfer(
+    from: &AccountInfo,
+    to: &AccountInfo,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    // account metadata
+    let account_metas: [AccountMeta; 2] = [
+        AccountMeta::writable_signer(from.key()),
+        AccountMeta::writable(to.key()),
+    ];
+
+    // Instruction data layout:
+    // -  [0..4]: instruction discriminator (4 bytes, u32)
+    // -  [4..12]: lamports (8 bytes, u64)
+    let mut instruction_data = [UNINIT_BYTE; 12];
+
+    // Set discriminator as u32 at offset [0..4]
+    write_bytes(&mut instruction_data, &2u32.to_le_bytes());
+    // Set lamports as u64 at offset [4..12]
+    write_bytes(&mut instruction_data[4..], &lamports.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: &SYSTEM_PROGRAM_ID,
+        accounts: &account_metas,
+        data: unsafe { core::slice::from_raw_parts(instruction_data.as_ptr() as _, 12) },
+    };
+
+    invoke_signed(&instruction, &[from, to], signers)
+}
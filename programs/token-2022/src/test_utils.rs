@@ -0,0 +1,117 @@
+//! Builder of synthetic mint and token account data carrying TLV entries,
+//! enabled by the `test-utils` feature.
+//!
+//! Parser unit tests and fuzz corpora can build the account data directly,
+//! instead of creating the accounts in a test validator. Besides valid
+//! extensions, entries can be malformed on purpose: unknown types, lengths
+//! running past the end of the data or truncated headers.
+//!
+//! ```ignore
+//! let data = TlvBuilder::mint()
+//!     .extension(ExtensionType::MintCloseAuthority, &[1; 32])
+//!     .entry(u16::MAX, &[])
+//!     .raw_entry(ExtensionType::TransferHook as u16, 64, &[2; 10])
+//!     .build();
+//!
+//! assert!(get_extension_from_bytes::<MintCloseAuthority>(&data).is_some());
+//! ```
+
+extern crate alloc;
+
+use {
+    crate::{
+        extension::{consts::ExtensionType, tlv::BASE_ACCOUNT_LENGTH},
+        state::{AccountState, Mint, TokenAccount},
+    },
+    alloc::vec::Vec,
+};
+
+/// Account type of a mint, stored right after the base account.
+pub const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Account type of a token account, stored right after the base account.
+pub const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// Offset of the `is_initialized` flag in a mint.
+const MINT_IS_INITIALIZED: usize = 45;
+
+/// Offset of the state in a token account.
+const TOKEN_ACCOUNT_STATE: usize = 108;
+
+/// Builder of mint or token account data: the base account, padded to
+/// [`BASE_ACCOUNT_LENGTH`], the account type and the TLV entries in the order
+/// they are added.
+#[derive(Clone, Debug)]
+pub struct TlvBuilder {
+    data: Vec<u8>,
+}
+
+impl TlvBuilder {
+    /// Initialized mint without authorities and a zero supply.
+    pub fn mint() -> Self {
+        let mut base = [0; Mint::BASE_LEN];
+        base[MINT_IS_INITIALIZED] = 1;
+
+        Self::new(&base, ACCOUNT_TYPE_MINT)
+    }
+
+    /// Initialized token account with zeroed mint, owner and amount.
+    pub fn token_account() -> Self {
+        let mut base = [0; TokenAccount::BASE_LEN];
+        base[TOKEN_ACCOUNT_STATE] = AccountState::Initialized as u8;
+
+        Self::new(&base, ACCOUNT_TYPE_ACCOUNT)
+    }
+
+    /// Account with the given base data, padded with zeros to
+    /// [`BASE_ACCOUNT_LENGTH`] bytes, and account type.
+    ///
+    /// Base data longer than [`BASE_ACCOUNT_LENGTH`] is truncated.
+    pub fn new(base: &[u8], account_type: u8) -> Self {
+        let mut data = Vec::with_capacity(BASE_ACCOUNT_LENGTH + 1);
+        data.extend_from_slice(&base[..base.len().min(BASE_ACCOUNT_LENGTH)]);
+        data.resize(BASE_ACCOUNT_LENGTH, 0);
+        data.push(account_type);
+
+        Self { data }
+    }
+
+    /// Append an entry of `extension_type` holding `value`.
+    pub fn extension(self, extension_type: ExtensionType, value: &[u8]) -> Self {
+        self.entry(extension_type as u16, value)
+    }
+
+    /// Append an entry of any TLV type, including types unknown to this crate
+    /// and `Uninitialized`, holding `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is longer than `u16::MAX` bytes.
+    pub fn entry(self, tlv_type: u16, value: &[u8]) -> Self {
+        let len = u16::try_from(value.len()).expect("TLV value longer than u16::MAX bytes");
+        self.raw_entry(tlv_type, len, value)
+    }
+
+    /// Append an entry whose header declares `len` bytes, followed by `value`
+    /// whatever its length.
+    ///
+    /// A `len` greater than the length of `value` makes the entry run into the
+    /// following ones, or past the end of the data if it is the last one.
+    pub fn raw_entry(mut self, tlv_type: u16, len: u16, value: &[u8]) -> Self {
+        self.data.extend_from_slice(&tlv_type.to_le_bytes());
+        self.data.extend_from_slice(&len.to_le_bytes());
+        self.data.extend_from_slice(value);
+        self
+    }
+
+    /// Append raw bytes, e.g. a truncated TLV header.
+    pub fn bytes(mut self, bytes: &[u8]) -> Self {
+        self.data.extend_from_slice(bytes);
+        self
+    }
+
+    /// Return the account data.
+    pub fn build(self) -> Vec<u8> {
+        self.data
+    }
+}
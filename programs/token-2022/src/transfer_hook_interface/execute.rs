@@ -0,0 +1,127 @@
+use core::{mem::MaybeUninit, slice};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::MAX_CPI_ACCOUNTS,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{cpi::slice_invoke_signed, write_bytes, UNINIT_BYTE};
+
+/// `Execute` discriminator, the first 8 bytes of
+/// `sha256("spl-transfer-hook-interface:execute")`.
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+
+/// The number of accounts of `Execute` preceding the additional accounts.
+pub const EXECUTE_ACCOUNTS_LEN: usize = 5;
+
+/// The maximum number of additional accounts, the 5 accounts of `Execute` are
+/// also passed to the CPI.
+pub const MAX_ADDITIONAL_ACCOUNTS: usize = MAX_CPI_ACCOUNTS - EXECUTE_ACCOUNTS_LEN;
+
+/// Execute the transfer hook.
+///
+/// ### Accounts:
+///   0. `[]` Source account
+///   1. `[]` Token mint
+///   2. `[]` Destination account
+///   3. `[]` Source account's owner/delegate
+///   4. `[]` Validation account (extra account metas)
+///   5. `..+M` `[?]` M additional accounts, written in the validation account
+pub struct Execute<'a, 'b> {
+    /// Source account.
+    pub source: &'a AccountInfo,
+    /// Token mint.
+    pub mint: &'a AccountInfo,
+    /// Destination account.
+    pub destination: &'a AccountInfo,
+    /// Source account's owner or delegate.
+    pub owner: &'a AccountInfo,
+    /// Validation account storing the extra account metas.
+    pub extra_account_metas: &'a AccountInfo,
+    /// Additional accounts, passed with their own writable and signer flags.
+    pub additional_accounts: &'b [&'a AccountInfo],
+    /// Amount of tokens transferred.
+    pub amount: u64,
+    /// Transfer hook program.
+    pub program_id: &'b Pubkey,
+}
+
+impl Execute<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::<&AccountInfo>::uninit();
+
+        if self.additional_accounts.len() > MAX_ADDITIONAL_ACCOUNTS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let num_accounts = EXECUTE_ACCOUNTS_LEN + self.additional_accounts.len();
+
+        let mut account_metas = [UNINIT_META; MAX_CPI_ACCOUNTS];
+        let mut account_infos = [UNINIT_INFO; MAX_CPI_ACCOUNTS];
+
+        let accounts = [
+            self.source,
+            self.mint,
+            self.destination,
+            self.owner,
+            self.extra_account_metas,
+        ];
+
+        for ((account_meta, account_info), account) in account_metas
+            .iter_mut()
+            .zip(account_infos.iter_mut())
+            .zip(accounts.iter())
+        {
+            account_meta.write(AccountMeta::readonly(account.key()));
+            account_info.write(account);
+        }
+
+        for ((account_meta, account_info), account) in account_metas[EXECUTE_ACCOUNTS_LEN..]
+            .iter_mut()
+            .zip(account_infos[EXECUTE_ACCOUNTS_LEN..].iter_mut())
+            .zip(self.additional_accounts.iter())
+        {
+            account_meta.write(AccountMeta::from(*account));
+            account_info.write(account);
+        }
+
+        let instruction_data = execute_instruction_data(self.amount);
+
+        // SAFETY: the first `num_accounts` metas and infos and all the data are initialized
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: unsafe { slice::from_raw_parts(account_metas.as_ptr() as _, num_accounts) },
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 16) },
+        };
+
+        slice_invoke_signed(
+            &instruction,
+            unsafe { slice::from_raw_parts(account_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
+
+/// Encode the `Execute` instruction data:
+/// - `[0..8]`: instruction discriminator (8 bytes)
+/// - `[8..16]`: amount (8 bytes, u64)
+#[inline(always)]
+pub fn execute_instruction_data(amount: u64) -> [MaybeUninit<u8>; 16] {
+    let mut instruction_data = [UNINIT_BYTE; 16];
+
+    write_bytes(&mut instruction_data, &EXECUTE_DISCRIMINATOR);
+    write_bytes(&mut instruction_data[8..16], &amount.to_le_bytes());
+
+    instruction_data
+}
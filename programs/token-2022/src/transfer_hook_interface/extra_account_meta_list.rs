@@ -0,0 +1,490 @@
+use core::{mem::MaybeUninit, slice};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey, MAX_SEEDS},
+};
+
+use super::{EXECUTE_ACCOUNTS_LEN, EXECUTE_DISCRIMINATOR};
+use crate::{
+    extension::tlv::{get_interface_value_from_bytes, INTERFACE_TLV_HEADER_LEN},
+    pod::{read_pod_slice, Pod, LENGTH_PREFIX_LEN},
+};
+
+/// Length of the TLV header of the validation account: discriminator (8 bytes)
+/// and length (4 bytes, u32).
+pub const EXTRA_ACCOUNT_METAS_TLV_HEADER_LEN: usize = INTERFACE_TLV_HEADER_LEN;
+
+/// Length of the list header: number of metas (4 bytes, u32).
+pub const EXTRA_ACCOUNT_METAS_LIST_HEADER_LEN: usize = LENGTH_PREFIX_LEN;
+
+/// First discriminator of the PDAs derived from another program: `128 + i` is a
+/// PDA of the program at account index `i`.
+pub const EXTERNAL_PDA_DISCRIMINATOR_START: u8 = 1 << 7;
+
+/// Discriminators of the `ExtraAccountMeta` address configs, see also
+/// [`EXTERNAL_PDA_DISCRIMINATOR_START`].
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtraAccountMetaDiscriminator {
+    /// Fixed address
+    Pubkey = 0,
+    /// PDA of the hook program derived from the packed seeds
+    ProgramDerived = 1,
+    /// Address read from the instruction data or from account data
+    PubkeyData = 2,
+}
+
+/// PDA seed packed into the address config of an `ExtraAccountMeta`.
+///
+/// Account indices refer to the `Execute` accounts followed by the extra
+/// accounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Seed<'a> {
+    /// Hard-coded bytes, packed as `[1, length, bytes..]`
+    Literal { bytes: &'a [u8] },
+    /// Slice of the `Execute` instruction data, packed as `[2, index, length]`
+    InstructionData { index: u8, length: u8 },
+    /// Address of an account, packed as `[3, index]`
+    AccountKey { index: u8 },
+    /// Slice of the data of an account, packed as
+    /// `[4, account_index, data_index, length]`
+    AccountData {
+        account_index: u8,
+        data_index: u8,
+        length: u8,
+    },
+}
+
+impl<'a> Seed<'a> {
+    /// Unpack the seed at the start of `bytes` together with the remaining
+    /// bytes, `None` at the end of the seeds.
+    pub fn unpack(bytes: &'a [u8]) -> Result<Option<(Self, &'a [u8])>, ProgramError> {
+        let seed = match bytes {
+            [] | [0, ..] => return Ok(None),
+            [1, length, rest @ ..] if rest.len() >= *length as usize => {
+                let (bytes, rest) = rest.split_at(*length as usize);
+                (Self::Literal { bytes }, rest)
+            }
+            [2, index, length, rest @ ..] => (
+                Self::InstructionData {
+                    index: *index,
+                    length: *length,
+                },
+                rest,
+            ),
+            [3, index, rest @ ..] => (Self::AccountKey { index: *index }, rest),
+            [4, account_index, data_index, length, rest @ ..] => (
+                Self::AccountData {
+                    account_index: *account_index,
+                    data_index: *data_index,
+                    length: *length,
+                },
+                rest,
+            ),
+            _ => Err(ProgramError::InvalidAccountData)?,
+        };
+
+        Ok(Some(seed))
+    }
+
+    /// Length of the packed seed.
+    #[inline]
+    pub fn packed_len(&self) -> usize {
+        match self {
+            Self::Literal { bytes } => 2 + bytes.len(),
+            Self::InstructionData { .. } => 3,
+            Self::AccountKey { .. } => 2,
+            Self::AccountData { .. } => 4,
+        }
+    }
+
+    /// Pack the seed at the start of `buffer`, returning the packed length.
+    pub fn pack_into(&self, buffer: &mut [u8]) -> Result<usize, ProgramError> {
+        let len = self.packed_len();
+        let buffer = buffer
+            .get_mut(..len)
+            .ok_or(ProgramError::MaxSeedLengthExceeded)?;
+
+        match *self {
+            Self::Literal { bytes } => {
+                buffer[0] = 1;
+                buffer[1] = u8::try_from(bytes.len()).map_err(|_| ProgramError::InvalidSeeds)?;
+                buffer[2..].copy_from_slice(bytes);
+            }
+            Self::InstructionData { index, length } => {
+                buffer.copy_from_slice(&[2, index, length]);
+            }
+            Self::AccountKey { index } => buffer.copy_from_slice(&[3, index]),
+            Self::AccountData {
+                account_index,
+                data_index,
+                length,
+            } => buffer.copy_from_slice(&[4, account_index, data_index, length]),
+        }
+
+        Ok(len)
+    }
+}
+
+/// Iterator over the seeds packed into an address config, see
+/// [`ExtraAccountMeta::seeds`].
+#[derive(Clone, Debug)]
+pub struct SeedIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for SeedIter<'a> {
+    type Item = Result<Seed<'a>, ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match Seed::unpack(self.bytes) {
+            Ok(Some((seed, rest))) => {
+                self.bytes = rest;
+                Some(Ok(seed))
+            }
+            Ok(None) => None,
+            Err(error) => {
+                self.bytes = &[];
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// Models the SPL `ExtraAccountMeta`: an extra account required by `Execute`
+/// with the config to resolve its address.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExtraAccountMeta {
+    /// Tells how to resolve the address, see [`ExtraAccountMetaDiscriminator`]
+    pub discriminator: u8,
+    /// Fixed address, packed seeds or the location of the address
+    pub address_config: [u8; 32],
+    /// Whether the account must sign (`PodBool`)
+    pub is_signer: u8,
+    /// Whether the account is writable (`PodBool`)
+    pub is_writable: u8,
+}
+
+// SAFETY: `ExtraAccountMeta` is `#[repr(C)]` with byte fields only
+unsafe impl Pod for ExtraAccountMeta {}
+
+impl ExtraAccountMeta {
+    pub const LEN: usize = core::mem::size_of::<ExtraAccountMeta>();
+
+    /// Meta with a fixed address.
+    #[inline]
+    pub fn new_with_pubkey(address: &Pubkey, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            discriminator: ExtraAccountMetaDiscriminator::Pubkey as u8,
+            address_config: *address,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        }
+    }
+
+    /// Meta of a PDA of the hook program, the packed seeds must fit in 32 bytes.
+    #[inline]
+    pub fn new_with_seeds(
+        seeds: &[Seed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self, ProgramError> {
+        Self::new_pda(
+            ExtraAccountMetaDiscriminator::ProgramDerived as u8,
+            seeds,
+            is_signer,
+            is_writable,
+        )
+    }
+
+    /// Meta of a PDA of the program at `program_index` among the `Execute`
+    /// accounts followed by the extra accounts, the packed seeds must fit in
+    /// 32 bytes.
+    #[inline]
+    pub fn new_external_pda_with_seeds(
+        program_index: u8,
+        seeds: &[Seed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self, ProgramError> {
+        let discriminator = EXTERNAL_PDA_DISCRIMINATOR_START
+            .checked_add(program_index)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        Self::new_pda(discriminator, seeds, is_signer, is_writable)
+    }
+
+    fn new_pda(
+        discriminator: u8,
+        seeds: &[Seed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self, ProgramError> {
+        let mut address_config = [0; 32];
+        let mut offset = 0;
+
+        for seed in seeds {
+            offset += seed.pack_into(&mut address_config[offset..])?;
+        }
+
+        Ok(Self {
+            discriminator,
+            address_config,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        })
+    }
+
+    #[inline(always)]
+    pub fn is_signer(&self) -> bool {
+        self.is_signer != 0
+    }
+
+    #[inline(always)]
+    pub fn is_writable(&self) -> bool {
+        self.is_writable != 0
+    }
+
+    /// Seeds of a PDA, meaningful for program derived metas only.
+    #[inline]
+    pub fn seeds(&self) -> SeedIter<'_> {
+        SeedIter {
+            bytes: &self.address_config,
+        }
+    }
+
+    /// Resolve the address of the account.
+    ///
+    /// `get_account` returns the account at an index of the `Execute` accounts
+    /// followed by the extra accounts resolved before this one, `program_id` is
+    /// the hook program and `instruction_data` is the `Execute` instruction data.
+    pub fn resolve<'b>(
+        &self,
+        instruction_data: &[u8],
+        program_id: &Pubkey,
+        get_account: impl Fn(usize) -> Option<&'b AccountInfo>,
+    ) -> Result<Pubkey, ProgramError> {
+        let get_account =
+            |index: u8| get_account(index as usize).ok_or(ProgramError::NotEnoughAccountKeys);
+
+        match self.discriminator {
+            x if x == ExtraAccountMetaDiscriminator::Pubkey as u8 => Ok(self.address_config),
+            x if x == ExtraAccountMetaDiscriminator::ProgramDerived as u8 => {
+                self.find_program_address(instruction_data, program_id, &get_account)
+            }
+            x if x == ExtraAccountMetaDiscriminator::PubkeyData as u8 => {
+                self.read_pubkey_data(instruction_data, &get_account)
+            }
+            x if x >= EXTERNAL_PDA_DISCRIMINATOR_START => {
+                let program = get_account(x - EXTERNAL_PDA_DISCRIMINATOR_START)?;
+                self.find_program_address(instruction_data, program.key(), &get_account)
+            }
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    fn find_program_address<'b>(
+        &self,
+        instruction_data: &[u8],
+        program_id: &Pubkey,
+        get_account: &impl Fn(u8) -> Result<&'b AccountInfo, ProgramError>,
+    ) -> Result<Pubkey, ProgramError> {
+        let mut seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+        let mut num_seeds = 0;
+
+        for seed in self.seeds() {
+            if num_seeds == MAX_SEEDS {
+                Err(ProgramError::MaxSeedLengthExceeded)?;
+            }
+
+            seeds[num_seeds] = match seed? {
+                Seed::Literal { bytes } => bytes,
+                Seed::InstructionData { index, length } => {
+                    let start = index as usize;
+                    instruction_data
+                        .get(start..start + length as usize)
+                        .ok_or(ProgramError::InvalidInstructionData)?
+                }
+                Seed::AccountKey { index } => get_account(index)?.key(),
+                Seed::AccountData {
+                    account_index,
+                    data_index,
+                    length,
+                } => {
+                    let start = data_index as usize;
+                    // SAFETY: the data is only read while the address is derived
+                    unsafe { get_account(account_index)?.borrow_data_unchecked() }
+                        .get(start..start + length as usize)
+                        .ok_or(ProgramError::InvalidAccountData)?
+                }
+            };
+            num_seeds += 1;
+        }
+
+        try_find_program_address(&seeds[..num_seeds], program_id)
+            .map(|(address, _)| address)
+            .ok_or(ProgramError::InvalidSeeds)
+    }
+
+    fn read_pubkey_data<'b>(
+        &self,
+        instruction_data: &[u8],
+        get_account: &impl Fn(u8) -> Result<&'b AccountInfo, ProgramError>,
+    ) -> Result<Pubkey, ProgramError> {
+        // the config is packed as `[1, index]` for the instruction data and as
+        // `[2, account_index, data_index]` for account data
+        let (bytes, error) = match self.address_config {
+            [1, index, ..] => (
+                instruction_data.get(index as usize..),
+                ProgramError::InvalidInstructionData,
+            ),
+            [2, account_index, data_index, ..] => (
+                // SAFETY: the data is only read while the address is copied
+                unsafe { get_account(account_index)?.borrow_data_unchecked() }
+                    .get(data_index as usize..),
+                ProgramError::InvalidAccountData,
+            ),
+            _ => Err(ProgramError::InvalidAccountData)?,
+        };
+
+        match bytes.and_then(|x| x.first_chunk::<32>()) {
+            Some(address) => Ok(*address),
+            None => Err(error),
+        }
+    }
+}
+
+/// Extra account metas of `Execute` stored in the validation account of a
+/// mint, see [`super::find_extra_account_metas_address`].
+#[derive(Clone, Copy, Debug)]
+pub struct ExtraAccountMetaList<'a> {
+    metas: &'a [ExtraAccountMeta],
+}
+
+impl<'a> ExtraAccountMetaList<'a> {
+    /// Parse the validation account data, a TLV list with the metas stored
+    /// under the `Execute` discriminator.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        get_interface_value_from_bytes(&EXECUTE_DISCRIMINATOR, bytes)
+            .ok_or(ProgramError::InvalidAccountData)
+            .and_then(Self::from_value_bytes)
+    }
+
+    /// Parse the TLV value: the number of metas followed by the metas.
+    fn from_value_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        let (metas, _) = read_pod_slice(bytes)?;
+
+        Ok(Self { metas })
+    }
+
+    #[inline(always)]
+    pub fn metas(&self) -> &'a [ExtraAccountMeta] {
+        self.metas
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.metas.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.metas.is_empty()
+    }
+
+    /// Size of the validation account storing `num_metas` metas.
+    #[inline(always)]
+    pub const fn size_of(num_metas: usize) -> usize {
+        EXTRA_ACCOUNT_METAS_TLV_HEADER_LEN + Self::packed_len(num_metas)
+    }
+
+    /// Length of the packed list: the number of metas followed by the metas.
+    #[inline(always)]
+    pub const fn packed_len(num_metas: usize) -> usize {
+        EXTRA_ACCOUNT_METAS_LIST_HEADER_LEN + num_metas * ExtraAccountMeta::LEN
+    }
+
+    /// Write `metas` as the `Execute` entry at the start of the validation
+    /// account data, used by hook programs to initialize or update the account.
+    ///
+    /// The data must hold at least [`Self::size_of`] bytes, the bytes after the
+    /// entry are left untouched.
+    pub fn write(data: &mut [u8], metas: &[ExtraAccountMeta]) -> Result<(), ProgramError> {
+        let length = Self::packed_len(metas.len());
+        let data = data
+            .get_mut(..EXTRA_ACCOUNT_METAS_TLV_HEADER_LEN + length)
+            .ok_or(ProgramError::AccountDataTooSmall)?;
+
+        data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        data[8..EXTRA_ACCOUNT_METAS_TLV_HEADER_LEN].copy_from_slice(&(length as u32).to_le_bytes());
+        Self::pack_into(&mut data[EXTRA_ACCOUNT_METAS_TLV_HEADER_LEN..], metas)?;
+
+        Ok(())
+    }
+
+    /// Pack the number of metas followed by the metas at the start of `buffer`,
+    /// returning the packed length.
+    pub fn pack_into(buffer: &mut [u8], metas: &[ExtraAccountMeta]) -> Result<usize, ProgramError> {
+        let length = Self::packed_len(metas.len());
+        let buffer = buffer
+            .get_mut(..length)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        // SAFETY: `ExtraAccountMeta` is `#[repr(C)]` with u8 fields only
+        let bytes = unsafe {
+            slice::from_raw_parts(
+                metas.as_ptr() as *const u8,
+                metas.len() * ExtraAccountMeta::LEN,
+            )
+        };
+
+        buffer[..EXTRA_ACCOUNT_METAS_LIST_HEADER_LEN]
+            .copy_from_slice(&(metas.len() as u32).to_le_bytes());
+        buffer[EXTRA_ACCOUNT_METAS_LIST_HEADER_LEN..].copy_from_slice(bytes);
+
+        Ok(length)
+    }
+
+    /// Resolve the extra accounts in order and write them to `extra_accounts`,
+    /// each one is picked from `account_infos` by the resolved address.
+    ///
+    /// `execute_accounts` are the accounts of `Execute`: source, mint,
+    /// destination, owner and the validation account. Returns the number of
+    /// extra accounts.
+    pub fn resolve<'b>(
+        &self,
+        instruction_data: &[u8],
+        program_id: &Pubkey,
+        execute_accounts: &[&'b AccountInfo; EXECUTE_ACCOUNTS_LEN],
+        account_infos: &'b [AccountInfo],
+        extra_accounts: &mut [MaybeUninit<&'b AccountInfo>],
+    ) -> Result<usize, ProgramError> {
+        if self.metas.len() > extra_accounts.len() {
+            Err(ProgramError::InvalidArgument)?;
+        }
+
+        for (i, meta) in self.metas.iter().enumerate() {
+            let resolved = &extra_accounts[..i];
+
+            let address = meta.resolve(instruction_data, program_id, |index| {
+                match index.checked_sub(EXECUTE_ACCOUNTS_LEN) {
+                    None => execute_accounts.get(index).copied(),
+                    // SAFETY: the extra accounts before `i` are written
+                    Some(index) => resolved.get(index).map(|x| unsafe { x.assume_init() }),
+                }
+            })?;
+
+            let account = account_infos
+                .iter()
+                .find(|x| x.key() == &address)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            extra_accounts[i].write(account);
+        }
+
+        Ok(self.metas.len())
+    }
+}
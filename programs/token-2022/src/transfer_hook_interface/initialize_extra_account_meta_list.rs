@@ -0,0 +1,112 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::cpi::invoke_signed;
+
+use super::{ExtraAccountMeta, ExtraAccountMetaList, MAX_ADDITIONAL_ACCOUNTS};
+
+/// `InitializeExtraAccountMetaList` discriminator, the first 8 bytes of
+/// `sha256("spl-transfer-hook-interface:initialize-extra-account-metas")`.
+pub const INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR: [u8; 8] =
+    [43, 34, 13, 49, 167, 88, 235, 235];
+
+/// The maximum length of the `InitializeExtraAccountMetaList` and
+/// `UpdateExtraAccountMetaList` instruction data.
+pub const MAX_EXTRA_ACCOUNT_METAS_INSTRUCTION_DATA_LEN: usize =
+    8 + ExtraAccountMetaList::packed_len(MAX_ADDITIONAL_ACCOUNTS);
+
+/// Initialize the validation account of a mint with the extra account metas
+/// required by `Execute`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Validation account (extra account metas)
+///   1. `[]` Token mint
+///   2. `[SIGNER]` Mint authority
+///   3. `[]` System program
+pub struct InitializeExtraAccountMetaList<'a, 'b> {
+    /// Validation account, see [`super::find_extra_account_metas_address`].
+    pub extra_account_metas: &'a AccountInfo,
+    /// Token mint.
+    pub mint: &'a AccountInfo,
+    /// Mint authority.
+    pub authority: &'a AccountInfo,
+    /// System program.
+    pub system_program: &'a AccountInfo,
+    /// Extra account metas.
+    pub metas: &'b [ExtraAccountMeta],
+    /// Transfer hook program.
+    pub program_id: &'b Pubkey,
+}
+
+impl InitializeExtraAccountMetaList<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.extra_account_metas.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+            AccountMeta::readonly(self.system_program.key()),
+        ];
+
+        let mut buffer = [0u8; MAX_EXTRA_ACCOUNT_METAS_INSTRUCTION_DATA_LEN];
+        let data = initialize_extra_account_meta_list_instruction_data(&mut buffer, self.metas)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.extra_account_metas,
+                self.mint,
+                self.authority,
+                self.system_program,
+            ],
+            signers,
+        )
+    }
+}
+
+/// Encode the `InitializeExtraAccountMetaList` instruction data:
+/// - `[0..8]`: instruction discriminator (8 bytes)
+/// - `[8..12]`: number of metas (4 bytes, u32)
+/// - `[12..]`: metas (35 bytes each)
+pub fn initialize_extra_account_meta_list_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    metas: &[ExtraAccountMeta],
+) -> Result<&'a [u8], ProgramError> {
+    extra_account_metas_instruction_data(
+        buffer,
+        &INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR,
+        metas,
+    )
+}
+
+/// Encode the discriminator followed by the packed metas.
+pub(super) fn extra_account_metas_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    discriminator: &[u8; 8],
+    metas: &[ExtraAccountMeta],
+) -> Result<&'a [u8], ProgramError> {
+    if buffer.len() < 8 {
+        Err(ProgramError::InvalidArgument)?;
+    }
+
+    buffer[..8].copy_from_slice(discriminator);
+    let length = ExtraAccountMetaList::pack_into(&mut buffer[8..], metas)?;
+
+    Ok(&buffer[..8 + length])
+}
@@ -0,0 +1,36 @@
+//! SPL Transfer Hook interface CPI.
+//!
+//! Token-2022 invokes `Execute` on the hook program of a mint with the
+//! `TransferHook` extension during every transfer. Programs calling a hook
+//! program directly (or hook programs forwarding to another one) use the same
+//! instruction.
+//!
+//! The extra accounts required by `Execute` are stored in a validation account
+//! owned by the hook program, see [`ExtraAccountMetaList`].
+
+pub mod execute;
+pub mod extra_account_meta_list;
+pub mod initialize_extra_account_meta_list;
+#[cfg(feature = "transfer-hook")]
+pub mod transfer_checked_with_hook;
+pub mod update_extra_account_meta_list;
+
+pub use {
+    execute::*, extra_account_meta_list::*, initialize_extra_account_meta_list::*,
+    update_extra_account_meta_list::*,
+};
+
+#[cfg(feature = "transfer-hook")]
+pub use transfer_checked_with_hook::*;
+
+use pinocchio::pubkey::{find_program_address, Pubkey};
+
+/// Seed of the account storing the extra account metas of a mint.
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// Returns the address of the extra account metas account of `mint` for the
+/// hook program `program_id` and its bump.
+#[inline]
+pub fn find_extra_account_metas_address(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, mint], program_id)
+}
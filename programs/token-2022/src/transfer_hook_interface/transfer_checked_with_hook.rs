@@ -0,0 +1,213 @@
+use core::{mem::MaybeUninit, slice};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::MAX_CPI_ACCOUNTS,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use super::{
+    execute_instruction_data, find_extra_account_metas_address, ExtraAccountMetaList,
+    EXECUTE_ACCOUNTS_LEN,
+};
+use crate::{
+    cpi::slice_invoke_signed,
+    extension::{tlv::get_extension_from_bytes, transfer_hook::TransferHook},
+    instructions::TransferChecked,
+    write_bytes, UNINIT_BYTE,
+};
+
+/// The number of accounts of `TransferChecked` preceding the extra accounts.
+const TRANSFER_CHECKED_ACCOUNTS_LEN: usize = 4;
+
+/// The maximum number of extra accounts, the accounts of `TransferChecked`, the
+/// hook program and the validation account are also passed to the CPI.
+pub const MAX_TRANSFER_HOOK_EXTRA_ACCOUNTS: usize =
+    MAX_CPI_ACCOUNTS - TRANSFER_CHECKED_ACCOUNTS_LEN - 2;
+
+/// Transfer tokens of a mint with the `TransferHook` extension, forwarding the
+/// accounts Token-2022 needs to invoke `Execute` on the hook program.
+///
+/// The extra accounts are resolved from the validation account of the mint and
+/// picked from `additional_accounts`. Mints without a hook program get a plain
+/// `TransferChecked`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The source account's owner/delegate.
+///   4. `..4+M` `[?]` M extra accounts, written in the validation account
+///   5. `4+M` `[]` Transfer hook program
+///   6. `4+M+1` `[]` Validation account (extra account metas)
+pub struct TransferCheckedWithHook<'a, 'b> {
+    /// Sender account.
+    pub from: &'a AccountInfo,
+    /// Mint Account
+    pub mint: &'a AccountInfo,
+    /// Recipient account.
+    pub to: &'a AccountInfo,
+    /// Authority account.
+    pub authority: &'a AccountInfo,
+    /// Accounts holding the hook program, the validation account and the extra
+    /// accounts, in any order.
+    pub additional_accounts: &'a [AccountInfo],
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token
+    pub decimals: u8,
+    /// Token Program
+    pub token_program: &'b Pubkey,
+}
+
+impl TransferCheckedWithHook<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        const UNINIT_META: MaybeUninit<AccountMeta> = MaybeUninit::<AccountMeta>::uninit();
+        const UNINIT_INFO: MaybeUninit<&AccountInfo> = MaybeUninit::<&AccountInfo>::uninit();
+
+        let hook_program_id =
+            get_extension_from_bytes::<TransferHook>(&self.mint.try_borrow_data()?)
+                .and_then(|x| x.program_id())
+                .copied();
+
+        let Some(hook_program_id) = hook_program_id else {
+            return TransferChecked {
+                from: self.from,
+                mint: self.mint,
+                to: self.to,
+                authority: self.authority,
+                amount: self.amount,
+                decimals: self.decimals,
+                token_program: self.token_program,
+            }
+            .invoke_signed(signers);
+        };
+
+        #[cfg(feature = "preflight")]
+        crate::guards::assert_cpi_guard_allows(
+            self.from,
+            crate::guards::CpiGuardedInstruction::Transfer {
+                authority: self.authority.key(),
+            },
+        )?;
+
+        let find_account = |address: &Pubkey| {
+            self.additional_accounts
+                .iter()
+                .find(|x| x.key() == address)
+                .ok_or(ProgramError::NotEnoughAccountKeys)
+        };
+
+        let hook_program = find_account(&hook_program_id)?;
+        let (extra_account_metas_address, _) =
+            find_extra_account_metas_address(self.mint.key(), &hook_program_id);
+        let extra_account_metas = find_account(&extra_account_metas_address)?;
+
+        let mut account_metas = [UNINIT_META; MAX_CPI_ACCOUNTS];
+        let mut account_infos = [UNINIT_INFO; MAX_CPI_ACCOUNTS];
+
+        let accounts = [
+            (AccountMeta::writable(self.from.key()), self.from),
+            (AccountMeta::readonly(self.mint.key()), self.mint),
+            (AccountMeta::writable(self.to.key()), self.to),
+            (
+                AccountMeta::readonly_signer(self.authority.key()),
+                self.authority,
+            ),
+        ];
+
+        for ((account_meta, account_info), (meta, account)) in account_metas
+            .iter_mut()
+            .zip(account_infos.iter_mut())
+            .zip(accounts)
+        {
+            account_meta.write(meta);
+            account_info.write(account);
+        }
+
+        let num_extra_accounts = {
+            let data = extra_account_metas.try_borrow_data()?;
+
+            // a hook program without extra accounts may leave the account empty
+            if data.is_empty() {
+                0
+            } else {
+                let list = ExtraAccountMetaList::from_bytes(&data)?;
+                let instruction_data = execute_instruction_data(self.amount);
+                let execute_accounts: [&AccountInfo; EXECUTE_ACCOUNTS_LEN] = [
+                    self.from,
+                    self.mint,
+                    self.to,
+                    self.authority,
+                    extra_account_metas,
+                ];
+
+                let num_extra_accounts = list.resolve(
+                    // SAFETY: all the data is initialized
+                    unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 16) },
+                    &hook_program_id,
+                    &execute_accounts,
+                    self.additional_accounts,
+                    &mut account_infos[TRANSFER_CHECKED_ACCOUNTS_LEN
+                        ..TRANSFER_CHECKED_ACCOUNTS_LEN + MAX_TRANSFER_HOOK_EXTRA_ACCOUNTS],
+                )?;
+
+                for ((account_meta, account_info), meta) in account_metas
+                    [TRANSFER_CHECKED_ACCOUNTS_LEN..]
+                    .iter_mut()
+                    .zip(account_infos[TRANSFER_CHECKED_ACCOUNTS_LEN..].iter())
+                    .zip(list.metas())
+                {
+                    // SAFETY: the first `num_extra_accounts` extra accounts are resolved
+                    let account = unsafe { account_info.assume_init() };
+                    account_meta.write(AccountMeta::new(
+                        account.key(),
+                        meta.is_writable(),
+                        meta.is_signer(),
+                    ));
+                }
+
+                num_extra_accounts
+            }
+        };
+
+        // the hook program and the validation account follow the extra accounts
+        let offset = TRANSFER_CHECKED_ACCOUNTS_LEN + num_extra_accounts;
+        for (i, account) in [hook_program, extra_account_metas].into_iter().enumerate() {
+            account_metas[offset + i].write(AccountMeta::readonly(account.key()));
+            account_infos[offset + i].write(account);
+        }
+        let num_accounts = offset + 2;
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        // -  [9]: decimals (1 byte, u8)
+        let mut instruction_data = [UNINIT_BYTE; 10];
+
+        write_bytes(&mut instruction_data, &[12]);
+        write_bytes(&mut instruction_data[1..9], &self.amount.to_le_bytes());
+        write_bytes(&mut instruction_data[9..], &[self.decimals]);
+
+        // SAFETY: the first `num_accounts` metas and infos and all the data are initialized
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: unsafe { slice::from_raw_parts(account_metas.as_ptr() as _, num_accounts) },
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 10) },
+        };
+
+        slice_invoke_signed(
+            &instruction,
+            unsafe { slice::from_raw_parts(account_infos.as_ptr() as _, num_accounts) },
+            signers,
+        )
+    }
+}
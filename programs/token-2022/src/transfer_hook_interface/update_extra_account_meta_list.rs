@@ -0,0 +1,84 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::cpi::invoke_signed;
+
+use super::{
+    initialize_extra_account_meta_list::extra_account_metas_instruction_data, ExtraAccountMeta,
+    MAX_EXTRA_ACCOUNT_METAS_INSTRUCTION_DATA_LEN,
+};
+
+/// `UpdateExtraAccountMetaList` discriminator, the first 8 bytes of
+/// `sha256("spl-transfer-hook-interface:update-extra-account-metas")`.
+pub const UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR: [u8; 8] =
+    [157, 105, 42, 146, 102, 85, 241, 174];
+
+/// Replace the extra account metas stored in the validation account of a mint.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Validation account (extra account metas)
+///   1. `[]` Token mint
+///   2. `[SIGNER]` Mint authority
+pub struct UpdateExtraAccountMetaList<'a, 'b> {
+    /// Validation account, see [`super::find_extra_account_metas_address`].
+    pub extra_account_metas: &'a AccountInfo,
+    /// Token mint.
+    pub mint: &'a AccountInfo,
+    /// Mint authority.
+    pub authority: &'a AccountInfo,
+    /// New extra account metas.
+    pub metas: &'b [ExtraAccountMeta],
+    /// Transfer hook program.
+    pub program_id: &'b Pubkey,
+}
+
+impl UpdateExtraAccountMetaList<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.extra_account_metas.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        let mut buffer = [0u8; MAX_EXTRA_ACCOUNT_METAS_INSTRUCTION_DATA_LEN];
+        let data = update_extra_account_meta_list_instruction_data(&mut buffer, self.metas)?;
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &account_metas,
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.extra_account_metas, self.mint, self.authority],
+            signers,
+        )
+    }
+}
+
+/// Encode the `UpdateExtraAccountMetaList` instruction data:
+/// - `[0..8]`: instruction discriminator (8 bytes)
+/// - `[8..12]`: number of metas (4 bytes, u32)
+/// - `[12..]`: metas (35 bytes each)
+pub fn update_extra_account_meta_list_instruction_data<'a>(
+    buffer: &'a mut [u8],
+    metas: &[ExtraAccountMeta],
+) -> Result<&'a [u8], ProgramError> {
+    extra_account_metas_instruction_data(
+        buffer,
+        &UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR,
+        metas,
+    )
+}
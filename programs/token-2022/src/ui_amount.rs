@@ -0,0 +1,111 @@
+//! UI representation of token amounts, without CPI nor heap allocation.
+//!
+//! The conversions match `AmountToUiAmount` and `UiAmountToAmount` of
+//! Token-2022 for mints without the `InterestBearingConfig` or
+//! `ScaledUiAmount` extensions, whose UI amount also depends on the clock or
+//! on the multiplier of the mint.
+
+use pinocchio::program_error::ProgramError;
+
+/// Length of the longest UI amount, `0.` followed by 255 decimals.
+pub const MAX_UI_AMOUNT_LEN: usize = u8::MAX as usize + 2;
+
+/// Write the UI representation of `amount` for a mint with `decimals` into
+/// `buf` and return it, e.g. `1.5` for `1_500_000` with 6 decimals.
+///
+/// Like Token-2022, trailing zeros of the decimals and an unneeded decimal
+/// point are trimmed.
+pub fn format_ui_amount(amount: u64, decimals: u8, buf: &mut [u8; MAX_UI_AMOUNT_LEN]) -> &str {
+    let mut amount = amount;
+    let mut start = buf.len();
+
+    // Digits are written from the end of the buffer: the decimals, then the
+    // integer part, which has at least one digit
+    for _ in 0..decimals {
+        start -= 1;
+        buf[start] = b'0' + (amount % 10) as u8;
+        amount /= 10;
+    }
+
+    if decimals > 0 {
+        start -= 1;
+        buf[start] = b'.';
+    }
+
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (amount % 10) as u8;
+        amount /= 10;
+
+        if amount == 0 {
+            break;
+        }
+    }
+
+    let mut end = buf.len();
+
+    if decimals > 0 {
+        while buf[end - 1] == b'0' {
+            end -= 1;
+        }
+
+        if buf[end - 1] == b'.' {
+            end -= 1;
+        }
+    }
+
+    // SAFETY: only ASCII digits and `.` were written
+    unsafe { core::str::from_utf8_unchecked(&buf[start..end]) }
+}
+
+/// Return the raw amount of the UI amount `ui_amount` for a mint with
+/// `decimals`, e.g. `1_500_000` for `1.5` with 6 decimals.
+///
+/// Like Token-2022, fails with `InvalidArgument` if `ui_amount` isn't a
+/// decimal number, has more significant decimals than `decimals`, or if the
+/// raw amount overflows a `u64`.
+pub fn parse_ui_amount(ui_amount: &str, decimals: u8) -> Result<u64, ProgramError> {
+    let decimals = decimals as usize;
+    let mut parts = ui_amount.split('.');
+    // Splitting a string always yields at least one part
+    let integer = parts.next().unwrap_or_default();
+    let fraction = parts.next().unwrap_or_default().trim_end_matches('0');
+
+    if (integer.is_empty() && fraction.is_empty())
+        || parts.next().is_some()
+        || fraction.len() > decimals
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Token-2022 parses the integer part followed by the decimals padded with
+    // zeros with `u64::from_str`, which accepts a leading `+` followed by at
+    // least one digit
+    let mut digits = integer.bytes().chain(fraction.bytes()).peekable();
+
+    if digits.next_if_eq(&b'+').is_some() && integer.len() + decimals == 1 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut amount: u64 = 0;
+
+    for digit in digits {
+        if !digit.is_ascii_digit() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        amount = amount
+            .checked_mul(10)
+            .and_then(|amount| amount.checked_add(u64::from(digit - b'0')))
+            .ok_or(ProgramError::InvalidArgument)?;
+    }
+
+    // Pad the decimals with zeros
+    for _ in fraction.len()..decimals {
+        amount = amount
+            .checked_mul(10)
+            .ok_or(ProgramError::InvalidArgument)?;
+    }
+
+    Ok(amount)
+}